@@ -18,6 +18,7 @@ use std::fmt;
 use std::result;
 
 use hyper;
+use rustc_serialize::json;
 use url;
 
 use hab_core;
@@ -30,6 +31,7 @@ pub enum Error {
     HabitatHttpClient(hab_http::Error),
     HyperError(hyper::error::Error),
     IO(io::Error),
+    JsonDecode(json::DecoderError),
     NoFilePart,
     NoXFilename,
     UrlParseError(url::ParseError),
@@ -47,6 +49,7 @@ impl fmt::Display for Error {
             Error::HabitatHttpClient(ref e) => format!("{}", e),
             Error::HyperError(ref err) => format!("{}", err),
             Error::IO(ref e) => format!("{}", e),
+            Error::JsonDecode(ref e) => format!("Invalid response from a Depot: {}", e),
             Error::NoFilePart => {
                 format!("An invalid path was passed - we needed a filename, and this path does \
                          not have one")
@@ -71,6 +74,7 @@ impl error::Error for Error {
             Error::HabitatHttpClient(ref err) => err.description(),
             Error::HyperError(ref err) => err.description(),
             Error::IO(ref err) => err.description(),
+            Error::JsonDecode(_) => "Invalid response from a Depot",
             Error::NoFilePart => {
                 "An invalid path was passed - we needed a filename, and this path does not have one"
             }
@@ -107,6 +111,12 @@ impl From<io::Error> for Error {
     }
 }
 
+impl From<json::DecoderError> for Error {
+    fn from(err: json::DecoderError) -> Error {
+        Error::JsonDecode(err)
+    }
+}
+
 impl From<url::ParseError> for Error {
     fn from(err: url::ParseError) -> Error {
         Error::UrlParseError(err)
@@ -46,6 +46,7 @@ use tee::TeeReader;
 
 header! { (XFileName, "X-Filename") => [String] }
 header! { (ETag, "ETag") => [String] }
+header! { (PageRange, "Range") => [usize] }
 
 pub trait DisplayProgress: Write {
     fn size(&mut self, size: u64);
@@ -281,6 +282,42 @@ impl Client {
         }
     }
 
+    /// Returns a vector of every PackageIdent in the remote Depot matching the given, possibly
+    /// partial, package identifier. Pages through the results until the Depot has returned all
+    /// of them.
+    ///
+    /// # Failures
+    ///
+    /// * Remote depot unavailable
+    pub fn list_packages<I: Identifiable>(&self,
+                                          ident: &I)
+                                          -> Result<Vec<hab_core::package::PackageIdent>> {
+        let mut packages = Vec::new();
+        let mut range = 0;
+        loop {
+            let mut res = try!(self.inner
+                .get(&format!("pkgs/{}", ident))
+                .header(PageRange(range))
+                .send());
+            match res.status {
+                StatusCode::Ok |
+                StatusCode::PartialContent => {
+                    let mut encoded = String::new();
+                    try!(res.read_to_string(&mut encoded));
+                    let mut page: Vec<hab_core::package::PackageIdent> =
+                        try!(json::decode(&encoded));
+                    let count = page.len();
+                    packages.append(&mut page);
+                    if res.status == StatusCode::Ok || count == 0 {
+                        return Ok(packages);
+                    }
+                    range = packages.len() + 1;
+                }
+                _ => return Err(err_from_response(res)),
+            }
+        }
+    }
+
     /// Returns a vector of PackageIdent structs
     ///
     /// # Failures
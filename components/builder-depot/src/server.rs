@@ -609,8 +609,14 @@ fn list_packages(req: &mut Request) -> IronResult<Response> {
     if let Some(channel) = params.find("channel") {
         match depot.datastore.channels.channel_pkg_idx.all(channel, &ident) {
             Ok(packages) => {
-                let count = depot.datastore.packages.index.count(&ident).unwrap();
-                let body = json::encode(&packages).unwrap();
+                // We read the channel's index whole, so we page through it here, and count
+                // only the packages in the channel.
+                let count = packages.len();
+                let page: Vec<_> = packages.into_iter()
+                    .skip(offset as usize)
+                    .take((num - offset + 1) as usize)
+                    .collect();
+                let body = json::encode(&page).unwrap();
                 let next_range = num + 1;
                 let mut response = if count as isize >= next_range {
                     let mut response = Response::with((status::PartialContent, body));
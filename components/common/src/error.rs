@@ -21,6 +21,7 @@ use std::string;
 
 use depot_client;
 use hcore;
+use protobuf;
 use rustc_serialize::json;
 
 pub type Result<T> = result::Result<T, Error>;
@@ -30,7 +31,10 @@ pub enum Error {
     ArtifactIdentMismatch((String, String, String)),
     CantUploadGossipToml,
     CryptoKeyError(String),
+    GossipFileBadSignature(String),
     GossipFileRelativePath(String),
+    GossipFileUnsigned,
+    GossipFileUntrusted(String),
     DepotClient(depot_client::Error),
     FileNameError,
     HabitatCore(hcore::Error),
@@ -39,6 +43,7 @@ pub enum Error {
     IO(io::Error),
     JsonDecode(json::DecoderError),
     JsonEncode(json::EncoderError),
+    Protobuf(protobuf::ProtobufError),
     StrFromUtf8Error(str::Utf8Error),
     StringFromUtf8Error(string::FromUtf8Error),
     WireDecode(String),
@@ -57,10 +62,17 @@ impl fmt::Display for Error {
                 format!("Can't upload gossip.toml, it's a reserved file name")
             }
             Error::CryptoKeyError(ref s) => format!("Missing or invalid key: {}", s),
+            Error::GossipFileBadSignature(ref s) => {
+                format!("Gossip file signature is not valid: {}", s)
+            }
             Error::GossipFileRelativePath(ref s) => {
                 format!("Path for gossip file cannot have relative components (eg: ..): {}",
                         s)
             }
+            Error::GossipFileUnsigned => format!("Gossip file is not signed by a user key"),
+            Error::GossipFileUntrusted(ref s) => {
+                format!("Gossip file is signed by {}, which is not a trusted user key", s)
+            }
             Error::DepotClient(ref err) => format!("{}", err),
             Error::FileNameError => format!("Failed to extract a filename"),
             Error::HabitatCore(ref e) => format!("{}", e),
@@ -68,6 +80,7 @@ impl fmt::Display for Error {
             Error::IO(ref err) => format!("{}", err),
             Error::JsonDecode(ref e) => format!("JSON decoding error: {}", e),
             Error::JsonEncode(ref e) => format!("JSON encoding error: {}", e),
+            Error::Protobuf(ref e) => format!("{}", e),
             Error::StrFromUtf8Error(ref e) => format!("{}", e),
             Error::StringFromUtf8Error(ref e) => format!("{}", e),
             Error::WireDecode(ref m) => format!("Failed to decode wire message: {}", m),
@@ -84,9 +97,12 @@ impl error::Error for Error {
             }
             Error::CantUploadGossipToml => "Can't upload gossip.toml, it's a reserved filename",
             Error::CryptoKeyError(_) => "Missing or invalid key",
+            Error::GossipFileBadSignature(_) => "Gossip file signature is not valid",
             Error::GossipFileRelativePath(_) => {
                 "Path for gossip file cannot have relative components (eg: ..)"
             }
+            Error::GossipFileUnsigned => "Gossip file is not signed by a user key",
+            Error::GossipFileUntrusted(_) => "Gossip file is not signed by a trusted user key",
             Error::DepotClient(ref err) => err.description(),
             Error::FileNameError => "Failed to extract a filename from a path",
             Error::HabitatCore(ref err) => err.description(),
@@ -94,6 +110,7 @@ impl error::Error for Error {
             Error::IO(ref err) => err.description(),
             Error::JsonDecode(_) => "JSON decoding error: {:?}",
             Error::JsonEncode(_) => "JSON encoding error",
+            Error::Protobuf(ref err) => err.description(),
             Error::StrFromUtf8Error(_) => "Failed to convert a string as UTF-8",
             Error::StringFromUtf8Error(_) => "Failed to convert a string as UTF-8",
             Error::WireDecode(_) => "Failed to decode wire message",
@@ -131,6 +148,12 @@ impl From<json::EncoderError> for Error {
     }
 }
 
+impl From<protobuf::ProtobufError> for Error {
+    fn from(err: protobuf::ProtobufError) -> Self {
+        Error::Protobuf(err)
+    }
+}
+
 impl From<str::Utf8Error> for Error {
     fn from(err: str::Utf8Error) -> Self {
         Error::StrFromUtf8Error(err)
@@ -13,21 +13,25 @@
 // limitations under the License.
 
 use std;
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::fmt;
 use std::fs::File;
 use std::io::prelude::*;
 use std::path::{Path, PathBuf};
-use std::str::FromStr;
+use std::str::{self, FromStr};
 
-use hcore::crypto::{BoxKeyPair, default_cache_key_path};
+use hcore::crypto::{BoxKeyPair, SigKeyPair, default_cache_key_path};
+use hcore::crypto::keys::parse_name_with_rev;
 use hcore::fs;
 use hcore::service::ServiceGroup;
 use hcore::util::perm::{set_owner, set_permissions};
 use openssl::crypto::hash as openssl_hash;
+use protobuf::Message;
 use rustc_serialize::{Encodable, Encoder};
+use rustc_serialize::base64::{FromBase64, STANDARD, ToBase64};
 use rustc_serialize::hex::ToHex;
-use time::{SteadyTime, Duration};
+use sup_protocol::gossip as proto;
+use time::{self, SteadyTime, Duration, Tm};
 use toml;
 use error::{Error, Result};
 
@@ -37,6 +41,9 @@ pub const GOSSIP_TOML: &'static str = "gossip.toml";
 
 pub const UPLOADED_FILE_PERMISSIONS: u32 = 0o770;
 
+/// How many versions of each service group's `gossip.toml` we remember.
+pub const CONFIG_HISTORY_SIZE: usize = 10;
+
 /// The gossip file struct.
 #[derive(Clone, Debug, Eq, RustcDecodable, RustcEncodable)]
 pub struct GossipFile {
@@ -47,6 +54,9 @@ pub struct GossipFile {
     version_number: u64,
     written: bool,
     encrypted: bool,
+    // The signer's key name with revision, a newline, then the base64 of the signed content
+    // signed with the user's signing key
+    signature: Option<Vec<u8>>,
 }
 
 impl GossipFile {
@@ -81,6 +91,7 @@ impl GossipFile {
             version_number: version_number,
             written: false,
             encrypted: false,
+            signature: None,
         };
         Ok(cf)
     }
@@ -122,6 +133,7 @@ impl GossipFile {
             version_number: version_number,
             written: false,
             encrypted: true,
+            signature: None,
         };
         Ok(cf)
     }
@@ -146,10 +158,86 @@ impl GossipFile {
             version_number: version_number,
             written: false,
             encrypted: false,
+            signature: None,
         };
         Ok(cf)
     }
 
+    /// Sign the file with a user's signing key. Supervisors holding the user's public signing key
+    /// can then check who sent the file.
+    pub fn sign(&mut self, user_pair: &SigKeyPair) -> Result<()> {
+        let signed = try!(user_pair.sign(self.signed_content().as_bytes()));
+        let signature = format!("{}\n{}", user_pair.name_with_rev(), signed.to_base64(STANDARD));
+        self.signature = Some(signature.into_bytes());
+        Ok(())
+    }
+
+    /// Check the file is signed by one of the `trusted` users, given as user names, signing key
+    /// names (any revision) or signing key names with revisions. Returns the name with revision
+    /// of the signer's key.
+    ///
+    /// # Failures
+    ///
+    /// * The file is not signed
+    /// * The signer is not trusted
+    /// * The signer's public signing key is not in the cache
+    /// * The signature does not verify with that key, or was made for other content
+    pub fn verify<P: AsRef<Path> + ?Sized>(&self,
+                                           trusted: &[String],
+                                           cache_key_path: &P)
+                                           -> Result<String> {
+        let signature = match self.signature {
+            Some(ref signature) => signature,
+            None => return Err(Error::GossipFileUnsigned),
+        };
+        let mut lines = match str::from_utf8(signature) {
+            Ok(signature) => signature.lines(),
+            Err(_) => {
+                return Err(Error::GossipFileBadSignature("the signature is not UTF-8".to_string()))
+            }
+        };
+        let (signer, signed) = match (lines.next(), lines.next()) {
+            (Some(signer), Some(signed)) => (signer.to_string(), signed),
+            _ => {
+                return Err(Error::GossipFileBadSignature("can't read the signer's key name"
+                    .to_string()))
+            }
+        };
+        let (signer_name, _) = try!(parse_name_with_rev(&signer));
+        let signer_user = SigKeyPair::user_for_key_name(&signer_name);
+        if !trusted.iter()
+            .any(|t| t == &signer || t == &signer_name || Some(t.as_str()) == signer_user) {
+            return Err(Error::GossipFileUntrusted(signer));
+        }
+        let signed = match signed.from_base64() {
+            Ok(signed) => signed,
+            Err(e) => return Err(Error::GossipFileBadSignature(e.to_string())),
+        };
+        let pair = match SigKeyPair::get_pair_for(&signer, cache_key_path) {
+            Ok(pair) => pair,
+            Err(e) => return Err(Error::GossipFileBadSignature(e.to_string())),
+        };
+        let content = match pair.verify(&signed) {
+            Ok(content) => content,
+            Err(e) => return Err(Error::GossipFileBadSignature(e.to_string())),
+        };
+        if content != self.signed_content().into_bytes() {
+            return Err(Error::GossipFileBadSignature(format!("{} signed different content",
+                                                             signer)));
+        }
+        Ok(signer)
+    }
+
+    // Everything that decides where, and whether, the file is applied; signing only the body
+    // would let an old signed file be replayed with a higher version number.
+    fn signed_content(&self) -> String {
+        format!("{}\n{}\n{}\n{}",
+                self.service_group,
+                self.file_name,
+                self.version_number,
+                self.checksum)
+    }
+
     /// Updates this struct against another `GossipFile`. If true is returned, we have changed the
     /// gossip file and the rumour should stay hot. If false is returned, nothing has changed and
     /// the rumour can start to go cold. The algorithm is as follows:
@@ -179,6 +267,14 @@ impl GossipFile {
         }
     }
 
+    pub fn version_number(&self) -> u64 {
+        self.version_number
+    }
+
+    pub fn is_encrypted(&self) -> bool {
+        self.encrypted
+    }
+
     pub fn on_disk_path(&self) -> PathBuf {
         if &self.file_name == GOSSIP_TOML {
             fs::svc_path(&self.service_group.service).join(&self.file_name)
@@ -239,6 +335,48 @@ impl GossipFile {
             Ok(true)
         }
     }
+
+    /// The file as the `GossipFile` message of the supervisor's gossip wire format. Whether we
+    /// have written the file out is not sent.
+    pub fn to_proto(&self) -> proto::GossipFile {
+        let mut msg = proto::GossipFile::new();
+        msg.set_service_group(self.service_group.to_string());
+        msg.set_file_name(self.file_name.clone());
+        msg.set_body(self.body.clone());
+        msg.set_checksum(self.checksum.clone());
+        msg.set_version_number(self.version_number);
+        msg.set_encrypted(self.encrypted);
+        if let Some(ref signature) = self.signature {
+            msg.set_signature(signature.clone());
+        }
+        msg
+    }
+
+    /// The file a `GossipFile` message of the supervisor's gossip wire format describes.
+    ///
+    /// # Errors
+    ///
+    /// * A required field is missing, or the service group is not valid
+    pub fn from_proto(mut msg: proto::GossipFile) -> Result<GossipFile> {
+        if !msg.is_initialized() {
+            return Err(Error::WireDecode("GossipFile is missing a required field".to_string()));
+        }
+        let signature = if msg.has_signature() {
+            Some(msg.take_signature())
+        } else {
+            None
+        };
+        Ok(GossipFile {
+            service_group: try!(ServiceGroup::from_str(msg.get_service_group())),
+            file_name: msg.take_file_name(),
+            body: msg.take_body(),
+            checksum: msg.take_checksum(),
+            version_number: msg.get_version_number(),
+            written: false,
+            encrypted: msg.get_encrypted(),
+            signature: signature,
+        })
+    }
 }
 
 impl fmt::Display for GossipFile {
@@ -325,6 +463,101 @@ impl Encodable for FileWriteRetry {
     }
 }
 
+/// A version of a service group's `gossip.toml`, as we received it. We keep no body for an
+/// encrypted version, so its contents are never served by the sidecar.
+#[derive(Clone, Debug)]
+pub struct ConfigVersion {
+    pub version_number: u64,
+    pub checksum: String,
+    pub body: String,
+    pub encrypted: bool,
+    pub received_at: Tm,
+}
+
+impl ConfigVersion {
+    fn from_gossip_file(gf: &GossipFile) -> ConfigVersion {
+        let body = if gf.encrypted {
+            String::new()
+        } else {
+            String::from_utf8_lossy(&gf.body).into_owned()
+        };
+        ConfigVersion {
+            version_number: gf.version_number,
+            checksum: gf.checksum.clone(),
+            body: body,
+            encrypted: gf.encrypted,
+            received_at: time::now_utc(),
+        }
+    }
+
+    /// A line by line diff from this version to `other`. Lines only in this version start with
+    /// `-`, lines only in `other` with `+`, and lines in both with a space. We cannot diff an
+    /// encrypted version, so we say which one is instead.
+    pub fn diff(&self, other: &ConfigVersion) -> String {
+        let mut diff = format!("--- version {}\n+++ version {}\n",
+                               self.version_number,
+                               other.version_number);
+        match (self.encrypted, other.encrypted) {
+            (false, false) => diff.push_str(&diff_lines(&self.body, &other.body)),
+            (true, _) => diff.push_str(&format!("version {} is encrypted\n", self.version_number)),
+            (_, true) => {
+                diff.push_str(&format!("version {} is encrypted\n", other.version_number))
+            }
+        }
+        diff
+    }
+}
+
+// supply a custom encoder due to the use of Tm
+impl Encodable for ConfigVersion {
+    fn encode<S: Encoder>(&self, s: &mut S) -> std::result::Result<(), S::Error> {
+        try!(s.emit_struct("ConfigVersion", 5, |s| {
+            try!(s.emit_struct_field("version_number", 0, |s| self.version_number.encode(s)));
+            try!(s.emit_struct_field("checksum", 1, |s| self.checksum.encode(s)));
+            try!(s.emit_struct_field("body", 2, |s| self.body.encode(s)));
+            try!(s.emit_struct_field("encrypted", 3, |s| self.encrypted.encode(s)));
+            try!(s.emit_struct_field("received_at",
+                                     4,
+                                     |s| format!("{}", self.received_at.rfc3339()).encode(s)));
+            Ok(())
+        }));
+        Ok(())
+    }
+}
+
+/// Diff two texts line by line, using their longest common subsequence of lines.
+fn diff_lines(old: &str, new: &str) -> String {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+    // lcs[i][j] is the length of the longest common subsequence of old[i..] and new[j..]
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                std::cmp::max(lcs[i + 1][j], lcs[i][j + 1])
+            };
+        }
+    }
+    let mut diff = String::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            diff.push_str(&format!(" {}\n", old[i]));
+            i += 1;
+            j += 1;
+        } else if i < old.len() && (j == new.len() || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            diff.push_str(&format!("-{}\n", old[i]));
+            i += 1;
+        } else {
+            diff.push_str(&format!("+{}\n", new[j]));
+            j += 1;
+        }
+    }
+    diff
+}
+
 #[derive(Debug)]
 pub struct GossipFileList {
     my_service_group: ServiceGroup,
@@ -332,6 +565,10 @@ pub struct GossipFileList {
     gossip_files: HashMap<(ServiceGroup, String), GossipFile>,
     next_idempotency_check: SteadyTime,
     pub file_write_retries: HashMap<String, FileWriteRetry>,
+    // The versions of each service group's gossip.toml we have applied, oldest first
+    config_history: HashMap<ServiceGroup, VecDeque<ConfigVersion>>,
+    // The user keys allowed to change my service group's files; anyone may when empty
+    trusted_users: Vec<String>,
 }
 
 impl GossipFileList {
@@ -342,7 +579,46 @@ impl GossipFileList {
             next_idempotency_check: SteadyTime::now() +
                                     Duration::minutes(IDEMPOTENCY_INTERVAL_MINUTES),
             file_write_retries: HashMap::new(),
+            config_history: HashMap::new(),
+            trusted_users: Vec::new(),
+        }
+    }
+
+    /// Only apply files for my service group that are signed by one of these user keys. With no
+    /// trusted users, files are applied whoever sent them.
+    pub fn set_trusted_users(&mut self, trusted_users: Vec<String>) {
+        self.trusted_users = trusted_users;
+    }
+
+    /// The versions of my service group's `gossip.toml` we have applied, oldest first.
+    pub fn config_history(&self) -> Vec<&ConfigVersion> {
+        match self.config_history.get(&self.my_service_group) {
+            Some(history) => history.iter().collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// The version of my service group's `gossip.toml` with this version number, if we still
+    /// remember it.
+    pub fn config_version(&self, version_number: u64) -> Option<&ConfigVersion> {
+        self.config_history
+            .get(&self.my_service_group)
+            .and_then(|history| history.iter().find(|v| v.version_number == version_number))
+    }
+
+    // Remember a newly applied gossip.toml, forgetting the oldest version if we remember too many.
+    // Encrypted versions are remembered without their contents.
+    fn record_config(&mut self, gf: &GossipFile) {
+        if gf.file_name != GOSSIP_TOML {
+            return;
+        }
+        let history = self.config_history
+            .entry(gf.service_group.clone())
+            .or_insert(VecDeque::with_capacity(CONFIG_HISTORY_SIZE));
+        if history.len() >= CONFIG_HISTORY_SIZE {
+            history.pop_front();
         }
+        history.push_back(ConfigVersion::from_gossip_file(gf));
     }
 
     // We should write out if our metadata tells us to. Also, if we haven't checked the state of
@@ -444,12 +720,43 @@ impl GossipFileList {
     }
 
     pub fn process(&mut self, remote_gf: GossipFile) -> bool {
-        if let Some(mut current_gf) = self.get_mut(&remote_gf.service_group, &remote_gf.file_name) {
-            return current_gf.update_via(remote_gf);
+        let key = (remote_gf.service_group.clone(), remote_gf.file_name.clone());
+        let newer = match self.gossip_files.get(&key) {
+            Some(current_gf) => current_gf.version_number < remote_gf.version_number,
+            None => true,
+        };
+        if newer && !self.is_trusted(&remote_gf) {
+            return false;
+        }
+        let updated = if self.gossip_files.contains_key(&key) {
+            let current_gf = self.get_mut(&remote_gf.service_group, &remote_gf.file_name).unwrap();
+            current_gf.update_via(remote_gf.clone())
+        } else {
+            self.gossip_files.insert(key, remote_gf.clone());
+            true
+        };
+        if updated {
+            self.record_config(&remote_gf);
+        }
+        updated
+    }
+
+    // Whether we may apply the file. Only files for my service group are checked, as they are
+    // the only ones we write out.
+    fn is_trusted(&self, gf: &GossipFile) -> bool {
+        if self.trusted_users.is_empty() || gf.service_group != self.my_service_group {
+            return true;
+        }
+        match gf.verify(&self.trusted_users, &default_cache_key_path(None)) {
+            Ok(signer) => {
+                debug!("{} is signed by {}", gf, signer);
+                true
+            }
+            Err(e) => {
+                warn!("Rejecting {}: {}", gf, e);
+                false
+            }
         }
-        self.gossip_files.insert((remote_gf.service_group.clone(), remote_gf.file_name.clone()),
-                                 remote_gf);
-        true
     }
 
     fn get_mut(&mut self,
@@ -467,13 +774,15 @@ mod test {
     use std::path::PathBuf;
     use std::str::FromStr;
 
+    use protobuf::{self, Message};
     use rustc_serialize::json;
     use tempdir::TempDir;
     use time::SteadyTime;
 
-    use hcore::crypto::BoxKeyPair;
+    use hcore::crypto::{BoxKeyPair, SigKeyPair};
     use hcore::service::ServiceGroup;
-    use gossip_file::{GossipFile, FileWriteRetry, GOSSIP_TOML};
+    use gossip_file::{GossipFile, GossipFileList, FileWriteRetry, CONFIG_HISTORY_SIZE,
+                      GOSSIP_TOML};
 
     fn fixture(name: &str) -> PathBuf {
         PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join(name)
@@ -550,6 +859,22 @@ mod test {
     }
 
 
+    #[test]
+    fn round_trips_through_the_wire_format() {
+        let mut gf = GossipFile::from_body(ServiceGroup::from_str("chromeo.footwork@org")
+                                               .unwrap(),
+                                           "Rage=1\n".as_bytes().to_vec(),
+                                           45)
+            .unwrap();
+        gf.signature = Some(vec![1, 2, 3]);
+        let bytes = gf.to_proto().write_to_bytes().unwrap();
+        let decoded = GossipFile::from_proto(protobuf::parse_from_bytes(&bytes).unwrap()).unwrap();
+        assert_eq!(decoded, gf);
+        assert_eq!(decoded.body, gf.body);
+        assert_eq!(decoded.signature, gf.signature);
+        assert!(!decoded.written);
+    }
+
     #[test]
     #[should_panic]
     fn new_from_body_invalid_toml() {
@@ -644,6 +969,84 @@ mod test {
         assert_eq!(me == other, true);
     }
 
+    #[test]
+    fn sign_and_verify() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let user_pair = SigKeyPair::generate_pair_for_user("testuser", cache.path()).unwrap();
+        let other_pair = SigKeyPair::generate_pair_for_user("mallory", cache.path()).unwrap();
+        let sg = ServiceGroup::from_str("petty.gunslingers@someorg").unwrap();
+        let trusted = vec!["testuser".to_string()];
+
+        let mut gf = GossipFile::from_body(sg.clone(), "woot=1\n".as_bytes().to_vec(), 2)
+            .unwrap();
+        assert!(gf.verify(&trusted, cache.path()).is_err());
+
+        gf.sign(&user_pair).unwrap();
+        assert_eq!(gf.verify(&trusted, cache.path()).unwrap(),
+                   user_pair.name_with_rev());
+        assert!(gf.verify(&vec![user_pair.name_with_rev()], cache.path()).is_ok());
+        assert!(gf.verify(&vec![user_pair.name.clone()], cache.path()).is_ok());
+
+        let mut untrusted = GossipFile::from_body(sg.clone(), "woot=1\n".as_bytes().to_vec(), 2)
+            .unwrap();
+        untrusted.sign(&other_pair).unwrap();
+        assert!(untrusted.verify(&trusted, cache.path()).is_err());
+
+        // a signature can't be moved to a newer version
+        let mut replayed = GossipFile::from_body(sg.clone(), "woot=1\n".as_bytes().to_vec(), 3)
+            .unwrap();
+        replayed.signature = gf.signature.clone();
+        assert!(replayed.verify(&trusted, cache.path()).is_err());
+    }
+
+    #[test]
+    fn config_history_is_bounded() {
+        let sg = ServiceGroup::from_str("heart.barracuda").unwrap();
+        let mut list = GossipFileList::new(sg.clone());
+        for x in 1..CONFIG_HISTORY_SIZE as u64 + 3 {
+            let body = format!("woot={}\n", x);
+            let gf = GossipFile::from_body(sg.clone(), body.into_bytes(), x).unwrap();
+            assert!(list.process(gf));
+        }
+        // older versions are not applied, so are not remembered
+        let old = GossipFile::from_body(sg.clone(), "woot=1\n".as_bytes().to_vec(), 1).unwrap();
+        assert!(!list.process(old));
+        let history = list.config_history();
+        assert_eq!(history.len(), CONFIG_HISTORY_SIZE);
+        assert_eq!(history[0].version_number, 3);
+        assert!(list.config_version(2).is_none());
+        assert_eq!(list.config_version(4).unwrap().body, "woot=4\n");
+    }
+
+    #[test]
+    fn config_version_diff() {
+        let sg = ServiceGroup::from_str("heart.barracuda").unwrap();
+        let mut list = GossipFileList::new(sg.clone());
+        let first = "a=1\nb=2\nc=3\n".as_bytes().to_vec();
+        let second = "a=1\nb=20\nc=3\nd=4\n".as_bytes().to_vec();
+        list.process(GossipFile::from_body(sg.clone(), first, 1).unwrap());
+        list.process(GossipFile::from_body(sg.clone(), second, 2).unwrap());
+        let diff = list.config_version(1).unwrap().diff(list.config_version(2).unwrap());
+        assert_eq!(diff,
+                   "--- version 1\n+++ version 2\n a=1\n-b=2\n+b=20\n c=3\n+d=4\n");
+    }
+
+    #[test]
+    fn encrypted_config_history() {
+        let sg = ServiceGroup::from_str("heart.barracuda").unwrap();
+        let mut list = GossipFileList::new(sg.clone());
+        list.process(GossipFile::from_body(sg.clone(), "a=1\n".as_bytes().to_vec(), 1).unwrap());
+        let body = "password=hunter2\n".as_bytes().to_vec();
+        let mut secret = GossipFile::from_body(sg.clone(), body, 2).unwrap();
+        secret.encrypted = true;
+        list.record_config(&secret);
+        let version = list.config_version(2).unwrap();
+        assert!(version.encrypted);
+        assert_eq!(version.body, "");
+        assert_eq!(list.config_version(1).unwrap().diff(version),
+                   "--- version 1\n+++ version 2\nversion 2 is encrypted\n");
+    }
+
     #[test]
     fn file_write_retry_encode() {
         let fwr = FileWriteRetry::new("foo".to_string(),
@@ -0,0 +1,48 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The frames gossip messages travel in over TCP, to and from a Supervisor's push-pull listener.
+//!
+//! A frame starts with a header: the version of the gossip protocol its message is written in,
+//! then the length of the message in bytes, as a big-endian `u32`. The message follows.
+
+/// The length of a frame's header, in bytes
+pub const HEADER_LEN: usize = 5;
+
+/// The largest message we put in a frame, or accept from one, in bytes
+pub const MAX_FRAME: usize = 16 * 1024 * 1024;
+
+/// The header of a frame holding a message of `len` bytes, written in a version of the gossip
+/// protocol.
+pub fn header(version: u8, len: u32) -> [u8; HEADER_LEN] {
+    [version, (len >> 24) as u8, (len >> 16) as u8, (len >> 8) as u8, len as u8]
+}
+
+/// The version of the gossip protocol, and the length of the message, a frame's header gives.
+pub fn parse_header(header: &[u8; HEADER_LEN]) -> (u8, usize) {
+    let len = ((header[1] as usize) << 24) | ((header[2] as usize) << 16) |
+              ((header[3] as usize) << 8) | (header[4] as usize);
+    (header[0], len)
+}
+
+#[cfg(test)]
+mod test {
+    use gossip_frame::{header, parse_header};
+
+    #[test]
+    fn round_trips_a_header() {
+        assert_eq!(parse_header(&header(2, 70000)), (2, 70000));
+        assert_eq!(header(1, 258), [1, 0, 0, 1, 2]);
+    }
+}
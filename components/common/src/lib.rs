@@ -15,11 +15,13 @@
 extern crate habitat_builder_protocol as protocol;
 extern crate habitat_core as hcore;
 extern crate habitat_depot_client as depot_client;
+extern crate habitat_sup_protocol as sup_protocol;
 extern crate ansi_term;
 #[macro_use]
 extern crate log;
 extern crate openssl;
 extern crate pbr;
+extern crate protobuf;
 extern crate regex;
 extern crate rustc_serialize;
 #[cfg(test)]
@@ -32,6 +34,7 @@ pub use self::error::{Error, Result};
 
 pub mod command;
 pub mod gossip_file;
+pub mod gossip_frame;
 pub mod error;
 pub mod ui;
 pub mod wire_message;
@@ -158,6 +158,11 @@ impl WireMessage {
         })
     }
 
+    /// The name with revision of the key which encrypted this message, if it is encrypted.
+    pub fn key(&self) -> Option<&str> {
+        self.key.as_ref().map(|k| &k[..])
+    }
+
     /// # Examples
     ///
     /// Basic usage:
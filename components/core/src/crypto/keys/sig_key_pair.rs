@@ -28,6 +28,9 @@ use super::{get_key_revisions, mk_key_filename, mk_revision_string, parse_name_w
 use super::super::{PUBLIC_KEY_SUFFIX, PUBLIC_SIG_KEY_VERSION, SECRET_SIG_KEY_SUFFIX,
                    SECRET_SIG_KEY_VERSION, hash};
 
+/// What a user's name is followed by in the name of their signing key
+const USER_SIG_KEY_SUFFIX: &'static str = ".sig";
+
 pub type SigKeyPair = KeyPair<SigPublicKey, SigSecretKey>;
 
 impl SigKeyPair {
@@ -45,6 +48,56 @@ impl SigKeyPair {
                      Some(secret_key)))
     }
 
+    /// Generate a signing key for a user, to sign what they send a ring with. It is named
+    /// `user_key_name(user)`, so its files do not collide with those of the user's box key.
+    pub fn generate_pair_for_user<P: AsRef<Path> + ?Sized>(user: &str,
+                                                           cache_key_path: &P)
+                                                           -> Result<Self> {
+        let revision = try!(mk_revision_string());
+        let name = Self::user_key_name(user);
+        let keyname = Self::mk_key_name(&name, &revision);
+        debug!("new user sig key name = {}", &keyname);
+        let (public_key, secret_key) = try!(Self::generate_pair_files(&keyname,
+                                                                      cache_key_path.as_ref()));
+        Ok(Self::new(name, revision, Some(public_key), Some(secret_key)))
+    }
+
+    /// The name of a user's signing key.
+    pub fn user_key_name(user: &str) -> String {
+        format!("{}{}", user, USER_SIG_KEY_SUFFIX)
+    }
+
+    /// The user a signing key belongs to, if it is a user's.
+    pub fn user_for_key_name(name: &str) -> Option<&str> {
+        if name.ends_with(USER_SIG_KEY_SUFFIX) {
+            Some(&name[..name.len() - USER_SIG_KEY_SUFFIX.len()])
+        } else {
+            None
+        }
+    }
+
+    /// Sign `data` with the secret key. The signed message holds the signature, then `data`.
+    ///
+    /// # Failures
+    ///
+    /// * We do not have the secret key
+    pub fn sign(&self, data: &[u8]) -> Result<Vec<u8>> {
+        Ok(sign::sign(data, try!(self.secret())))
+    }
+
+    /// Check a message `sign` made was signed with the secret key, and return the data it signs.
+    ///
+    /// # Failures
+    ///
+    /// * We do not have the public key
+    /// * The message was not signed with the secret key, or has been changed since
+    pub fn verify(&self, signed: &[u8]) -> Result<Vec<u8>> {
+        match sign::verify(signed, try!(self.public())) {
+            Ok(data) => Ok(data),
+            Err(_) => Err(Error::CryptoError("Verification failed".to_string())),
+        }
+    }
+
     fn mk_key_name(name: &str, revision: &str) -> String {
         format!("{}-{}", name, revision)
     }
@@ -467,6 +520,20 @@ mod test {
         assert!(cache.path().join(format!("{}.sig.key", pair.name_with_rev())).exists());
     }
 
+    #[test]
+    fn generated_user_pair_signs_and_verifies() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let pair = SigKeyPair::generate_pair_for_user("wecoyote", cache.path()).unwrap();
+        let other = SigKeyPair::generate_pair_for_user("roadrunner", cache.path()).unwrap();
+
+        assert_eq!(pair.name, "wecoyote.sig");
+        assert_eq!(SigKeyPair::user_for_key_name(&pair.name), Some("wecoyote"));
+        assert_eq!(SigKeyPair::user_for_key_name("unicorn"), None);
+        let signed = pair.sign(b"meep meep").unwrap();
+        assert_eq!(pair.verify(&signed).unwrap(), b"meep meep".to_vec());
+        assert!(other.verify(&signed).is_err());
+    }
+
     #[test]
     fn get_pairs_for() {
         let cache = TempDir::new("key_cache").unwrap();
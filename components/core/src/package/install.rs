@@ -243,6 +243,50 @@ impl PackageInstall {
         }
     }
 
+    /// Returns the most memory the package's service may use, such as `512M`,
+    /// or None if the package doesn't contain a SVC_MEMORY_MAX Metafile
+    pub fn svc_memory_max(&self) -> Result<Option<String>> {
+        self.read_optional_metafile(MetaFile::SvcMemoryMax)
+    }
+
+    /// Returns how many CPUs' worth of time the package's service may use, such as `0.5`,
+    /// or None if the package doesn't contain a SVC_CPU_MAX Metafile
+    pub fn svc_cpu_max(&self) -> Result<Option<String>> {
+        self.read_optional_metafile(MetaFile::SvcCpuMax)
+    }
+
+    /// Returns how many processes the package's service may run at once,
+    /// or None if the package doesn't contain a SVC_PIDS_MAX Metafile
+    pub fn svc_pids_max(&self) -> Result<Option<String>> {
+        self.read_optional_metafile(MetaFile::SvcPidsMax)
+    }
+
+    /// Returns the signal that asks the package's service to stop, such as `INT`,
+    /// or None if the package doesn't contain a SVC_STOP_SIGNAL Metafile
+    pub fn svc_stop_signal(&self) -> Result<Option<String>> {
+        self.read_optional_metafile(MetaFile::SvcStopSignal)
+    }
+
+    /// Returns how many seconds the package's service may take to stop before it is killed,
+    /// or None if the package doesn't contain a SVC_STOP_TIMEOUT Metafile
+    pub fn svc_stop_timeout(&self) -> Result<Option<String>> {
+        self.read_optional_metafile(MetaFile::SvcStopTimeout)
+    }
+
+    /// Read the contents of a metafile the package may not have.
+    ///
+    /// # Failures
+    ///
+    /// * Contents of the metafile could not be read
+    /// * Contents of the metafile are unreadable or malformed
+    fn read_optional_metafile(&self, file: MetaFile) -> Result<Option<String>> {
+        match self.read_metafile(file) {
+            Ok(body) => Ok(Some(body)),
+            Err(Error::MetaFileNotFound(_)) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
     /// Read the contents of a given metafile.
     ///
     /// # Failures
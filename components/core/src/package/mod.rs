@@ -38,6 +38,11 @@ pub enum MetaFile {
     Path,
     SvcUser,
     SvcGroup,
+    SvcMemoryMax,
+    SvcCpuMax,
+    SvcPidsMax,
+    SvcStopSignal,
+    SvcStopTimeout,
 }
 
 impl fmt::Display for MetaFile {
@@ -55,6 +60,11 @@ impl fmt::Display for MetaFile {
             MetaFile::Path => "PATH",
             MetaFile::SvcUser => "SVC_USER",
             MetaFile::SvcGroup => "SVC_GROUP",
+            MetaFile::SvcMemoryMax => "SVC_MEMORY_MAX",
+            MetaFile::SvcCpuMax => "SVC_CPU_MAX",
+            MetaFile::SvcPidsMax => "SVC_PIDS_MAX",
+            MetaFile::SvcStopSignal => "SVC_STOP_SIGNAL",
+            MetaFile::SvcStopTimeout => "SVC_STOP_TIMEOUT",
         };
         write!(f, "{}", id)
     }
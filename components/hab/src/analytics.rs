@@ -47,6 +47,7 @@
 //! * `pkg upload`
 //! * `cli setup`
 //! * `config apply`
+//! * `config rollback`
 //! * `file upload`
 //! * `origin key generate`
 //! * `pkg build`
@@ -66,6 +67,7 @@
 //! * `pkg upload`
 //! * `cli setup`
 //! * `config apply`
+//! * `config rollback`
 //! * `file upload`
 //! * `install`
 //! * `origin key upload`
@@ -261,6 +263,7 @@ pub fn instrument_subcommand() {
         // potential arguments, options, or flags to that subcommand--these extras will not be
         // reported.
         ("config", "apply", _) |
+        ("config", "rollback", _) |
         ("file", "upload", _) |
         ("pkg", "build", _) |
         ("pkg", "upload", _) |
@@ -475,6 +478,7 @@ fn should_send() -> bool {
            args.next().unwrap_or_default().as_str()) {
         ("apply", _, _) |
         ("config", "apply", _) |
+        ("config", "rollback", _) |
         ("file", "upload", _) |
         ("install", _, _) |
         ("origin", "key", "upload") |
@@ -53,6 +53,25 @@ pub fn get() -> App<'static, 'static> {
             (aliases: &["co", "con", "conf", "confi"])
             (@setting ArgRequiredElseHelp)
             (subcommand: sub_config_apply().aliases(&["a", "ap", "app", "appl"]))
+            (@subcommand rollback =>
+                (about: "Re-applies an earlier configuration to a group of Habitat Supervisors")
+                (aliases: &["r", "ro", "rol", "roll", "rollb", "rollba", "rollbac"])
+                (@arg PEER: -p --peer +takes_value
+                    "A comma-delimited list of one or more Habitat Supervisor peers to infect \
+                    (default: 127.0.0.1:9634)")
+                (@arg RING: -r --ring +takes_value
+                    "Ring key name, which will encrypt communication messages")
+                (@arg SIDECAR: -s --sidecar +takes_value
+                    "The HTTP API address of a Supervisor running the service group, to read \
+                    the configuration history from (default: 127.0.0.1:9631)")
+                (@arg SERVICE_GROUP: +required {valid_service_group}
+                    "Target service group (ex: redis.default)")
+                (@arg VERSION_NUMBER: +required
+                    "The version number of the configuration to re-apply (ex: 41)")
+                (@arg ORG: --org +takes_value "Name of service organization")
+                (@arg USER: --user +takes_value
+                    "Name of the user whose signing key signs the configuration")
+            )
         )
         (@subcommand file =>
             (about: "Commands relating to Habitat files")
@@ -233,7 +252,13 @@ pub fn get() -> App<'static, 'static> {
                     (aliases: &["g", "ge", "gen", "gene", "gener", "genera", "generat"])
                     (@arg RING: +required +takes_value "Ring key name")
                 )
+                (subcommand: sub_ring_key_roll().aliases(&["r", "ro", "rol"]))
             )
+            (subcommand: sub_ring_members().aliases(&["m", "me", "mem", "memb", "membe"]))
+            (subcommand: sub_ring_census().aliases(&["c", "ce", "cen", "cens", "censu"]))
+            (subcommand: sub_ring_elections().aliases(&["el", "ele", "elec", "elect"]))
+            (subcommand: sub_ring_diff().aliases(&["d", "di", "dif"]))
+            (subcommand: sub_ring_inject().aliases(&["in", "inj", "inje", "injec"]))
         )
         (@subcommand service =>
             (about: "Commands relating to Habitat services")
@@ -316,9 +341,76 @@ fn sub_config_apply() -> App<'static, 'static> {
         (@arg FILE: {file_exists_or_stdin}
             "Path to local file on disk (ex: /tmp/config.toml, default: <stdin>)")
         (@arg ORG: --org +takes_value "Name of service organization")
+        (@arg USER: --user +takes_value
+            "Name of the user whose signing key signs the configuration")
     )
 }
 
+fn sub_ring_key_roll() -> App<'static, 'static> {
+    ring_peer_args(clap_app!(@subcommand roll =>
+        (about: "Rolls a ring onto the newest revision of its key, then retires the older \
+            revisions")
+        (@arg RING: +required +takes_value "Ring key name")
+    ))
+}
+
+fn sub_ring_members() -> App<'static, 'static> {
+    ring_observer_args(clap_app!(@subcommand members =>
+        (about: "Lists the members of a ring, as the ring sees them")
+    ))
+}
+
+fn sub_ring_census() -> App<'static, 'static> {
+    ring_observer_args(clap_app!(@subcommand census =>
+        (about: "Lists the census of every service group in a ring")
+    ))
+}
+
+fn sub_ring_elections() -> App<'static, 'static> {
+    ring_observer_args(clap_app!(@subcommand elections =>
+        (about: "Lists the election of every service group in a ring")
+    ))
+}
+
+fn sub_ring_diff() -> App<'static, 'static> {
+    ring_observer_args(clap_app!(@subcommand diff =>
+        (about: "Shows the rumors the members of a ring do not agree on yet")
+    ))
+}
+
+fn sub_ring_inject() -> App<'static, 'static> {
+    ring_observer_args(clap_app!(@subcommand inject =>
+        (about: "Gossips that a member of a ring is suspect, confirmed dead, or has departed")
+        (@arg HEALTH: +required {valid_member_health} "The member's health \
+            (suspect, confirm, or depart)")
+        (@arg MEMBER_ID: +required +takes_value "The member's ID, as `hab ring members` shows it")
+    ))
+}
+
+/// The arguments every `hab ring` subcommand that joins the ring as an observer takes.
+fn ring_observer_args(sub: App<'static, 'static>) -> App<'static, 'static> {
+    ring_peer_args(sub.arg(Arg::with_name("RING")
+        .help("Ring key name, which will encrypt communication messages")
+        .short("r")
+        .long("ring")
+        .takes_value(true)))
+}
+
+/// The arguments that say where to find a ring, and how to gossip with it.
+fn ring_peer_args(sub: App<'static, 'static>) -> App<'static, 'static> {
+    sub.arg(Arg::with_name("PEER")
+            .help("A comma-delimited list of one or more Habitat Supervisor peers to ask \
+                (default: 127.0.0.1:9634)")
+            .short("p")
+            .long("peer")
+            .takes_value(true))
+        .arg(Arg::with_name("GOSSIP_TRANSPORT")
+            .help("The transport the ring gossips over (default: utp)")
+            .long("gossip-transport")
+            .takes_value(true)
+            .possible_values(&["utp", "udp"]))
+}
+
 fn sub_pkg_build() -> App<'static, 'static> {
     let sub = clap_app!(@subcommand build =>
         (about: "Builds a Plan using a Studio")
@@ -377,6 +469,13 @@ fn valid_pair_type(val: String) -> result::Result<(), String> {
     }
 }
 
+fn valid_member_health(val: String) -> result::Result<(), String> {
+    match &val[..] {
+        "suspect" | "confirm" | "depart" => Ok(()),
+        _ => Err(format!("Health: '{}' is not one of suspect, confirm, or depart", &val)),
+    }
+}
+
 fn valid_service_group(val: String) -> result::Result<(), String> {
     let regex = Regex::new(r"([A-Za-z_0-9]+)\.([A-Za-z_0-9]+)").unwrap();
     if regex.is_match(&val) {
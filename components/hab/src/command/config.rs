@@ -18,7 +18,7 @@ pub mod apply {
 
     use common::gossip_file::GossipFile;
     use common::ui::{Status, UI};
-    use hcore::crypto::SymKey;
+    use hcore::crypto::{SigKeyPair, SymKey};
     use hcore::service::ServiceGroup;
 
     use error::Result;
@@ -27,6 +27,7 @@ pub mod apply {
     pub fn start(ui: &mut UI,
                  peers: &Vec<String>,
                  ring_key: Option<&SymKey>,
+                 signer: Option<&SigKeyPair>,
                  sg: &ServiceGroup,
                  number: u64,
                  file_path: Option<&Path>)
@@ -40,6 +41,25 @@ pub mod apply {
                 try!(GossipFile::from_body(sg.clone(), body.into(), number))
             }
         };
+        try!(publish(ui, peers, ring_key, signer, sg, file));
+        try!(ui.end("Applied configuration"));
+        Ok(())
+    }
+
+    /// Gossip a configuration file into the ring through `peers`, signed by the user signing key
+    /// `signer`.
+    pub fn publish(ui: &mut UI,
+                   peers: &Vec<String>,
+                   ring_key: Option<&SymKey>,
+                   signer: Option<&SigKeyPair>,
+                   sg: &ServiceGroup,
+                   mut file: GossipFile)
+                   -> Result<()> {
+        if let Some(user_pair) = signer {
+            try!(ui.status(Status::Signing,
+                           format!("configuration with {}", user_pair.name_with_rev())));
+            try!(file.sign(user_pair));
+        }
         let rumor = hab_gossip::Rumor::gossip_file(file);
 
         let mut list = hab_gossip::RumorList::new();
@@ -54,7 +74,79 @@ pub mod apply {
         try!(ui.status(Status::Applying,
                        format!("configuration for {} into ring via {:?}", &sg, &peers)));
         try!(gossip::send_rumors_to_peers(&peers, ring_key, &list));
-        try!(ui.end("Applied configuration"));
         Ok(())
     }
 }
+
+pub mod rollback {
+    use std::io::Read;
+
+    use common::gossip_file::GossipFile;
+    use common::ui::{Status, UI};
+    use hcore::crypto::{SigKeyPair, SymKey};
+    use hcore::service::ServiceGroup;
+    use hyper::client::Client;
+    use hyper::status::StatusCode;
+    use rustc_serialize::json;
+
+    use error::{Error, Result};
+    use super::apply;
+
+    /// A version of a service group's configuration, from a Supervisor's history.
+    #[derive(Debug, RustcDecodable)]
+    struct ConfigVersion {
+        version_number: u64,
+        body: String,
+        // Supervisors from before encrypted versions were kept in the history do not say
+        encrypted: Option<bool>,
+    }
+
+    /// Re-apply a version of the service group's configuration from the history kept by the
+    /// Supervisor whose sidecar listens at `sidecar`. The version is applied with a version
+    /// number one higher than the latest in the history, so it wins over the current one.
+    pub fn start(ui: &mut UI,
+                 peers: &Vec<String>,
+                 ring_key: Option<&SymKey>,
+                 signer: Option<&SigKeyPair>,
+                 sg: &ServiceGroup,
+                 number: u64,
+                 sidecar: &str)
+                 -> Result<()> {
+        try!(ui.begin(format!("Rolling back configuration for {} to version {}", sg, number)));
+        try!(ui.status(Status::Downloading,
+                       format!("configuration history from {}", sidecar)));
+        let history = try!(history(sidecar, sg));
+        let version = match history.iter().find(|v| v.version_number == number) {
+            Some(version) => version,
+            None => return Err(Error::ConfigVersionNotFound(number)),
+        };
+        if version.encrypted == Some(true) {
+            return Err(Error::ConfigVersionEncrypted(number));
+        }
+        let latest = history.iter().map(|v| v.version_number).max().unwrap_or(number);
+        let file = try!(GossipFile::from_body(sg.clone(),
+                                              version.body.clone().into(),
+                                              latest + 1));
+        try!(ui.status(Status::Applying,
+                       format!("version {} as version {}", number, latest + 1)));
+        try!(apply::publish(ui, peers, ring_key, signer, sg, file));
+        try!(ui.end("Rolled back configuration"));
+        Ok(())
+    }
+
+    fn history(sidecar: &str, sg: &ServiceGroup) -> Result<Vec<ConfigVersion>> {
+        let url = format!("http://{}/services/{}.{}/config/history",
+                          sidecar,
+                          sg.service,
+                          sg.group);
+        debug!("Fetching configuration history from {}", url);
+        let client = Client::new();
+        let mut res = try!(client.get(&url).send());
+        let mut body = String::new();
+        try!(res.read_to_string(&mut body));
+        if res.status != StatusCode::Ok {
+            return Err(Error::HTTP(res.status));
+        }
+        Ok(try!(json::decode(&body)))
+    }
+}
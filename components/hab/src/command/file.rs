@@ -17,7 +17,7 @@ pub mod upload {
 
     use common::gossip_file::GossipFile;
     use common::ui::{Status, UI};
-    use hcore::crypto::{BoxKeyPair, SymKey};
+    use hcore::crypto::{BoxKeyPair, SigKeyPair, SymKey};
 
     use error::Result;
     use gossip::{self, hab_gossip};
@@ -26,13 +26,17 @@ pub mod upload {
                  peers: &Vec<String>,
                  ring_key: Option<&SymKey>,
                  user_pair: &BoxKeyPair,
+                 user_sig_pair: Option<&SigKeyPair>,
                  service_pair: &BoxKeyPair,
                  number: u64,
                  file_path: &Path)
                  -> Result<()> {
         try!(ui.begin(format!("Uploading file {}", &file_path.display())));
-        let file =
+        let mut file =
             try!(GossipFile::from_file_encrypt(&user_pair, &service_pair, file_path, number));
+        if let Some(user_sig_pair) = user_sig_pair {
+            try!(file.sign(user_sig_pair));
+        }
 
         let rumor = hab_gossip::Rumor::gossip_file(file);
         let mut list = hab_gossip::RumorList::new();
@@ -66,4 +66,281 @@ pub mod key {
             Ok(())
         }
     }
+
+    pub mod roll {
+        use std::path::Path;
+        use std::thread;
+        use std::time::Duration;
+
+        use common::ui::{Status, UI};
+        use hcore::crypto::SymKey;
+        use sup_protocol::gossip as proto;
+        use uuid::Uuid;
+
+        use error::{Error, Result};
+        use gossip::observer::{Observer, Survey, TransportKind, View};
+        use gossip::wire::RingKeys;
+
+        /// How many times we survey the ring, a second apart, waiting for every member to send
+        /// with the new revision
+        const ROLL_ATTEMPTS: u32 = 60;
+
+        /// Rolls the ring onto the newest revision of its key in our cache, then retires the
+        /// revisions before it. We only start once every member that answers with the previous
+        /// revision answers with the new one too, and only retire once every member sends with
+        /// it.
+        pub fn start(ui: &mut UI,
+                     kind: TransportKind,
+                     peers: &Vec<String>,
+                     ring: &str,
+                     cache: &Path)
+                     -> Result<()> {
+            let latest = try!(SymKey::get_latest_pair_for(ring, cache));
+            try!(ui.begin(format!("Rolling ring {} onto ring key {}",
+                                  ring,
+                                  latest.name_with_rev())));
+            let keys = try!(RingKeys::load(ring, cache));
+            let observer = try!(Observer::new(kind, keys.clone()));
+            let survey = try!(observer.survey(peers));
+
+            let pairs = try!(SymKey::get_pairs_for(ring, cache));
+            if let Some(previous) = pairs.iter().find(|p| p.rev < latest.rev) {
+                let mut previous_keys = keys.clone();
+                previous_keys.send_with(previous);
+                let previous_survey = try!(try!(Observer::new(kind, previous_keys)).survey(peers));
+                let missing: Vec<String> = previous_survey.views
+                    .iter()
+                    .filter(|pv| !survey.views.iter().any(|v| v.member_id == pv.member_id))
+                    .map(|pv| pv.member_id.clone())
+                    .collect();
+                if !missing.is_empty() {
+                    return Err(Error::RingKeyNotInstalled(latest.name_with_rev(), missing));
+                }
+            }
+            try!(ui.status(Status::Verified,
+                           format!("{} members have ring key {}",
+                                   survey.views.len(),
+                                   latest.name_with_rev())));
+
+            let roll = key_roll(ring, &latest.rev, false);
+            let sent = inject(&observer, &survey, &roll);
+            try!(ui.status(Status::Custom('→', "Rolling".to_string()),
+                           format!("sent the roll to {} members", sent)));
+
+            let mut waiting = Vec::new();
+            for _ in 0..ROLL_ATTEMPTS {
+                thread::sleep(Duration::from_secs(1));
+                let survey = try!(observer.survey(peers));
+                waiting = survey.views
+                    .iter()
+                    .filter(|v| !has_rolled(v, &roll))
+                    .map(|v| v.member_id.clone())
+                    .collect();
+                if waiting.is_empty() {
+                    break;
+                }
+            }
+            if !waiting.is_empty() {
+                return Err(Error::RingKeyRollIncomplete(latest.name_with_rev(), waiting));
+            }
+
+            let retire = key_roll(ring, &latest.rev, true);
+            let survey = try!(observer.survey(peers));
+            let sent = inject(&observer, &survey, &retire);
+            try!(ui.end(format!("Every member sends with ring key {}; told {} members to retire \
+                                 the revisions before it.",
+                                latest.name_with_rev(),
+                                sent)));
+            Ok(())
+        }
+
+        /// A roll of the ring onto a revision of its key.
+        fn key_roll(ring: &str, revision: &str, retire: bool) -> proto::KeyRoll {
+            let mut roll = proto::KeyRoll::new();
+            roll.set_ring(ring.to_string());
+            roll.set_revision(revision.to_string());
+            roll.set_retire(retire);
+            roll
+        }
+
+        /// Sends the roll to every member that answered the survey, and returns how many we sent
+        /// it to.
+        fn inject(observer: &Observer, survey: &Survey, roll: &proto::KeyRoll) -> usize {
+            let mut rumor = proto::Rumor::new();
+            rumor.set_id(Uuid::new_v4().to_string());
+            rumor.set_key_roll(roll.clone());
+            let targets: Vec<String> = survey.alive_members()
+                .iter()
+                .map(|m| m.get_gossip_listener().to_string())
+                .collect();
+            observer.inject(&targets, &[rumor])
+        }
+
+        /// Whether the member took part in the roll, or a newer one. Like a member, we compare
+        /// rolls by revision, then by whether they retire the revisions before.
+        fn has_rolled(view: &View, roll: &proto::KeyRoll) -> bool {
+            view.rumors.iter().filter(|rumor| rumor.has_key_roll()).any(|rumor| {
+                let r = rumor.get_key_roll();
+                r.get_ring() == roll.get_ring() &&
+                (r.get_revision(), r.get_retire()) >= (roll.get_revision(), roll.get_retire())
+            })
+        }
+    }
+}
+
+pub mod members {
+    use error::Result;
+    use gossip::observer::{self, Observer};
+
+    pub fn start(observer: &Observer, peers: &Vec<String>) -> Result<()> {
+        let survey = try!(observer.survey(peers));
+        let mut members: Vec<_> = survey.members.values().collect();
+        members.sort_by(|a, b| {
+            (a.get_hostname(), a.get_id()).cmp(&(b.get_hostname(), b.get_id()))
+        });
+        for m in members {
+            println!("{} {} {} {} incarnation={}{}",
+                     m.get_id(),
+                     m.get_hostname(),
+                     m.get_gossip_listener(),
+                     observer::health(m.get_health()),
+                     m.get_incarnation(),
+                     if m.get_permanent() { " permanent" } else { "" });
+        }
+        Ok(())
+    }
+}
+
+pub mod census {
+    use std::collections::BTreeMap;
+
+    use error::Result;
+    use gossip::observer::{self, Observer};
+
+    pub fn start(observer: &Observer, peers: &Vec<String>) -> Result<()> {
+        let survey = try!(observer.survey(peers));
+        let mut censuses = BTreeMap::new();
+        for ce in survey.census.values() {
+            censuses.entry(observer::service_group(ce.get_service(), ce.get_group()))
+                .or_insert(Vec::new())
+                .push(ce);
+        }
+        for (sg, mut entries) in censuses.into_iter() {
+            entries.sort_by(|a, b| {
+                (a.get_hostname(), a.get_id()).cmp(&(b.get_hostname(), b.get_id()))
+            });
+            println!("{}", sg);
+            for ce in entries {
+                let health = if ce.get_detached() {
+                    "detached"
+                } else if ce.get_confirmed() {
+                    "confirmed"
+                } else if ce.get_suspect() {
+                    "suspect"
+                } else {
+                    "alive"
+                };
+                let process = if ce.get_crash_loop() { " crash-loop" } else { "" };
+                let role = if ce.get_leader() {
+                    " leader"
+                } else if ce.get_follower() {
+                    " follower"
+                } else {
+                    ""
+                };
+                println!("  {} {} {} member={} {}{}{} incarnation={}",
+                         ce.get_id(),
+                         ce.get_hostname(),
+                         ce.get_ip(),
+                         ce.get_member_id(),
+                         health,
+                         process,
+                         role,
+                         ce.get_incarnation());
+            }
+        }
+        Ok(())
+    }
+}
+
+pub mod elections {
+    use error::Result;
+    use gossip::observer::{self, Observer};
+
+    pub fn start(observer: &Observer, peers: &Vec<String>) -> Result<()> {
+        let survey = try!(observer.survey(peers));
+        let mut elections: Vec<_> = survey.elections.iter().collect();
+        elections.sort_by(|a, b| a.0.cmp(b.0));
+        for (sg, e) in elections {
+            println!("{} {} term={} leader={} votes={}",
+                     sg,
+                     observer::status(e.get_status()),
+                     e.get_term(),
+                     e.get_leader_id(),
+                     e.get_votes().len());
+        }
+        Ok(())
+    }
+}
+
+pub mod diff {
+    use common::ui::UI;
+
+    use error::Result;
+    use gossip::observer::Observer;
+
+    pub fn start(ui: &mut UI, observer: &Observer, peers: &Vec<String>) -> Result<()> {
+        let survey = try!(observer.survey(peers));
+        let disagreements = survey.disagreements();
+        for d in disagreements.iter() {
+            println!("{}", d.subject);
+            for &(ref member_id, ref state) in d.states.iter() {
+                println!("  {}: {}",
+                         member_id,
+                         state.as_ref().map(|s| &s[..]).unwrap_or("(not heard of)"));
+            }
+        }
+        if disagreements.is_empty() {
+            try!(ui.end(format!("All {} members agree on every rumor.", survey.views.len())));
+        } else {
+            try!(ui.end(format!("The {} members disagree on {} rumors.",
+                                survey.views.len(),
+                                disagreements.len())));
+        }
+        Ok(())
+    }
+}
+
+pub mod inject {
+    use common::ui::UI;
+    use sup_protocol::gossip as proto;
+    use uuid::Uuid;
+
+    use error::{Error, Result};
+    use gossip::observer::{self, Observer};
+
+    pub fn start(ui: &mut UI,
+                 observer: &Observer,
+                 peers: &Vec<String>,
+                 health: proto::Member_Health,
+                 member_id: &str)
+                 -> Result<()> {
+        let id = try!(Uuid::parse_str(member_id)
+                .map_err(|_| Error::ArgumentError("MEMBER_ID is not a member ID")))
+            .to_string();
+        try!(ui.begin(format!("Gossiping that member {} is {}", id, observer::health(health))));
+        let survey = try!(observer.survey(peers));
+        let mut member = try!(survey.member(&id)).clone();
+        member.set_health(health);
+        let mut rumor = proto::Rumor::new();
+        rumor.set_id(id.clone());
+        rumor.set_member(member);
+        let targets: Vec<String> = survey.alive_members()
+            .iter()
+            .map(|m| m.get_gossip_listener().to_string())
+            .collect();
+        let sent = observer.inject(&targets, &[rumor]);
+        try!(ui.end(format!("Sent the rumor to {} of {} alive members.", sent, targets.len())));
+        Ok(())
+    }
 }
@@ -17,14 +17,17 @@ pub mod key {
         use std::path::Path;
 
         use common::ui::UI;
-        use hcore::crypto::BoxKeyPair;
+        use hcore::crypto::{BoxKeyPair, SigKeyPair};
 
         use error::Result;
 
         pub fn start(ui: &mut UI, user: &str, cache: &Path) -> Result<()> {
             try!(ui.begin(format!("Generating user key for {}", &user)));
             let pair = try!(BoxKeyPair::generate_pair_for_user(user, cache));
-            try!(ui.end(format!("Generated user key pair {}.", &pair.name_with_rev())));
+            let sig_pair = try!(SigKeyPair::generate_pair_for_user(user, cache));
+            try!(ui.end(format!("Generated user key pair {} and signing key pair {}.",
+                                &pair.name_with_rev(),
+                                &sig_pair.name_with_rev())));
             Ok(())
         }
     }
@@ -22,6 +22,9 @@ use std::result;
 use depot_client;
 use common;
 use hcore;
+use hyper;
+use protobuf;
+use rustc_serialize::json;
 
 pub type Result<T> = result::Result<T, Error>;
 
@@ -30,18 +33,30 @@ pub type Result<T> = result::Result<T, Error>;
 pub enum Error {
     ArgumentError(&'static str),
     CommandNotFoundInPkg((String, String)),
+    ConfigVersionEncrypted(u64),
+    ConfigVersionNotFound(u64),
     CryptoCLI(String),
     DepotClient(depot_client::Error),
     ExecCommandNotFound(String),
     FFINulError(ffi::NulError),
     FileNotFound(String),
+    GossipDecode(String),
+    GossipMessageTooLarge(usize, usize),
     HabitatCommon(common::Error),
     HabitatCore(hcore::Error),
+    HTTP(hyper::status::StatusCode),
+    HyperError(hyper::error::Error),
     IO(io::Error),
+    JsonDecode(json::DecoderError),
     PackageArchiveMalformed(String),
     PathPrefixError(path::StripPrefixError),
+    Protobuf(protobuf::ProtobufError),
     ProvidesError(String),
+    RingKeyNotInstalled(String, Vec<String>),
+    RingKeyRollIncomplete(String, Vec<String>),
+    RingUnreachable(String),
     SubcommandNotSupported(String),
+    UnknownMember(String),
     UnsupportedExportFormat(String),
 }
 
@@ -54,6 +69,14 @@ impl fmt::Display for Error {
                         c,
                         p)
             }
+            Error::ConfigVersionEncrypted(ref v) => {
+                format!("Configuration version {} is encrypted, so the Supervisor's history does \
+                         not have its contents",
+                        v)
+            }
+            Error::ConfigVersionNotFound(ref v) => {
+                format!("Configuration version {} is not in the Supervisor's history", v)
+            }
             Error::CryptoCLI(ref e) => format!("{}", e),
             Error::DepotClient(ref err) => format!("{}", err),
             Error::ExecCommandNotFound(ref c) => {
@@ -61,18 +84,44 @@ impl fmt::Display for Error {
             }
             Error::FFINulError(ref e) => format!("{}", e),
             Error::FileNotFound(ref e) => format!("File not found at: {}", e),
+            Error::GossipDecode(ref e) => format!("Cannot read the gossip message: {}", e),
+            Error::GossipMessageTooLarge(ref len, ref max) => {
+                format!("The gossip message is {} bytes; the most we send or take is {}",
+                        len,
+                        max)
+            }
             Error::HabitatCommon(ref e) => format!("{}", e),
             Error::HabitatCore(ref e) => format!("{}", e),
+            Error::HTTP(ref e) => format!("{}", e),
+            Error::HyperError(ref err) => format!("{}", err),
             Error::IO(ref err) => format!("{}", err),
+            Error::JsonDecode(ref e) => format!("JSON decoding error: {}", e),
             Error::PackageArchiveMalformed(ref e) => {
                 format!("Package archive was unreadable or contained unexpected contents: {:?}",
                         e)
             }
             Error::PathPrefixError(ref err) => format!("{}", err),
+            Error::Protobuf(ref e) => format!("{}", e),
             Error::ProvidesError(ref err) => format!("Can't find {}", err),
+            Error::RingKeyNotInstalled(ref key, ref members) => {
+                format!("Members {} cannot read ring key {}; import it on them with `hab ring \
+                         key import` first",
+                        members.join(", "),
+                        key)
+            }
+            Error::RingKeyRollIncomplete(ref key, ref members) => {
+                format!("Members {} do not send with ring key {} yet; the older revisions are \
+                         not retired",
+                        members.join(", "),
+                        key)
+            }
+            Error::RingUnreachable(ref peers) => {
+                format!("No member of the ring answered us at {}", peers)
+            }
             Error::SubcommandNotSupported(ref e) => {
                 format!("Subcommand `{}' not supported on this operating system", e)
             }
+            Error::UnknownMember(ref id) => format!("No member of the ring has the ID {}", id),
             Error::UnsupportedExportFormat(ref e) => format!("Unsupported export format: {}", e),
         };
         write!(f, "{}", msg)
@@ -86,22 +135,34 @@ impl error::Error for Error {
             Error::CommandNotFoundInPkg(_) => {
                 "Command was not found under any 'PATH' directories in the package"
             }
+            Error::ConfigVersionEncrypted(_) => "Configuration version is encrypted",
+            Error::ConfigVersionNotFound(_) => "Configuration version is not in the history",
             Error::CryptoCLI(_) => "A cryptographic error has occurred",
             Error::DepotClient(ref err) => err.description(),
             Error::ExecCommandNotFound(_) => "Exec command was not found on filesystem or in PATH",
             Error::FFINulError(ref err) => err.description(),
             Error::FileNotFound(_) => "File not found",
+            Error::GossipDecode(_) => "Cannot read a gossip message",
+            Error::GossipMessageTooLarge(_, _) => "The gossip message is too large",
             Error::HabitatCommon(ref err) => err.description(),
             Error::HabitatCore(ref err) => err.description(),
+            Error::HTTP(_) => "Received an HTTP error",
+            Error::HyperError(ref err) => err.description(),
             Error::IO(ref err) => err.description(),
+            Error::JsonDecode(_) => "JSON decoding error",
             Error::PackageArchiveMalformed(_) => {
                 "Package archive was unreadable or had unexpected contents"
             }
             Error::PathPrefixError(ref err) => err.description(),
+            Error::Protobuf(ref err) => err.description(),
             Error::ProvidesError(_) => {
                 "Can't find a package that provides the given search parameter"
             }
+            Error::RingKeyNotInstalled(_, _) => "Some members cannot read the new ring key",
+            Error::RingKeyRollIncomplete(_, _) => "Some members did not roll onto the new ring key",
+            Error::RingUnreachable(_) => "No member of the ring answered us",
             Error::SubcommandNotSupported(_) => "Subcommand not supported on this operating system",
+            Error::UnknownMember(_) => "No member of the ring has that ID",
             Error::UnsupportedExportFormat(_) => "Unsupported export format",
         }
     }
@@ -131,6 +192,24 @@ impl From<hcore::Error> for Error {
     }
 }
 
+impl From<hyper::error::Error> for Error {
+    fn from(err: hyper::error::Error) -> Error {
+        Error::HyperError(err)
+    }
+}
+
+impl From<json::DecoderError> for Error {
+    fn from(err: json::DecoderError) -> Error {
+        Error::JsonDecode(err)
+    }
+}
+
+impl From<protobuf::ProtobufError> for Error {
+    fn from(err: protobuf::ProtobufError) -> Error {
+        Error::Protobuf(err)
+    }
+}
+
 impl From<io::Error> for Error {
     fn from(err: io::Error) -> Error {
         Error::IO(err)
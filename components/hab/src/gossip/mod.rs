@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod observer;
+pub mod wire;
+
 use hcore::crypto::SymKey;
 
 use error::Result;
@@ -45,6 +48,15 @@ fn try_peers(peer_listeners: &Vec<String>,
     let mut initialized = false;
     for to in peer_listeners {
         println!("Joining peer: {}", to);
+        // Supervisors gossiping over UDP take rumors too large for a datagram over TCP
+        match hab_gossip::inject_over_tcp(&to[..], ring_key.clone(), rumor_list.clone()) {
+            Ok(()) => {
+                println!("Configuration applied to: {}", to);
+                initialized = true;
+                continue;
+            }
+            Err(e) => debug!("Could not inject over TCP to {}, trying uTP - {:?}", to, e),
+        }
         let mut c = match hab_gossip::Client::new(&to[..], ring_key.clone()) {
             Ok(c) => c,
             Err(e) => {
@@ -76,12 +88,14 @@ pub mod hab_gossip {
     use std::collections::HashMap;
     use std::error;
     use std::fmt;
-    use std::net::ToSocketAddrs;
-    use std::io;
+    use std::net::{TcpStream, ToSocketAddrs};
+    use std::io::{self, Write};
     use std::result;
+    use std::time::Duration;
 
     use common;
     use common::gossip_file::GossipFile;
+    use common::gossip_frame;
     use common::wire_message::WireMessage;
     use hcore::crypto::SymKey;
     use rustc_serialize::json;
@@ -91,6 +105,13 @@ pub mod hab_gossip {
     /// The default port for the Gossip protocol
     pub static GOSSIP_DEFAULT_PORT: usize = 9634;
 
+    /// The version of the gossip protocol we write in TCP frames. Messages are JSON in version 1,
+    /// which supervisors still read.
+    pub const GOSSIP_VERSION: u8 = 1;
+
+    /// How long we wait to write an injection over TCP, in milliseconds
+    pub const TCP_TIMEOUT: u64 = 10000;
+
     pub type Result<T> = result::Result<T, Error>;
 
     #[derive(Debug)]
@@ -171,13 +192,7 @@ pub mod hab_gossip {
         /// * We cannot encode the `Message`
         /// * We fail to send the encoded buffer to the remote
         pub fn send_message(&mut self, msg: Protocol) -> Result<()> {
-            let encoded = {
-                let wire_msg = match self.ring_key.as_ref() {
-                    Some(key) => try!(WireMessage::encrypted(&msg, &key)),
-                    None => try!(WireMessage::plain(&msg)),
-                };
-                try!(json::encode(&wire_msg))
-            };
+            let encoded = try!(encode(&msg, self.ring_key));
             debug!("Encoded message {:#?}", encoded);
             try!(self.socket.send_to(encoded.as_bytes()));
             debug!("Sent protocol: {:?}", msg);
@@ -185,6 +200,38 @@ pub mod hab_gossip {
         }
     }
 
+    /// Inject rumors over the TCP push-pull listener of a Supervisor that gossips over UDP. The
+    /// message is framed with the protocol version and its length.
+    ///
+    /// # Errors
+    ///
+    /// * We cannot connect to the Supervisor over TCP; it likely gossips over uTP
+    /// * We cannot encode or write the message
+    pub fn inject_over_tcp<A: ToSocketAddrs>(dst: A,
+                                             ring_key: Option<&SymKey>,
+                                             rumors_for_remote: RumorList)
+                                             -> Result<()> {
+        let msg = Protocol::Inject(rumors_for_remote);
+        let encoded = try!(encode(&msg, ring_key));
+        let header = gossip_frame::header(GOSSIP_VERSION, encoded.len() as u32);
+        let mut stream = try!(TcpStream::connect(dst));
+        try!(stream.set_write_timeout(Some(Duration::from_millis(TCP_TIMEOUT))));
+        try!(stream.write_all(&header));
+        try!(stream.write_all(encoded.as_bytes()));
+        try!(stream.flush());
+        debug!("Sent protocol over TCP: {:?}", msg);
+        Ok(())
+    }
+
+    /// Encode a message, encrypting it if we have a ring key.
+    fn encode(msg: &Protocol, ring_key: Option<&SymKey>) -> Result<String> {
+        let wire_msg = match ring_key {
+            Some(key) => try!(WireMessage::encrypted(msg, key)),
+            None => try!(WireMessage::plain(msg)),
+        };
+        Ok(try!(json::encode(&wire_msg)))
+    }
+
     /// Each member has a Uuid.
     pub type MemberId = Uuid;
 
@@ -0,0 +1,545 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A short-lived observer of a gossip ring.
+//!
+//! The observer takes part in the ring for as long as it takes to ask every alive member for the
+//! rumors it knows, and to send rumors of its own. It never gossips a `Member` rumor about itself,
+//! so the members never add it to their member lists, and nothing needs to clean up after it.
+//!
+//! Over uTP, the observer pings each member with no rumors. A member acks a member it has never
+//! heard of with every rumor it knows, as they are all hot for it. Over UDP, an ack only carries
+//! what fits in a datagram, so the observer push-pulls over TCP instead.
+//!
+//! The observer speaks version 2 of the gossip protocol, so only Supervisors that speak it too
+//! answer; members that only speak version 1 are left out of the survey.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::hash::Hash;
+use std::str::FromStr;
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use hcore::util::sys;
+use protobuf::ProtobufEnum;
+use sup_protocol::gossip as proto;
+use utp::{UtpListener, UtpSocket};
+use uuid::Uuid;
+
+use error::{Error, Result};
+use gossip::wire::{self, RingKeys, GOSSIP_VERSION};
+
+/// How long we wait for members to ack our pings over uTP, in milliseconds
+pub const ACK_TIMEOUT: u64 = 3000;
+
+/// The most we read from a uTP socket at once
+const BUFFER_SIZE: usize = 10000;
+
+/// The transports a ring can gossip over.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransportKind {
+    Utp,
+    Udp,
+}
+
+impl FromStr for TransportKind {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<TransportKind> {
+        match value {
+            "utp" => Ok(TransportKind::Utp),
+            "udp" => Ok(TransportKind::Udp),
+            _ => Err(Error::ArgumentError("The gossip transport must be utp or udp")),
+        }
+    }
+}
+
+/// What one member told us it knows.
+#[derive(Debug)]
+pub struct View {
+    /// The member that answered us
+    pub member_id: String,
+    /// Every rumor it knows
+    pub rumors: Vec<proto::Rumor>,
+}
+
+/// A subject the members who answered us do not agree on.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Disagreement {
+    /// What the rumors are about, such as `member 3b1c...` or `election redis.default`
+    pub subject: String,
+    /// What each member that answered us knows about the subject, if anything
+    pub states: Vec<(String, Option<String>)>,
+}
+
+/// Everything the ring knows, converged from the views of every member that answered us.
+#[derive(Debug)]
+pub struct Survey {
+    /// Each member's own view, in the order they answered
+    pub views: Vec<View>,
+    pub members: HashMap<String, proto::Member>,
+    pub census: HashMap<String, proto::CensusEntry>,
+    /// The elections, by service group
+    pub elections: HashMap<String, proto::Election>,
+    /// The gossip files, by service group and file name
+    pub gossip_files: HashMap<(String, String), proto::GossipFile>,
+}
+
+impl Survey {
+    /// Converge the views, keeping the newest state of everything the way a member would.
+    pub fn new(views: Vec<View>) -> Survey {
+        let mut survey = Survey {
+            views: Vec::new(),
+            members: HashMap::new(),
+            census: HashMap::new(),
+            elections: HashMap::new(),
+            gossip_files: HashMap::new(),
+        };
+        for view in views.iter() {
+            for rumor in view.rumors.iter() {
+                survey.converge(rumor);
+            }
+        }
+        survey.views = views;
+        survey
+    }
+
+    fn converge(&mut self, rumor: &proto::Rumor) {
+        if rumor.has_member() {
+            let m = rumor.get_member();
+            keep_newest(&mut self.members, m.get_id().to_string(), m, member_is_newer);
+        } else if rumor.has_census_entry() {
+            let ce = rumor.get_census_entry();
+            keep_newest(&mut self.census,
+                        ce.get_id().to_string(),
+                        ce,
+                        |a, b| a.get_incarnation() > b.get_incarnation());
+        } else if rumor.has_election() {
+            let e = rumor.get_election();
+            let sg = service_group(e.get_service(), e.get_group());
+            keep_newest(&mut self.elections, sg, e, election_is_newer);
+        } else if rumor.has_gossip_file() {
+            // Members warn about files at the same version with different contents when they
+            // hear them; we only want the newest.
+            let gf = rumor.get_gossip_file();
+            let key = (gf.get_service_group().to_string(), gf.get_file_name().to_string());
+            keep_newest(&mut self.gossip_files,
+                        key,
+                        gf,
+                        |a, b| a.get_version_number() > b.get_version_number());
+        }
+    }
+
+    /// The member with the given ID.
+    ///
+    /// # Errors
+    ///
+    /// * No member that answered us has heard of it
+    pub fn member(&self, member_id: &str) -> Result<&proto::Member> {
+        match self.members.get(member_id) {
+            Some(member) => Ok(member),
+            None => Err(Error::UnknownMember(member_id.to_string())),
+        }
+    }
+
+    /// The members we believe are alive, whoever told us.
+    pub fn alive_members(&self) -> Vec<&proto::Member> {
+        self.members
+            .values()
+            .filter(|m| m.get_health() == proto::Member_Health::ALIVE)
+            .collect()
+    }
+
+    /// The subjects on which the members that answered us disagree, or that some of them have not
+    /// heard of yet, sorted by subject.
+    pub fn disagreements(&self) -> Vec<Disagreement> {
+        let mut subjects: BTreeMap<String, HashMap<String, String>> = BTreeMap::new();
+        for view in self.views.iter() {
+            for rumor in view.rumors.iter() {
+                if let Some((subject, state)) = summarize(rumor) {
+                    subjects.entry(subject)
+                        .or_insert(HashMap::new())
+                        .insert(view.member_id.clone(), state);
+                }
+            }
+        }
+        let mut disagreements = Vec::new();
+        for (subject, states) in subjects.into_iter() {
+            let distinct: HashSet<&String> = states.values().collect();
+            if distinct.len() == 1 && states.len() == self.views.len() {
+                continue;
+            }
+            disagreements.push(Disagreement {
+                subject: subject,
+                states: self.views
+                    .iter()
+                    .map(|v| (v.member_id.clone(), states.get(&v.member_id).cloned()))
+                    .collect(),
+            });
+        }
+        disagreements
+    }
+}
+
+/// A service group's name, as members gossip it.
+pub fn service_group(service: &str, group: &str) -> String {
+    format!("{}.{}", service, group)
+}
+
+/// The name of a member's health, as `hab ring` prints it.
+pub fn health(health: proto::Member_Health) -> &'static str {
+    match health {
+        proto::Member_Health::ALIVE => "alive",
+        proto::Member_Health::SUSPECT => "suspect",
+        proto::Member_Health::CONFIRMED => "confirmed",
+        proto::Member_Health::DEPARTED => "departed",
+    }
+}
+
+/// The name of an election's status, as `hab ring` prints it.
+pub fn status(status: proto::Election_Status) -> &'static str {
+    match status {
+        proto::Election_Status::RUNNING => "running",
+        proto::Election_Status::FINISHED => "finished",
+    }
+}
+
+/// Keep `value` under `key`, unless what we have there already is at least as new.
+fn keep_newest<K, V, F>(map: &mut HashMap<K, V>, key: K, value: &V, is_newer: F)
+    where K: Eq + Hash,
+          V: Clone,
+          F: Fn(&V, &V) -> bool
+{
+    let newer = match map.get(&key) {
+        Some(current) => is_newer(value, current),
+        None => true,
+    };
+    if newer {
+        map.insert(key, value.clone());
+    }
+}
+
+/// A higher incarnation wins; at the same incarnation, the worse health does, as it does on a
+/// member.
+fn member_is_newer(m: &proto::Member, current: &proto::Member) -> bool {
+    (m.get_incarnation(), m.get_health().value()) >
+    (current.get_incarnation(), current.get_health().value())
+}
+
+/// A later term wins, then a finished election, then the one with more votes. Members weigh more
+/// than this, but it is close enough for a report.
+fn election_is_newer(e: &proto::Election, current: &proto::Election) -> bool {
+    let rank = |e: &proto::Election| {
+        (e.get_term(), e.get_status() == proto::Election_Status::FINISHED, e.get_votes().len())
+    };
+    rank(e) > rank(current)
+}
+
+/// The subject of a rumor, and the parts of its state members have to agree on.
+fn summarize(rumor: &proto::Rumor) -> Option<(String, String)> {
+    if rumor.has_member() {
+        let m = rumor.get_member();
+        Some((format!("member {}", m.get_id()),
+              format!("{} at incarnation {}", health(m.get_health()), m.get_incarnation())))
+    } else if rumor.has_census_entry() {
+        let ce = rumor.get_census_entry();
+        Some((format!("census entry {} ({})",
+                      ce.get_id(),
+                      service_group(ce.get_service(), ce.get_group())),
+              format!("incarnation {}", ce.get_incarnation())))
+    } else if rumor.has_election() {
+        let e = rumor.get_election();
+        Some((format!("election {}", service_group(e.get_service(), e.get_group())),
+              format!("term {} {}, leader {}",
+                      e.get_term(),
+                      status(e.get_status()),
+                      e.get_leader_id())))
+    } else if rumor.has_gossip_file() {
+        let gf = rumor.get_gossip_file();
+        Some((format!("gossip file {} {}", gf.get_service_group(), gf.get_file_name()),
+              format!("version {}", gf.get_version_number())))
+    } else if rumor.has_key_roll() {
+        let roll = rumor.get_key_roll();
+        Some((format!("key roll {}", roll.get_ring()),
+              format!("revision {}, retire {}", roll.get_revision(), roll.get_retire())))
+    } else {
+        None
+    }
+}
+
+/// An observer of a ring.
+pub struct Observer {
+    peer: proto::Peer,
+    kind: TransportKind,
+    ring_keys: RingKeys,
+    /// The acks to our pings, when we gossip over uTP
+    acks: Option<mpsc::Receiver<proto::Swim>>,
+}
+
+impl Observer {
+    /// Create an observer with a new member ID. Over uTP, we listen for acks on an ephemeral port
+    /// until the process exits.
+    ///
+    /// # Errors
+    ///
+    /// * We cannot work out our IP address
+    /// * We cannot listen for acks
+    pub fn new(kind: TransportKind, ring_keys: RingKeys) -> Result<Observer> {
+        let ip = try!(sys::ip());
+        let (listening_on, acks) = match kind {
+            TransportKind::Utp => {
+                let listener = try!(UtpListener::bind(&format!("{}:0", ip)[..]));
+                let listening_on = try!(listener.local_addr()).to_string();
+                let (tx, rx) = mpsc::channel();
+                let keys = ring_keys.clone();
+                let _t = thread::Builder::new().name("observer".to_string()).spawn(move || {
+                    receive_acks(listener, keys, tx)
+                });
+                (listening_on, Some(rx))
+            }
+            TransportKind::Udp => (format!("{}:0", ip), None),
+        };
+        let mut peer = proto::Peer::new();
+        peer.set_member_id(Uuid::new_v4().to_string());
+        peer.set_listening_on(listening_on);
+        peer.set_gossip_version(GOSSIP_VERSION);
+        Ok(Observer {
+            peer: peer,
+            kind: kind,
+            ring_keys: ring_keys,
+            acks: acks,
+        })
+    }
+
+    /// Our member ID, which the ring never hears about.
+    pub fn member_id(&self) -> &str {
+        self.peer.get_member_id()
+    }
+
+    /// Ask the members at `peers` what they know, then every alive member they tell us about, until
+    /// we have asked every member we know of.
+    ///
+    /// # Errors
+    ///
+    /// * No member answered us
+    pub fn survey(&self, peers: &[String]) -> Result<Survey> {
+        let mut asked: HashSet<String> = HashSet::new();
+        let mut answered: HashSet<String> = HashSet::new();
+        let mut views = Vec::new();
+        let mut pending: Vec<String> = peers.to_vec();
+        while !pending.is_empty() {
+            asked.extend(pending.iter().cloned());
+            for view in self.ask(&pending) {
+                if answered.insert(view.member_id.clone()) {
+                    views.push(view);
+                }
+            }
+            let survey = Survey::new(views);
+            pending = survey.alive_members()
+                .iter()
+                .filter(|m| {
+                    !answered.contains(m.get_id()) && !asked.contains(m.get_gossip_listener())
+                })
+                .map(|m| m.get_gossip_listener().to_string())
+                .collect();
+            views = survey.views;
+        }
+        if views.is_empty() {
+            return Err(Error::RingUnreachable(peers.join(", ")));
+        }
+        Ok(Survey::new(views))
+    }
+
+    /// Ask each of the members at `addrs` for every rumor it knows. Members that do not answer are
+    /// left out.
+    fn ask(&self, addrs: &[String]) -> Vec<View> {
+        let mut views = Vec::new();
+        match self.kind {
+            TransportKind::Utp => {
+                let mut pinged = 0;
+                for addr in addrs.iter() {
+                    let sent = wire::swim(proto::Swim_Type::PING, Some(&self.peer), &[])
+                        .and_then(|swim| send_utp(&addr[..], &swim, &self.ring_keys));
+                    match sent {
+                        Ok(_) => pinged += 1,
+                        Err(e) => debug!("Failed to ping {}: {}", addr, e),
+                    }
+                }
+                let acks = self.acks.as_ref().expect("uTP observers receive acks");
+                let deadline = Instant::now() + Duration::from_millis(ACK_TIMEOUT);
+                while views.len() < pinged && Instant::now() < deadline {
+                    match acks.try_recv() {
+                        Ok(ack) => views.push(view(&ack)),
+                        Err(mpsc::TryRecvError::Empty) => thread::sleep(Duration::from_millis(10)),
+                        Err(mpsc::TryRecvError::Disconnected) => break,
+                    }
+                }
+            }
+            TransportKind::Udp => {
+                for addr in addrs.iter() {
+                    let answer = wire::swim(proto::Swim_Type::PUSHPULL, Some(&self.peer), &[])
+                        .and_then(|swim| wire::push_pull(&addr[..], &swim, &self.ring_keys, true));
+                    match answer {
+                        Ok(Some(swim)) => {
+                            if swim.get_field_type() == proto::Swim_Type::PUSHPULL &&
+                               swim.has_peer() {
+                                views.push(view(&swim));
+                            } else {
+                                debug!("Unexpected answer to a push-pull from {}: {:?}",
+                                       addr,
+                                       swim);
+                            }
+                        }
+                        Ok(None) => debug!("No answer to a push-pull from {}", addr),
+                        Err(e) => debug!("Failed to push-pull with {}: {}", addr, e),
+                    }
+                }
+            }
+        }
+        views
+    }
+
+    /// Send `rumors` to each of the members at `addrs`, and return how many we sent them to.
+    pub fn inject(&self, addrs: &[String], rumors: &[proto::Rumor]) -> usize {
+        let swim = match wire::swim(proto::Swim_Type::INJECT, None, rumors) {
+            Ok(swim) => swim,
+            Err(e) => {
+                debug!("Failed to encode the rumors to inject: {}", e);
+                return 0;
+            }
+        };
+        let mut sent = 0;
+        for addr in addrs.iter() {
+            let result = match self.kind {
+                TransportKind::Utp => send_utp(&addr[..], &swim, &self.ring_keys),
+                // A datagram might not fit the rumors; members take injections over TCP, too.
+                TransportKind::Udp => {
+                    wire::push_pull(&addr[..], &swim, &self.ring_keys, false).map(|_| ())
+                }
+            };
+            match result {
+                Ok(_) => sent += 1,
+                Err(e) => debug!("Failed to inject rumors into {}: {}", addr, e),
+            }
+        }
+        sent
+    }
+}
+
+/// What the member that sent a message with a peer told us it knows.
+fn view(swim: &proto::Swim) -> View {
+    View {
+        member_id: swim.get_peer().get_member_id().to_string(),
+        rumors: wire::rumors(swim),
+    }
+}
+
+/// Send a message to the member at `addr` over a uTP connection of its own.
+fn send_utp(addr: &str, swim: &proto::Swim, ring_keys: &RingKeys) -> Result<()> {
+    let encoded = try!(wire::encode(swim, ring_keys));
+    let mut socket = try!(UtpSocket::connect(addr));
+    try!(socket.send_to(&encoded));
+    Ok(())
+}
+
+/// Pass every ack we receive on to the observer, until it goes away.
+fn receive_acks(listener: UtpListener, ring_keys: RingKeys, acks: mpsc::Sender<proto::Swim>) {
+    for connection in listener.incoming() {
+        let (mut socket, src) = match connection {
+            Ok(connection) => connection,
+            Err(e) => {
+                debug!("Failed to accept an ack: {}", e);
+                continue;
+            }
+        };
+        let mut buf = [0u8; BUFFER_SIZE];
+        let mut encoded = Vec::new();
+        loop {
+            match socket.recv_from(&mut buf) {
+                Ok((0, _)) => break,
+                Ok((amt, _)) => encoded.extend_from_slice(&buf[..amt]),
+                Err(e) => {
+                    debug!("Failed to receive a message from {:?}: {}", src, e);
+                    break;
+                }
+            }
+        }
+        match wire::decode(&encoded, &ring_keys) {
+            Ok(swim) => {
+                if swim.get_field_type() != proto::Swim_Type::ACK || !swim.has_peer() {
+                    debug!("Ignoring a message from {:?}: {:?}", src, swim);
+                } else if acks.send(swim).is_err() {
+                    return;
+                }
+            }
+            Err(e) => debug!("Failed to decode a message from {:?}: {}", src, e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use sup_protocol::gossip as proto;
+    use uuid::Uuid;
+
+    use gossip::observer::{Survey, View};
+
+    fn member(health: proto::Member_Health) -> proto::Member {
+        let mut member = proto::Member::new();
+        member.set_id(String::from("3b1c61a2-4d6f-4f1e-9a5e-2c1b0e6a9d7f"));
+        member.set_hostname(String::from("bobo"));
+        member.set_ip(String::from("192.168.1.1"));
+        member.set_gossip_listener(String::from("192.168.1.1:9634"));
+        member.set_incarnation(0);
+        member.set_health(health);
+        member
+    }
+
+    fn view(member: &proto::Member) -> View {
+        let mut rumor = proto::Rumor::new();
+        rumor.set_id(member.get_id().to_string());
+        rumor.set_member(member.clone());
+        View {
+            member_id: Uuid::new_v4().to_string(),
+            rumors: vec![rumor],
+        }
+    }
+
+    #[test]
+    fn converges_to_the_newest_state() {
+        let alive = member(proto::Member_Health::ALIVE);
+        let confirmed = member(proto::Member_Health::CONFIRMED);
+        let survey = Survey::new(vec![view(&alive), view(&confirmed)]);
+        assert_eq!(survey.members.get(alive.get_id()).unwrap().get_health(),
+                   proto::Member_Health::CONFIRMED);
+        assert!(survey.alive_members().is_empty());
+    }
+
+    #[test]
+    fn finds_disagreements() {
+        let alive = member(proto::Member_Health::ALIVE);
+        let suspect = member(proto::Member_Health::SUSPECT);
+
+        let agreed = Survey::new(vec![view(&alive), view(&alive)]);
+        assert!(agreed.disagreements().is_empty());
+
+        let survey = Survey::new(vec![view(&alive), view(&suspect)]);
+        let disagreements = survey.disagreements();
+        assert_eq!(disagreements.len(), 1);
+        assert_eq!(disagreements[0].subject, format!("member {}", alive.get_id()));
+        assert_eq!(disagreements[0].states[1].1,
+                   Some(String::from("suspect at incarnation 0")));
+    }
+}
@@ -0,0 +1,267 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The gossip wire format, as the ring observer speaks it.
+//!
+//! The observer only speaks version 2 of the gossip protocol, where every message is one of the
+//! protobuf messages in `habitat_sup_protocol`. Supervisors answer a member in the newest version
+//! it says it speaks, so the members that speak version 2 answer the observer in it too.
+
+use std::io::{self, Read, Write};
+use std::net::TcpStream;
+use std::path::Path;
+use std::time::Duration;
+
+use common::gossip_frame::{self, MAX_FRAME};
+use hcore::crypto::SymKey;
+use protobuf::{CodedInputStream, Message, MessageStatic, RepeatedField};
+use sup_protocol::gossip as proto;
+
+use error::{Error, Result};
+
+/// The version of the gossip protocol we speak
+pub const GOSSIP_VERSION: u32 = 2;
+
+/// How long we wait on a push-pull connection before giving up, in milliseconds
+pub const PUSH_PULL_TIMEOUT: u64 = 10000;
+
+/// The revisions of a ring key we read messages with, and the one we send with.
+#[derive(Clone)]
+pub struct RingKeys {
+    keys: Vec<SymKey>,
+    sending: Option<SymKey>,
+}
+
+impl RingKeys {
+    /// Gossip in the clear.
+    pub fn none() -> RingKeys {
+        RingKeys {
+            keys: Vec::new(),
+            sending: None,
+        }
+    }
+
+    /// Load every revision of the ring key `name` in the key cache, sending with the newest.
+    ///
+    /// # Failures
+    ///
+    /// * There is no revision of the key in the cache
+    pub fn load(name: &str, cache: &Path) -> Result<RingKeys> {
+        let latest = try!(SymKey::get_latest_pair_for(name, cache));
+        Ok(RingKeys {
+            keys: try!(SymKey::get_pairs_for(name, cache)),
+            sending: Some(latest),
+        })
+    }
+
+    /// Send with another revision of the key.
+    pub fn send_with(&mut self, key: &SymKey) {
+        self.sending = Some(key.clone());
+    }
+
+    /// The key we encrypt with, if we encrypt at all.
+    pub fn sending(&self) -> Option<&SymKey> {
+        self.sending.as_ref()
+    }
+
+    /// The key for a revision we have, by its name with revision.
+    pub fn get(&self, name_with_rev: &str) -> Option<&SymKey> {
+        self.keys.iter().find(|k| k.name_with_rev() == name_with_rev)
+    }
+}
+
+/// A SWIM message of the given type, from `peer` unless it is an injection, carrying `rumors`.
+///
+/// # Failures
+///
+/// * A rumor cannot be encoded
+pub fn swim(kind: proto::Swim_Type,
+            peer: Option<&proto::Peer>,
+            rumors: &[proto::Rumor])
+            -> Result<proto::Swim> {
+    let mut swim = proto::Swim::new();
+    swim.set_field_type(kind);
+    if let Some(peer) = peer {
+        swim.set_peer(peer.clone());
+    }
+    let mut encoded = Vec::with_capacity(rumors.len());
+    for rumor in rumors.iter() {
+        encoded.push(try!(rumor.write_to_bytes()));
+    }
+    swim.set_rumors(RepeatedField::from_vec(encoded));
+    Ok(swim)
+}
+
+/// The rumors a SWIM message carries. Like a Supervisor, we drop each rumor we cannot read on its
+/// own.
+pub fn rumors(swim: &proto::Swim) -> Vec<proto::Rumor> {
+    let mut rumors = Vec::new();
+    for bytes in swim.get_rumors().iter() {
+        match parse::<proto::Rumor>(bytes) {
+            Ok(ref rumor) if !readable(rumor) => {
+                debug!("Dropping a rumor with no payload we can read")
+            }
+            Ok(rumor) => rumors.push(rumor),
+            Err(e) => debug!("Dropping a rumor we cannot decode: {}", e),
+        }
+    }
+    rumors
+}
+
+/// Whether a rumor has a payload we know, with all its required fields. Parsing the rumor leaves
+/// its payload unchecked.
+fn readable(rumor: &proto::Rumor) -> bool {
+    if rumor.has_member() {
+        rumor.get_member().is_initialized()
+    } else if rumor.has_census_entry() {
+        rumor.get_census_entry().is_initialized()
+    } else if rumor.has_election() {
+        rumor.get_election().is_initialized()
+    } else if rumor.has_gossip_file() {
+        rumor.get_gossip_file().is_initialized()
+    } else if rumor.has_key_roll() {
+        rumor.get_key_roll().is_initialized()
+    } else {
+        false
+    }
+}
+
+/// Encode a SWIM message in an envelope, encrypting it with our sending ring key if we have one.
+///
+/// # Failures
+///
+/// * The message cannot be encoded, or encrypted
+pub fn encode(swim: &proto::Swim, ring_keys: &RingKeys) -> Result<Vec<u8>> {
+    let payload = try!(swim.write_to_bytes());
+    let mut envelope = proto::Envelope::new();
+    envelope.set_version(GOSSIP_VERSION);
+    envelope.set_min_version(GOSSIP_VERSION);
+    match ring_keys.sending() {
+        Some(key) => {
+            let (nonce, ciphertext) = try!(key.encrypt(&payload));
+            envelope.set_key(key.name_with_rev());
+            envelope.set_nonce(nonce);
+            envelope.set_payload(ciphertext);
+        }
+        None => envelope.set_payload(payload),
+    }
+    Ok(try!(envelope.write_to_bytes()))
+}
+
+/// Decode a SWIM message from its envelope, decrypting it if it is encrypted.
+///
+/// # Failures
+///
+/// * The message is JSON, from a Supervisor that only speaks version 1 of the gossip protocol
+/// * The message needs a newer version of the gossip protocol than ours
+/// * The message is encrypted with a ring key revision we do not have
+/// * The message cannot be decoded
+pub fn decode(bytes: &[u8], ring_keys: &RingKeys) -> Result<proto::Swim> {
+    if bytes.first() == Some(&b'{') {
+        let msg = "the member only speaks version 1 of the gossip protocol".to_string();
+        return Err(Error::GossipDecode(msg));
+    }
+    let mut envelope: proto::Envelope = try!(parse(bytes));
+    if envelope.get_min_version() > GOSSIP_VERSION {
+        let msg = format!("the message needs version {} of the gossip protocol",
+                          envelope.get_min_version());
+        return Err(Error::GossipDecode(msg));
+    }
+    let payload = if envelope.has_key() {
+        let key = match ring_keys.get(envelope.get_key()) {
+            Some(key) => key,
+            None => {
+                let msg = format!("the message is encrypted with {}, which we do not have",
+                                  envelope.get_key());
+                return Err(Error::GossipDecode(msg));
+            }
+        };
+        if !envelope.has_nonce() {
+            return Err(Error::GossipDecode("the encrypted message has no nonce".to_string()));
+        }
+        try!(key.decrypt(envelope.get_nonce(), envelope.get_payload()))
+    } else {
+        envelope.take_payload()
+    };
+    parse(&payload)
+}
+
+/// Send a SWIM message to the push-pull listener of the member at `addr`, and return the message
+/// it answers with, if any; members do not answer injections.
+///
+/// # Failures
+///
+/// * We cannot connect to the member, or it takes too long to answer
+/// * We cannot write our message, or read its answer
+pub fn push_pull(addr: &str,
+                 swim: &proto::Swim,
+                 ring_keys: &RingKeys,
+                 answer: bool)
+                 -> Result<Option<proto::Swim>> {
+    let mut stream = try!(TcpStream::connect(addr));
+    let timeout = Some(Duration::from_millis(PUSH_PULL_TIMEOUT));
+    try!(stream.set_read_timeout(timeout));
+    try!(stream.set_write_timeout(timeout));
+    try!(write_frame(&mut stream, &try!(encode(swim, ring_keys))));
+    if !answer {
+        return Ok(None);
+    }
+    let encoded = try!(read_frame(&mut stream));
+    Ok(Some(try!(decode(&encoded, ring_keys))))
+}
+
+/// Write an encoded message to a push-pull stream, in a frame.
+fn write_frame<W: Write>(stream: &mut W, encoded: &[u8]) -> Result<()> {
+    if encoded.len() > MAX_FRAME {
+        return Err(Error::GossipMessageTooLarge(encoded.len(), MAX_FRAME));
+    }
+    try!(stream.write_all(&gossip_frame::header(GOSSIP_VERSION as u8, encoded.len() as u32)));
+    try!(stream.write_all(encoded));
+    try!(stream.flush());
+    Ok(())
+}
+
+/// Read an encoded message from a push-pull stream. We only take as much memory as the bytes that
+/// actually arrive, whatever the frame's header says.
+fn read_frame<R: Read>(stream: &mut R) -> Result<Vec<u8>> {
+    let mut header = [0u8; gossip_frame::HEADER_LEN];
+    try!(stream.read_exact(&mut header));
+    let (version, len) = gossip_frame::parse_header(&header);
+    if version as u32 != GOSSIP_VERSION {
+        let msg = format!("the answer is in version {} of the gossip protocol", version);
+        return Err(Error::GossipDecode(msg));
+    }
+    if len > MAX_FRAME {
+        return Err(Error::GossipMessageTooLarge(len, MAX_FRAME));
+    }
+    let mut encoded = Vec::new();
+    try!(stream.by_ref().take(len as u64).read_to_end(&mut encoded));
+    if encoded.len() < len {
+        return Err(Error::IO(io::Error::new(io::ErrorKind::UnexpectedEof,
+                                            "the push-pull frame ended early")));
+    }
+    Ok(encoded)
+}
+
+/// Parse a message of the gossip wire format. rust-protobuf panics rather than fail on a message
+/// without its required fields, so we check for them ourselves.
+fn parse<M: Message + MessageStatic>(bytes: &[u8]) -> Result<M> {
+    let mut msg: M = MessageStatic::new();
+    try!(msg.merge_from(&mut CodedInputStream::from_bytes(bytes)));
+    if !msg.is_initialized() {
+        let msg = format!("{} is missing a required field", msg.descriptor().full_name());
+        return Err(Error::GossipDecode(msg));
+    }
+    Ok(msg)
+}
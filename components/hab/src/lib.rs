@@ -16,6 +16,7 @@ extern crate habitat_core as hcore;
 extern crate habitat_common as common;
 extern crate habitat_depot_client as depot_client;
 extern crate habitat_http_client as http_client;
+extern crate habitat_sup_protocol as sup_protocol;
 
 extern crate ansi_term;
 #[macro_use]
@@ -24,6 +25,7 @@ extern crate hyper;
 #[macro_use]
 extern crate log;
 extern crate pbr;
+extern crate protobuf;
 extern crate regex;
 extern crate rustc_serialize;
 extern crate toml;
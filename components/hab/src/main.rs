@@ -18,6 +18,7 @@ extern crate env_logger;
 extern crate hab;
 extern crate habitat_core as hcore;
 extern crate habitat_common as common;
+extern crate habitat_sup_protocol as sup_protocol;
 #[macro_use]
 extern crate log;
 
@@ -42,6 +43,9 @@ use hcore::url::{DEFAULT_DEPOT_URL, DEPOT_URL_ENVVAR};
 use hab::{analytics, cli, command, config, PRODUCT, VERSION};
 use hab::error::{Error, Result};
 use hab::gossip::hab_gossip;
+use hab::gossip::observer::{Observer, TransportKind};
+use hab::gossip::wire::RingKeys;
+use sup_protocol::gossip::Member_Health;
 
 /// Makes the --auth-token CLI param optional when this env var is set
 const HABITAT_AUTH_TOKEN_ENVVAR: &'static str = "HAB_AUTH_TOKEN";
@@ -90,6 +94,7 @@ fn start(ui: &mut UI) -> Result<()> {
         ("config", Some(matches)) => {
             match matches.subcommand() {
                 ("apply", Some(m)) => try!(sub_config_apply(ui, m)),
+                ("rollback", Some(m)) => try!(sub_config_rollback(ui, m)),
                 _ => unreachable!(),
             }
         }
@@ -139,9 +144,15 @@ fn start(ui: &mut UI) -> Result<()> {
                         ("export", Some(sc)) => try!(sub_ring_key_export(sc)),
                         ("import", Some(_)) => try!(sub_ring_key_import(ui)),
                         ("generate", Some(sc)) => try!(sub_ring_key_generate(ui, sc)),
+                        ("roll", Some(sc)) => try!(sub_ring_key_roll(ui, sc)),
                         _ => unreachable!(),
                     }
                 }
+                ("members", Some(m)) => try!(sub_ring_members(m)),
+                ("census", Some(m)) => try!(sub_ring_census(m)),
+                ("elections", Some(m)) => try!(sub_ring_elections(m)),
+                ("diff", Some(m)) => try!(sub_ring_diff(ui, m)),
+                ("inject", Some(m)) => try!(sub_ring_inject(ui, m)),
                 _ => unreachable!(),
             }
         }
@@ -215,8 +226,58 @@ fn sub_config_apply(ui: &mut UI, m: &ArgMatches) -> Result<()> {
         Err(_e) => None,
     };
     sg.organization = org;
+    let signer = try!(signing_key(&m, &cache));
 
-    command::config::apply::start(ui, &peers, ring_key.as_ref(), &sg, number, file_path)
+    command::config::apply::start(ui,
+                                  &peers,
+                                  ring_key.as_ref(),
+                                  signer.as_ref(),
+                                  &sg,
+                                  number,
+                                  file_path)
+}
+
+fn sub_config_rollback(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
+    let fs_root_path = Some(Path::new(&fs_root));
+    let peers_str = m.value_of("PEER").unwrap_or("127.0.0.1");
+    let mut peers: Vec<String> = peers_str.split(",").map(|p| p.into()).collect();
+    for p in peers.iter_mut() {
+        if p.find(':').is_none() {
+            p.push(':');
+            p.push_str(&hab_gossip::GOSSIP_DEFAULT_PORT.to_string());
+        }
+    }
+    let mut sidecar = m.value_of("SIDECAR").unwrap_or("127.0.0.1").to_string();
+    if sidecar.find(':').is_none() {
+        sidecar.push_str(":9631");
+    }
+    let number = value_t!(m, "VERSION_NUMBER", u64).unwrap_or_else(|e| e.exit());
+
+    init();
+    let cache = default_cache_key_path(fs_root_path);
+    let ring_key = match m.value_of("RING") {
+        Some(name) => Some(try!(SymKey::get_latest_pair_for(&name, &cache))),
+        None => None,
+    };
+
+    let mut sg = try!(ServiceGroup::from_str(m.value_of("SERVICE_GROUP").unwrap()));
+
+    // use the org if it's passed in on the CLI or set in an env var
+    let org = match org_param_or_env(&m) {
+        Ok(org) => Some(org.to_string()),
+        Err(_e) => None,
+    };
+    sg.organization = org;
+    let signer = try!(signing_key(&m, &cache));
+
+    command::config::rollback::start(ui,
+                                     &peers,
+                                     ring_key.as_ref(),
+                                     signer.as_ref(),
+                                     &sg,
+                                     number,
+                                     &sidecar)
 }
 
 fn sub_file_upload(ui: &mut UI, m: &ArgMatches) -> Result<()> {
@@ -262,11 +323,16 @@ fn sub_file_upload(ui: &mut UI, m: &ArgMatches) -> Result<()> {
 
     let user = try!(user_param_or_env(&m));
     let user_pair = try!(BoxKeyPair::get_latest_pair_for(&user, &cache));
+    // Users who made their keys before files were signed have no signing key. Their files go
+    // unsigned, which rings that trust no users still take.
+    let user_sig_pair = SigKeyPair::get_latest_pair_for(&SigKeyPair::user_key_name(&user), &cache)
+        .ok();
 
     command::file::upload::start(ui,
                                  &peers,
                                  ring_key.as_ref(),
                                  &user_pair,
+                                 user_sig_pair.as_ref(),
                                  &service_pair,
                                  number,
                                  file_path)
@@ -518,6 +584,86 @@ fn sub_ring_key_import(ui: &mut UI) -> Result<()> {
     command::ring::key::import::start(ui, &content, &default_cache_key_path(fs_root_path))
 }
 
+fn sub_ring_key_roll(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
+    let fs_root_path = Some(Path::new(&fs_root));
+    let ring = m.value_of("RING").unwrap();
+    let kind = try!(TransportKind::from_str(m.value_of("GOSSIP_TRANSPORT").unwrap_or("utp")));
+    init();
+
+    command::ring::key::roll::start(ui,
+                                    kind,
+                                    &ring_peers(m),
+                                    ring,
+                                    &default_cache_key_path(fs_root_path))
+}
+
+fn sub_ring_members(m: &ArgMatches) -> Result<()> {
+    let (peers, observer) = try!(ring_observer(m));
+    command::ring::members::start(&observer, &peers)
+}
+
+fn sub_ring_census(m: &ArgMatches) -> Result<()> {
+    let (peers, observer) = try!(ring_observer(m));
+    command::ring::census::start(&observer, &peers)
+}
+
+fn sub_ring_elections(m: &ArgMatches) -> Result<()> {
+    let (peers, observer) = try!(ring_observer(m));
+    command::ring::elections::start(&observer, &peers)
+}
+
+fn sub_ring_diff(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let (peers, observer) = try!(ring_observer(m));
+    command::ring::diff::start(ui, &observer, &peers)
+}
+
+fn sub_ring_inject(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let health = match m.value_of("HEALTH").unwrap() {
+        "suspect" => Member_Health::SUSPECT,
+        "confirm" => Member_Health::CONFIRMED,
+        "depart" => Member_Health::DEPARTED,
+        _ => unreachable!(),
+    };
+    let (peers, observer) = try!(ring_observer(m));
+    command::ring::inject::start(ui,
+                                 &observer,
+                                 &peers,
+                                 health,
+                                 m.value_of("MEMBER_ID").unwrap())
+}
+
+/// Joins the ring the PEER arguments name as an observer, with the RING key if there is one.
+fn ring_observer(m: &ArgMatches) -> Result<(Vec<String>, Observer)> {
+    let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
+    let fs_root_path = Some(Path::new(&fs_root));
+    let peers = ring_peers(m);
+    let kind = try!(TransportKind::from_str(m.value_of("GOSSIP_TRANSPORT").unwrap_or("utp")));
+
+    init();
+    let cache = default_cache_key_path(fs_root_path);
+    let ring_keys = match m.value_of("RING") {
+        Some(name) => try!(RingKeys::load(&name, &cache)),
+        None => RingKeys::none(),
+    };
+
+    let observer = try!(Observer::new(kind, ring_keys));
+    Ok((peers, observer))
+}
+
+/// The gossip listeners the PEER argument names, on the default port unless it says otherwise.
+fn ring_peers(m: &ArgMatches) -> Vec<String> {
+    let peers_str = m.value_of("PEER").unwrap_or("127.0.0.1");
+    let mut peers: Vec<String> = peers_str.split(",").map(|p| p.into()).collect();
+    for p in peers.iter_mut() {
+        if p.find(':').is_none() {
+            p.push(':');
+            p.push_str(&hab_gossip::GOSSIP_DEFAULT_PORT.to_string());
+        }
+    }
+    peers
+}
+
 fn sub_service_key_generate(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
     let fs_root_path = Some(Path::new(&fs_root));
@@ -627,6 +773,17 @@ fn org_param_or_env(m: &ArgMatches) -> Result<String> {
     }
 }
 
+/// When the user has passed in a USER param, return their latest signing key to sign a
+/// configuration with.
+fn signing_key<P: AsRef<Path> + ?Sized>(m: &ArgMatches, cache: &P) -> Result<Option<SigKeyPair>> {
+    let user = match m.value_of("USER") {
+        Some(user) => user,
+        None => return Ok(None),
+    };
+    let pair = try!(SigKeyPair::get_latest_pair_for(&SigKeyPair::user_key_name(user), cache));
+    Ok(Some(pair))
+}
+
 /// Check to see if the user has passed in a USER param.
 /// If not, check the HAB_USER env var. If that's
 /// empty too, then return an error.
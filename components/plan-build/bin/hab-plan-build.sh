@@ -177,6 +177,26 @@
 # pkg_expose=(80 443)
 # ```
 #
+# ### pkg_svc_memory_max, pkg_svc_cpu_max, pkg_svc_pids_max
+# Optional resource limits for the service. When any is set, the supervisor
+# runs the service in its own cgroup, limited to this much memory (in bytes,
+# or with a `K`, `M` or `G` suffix), this many CPUs' worth of time, and this
+# many processes at once. They can be overridden when the service is started.
+# ```
+# pkg_svc_memory_max=512M
+# pkg_svc_cpu_max=0.5
+# pkg_svc_pids_max=100
+# ```
+#
+# ### pkg_svc_stop_signal, pkg_svc_stop_timeout
+# The signal the supervisor sends to stop the service, and how many seconds it
+# waits for the service to exit before killing it. These are `TERM` and 8 by
+# default, and can be overridden when the service is started.
+# ```
+# pkg_svc_stop_signal=INT
+# pkg_svc_stop_timeout=60
+# ```
+#
 # ### pkg_origin
 # A string to use for the origin. The origin is used to denote a particular upstream of a
 # package; when we resolve dependencies, we consider a version of a package to be equal
@@ -360,6 +380,16 @@ pkg_expose=()
 pkg_svc_user=hab
 # The group to run the service as
 pkg_svc_group=$pkg_svc_user
+# The most memory the service may use; unlimited by default
+pkg_svc_memory_max=''
+# How many CPUs' worth of time the service may use; unlimited by default
+pkg_svc_cpu_max=''
+# How many processes the service may run at once; unlimited by default
+pkg_svc_pids_max=''
+# The signal that stops the service; TERM by default
+pkg_svc_stop_signal=''
+# How many seconds the service may take to stop before it is killed; 8 by default
+pkg_svc_stop_timeout=''
 
 # Initially set $pkg_svc_* variables. This happens before the Plan is sourced,
 # meaning that `$pkg_name` is not yet set. However, `$pkg_svc_run` wants
@@ -1823,6 +1853,9 @@ do_default_install() {
 # * `$pkg_prefix/LDFLAGS` - Any LDFLAGS for things that link against us
 # * `$pkg_prefix/LD_RUN_PATH` - The LD_RUN_PATH for things that link against us
 # * `$pkg_prefix/PATH` - Any PATH entries for things that link against us
+# * `$pkg_prefix/SVC_MEMORY_MAX`, `SVC_CPU_MAX`, `SVC_PIDS_MAX` - Any resource
+#   limits for the service
+# * `$pkg_prefix/SVC_STOP_SIGNAL`, `SVC_STOP_TIMEOUT` - How to stop the service
 _build_metadata() {
   build_line "Building package metadata"
   local ld_run_path_part=""
@@ -1954,6 +1987,22 @@ _build_metadata() {
   echo "$pkg_svc_user" > $pkg_prefix/SVC_USER
   echo "$pkg_svc_group" > $pkg_prefix/SVC_GROUP
 
+  if [[ -n "${pkg_svc_memory_max}" ]]; then
+    echo "$pkg_svc_memory_max" > $pkg_prefix/SVC_MEMORY_MAX
+  fi
+  if [[ -n "${pkg_svc_cpu_max}" ]]; then
+    echo "$pkg_svc_cpu_max" > $pkg_prefix/SVC_CPU_MAX
+  fi
+  if [[ -n "${pkg_svc_pids_max}" ]]; then
+    echo "$pkg_svc_pids_max" > $pkg_prefix/SVC_PIDS_MAX
+  fi
+  if [[ -n "${pkg_svc_stop_signal}" ]]; then
+    echo "$pkg_svc_stop_signal" > $pkg_prefix/SVC_STOP_SIGNAL
+  fi
+  if [[ -n "${pkg_svc_stop_timeout}" ]]; then
+    echo "$pkg_svc_stop_timeout" > $pkg_prefix/SVC_STOP_TIMEOUT
+  fi
+
   # Generate the blake2b hashes of all the files in the package. This
   # is not in the resulting MANIFEST because MANIFEST is included!
   pushd "$HAB_CACHE_SRC_PATH/$pkg_dirname" > /dev/null
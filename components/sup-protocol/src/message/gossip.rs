@@ -0,0 +1,5643 @@
+// This file is generated. Do not edit
+// @generated
+
+// https://github.com/Manishearth/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy)]
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+#![allow(dead_code)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(unused_imports)]
+
+use protobuf::Message as Message_imported_for_functions;
+use protobuf::ProtobufEnum as ProtobufEnum_imported_for_functions;
+
+#[derive(Clone,Default)]
+pub struct Envelope {
+    // message fields
+    version: ::std::option::Option<u32>,
+    min_version: ::std::option::Option<u32>,
+    key: ::protobuf::SingularField<::std::string::String>,
+    nonce: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    payload: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for Envelope {}
+
+impl Envelope {
+    pub fn new() -> Envelope {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static Envelope {
+        static mut instance: ::protobuf::lazy::Lazy<Envelope> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Envelope,
+        };
+        unsafe {
+            instance.get(|| {
+                Envelope {
+                    version: ::std::option::Option::None,
+                    min_version: ::std::option::Option::None,
+                    key: ::protobuf::SingularField::none(),
+                    nonce: ::protobuf::SingularField::none(),
+                    payload: ::protobuf::SingularField::none(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint32 version = 1;
+
+    pub fn clear_version(&mut self) {
+        self.version = ::std::option::Option::None;
+    }
+
+    pub fn has_version(&self) -> bool {
+        self.version.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_version(&mut self, v: u32) {
+        self.version = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_version(&self) -> u32 {
+        self.version.unwrap_or(0)
+    }
+
+    // required uint32 min_version = 2;
+
+    pub fn clear_min_version(&mut self) {
+        self.min_version = ::std::option::Option::None;
+    }
+
+    pub fn has_min_version(&self) -> bool {
+        self.min_version.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_min_version(&mut self, v: u32) {
+        self.min_version = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_min_version(&self) -> u32 {
+        self.min_version.unwrap_or(0)
+    }
+
+    // optional string key = 3;
+
+    pub fn clear_key(&mut self) {
+        self.key.clear();
+    }
+
+    pub fn has_key(&self) -> bool {
+        self.key.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key(&mut self, v: ::std::string::String) {
+        self.key = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key(&mut self) -> &mut ::std::string::String {
+        if self.key.is_none() {
+            self.key.set_default();
+        };
+        self.key.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_key(&mut self) -> ::std::string::String {
+        self.key.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_key(&self) -> &str {
+        match self.key.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // optional bytes nonce = 4;
+
+    pub fn clear_nonce(&mut self) {
+        self.nonce.clear();
+    }
+
+    pub fn has_nonce(&self) -> bool {
+        self.nonce.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_nonce(&mut self, v: ::std::vec::Vec<u8>) {
+        self.nonce = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_nonce(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if self.nonce.is_none() {
+            self.nonce.set_default();
+        };
+        self.nonce.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_nonce(&mut self) -> ::std::vec::Vec<u8> {
+        self.nonce.take().unwrap_or_else(|| ::std::vec::Vec::new())
+    }
+
+    pub fn get_nonce(&self) -> &[u8] {
+        match self.nonce.as_ref() {
+            Some(v) => &v,
+            None => &[],
+        }
+    }
+
+    // required bytes payload = 5;
+
+    pub fn clear_payload(&mut self) {
+        self.payload.clear();
+    }
+
+    pub fn has_payload(&self) -> bool {
+        self.payload.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_payload(&mut self, v: ::std::vec::Vec<u8>) {
+        self.payload = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_payload(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if self.payload.is_none() {
+            self.payload.set_default();
+        };
+        self.payload.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_payload(&mut self) -> ::std::vec::Vec<u8> {
+        self.payload.take().unwrap_or_else(|| ::std::vec::Vec::new())
+    }
+
+    pub fn get_payload(&self) -> &[u8] {
+        match self.payload.as_ref() {
+            Some(v) => &v,
+            None => &[],
+        }
+    }
+}
+
+impl ::protobuf::Message for Envelope {
+    fn is_initialized(&self) -> bool {
+        if self.version.is_none() {
+            return false;
+        };
+        if self.min_version.is_none() {
+            return false;
+        };
+        if self.payload.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint32());
+                    self.version = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint32());
+                    self.min_version = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.key));
+                },
+                4 => {
+                    try!(::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.nonce));
+                },
+                5 => {
+                    try!(::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.payload));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.version.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.min_version.iter() {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.key.iter() {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        for value in self.nonce.iter() {
+            my_size += ::protobuf::rt::bytes_size(4, &value);
+        };
+        for value in self.payload.iter() {
+            my_size += ::protobuf::rt::bytes_size(5, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.version {
+            try!(os.write_uint32(1, v));
+        };
+        if let Some(v) = self.min_version {
+            try!(os.write_uint32(2, v));
+        };
+        if let Some(v) = self.key.as_ref() {
+            try!(os.write_string(3, &v));
+        };
+        if let Some(v) = self.nonce.as_ref() {
+            try!(os.write_bytes(4, &v));
+        };
+        if let Some(v) = self.payload.as_ref() {
+            try!(os.write_bytes(5, &v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<Envelope>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for Envelope {
+    fn new() -> Envelope {
+        Envelope::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<Envelope>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u32_accessor(
+                    "version",
+                    Envelope::has_version,
+                    Envelope::get_version,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u32_accessor(
+                    "min_version",
+                    Envelope::has_min_version,
+                    Envelope::get_min_version,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "key",
+                    Envelope::has_key,
+                    Envelope::get_key,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_bytes_accessor(
+                    "nonce",
+                    Envelope::has_nonce,
+                    Envelope::get_nonce,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_bytes_accessor(
+                    "payload",
+                    Envelope::has_payload,
+                    Envelope::get_payload,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Envelope>(
+                    "Envelope",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for Envelope {
+    fn clear(&mut self) {
+        self.clear_version();
+        self.clear_min_version();
+        self.clear_key();
+        self.clear_nonce();
+        self.clear_payload();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for Envelope {
+    fn eq(&self, other: &Envelope) -> bool {
+        self.version == other.version &&
+        self.min_version == other.min_version &&
+        self.key == other.key &&
+        self.nonce == other.nonce &&
+        self.payload == other.payload &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for Envelope {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct Swim {
+    // message fields
+    field_type: ::std::option::Option<Swim_Type>,
+    peer: ::protobuf::SingularPtrField<Peer>,
+    rumors: ::protobuf::RepeatedField<::std::vec::Vec<u8>>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for Swim {}
+
+impl Swim {
+    pub fn new() -> Swim {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static Swim {
+        static mut instance: ::protobuf::lazy::Lazy<Swim> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Swim,
+        };
+        unsafe {
+            instance.get(|| {
+                Swim {
+                    field_type: ::std::option::Option::None,
+                    peer: ::protobuf::SingularPtrField::none(),
+                    rumors: ::protobuf::RepeatedField::new(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required .gossip.Swim.Type type = 1;
+
+    pub fn clear_field_type(&mut self) {
+        self.field_type = ::std::option::Option::None;
+    }
+
+    pub fn has_field_type(&self) -> bool {
+        self.field_type.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_field_type(&mut self, v: Swim_Type) {
+        self.field_type = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_field_type(&self) -> Swim_Type {
+        self.field_type.unwrap_or(Swim_Type::PING)
+    }
+
+    // optional .gossip.Peer peer = 2;
+
+    pub fn clear_peer(&mut self) {
+        self.peer.clear();
+    }
+
+    pub fn has_peer(&self) -> bool {
+        self.peer.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_peer(&mut self, v: Peer) {
+        self.peer = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_peer(&mut self) -> &mut Peer {
+        if self.peer.is_none() {
+            self.peer.set_default();
+        };
+        self.peer.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_peer(&mut self) -> Peer {
+        self.peer.take().unwrap_or_else(|| Peer::new())
+    }
+
+    pub fn get_peer(&self) -> &Peer {
+        self.peer.as_ref().unwrap_or_else(|| Peer::default_instance())
+    }
+
+    // repeated bytes rumors = 3;
+
+    pub fn clear_rumors(&mut self) {
+        self.rumors.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rumors(&mut self, v: ::protobuf::RepeatedField<::std::vec::Vec<u8>>) {
+        self.rumors = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_rumors(&mut self) -> &mut ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        &mut self.rumors
+    }
+
+    // Take field
+    pub fn take_rumors(&mut self) -> ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        ::std::mem::replace(&mut self.rumors, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_rumors(&self) -> &[::std::vec::Vec<u8>] {
+        &self.rumors
+    }
+}
+
+impl ::protobuf::Message for Swim {
+    fn is_initialized(&self) -> bool {
+        if self.field_type.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_enum());
+                    self.field_type = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    try!(::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.peer));
+                },
+                3 => {
+                    try!(::protobuf::rt::read_repeated_bytes_into(wire_type, is, &mut self.rumors));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.field_type.iter() {
+            my_size += ::protobuf::rt::enum_size(1, *value);
+        };
+        for value in self.peer.iter() {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in self.rumors.iter() {
+            my_size += ::protobuf::rt::bytes_size(3, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.field_type {
+            try!(os.write_enum(1, v.value()));
+        };
+        if let Some(v) = self.peer.as_ref() {
+            try!(os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited));
+            try!(os.write_raw_varint32(v.get_cached_size()));
+            try!(v.write_to_with_cached_sizes(os));
+        };
+        for v in self.rumors.iter() {
+            try!(os.write_bytes(3, &v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<Swim>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for Swim {
+    fn new() -> Swim {
+        Swim::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<Swim>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor(
+                    "type",
+                    Swim::has_field_type,
+                    Swim::get_field_type,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor(
+                    "peer",
+                    Swim::has_peer,
+                    Swim::get_peer,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_bytes_accessor(
+                    "rumors",
+                    Swim::get_rumors,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Swim>(
+                    "Swim",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for Swim {
+    fn clear(&mut self) {
+        self.clear_field_type();
+        self.clear_peer();
+        self.clear_rumors();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for Swim {
+    fn eq(&self, other: &Swim) -> bool {
+        self.field_type == other.field_type &&
+        self.peer == other.peer &&
+        self.rumors == other.rumors &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for Swim {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum Swim_Type {
+    PING = 1,
+    ACK = 2,
+    PINGREQ = 3,
+    INJECT = 4,
+    PUSHPULL = 5,
+}
+
+impl ::protobuf::ProtobufEnum for Swim_Type {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<Swim_Type> {
+        match value {
+            1 => ::std::option::Option::Some(Swim_Type::PING),
+            2 => ::std::option::Option::Some(Swim_Type::ACK),
+            3 => ::std::option::Option::Some(Swim_Type::PINGREQ),
+            4 => ::std::option::Option::Some(Swim_Type::INJECT),
+            5 => ::std::option::Option::Some(Swim_Type::PUSHPULL),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [Swim_Type] = &[
+            Swim_Type::PING,
+            Swim_Type::ACK,
+            Swim_Type::PINGREQ,
+            Swim_Type::INJECT,
+            Swim_Type::PUSHPULL,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static(_: Option<Swim_Type>) -> &'static ::protobuf::reflect::EnumDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new("Swim_Type", file_descriptor_proto())
+            })
+        }
+    }
+}
+
+impl ::std::marker::Copy for Swim_Type {
+}
+
+#[derive(Clone,Default)]
+pub struct Peer {
+    // message fields
+    member_id: ::protobuf::SingularField<::std::string::String>,
+    listening_on: ::protobuf::SingularField<::std::string::String>,
+    proxy_through: ::protobuf::SingularField<::std::string::String>,
+    proxy_to: ::protobuf::SingularField<::std::string::String>,
+    gossip_version: ::std::option::Option<u32>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for Peer {}
+
+impl Peer {
+    pub fn new() -> Peer {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static Peer {
+        static mut instance: ::protobuf::lazy::Lazy<Peer> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Peer,
+        };
+        unsafe {
+            instance.get(|| {
+                Peer {
+                    member_id: ::protobuf::SingularField::none(),
+                    listening_on: ::protobuf::SingularField::none(),
+                    proxy_through: ::protobuf::SingularField::none(),
+                    proxy_to: ::protobuf::SingularField::none(),
+                    gossip_version: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required string member_id = 1;
+
+    pub fn clear_member_id(&mut self) {
+        self.member_id.clear();
+    }
+
+    pub fn has_member_id(&self) -> bool {
+        self.member_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_member_id(&mut self, v: ::std::string::String) {
+        self.member_id = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_member_id(&mut self) -> &mut ::std::string::String {
+        if self.member_id.is_none() {
+            self.member_id.set_default();
+        };
+        self.member_id.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_member_id(&mut self) -> ::std::string::String {
+        self.member_id.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_member_id(&self) -> &str {
+        match self.member_id.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required string listening_on = 2;
+
+    pub fn clear_listening_on(&mut self) {
+        self.listening_on.clear();
+    }
+
+    pub fn has_listening_on(&self) -> bool {
+        self.listening_on.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_listening_on(&mut self, v: ::std::string::String) {
+        self.listening_on = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_listening_on(&mut self) -> &mut ::std::string::String {
+        if self.listening_on.is_none() {
+            self.listening_on.set_default();
+        };
+        self.listening_on.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_listening_on(&mut self) -> ::std::string::String {
+        self.listening_on.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_listening_on(&self) -> &str {
+        match self.listening_on.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // optional string proxy_through = 3;
+
+    pub fn clear_proxy_through(&mut self) {
+        self.proxy_through.clear();
+    }
+
+    pub fn has_proxy_through(&self) -> bool {
+        self.proxy_through.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_proxy_through(&mut self, v: ::std::string::String) {
+        self.proxy_through = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_proxy_through(&mut self) -> &mut ::std::string::String {
+        if self.proxy_through.is_none() {
+            self.proxy_through.set_default();
+        };
+        self.proxy_through.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_proxy_through(&mut self) -> ::std::string::String {
+        self.proxy_through.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_proxy_through(&self) -> &str {
+        match self.proxy_through.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // optional string proxy_to = 4;
+
+    pub fn clear_proxy_to(&mut self) {
+        self.proxy_to.clear();
+    }
+
+    pub fn has_proxy_to(&self) -> bool {
+        self.proxy_to.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_proxy_to(&mut self, v: ::std::string::String) {
+        self.proxy_to = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_proxy_to(&mut self) -> &mut ::std::string::String {
+        if self.proxy_to.is_none() {
+            self.proxy_to.set_default();
+        };
+        self.proxy_to.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_proxy_to(&mut self) -> ::std::string::String {
+        self.proxy_to.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_proxy_to(&self) -> &str {
+        match self.proxy_to.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // optional uint32 gossip_version = 5;
+
+    pub fn clear_gossip_version(&mut self) {
+        self.gossip_version = ::std::option::Option::None;
+    }
+
+    pub fn has_gossip_version(&self) -> bool {
+        self.gossip_version.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_gossip_version(&mut self, v: u32) {
+        self.gossip_version = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_gossip_version(&self) -> u32 {
+        self.gossip_version.unwrap_or(0)
+    }
+}
+
+impl ::protobuf::Message for Peer {
+    fn is_initialized(&self) -> bool {
+        if self.member_id.is_none() {
+            return false;
+        };
+        if self.listening_on.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.member_id));
+                },
+                2 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.listening_on));
+                },
+                3 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.proxy_through));
+                },
+                4 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.proxy_to));
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint32());
+                    self.gossip_version = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.member_id.iter() {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        for value in self.listening_on.iter() {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        for value in self.proxy_through.iter() {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        for value in self.proxy_to.iter() {
+            my_size += ::protobuf::rt::string_size(4, &value);
+        };
+        for value in self.gossip_version.iter() {
+            my_size += ::protobuf::rt::value_size(5, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.member_id.as_ref() {
+            try!(os.write_string(1, &v));
+        };
+        if let Some(v) = self.listening_on.as_ref() {
+            try!(os.write_string(2, &v));
+        };
+        if let Some(v) = self.proxy_through.as_ref() {
+            try!(os.write_string(3, &v));
+        };
+        if let Some(v) = self.proxy_to.as_ref() {
+            try!(os.write_string(4, &v));
+        };
+        if let Some(v) = self.gossip_version {
+            try!(os.write_uint32(5, v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<Peer>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for Peer {
+    fn new() -> Peer {
+        Peer::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<Peer>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "member_id",
+                    Peer::has_member_id,
+                    Peer::get_member_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "listening_on",
+                    Peer::has_listening_on,
+                    Peer::get_listening_on,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "proxy_through",
+                    Peer::has_proxy_through,
+                    Peer::get_proxy_through,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "proxy_to",
+                    Peer::has_proxy_to,
+                    Peer::get_proxy_to,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u32_accessor(
+                    "gossip_version",
+                    Peer::has_gossip_version,
+                    Peer::get_gossip_version,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Peer>(
+                    "Peer",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for Peer {
+    fn clear(&mut self) {
+        self.clear_member_id();
+        self.clear_listening_on();
+        self.clear_proxy_through();
+        self.clear_proxy_to();
+        self.clear_gossip_version();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for Peer {
+    fn eq(&self, other: &Peer) -> bool {
+        self.member_id == other.member_id &&
+        self.listening_on == other.listening_on &&
+        self.proxy_through == other.proxy_through &&
+        self.proxy_to == other.proxy_to &&
+        self.gossip_version == other.gossip_version &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for Peer {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct Rumor {
+    // message fields
+    id: ::protobuf::SingularField<::std::string::String>,
+    member: ::protobuf::SingularPtrField<Member>,
+    census_entry: ::protobuf::SingularPtrField<CensusEntry>,
+    election: ::protobuf::SingularPtrField<Election>,
+    gossip_file: ::protobuf::SingularPtrField<GossipFile>,
+    key_roll: ::protobuf::SingularPtrField<KeyRoll>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for Rumor {}
+
+impl Rumor {
+    pub fn new() -> Rumor {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static Rumor {
+        static mut instance: ::protobuf::lazy::Lazy<Rumor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Rumor,
+        };
+        unsafe {
+            instance.get(|| {
+                Rumor {
+                    id: ::protobuf::SingularField::none(),
+                    member: ::protobuf::SingularPtrField::none(),
+                    census_entry: ::protobuf::SingularPtrField::none(),
+                    election: ::protobuf::SingularPtrField::none(),
+                    gossip_file: ::protobuf::SingularPtrField::none(),
+                    key_roll: ::protobuf::SingularPtrField::none(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required string id = 1;
+
+    pub fn clear_id(&mut self) {
+        self.id.clear();
+    }
+
+    pub fn has_id(&self) -> bool {
+        self.id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: ::std::string::String) {
+        self.id = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+        if self.id.is_none() {
+            self.id.set_default();
+        };
+        self.id.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_id(&mut self) -> ::std::string::String {
+        self.id.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_id(&self) -> &str {
+        match self.id.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // optional .gossip.Member member = 2;
+
+    pub fn clear_member(&mut self) {
+        self.member.clear();
+    }
+
+    pub fn has_member(&self) -> bool {
+        self.member.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_member(&mut self, v: Member) {
+        self.member = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_member(&mut self) -> &mut Member {
+        if self.member.is_none() {
+            self.member.set_default();
+        };
+        self.member.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_member(&mut self) -> Member {
+        self.member.take().unwrap_or_else(|| Member::new())
+    }
+
+    pub fn get_member(&self) -> &Member {
+        self.member.as_ref().unwrap_or_else(|| Member::default_instance())
+    }
+
+    // optional .gossip.CensusEntry census_entry = 3;
+
+    pub fn clear_census_entry(&mut self) {
+        self.census_entry.clear();
+    }
+
+    pub fn has_census_entry(&self) -> bool {
+        self.census_entry.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_census_entry(&mut self, v: CensusEntry) {
+        self.census_entry = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_census_entry(&mut self) -> &mut CensusEntry {
+        if self.census_entry.is_none() {
+            self.census_entry.set_default();
+        };
+        self.census_entry.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_census_entry(&mut self) -> CensusEntry {
+        self.census_entry.take().unwrap_or_else(|| CensusEntry::new())
+    }
+
+    pub fn get_census_entry(&self) -> &CensusEntry {
+        self.census_entry.as_ref().unwrap_or_else(|| CensusEntry::default_instance())
+    }
+
+    // optional .gossip.Election election = 4;
+
+    pub fn clear_election(&mut self) {
+        self.election.clear();
+    }
+
+    pub fn has_election(&self) -> bool {
+        self.election.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_election(&mut self, v: Election) {
+        self.election = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_election(&mut self) -> &mut Election {
+        if self.election.is_none() {
+            self.election.set_default();
+        };
+        self.election.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_election(&mut self) -> Election {
+        self.election.take().unwrap_or_else(|| Election::new())
+    }
+
+    pub fn get_election(&self) -> &Election {
+        self.election.as_ref().unwrap_or_else(|| Election::default_instance())
+    }
+
+    // optional .gossip.GossipFile gossip_file = 5;
+
+    pub fn clear_gossip_file(&mut self) {
+        self.gossip_file.clear();
+    }
+
+    pub fn has_gossip_file(&self) -> bool {
+        self.gossip_file.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_gossip_file(&mut self, v: GossipFile) {
+        self.gossip_file = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_gossip_file(&mut self) -> &mut GossipFile {
+        if self.gossip_file.is_none() {
+            self.gossip_file.set_default();
+        };
+        self.gossip_file.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_gossip_file(&mut self) -> GossipFile {
+        self.gossip_file.take().unwrap_or_else(|| GossipFile::new())
+    }
+
+    pub fn get_gossip_file(&self) -> &GossipFile {
+        self.gossip_file.as_ref().unwrap_or_else(|| GossipFile::default_instance())
+    }
+
+    // optional .gossip.KeyRoll key_roll = 6;
+
+    pub fn clear_key_roll(&mut self) {
+        self.key_roll.clear();
+    }
+
+    pub fn has_key_roll(&self) -> bool {
+        self.key_roll.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key_roll(&mut self, v: KeyRoll) {
+        self.key_roll = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key_roll(&mut self) -> &mut KeyRoll {
+        if self.key_roll.is_none() {
+            self.key_roll.set_default();
+        };
+        self.key_roll.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_key_roll(&mut self) -> KeyRoll {
+        self.key_roll.take().unwrap_or_else(|| KeyRoll::new())
+    }
+
+    pub fn get_key_roll(&self) -> &KeyRoll {
+        self.key_roll.as_ref().unwrap_or_else(|| KeyRoll::default_instance())
+    }
+}
+
+impl ::protobuf::Message for Rumor {
+    fn is_initialized(&self) -> bool {
+        if self.id.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.id));
+                },
+                2 => {
+                    try!(::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.member));
+                },
+                3 => {
+                    try!(::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.census_entry));
+                },
+                4 => {
+                    try!(::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.election));
+                },
+                5 => {
+                    try!(::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.gossip_file));
+                },
+                6 => {
+                    try!(::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.key_roll));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.id.iter() {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        for value in self.member.iter() {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in self.census_entry.iter() {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in self.election.iter() {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in self.gossip_file.iter() {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in self.key_roll.iter() {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.id.as_ref() {
+            try!(os.write_string(1, &v));
+        };
+        if let Some(v) = self.member.as_ref() {
+            try!(os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited));
+            try!(os.write_raw_varint32(v.get_cached_size()));
+            try!(v.write_to_with_cached_sizes(os));
+        };
+        if let Some(v) = self.census_entry.as_ref() {
+            try!(os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited));
+            try!(os.write_raw_varint32(v.get_cached_size()));
+            try!(v.write_to_with_cached_sizes(os));
+        };
+        if let Some(v) = self.election.as_ref() {
+            try!(os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited));
+            try!(os.write_raw_varint32(v.get_cached_size()));
+            try!(v.write_to_with_cached_sizes(os));
+        };
+        if let Some(v) = self.gossip_file.as_ref() {
+            try!(os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited));
+            try!(os.write_raw_varint32(v.get_cached_size()));
+            try!(v.write_to_with_cached_sizes(os));
+        };
+        if let Some(v) = self.key_roll.as_ref() {
+            try!(os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited));
+            try!(os.write_raw_varint32(v.get_cached_size()));
+            try!(v.write_to_with_cached_sizes(os));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<Rumor>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for Rumor {
+    fn new() -> Rumor {
+        Rumor::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<Rumor>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "id",
+                    Rumor::has_id,
+                    Rumor::get_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor(
+                    "member",
+                    Rumor::has_member,
+                    Rumor::get_member,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor(
+                    "census_entry",
+                    Rumor::has_census_entry,
+                    Rumor::get_census_entry,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor(
+                    "election",
+                    Rumor::has_election,
+                    Rumor::get_election,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor(
+                    "gossip_file",
+                    Rumor::has_gossip_file,
+                    Rumor::get_gossip_file,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor(
+                    "key_roll",
+                    Rumor::has_key_roll,
+                    Rumor::get_key_roll,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Rumor>(
+                    "Rumor",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for Rumor {
+    fn clear(&mut self) {
+        self.clear_id();
+        self.clear_member();
+        self.clear_census_entry();
+        self.clear_election();
+        self.clear_gossip_file();
+        self.clear_key_roll();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for Rumor {
+    fn eq(&self, other: &Rumor) -> bool {
+        self.id == other.id &&
+        self.member == other.member &&
+        self.census_entry == other.census_entry &&
+        self.election == other.election &&
+        self.gossip_file == other.gossip_file &&
+        self.key_roll == other.key_roll &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for Rumor {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct Member {
+    // message fields
+    id: ::protobuf::SingularField<::std::string::String>,
+    hostname: ::protobuf::SingularField<::std::string::String>,
+    ip: ::protobuf::SingularField<::std::string::String>,
+    gossip_listener: ::protobuf::SingularField<::std::string::String>,
+    incarnation: ::std::option::Option<u64>,
+    health: ::std::option::Option<Member_Health>,
+    permanent: ::std::option::Option<bool>,
+    suspected_by: ::protobuf::SingularField<::std::string::String>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for Member {}
+
+impl Member {
+    pub fn new() -> Member {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static Member {
+        static mut instance: ::protobuf::lazy::Lazy<Member> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Member,
+        };
+        unsafe {
+            instance.get(|| {
+                Member {
+                    id: ::protobuf::SingularField::none(),
+                    hostname: ::protobuf::SingularField::none(),
+                    ip: ::protobuf::SingularField::none(),
+                    gossip_listener: ::protobuf::SingularField::none(),
+                    incarnation: ::std::option::Option::None,
+                    health: ::std::option::Option::None,
+                    permanent: ::std::option::Option::None,
+                    suspected_by: ::protobuf::SingularField::none(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required string id = 1;
+
+    pub fn clear_id(&mut self) {
+        self.id.clear();
+    }
+
+    pub fn has_id(&self) -> bool {
+        self.id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: ::std::string::String) {
+        self.id = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+        if self.id.is_none() {
+            self.id.set_default();
+        };
+        self.id.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_id(&mut self) -> ::std::string::String {
+        self.id.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_id(&self) -> &str {
+        match self.id.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required string hostname = 2;
+
+    pub fn clear_hostname(&mut self) {
+        self.hostname.clear();
+    }
+
+    pub fn has_hostname(&self) -> bool {
+        self.hostname.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_hostname(&mut self, v: ::std::string::String) {
+        self.hostname = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_hostname(&mut self) -> &mut ::std::string::String {
+        if self.hostname.is_none() {
+            self.hostname.set_default();
+        };
+        self.hostname.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_hostname(&mut self) -> ::std::string::String {
+        self.hostname.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_hostname(&self) -> &str {
+        match self.hostname.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required string ip = 3;
+
+    pub fn clear_ip(&mut self) {
+        self.ip.clear();
+    }
+
+    pub fn has_ip(&self) -> bool {
+        self.ip.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ip(&mut self, v: ::std::string::String) {
+        self.ip = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ip(&mut self) -> &mut ::std::string::String {
+        if self.ip.is_none() {
+            self.ip.set_default();
+        };
+        self.ip.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_ip(&mut self) -> ::std::string::String {
+        self.ip.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_ip(&self) -> &str {
+        match self.ip.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required string gossip_listener = 4;
+
+    pub fn clear_gossip_listener(&mut self) {
+        self.gossip_listener.clear();
+    }
+
+    pub fn has_gossip_listener(&self) -> bool {
+        self.gossip_listener.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_gossip_listener(&mut self, v: ::std::string::String) {
+        self.gossip_listener = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_gossip_listener(&mut self) -> &mut ::std::string::String {
+        if self.gossip_listener.is_none() {
+            self.gossip_listener.set_default();
+        };
+        self.gossip_listener.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_gossip_listener(&mut self) -> ::std::string::String {
+        self.gossip_listener.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_gossip_listener(&self) -> &str {
+        match self.gossip_listener.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required uint64 incarnation = 5;
+
+    pub fn clear_incarnation(&mut self) {
+        self.incarnation = ::std::option::Option::None;
+    }
+
+    pub fn has_incarnation(&self) -> bool {
+        self.incarnation.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_incarnation(&mut self, v: u64) {
+        self.incarnation = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_incarnation(&self) -> u64 {
+        self.incarnation.unwrap_or(0)
+    }
+
+    // required .gossip.Member.Health health = 6;
+
+    pub fn clear_health(&mut self) {
+        self.health = ::std::option::Option::None;
+    }
+
+    pub fn has_health(&self) -> bool {
+        self.health.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_health(&mut self, v: Member_Health) {
+        self.health = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_health(&self) -> Member_Health {
+        self.health.unwrap_or(Member_Health::ALIVE)
+    }
+
+    // optional bool permanent = 7;
+
+    pub fn clear_permanent(&mut self) {
+        self.permanent = ::std::option::Option::None;
+    }
+
+    pub fn has_permanent(&self) -> bool {
+        self.permanent.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_permanent(&mut self, v: bool) {
+        self.permanent = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_permanent(&self) -> bool {
+        self.permanent.unwrap_or(false)
+    }
+
+    // optional string suspected_by = 8;
+
+    pub fn clear_suspected_by(&mut self) {
+        self.suspected_by.clear();
+    }
+
+    pub fn has_suspected_by(&self) -> bool {
+        self.suspected_by.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_suspected_by(&mut self, v: ::std::string::String) {
+        self.suspected_by = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_suspected_by(&mut self) -> &mut ::std::string::String {
+        if self.suspected_by.is_none() {
+            self.suspected_by.set_default();
+        };
+        self.suspected_by.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_suspected_by(&mut self) -> ::std::string::String {
+        self.suspected_by.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_suspected_by(&self) -> &str {
+        match self.suspected_by.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+}
+
+impl ::protobuf::Message for Member {
+    fn is_initialized(&self) -> bool {
+        if self.id.is_none() {
+            return false;
+        };
+        if self.hostname.is_none() {
+            return false;
+        };
+        if self.ip.is_none() {
+            return false;
+        };
+        if self.gossip_listener.is_none() {
+            return false;
+        };
+        if self.incarnation.is_none() {
+            return false;
+        };
+        if self.health.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.id));
+                },
+                2 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.hostname));
+                },
+                3 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.ip));
+                },
+                4 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.gossip_listener));
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.incarnation = ::std::option::Option::Some(tmp);
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_enum());
+                    self.health = ::std::option::Option::Some(tmp);
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_bool());
+                    self.permanent = ::std::option::Option::Some(tmp);
+                },
+                8 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.suspected_by));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.id.iter() {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        for value in self.hostname.iter() {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        for value in self.ip.iter() {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        for value in self.gossip_listener.iter() {
+            my_size += ::protobuf::rt::string_size(4, &value);
+        };
+        for value in self.incarnation.iter() {
+            my_size += ::protobuf::rt::value_size(5, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.health.iter() {
+            my_size += ::protobuf::rt::enum_size(6, *value);
+        };
+        if self.permanent.is_some() {
+            my_size += 2;
+        };
+        for value in self.suspected_by.iter() {
+            my_size += ::protobuf::rt::string_size(8, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.id.as_ref() {
+            try!(os.write_string(1, &v));
+        };
+        if let Some(v) = self.hostname.as_ref() {
+            try!(os.write_string(2, &v));
+        };
+        if let Some(v) = self.ip.as_ref() {
+            try!(os.write_string(3, &v));
+        };
+        if let Some(v) = self.gossip_listener.as_ref() {
+            try!(os.write_string(4, &v));
+        };
+        if let Some(v) = self.incarnation {
+            try!(os.write_uint64(5, v));
+        };
+        if let Some(v) = self.health {
+            try!(os.write_enum(6, v.value()));
+        };
+        if let Some(v) = self.permanent {
+            try!(os.write_bool(7, v));
+        };
+        if let Some(v) = self.suspected_by.as_ref() {
+            try!(os.write_string(8, &v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<Member>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for Member {
+    fn new() -> Member {
+        Member::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<Member>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "id",
+                    Member::has_id,
+                    Member::get_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "hostname",
+                    Member::has_hostname,
+                    Member::get_hostname,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "ip",
+                    Member::has_ip,
+                    Member::get_ip,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "gossip_listener",
+                    Member::has_gossip_listener,
+                    Member::get_gossip_listener,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "incarnation",
+                    Member::has_incarnation,
+                    Member::get_incarnation,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor(
+                    "health",
+                    Member::has_health,
+                    Member::get_health,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_bool_accessor(
+                    "permanent",
+                    Member::has_permanent,
+                    Member::get_permanent,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "suspected_by",
+                    Member::has_suspected_by,
+                    Member::get_suspected_by,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Member>(
+                    "Member",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for Member {
+    fn clear(&mut self) {
+        self.clear_id();
+        self.clear_hostname();
+        self.clear_ip();
+        self.clear_gossip_listener();
+        self.clear_incarnation();
+        self.clear_health();
+        self.clear_permanent();
+        self.clear_suspected_by();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for Member {
+    fn eq(&self, other: &Member) -> bool {
+        self.id == other.id &&
+        self.hostname == other.hostname &&
+        self.ip == other.ip &&
+        self.gossip_listener == other.gossip_listener &&
+        self.incarnation == other.incarnation &&
+        self.health == other.health &&
+        self.permanent == other.permanent &&
+        self.suspected_by == other.suspected_by &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for Member {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum Member_Health {
+    ALIVE = 1,
+    SUSPECT = 2,
+    CONFIRMED = 3,
+    DEPARTED = 4,
+}
+
+impl ::protobuf::ProtobufEnum for Member_Health {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<Member_Health> {
+        match value {
+            1 => ::std::option::Option::Some(Member_Health::ALIVE),
+            2 => ::std::option::Option::Some(Member_Health::SUSPECT),
+            3 => ::std::option::Option::Some(Member_Health::CONFIRMED),
+            4 => ::std::option::Option::Some(Member_Health::DEPARTED),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [Member_Health] = &[
+            Member_Health::ALIVE,
+            Member_Health::SUSPECT,
+            Member_Health::CONFIRMED,
+            Member_Health::DEPARTED,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static(_: Option<Member_Health>) -> &'static ::protobuf::reflect::EnumDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new("Member_Health", file_descriptor_proto())
+            })
+        }
+    }
+}
+
+impl ::std::marker::Copy for Member_Health {
+}
+
+#[derive(Clone,Default)]
+pub struct CensusEntry {
+    // message fields
+    id: ::protobuf::SingularField<::std::string::String>,
+    member_id: ::protobuf::SingularField<::std::string::String>,
+    hostname: ::protobuf::SingularField<::std::string::String>,
+    ip: ::protobuf::SingularField<::std::string::String>,
+    suitability: ::std::option::Option<u64>,
+    port: ::protobuf::SingularField<::std::string::String>,
+    has_exposes: ::std::option::Option<bool>,
+    exposes: ::protobuf::RepeatedField<::std::string::String>,
+    leader: ::std::option::Option<bool>,
+    follower: ::std::option::Option<bool>,
+    data_init: ::std::option::Option<bool>,
+    vote: ::protobuf::SingularField<::std::string::String>,
+    election: ::std::option::Option<bool>,
+    needs_write: ::std::option::Option<bool>,
+    initialized: ::std::option::Option<bool>,
+    keep_me: ::std::option::Option<bool>,
+    service: ::protobuf::SingularField<::std::string::String>,
+    group: ::protobuf::SingularField<::std::string::String>,
+    alive: ::std::option::Option<bool>,
+    suspect: ::std::option::Option<bool>,
+    confirmed: ::std::option::Option<bool>,
+    detached: ::std::option::Option<bool>,
+    update_pending: ::std::option::Option<bool>,
+    updating: ::std::option::Option<bool>,
+    health_check: ::std::option::Option<CensusEntry_HealthCheck>,
+    incarnation: ::std::option::Option<u64>,
+    crash_loop: ::std::option::Option<bool>,
+    rolled_back: ::protobuf::SingularField<::std::string::String>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for CensusEntry {}
+
+impl CensusEntry {
+    pub fn new() -> CensusEntry {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static CensusEntry {
+        static mut instance: ::protobuf::lazy::Lazy<CensusEntry> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const CensusEntry,
+        };
+        unsafe {
+            instance.get(|| {
+                CensusEntry {
+                    id: ::protobuf::SingularField::none(),
+                    member_id: ::protobuf::SingularField::none(),
+                    hostname: ::protobuf::SingularField::none(),
+                    ip: ::protobuf::SingularField::none(),
+                    suitability: ::std::option::Option::None,
+                    port: ::protobuf::SingularField::none(),
+                    has_exposes: ::std::option::Option::None,
+                    exposes: ::protobuf::RepeatedField::new(),
+                    leader: ::std::option::Option::None,
+                    follower: ::std::option::Option::None,
+                    data_init: ::std::option::Option::None,
+                    vote: ::protobuf::SingularField::none(),
+                    election: ::std::option::Option::None,
+                    needs_write: ::std::option::Option::None,
+                    initialized: ::std::option::Option::None,
+                    keep_me: ::std::option::Option::None,
+                    service: ::protobuf::SingularField::none(),
+                    group: ::protobuf::SingularField::none(),
+                    alive: ::std::option::Option::None,
+                    suspect: ::std::option::Option::None,
+                    confirmed: ::std::option::Option::None,
+                    detached: ::std::option::Option::None,
+                    update_pending: ::std::option::Option::None,
+                    updating: ::std::option::Option::None,
+                    health_check: ::std::option::Option::None,
+                    incarnation: ::std::option::Option::None,
+                    crash_loop: ::std::option::Option::None,
+                    rolled_back: ::protobuf::SingularField::none(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required string id = 1;
+
+    pub fn clear_id(&mut self) {
+        self.id.clear();
+    }
+
+    pub fn has_id(&self) -> bool {
+        self.id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: ::std::string::String) {
+        self.id = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+        if self.id.is_none() {
+            self.id.set_default();
+        };
+        self.id.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_id(&mut self) -> ::std::string::String {
+        self.id.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_id(&self) -> &str {
+        match self.id.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required string member_id = 2;
+
+    pub fn clear_member_id(&mut self) {
+        self.member_id.clear();
+    }
+
+    pub fn has_member_id(&self) -> bool {
+        self.member_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_member_id(&mut self, v: ::std::string::String) {
+        self.member_id = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_member_id(&mut self) -> &mut ::std::string::String {
+        if self.member_id.is_none() {
+            self.member_id.set_default();
+        };
+        self.member_id.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_member_id(&mut self) -> ::std::string::String {
+        self.member_id.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_member_id(&self) -> &str {
+        match self.member_id.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required string hostname = 3;
+
+    pub fn clear_hostname(&mut self) {
+        self.hostname.clear();
+    }
+
+    pub fn has_hostname(&self) -> bool {
+        self.hostname.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_hostname(&mut self, v: ::std::string::String) {
+        self.hostname = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_hostname(&mut self) -> &mut ::std::string::String {
+        if self.hostname.is_none() {
+            self.hostname.set_default();
+        };
+        self.hostname.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_hostname(&mut self) -> ::std::string::String {
+        self.hostname.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_hostname(&self) -> &str {
+        match self.hostname.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required string ip = 4;
+
+    pub fn clear_ip(&mut self) {
+        self.ip.clear();
+    }
+
+    pub fn has_ip(&self) -> bool {
+        self.ip.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ip(&mut self, v: ::std::string::String) {
+        self.ip = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ip(&mut self) -> &mut ::std::string::String {
+        if self.ip.is_none() {
+            self.ip.set_default();
+        };
+        self.ip.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_ip(&mut self) -> ::std::string::String {
+        self.ip.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_ip(&self) -> &str {
+        match self.ip.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // optional uint64 suitability = 5;
+
+    pub fn clear_suitability(&mut self) {
+        self.suitability = ::std::option::Option::None;
+    }
+
+    pub fn has_suitability(&self) -> bool {
+        self.suitability.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_suitability(&mut self, v: u64) {
+        self.suitability = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_suitability(&self) -> u64 {
+        self.suitability.unwrap_or(0)
+    }
+
+    // optional string port = 6;
+
+    pub fn clear_port(&mut self) {
+        self.port.clear();
+    }
+
+    pub fn has_port(&self) -> bool {
+        self.port.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_port(&mut self, v: ::std::string::String) {
+        self.port = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_port(&mut self) -> &mut ::std::string::String {
+        if self.port.is_none() {
+            self.port.set_default();
+        };
+        self.port.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_port(&mut self) -> ::std::string::String {
+        self.port.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_port(&self) -> &str {
+        match self.port.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // optional bool has_exposes = 7;
+
+    pub fn clear_has_exposes(&mut self) {
+        self.has_exposes = ::std::option::Option::None;
+    }
+
+    pub fn has_has_exposes(&self) -> bool {
+        self.has_exposes.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_has_exposes(&mut self, v: bool) {
+        self.has_exposes = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_has_exposes(&self) -> bool {
+        self.has_exposes.unwrap_or(false)
+    }
+
+    // repeated string exposes = 8;
+
+    pub fn clear_exposes(&mut self) {
+        self.exposes.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_exposes(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.exposes = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_exposes(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.exposes
+    }
+
+    // Take field
+    pub fn take_exposes(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.exposes, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_exposes(&self) -> &[::std::string::String] {
+        &self.exposes
+    }
+
+    // optional bool leader = 9;
+
+    pub fn clear_leader(&mut self) {
+        self.leader = ::std::option::Option::None;
+    }
+
+    pub fn has_leader(&self) -> bool {
+        self.leader.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_leader(&mut self, v: bool) {
+        self.leader = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_leader(&self) -> bool {
+        self.leader.unwrap_or(false)
+    }
+
+    // optional bool follower = 10;
+
+    pub fn clear_follower(&mut self) {
+        self.follower = ::std::option::Option::None;
+    }
+
+    pub fn has_follower(&self) -> bool {
+        self.follower.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_follower(&mut self, v: bool) {
+        self.follower = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_follower(&self) -> bool {
+        self.follower.unwrap_or(false)
+    }
+
+    // optional bool data_init = 11;
+
+    pub fn clear_data_init(&mut self) {
+        self.data_init = ::std::option::Option::None;
+    }
+
+    pub fn has_data_init(&self) -> bool {
+        self.data_init.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_data_init(&mut self, v: bool) {
+        self.data_init = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_data_init(&self) -> bool {
+        self.data_init.unwrap_or(false)
+    }
+
+    // optional string vote = 12;
+
+    pub fn clear_vote(&mut self) {
+        self.vote.clear();
+    }
+
+    pub fn has_vote(&self) -> bool {
+        self.vote.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_vote(&mut self, v: ::std::string::String) {
+        self.vote = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_vote(&mut self) -> &mut ::std::string::String {
+        if self.vote.is_none() {
+            self.vote.set_default();
+        };
+        self.vote.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_vote(&mut self) -> ::std::string::String {
+        self.vote.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_vote(&self) -> &str {
+        match self.vote.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // optional bool election = 13;
+
+    pub fn clear_election(&mut self) {
+        self.election = ::std::option::Option::None;
+    }
+
+    pub fn has_election(&self) -> bool {
+        self.election.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_election(&mut self, v: bool) {
+        self.election = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_election(&self) -> bool {
+        self.election.unwrap_or(false)
+    }
+
+    // optional bool needs_write = 14;
+
+    pub fn clear_needs_write(&mut self) {
+        self.needs_write = ::std::option::Option::None;
+    }
+
+    pub fn has_needs_write(&self) -> bool {
+        self.needs_write.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_needs_write(&mut self, v: bool) {
+        self.needs_write = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_needs_write(&self) -> bool {
+        self.needs_write.unwrap_or(false)
+    }
+
+    // optional bool initialized = 15;
+
+    pub fn clear_initialized(&mut self) {
+        self.initialized = ::std::option::Option::None;
+    }
+
+    pub fn has_initialized(&self) -> bool {
+        self.initialized.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_initialized(&mut self, v: bool) {
+        self.initialized = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_initialized(&self) -> bool {
+        self.initialized.unwrap_or(false)
+    }
+
+    // optional bool keep_me = 16;
+
+    pub fn clear_keep_me(&mut self) {
+        self.keep_me = ::std::option::Option::None;
+    }
+
+    pub fn has_keep_me(&self) -> bool {
+        self.keep_me.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_keep_me(&mut self, v: bool) {
+        self.keep_me = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_keep_me(&self) -> bool {
+        self.keep_me.unwrap_or(false)
+    }
+
+    // required string service = 17;
+
+    pub fn clear_service(&mut self) {
+        self.service.clear();
+    }
+
+    pub fn has_service(&self) -> bool {
+        self.service.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_service(&mut self, v: ::std::string::String) {
+        self.service = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_service(&mut self) -> &mut ::std::string::String {
+        if self.service.is_none() {
+            self.service.set_default();
+        };
+        self.service.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_service(&mut self) -> ::std::string::String {
+        self.service.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_service(&self) -> &str {
+        match self.service.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required string group = 18;
+
+    pub fn clear_group(&mut self) {
+        self.group.clear();
+    }
+
+    pub fn has_group(&self) -> bool {
+        self.group.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_group(&mut self, v: ::std::string::String) {
+        self.group = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_group(&mut self) -> &mut ::std::string::String {
+        if self.group.is_none() {
+            self.group.set_default();
+        };
+        self.group.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_group(&mut self) -> ::std::string::String {
+        self.group.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_group(&self) -> &str {
+        match self.group.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // optional bool alive = 19;
+
+    pub fn clear_alive(&mut self) {
+        self.alive = ::std::option::Option::None;
+    }
+
+    pub fn has_alive(&self) -> bool {
+        self.alive.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_alive(&mut self, v: bool) {
+        self.alive = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_alive(&self) -> bool {
+        self.alive.unwrap_or(false)
+    }
+
+    // optional bool suspect = 20;
+
+    pub fn clear_suspect(&mut self) {
+        self.suspect = ::std::option::Option::None;
+    }
+
+    pub fn has_suspect(&self) -> bool {
+        self.suspect.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_suspect(&mut self, v: bool) {
+        self.suspect = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_suspect(&self) -> bool {
+        self.suspect.unwrap_or(false)
+    }
+
+    // optional bool confirmed = 21;
+
+    pub fn clear_confirmed(&mut self) {
+        self.confirmed = ::std::option::Option::None;
+    }
+
+    pub fn has_confirmed(&self) -> bool {
+        self.confirmed.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_confirmed(&mut self, v: bool) {
+        self.confirmed = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_confirmed(&self) -> bool {
+        self.confirmed.unwrap_or(false)
+    }
+
+    // optional bool detached = 22;
+
+    pub fn clear_detached(&mut self) {
+        self.detached = ::std::option::Option::None;
+    }
+
+    pub fn has_detached(&self) -> bool {
+        self.detached.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_detached(&mut self, v: bool) {
+        self.detached = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_detached(&self) -> bool {
+        self.detached.unwrap_or(false)
+    }
+
+    // optional bool update_pending = 23;
+
+    pub fn clear_update_pending(&mut self) {
+        self.update_pending = ::std::option::Option::None;
+    }
+
+    pub fn has_update_pending(&self) -> bool {
+        self.update_pending.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_update_pending(&mut self, v: bool) {
+        self.update_pending = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_update_pending(&self) -> bool {
+        self.update_pending.unwrap_or(false)
+    }
+
+    // optional bool updating = 24;
+
+    pub fn clear_updating(&mut self) {
+        self.updating = ::std::option::Option::None;
+    }
+
+    pub fn has_updating(&self) -> bool {
+        self.updating.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_updating(&mut self, v: bool) {
+        self.updating = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_updating(&self) -> bool {
+        self.updating.unwrap_or(false)
+    }
+
+    // optional .gossip.CensusEntry.HealthCheck health_check = 25;
+
+    pub fn clear_health_check(&mut self) {
+        self.health_check = ::std::option::Option::None;
+    }
+
+    pub fn has_health_check(&self) -> bool {
+        self.health_check.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_health_check(&mut self, v: CensusEntry_HealthCheck) {
+        self.health_check = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_health_check(&self) -> CensusEntry_HealthCheck {
+        self.health_check.unwrap_or(CensusEntry_HealthCheck::OK)
+    }
+
+    // required uint64 incarnation = 26;
+
+    pub fn clear_incarnation(&mut self) {
+        self.incarnation = ::std::option::Option::None;
+    }
+
+    pub fn has_incarnation(&self) -> bool {
+        self.incarnation.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_incarnation(&mut self, v: u64) {
+        self.incarnation = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_incarnation(&self) -> u64 {
+        self.incarnation.unwrap_or(0)
+    }
+
+    // optional bool crash_loop = 27;
+
+    pub fn clear_crash_loop(&mut self) {
+        self.crash_loop = ::std::option::Option::None;
+    }
+
+    pub fn has_crash_loop(&self) -> bool {
+        self.crash_loop.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_crash_loop(&mut self, v: bool) {
+        self.crash_loop = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_crash_loop(&self) -> bool {
+        self.crash_loop.unwrap_or(false)
+    }
+
+    // optional string rolled_back = 28;
+
+    pub fn clear_rolled_back(&mut self) {
+        self.rolled_back.clear();
+    }
+
+    pub fn has_rolled_back(&self) -> bool {
+        self.rolled_back.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rolled_back(&mut self, v: ::std::string::String) {
+        self.rolled_back = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_rolled_back(&mut self) -> &mut ::std::string::String {
+        if self.rolled_back.is_none() {
+            self.rolled_back.set_default();
+        };
+        self.rolled_back.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_rolled_back(&mut self) -> ::std::string::String {
+        self.rolled_back.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_rolled_back(&self) -> &str {
+        match self.rolled_back.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+}
+
+impl ::protobuf::Message for CensusEntry {
+    fn is_initialized(&self) -> bool {
+        if self.id.is_none() {
+            return false;
+        };
+        if self.member_id.is_none() {
+            return false;
+        };
+        if self.hostname.is_none() {
+            return false;
+        };
+        if self.ip.is_none() {
+            return false;
+        };
+        if self.service.is_none() {
+            return false;
+        };
+        if self.group.is_none() {
+            return false;
+        };
+        if self.incarnation.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.id));
+                },
+                2 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.member_id));
+                },
+                3 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.hostname));
+                },
+                4 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.ip));
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.suitability = ::std::option::Option::Some(tmp);
+                },
+                6 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.port));
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_bool());
+                    self.has_exposes = ::std::option::Option::Some(tmp);
+                },
+                8 => {
+                    try!(::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.exposes));
+                },
+                9 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_bool());
+                    self.leader = ::std::option::Option::Some(tmp);
+                },
+                10 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_bool());
+                    self.follower = ::std::option::Option::Some(tmp);
+                },
+                11 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_bool());
+                    self.data_init = ::std::option::Option::Some(tmp);
+                },
+                12 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.vote));
+                },
+                13 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_bool());
+                    self.election = ::std::option::Option::Some(tmp);
+                },
+                14 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_bool());
+                    self.needs_write = ::std::option::Option::Some(tmp);
+                },
+                15 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_bool());
+                    self.initialized = ::std::option::Option::Some(tmp);
+                },
+                16 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_bool());
+                    self.keep_me = ::std::option::Option::Some(tmp);
+                },
+                17 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.service));
+                },
+                18 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.group));
+                },
+                19 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_bool());
+                    self.alive = ::std::option::Option::Some(tmp);
+                },
+                20 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_bool());
+                    self.suspect = ::std::option::Option::Some(tmp);
+                },
+                21 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_bool());
+                    self.confirmed = ::std::option::Option::Some(tmp);
+                },
+                22 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_bool());
+                    self.detached = ::std::option::Option::Some(tmp);
+                },
+                23 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_bool());
+                    self.update_pending = ::std::option::Option::Some(tmp);
+                },
+                24 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_bool());
+                    self.updating = ::std::option::Option::Some(tmp);
+                },
+                25 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_enum());
+                    self.health_check = ::std::option::Option::Some(tmp);
+                },
+                26 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.incarnation = ::std::option::Option::Some(tmp);
+                },
+                27 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_bool());
+                    self.crash_loop = ::std::option::Option::Some(tmp);
+                },
+                28 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.rolled_back));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.id.iter() {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        for value in self.member_id.iter() {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        for value in self.hostname.iter() {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        for value in self.ip.iter() {
+            my_size += ::protobuf::rt::string_size(4, &value);
+        };
+        for value in self.suitability.iter() {
+            my_size += ::protobuf::rt::value_size(5, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.port.iter() {
+            my_size += ::protobuf::rt::string_size(6, &value);
+        };
+        if self.has_exposes.is_some() {
+            my_size += 2;
+        };
+        for value in self.exposes.iter() {
+            my_size += ::protobuf::rt::string_size(8, &value);
+        };
+        if self.leader.is_some() {
+            my_size += 2;
+        };
+        if self.follower.is_some() {
+            my_size += 2;
+        };
+        if self.data_init.is_some() {
+            my_size += 2;
+        };
+        for value in self.vote.iter() {
+            my_size += ::protobuf::rt::string_size(12, &value);
+        };
+        if self.election.is_some() {
+            my_size += 2;
+        };
+        if self.needs_write.is_some() {
+            my_size += 2;
+        };
+        if self.initialized.is_some() {
+            my_size += 2;
+        };
+        if self.keep_me.is_some() {
+            my_size += 3;
+        };
+        for value in self.service.iter() {
+            my_size += ::protobuf::rt::string_size(17, &value);
+        };
+        for value in self.group.iter() {
+            my_size += ::protobuf::rt::string_size(18, &value);
+        };
+        if self.alive.is_some() {
+            my_size += 3;
+        };
+        if self.suspect.is_some() {
+            my_size += 3;
+        };
+        if self.confirmed.is_some() {
+            my_size += 3;
+        };
+        if self.detached.is_some() {
+            my_size += 3;
+        };
+        if self.update_pending.is_some() {
+            my_size += 3;
+        };
+        if self.updating.is_some() {
+            my_size += 3;
+        };
+        for value in self.health_check.iter() {
+            my_size += ::protobuf::rt::enum_size(25, *value);
+        };
+        for value in self.incarnation.iter() {
+            my_size += ::protobuf::rt::value_size(26, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        if self.crash_loop.is_some() {
+            my_size += 3;
+        };
+        for value in self.rolled_back.iter() {
+            my_size += ::protobuf::rt::string_size(28, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.id.as_ref() {
+            try!(os.write_string(1, &v));
+        };
+        if let Some(v) = self.member_id.as_ref() {
+            try!(os.write_string(2, &v));
+        };
+        if let Some(v) = self.hostname.as_ref() {
+            try!(os.write_string(3, &v));
+        };
+        if let Some(v) = self.ip.as_ref() {
+            try!(os.write_string(4, &v));
+        };
+        if let Some(v) = self.suitability {
+            try!(os.write_uint64(5, v));
+        };
+        if let Some(v) = self.port.as_ref() {
+            try!(os.write_string(6, &v));
+        };
+        if let Some(v) = self.has_exposes {
+            try!(os.write_bool(7, v));
+        };
+        for v in self.exposes.iter() {
+            try!(os.write_string(8, &v));
+        };
+        if let Some(v) = self.leader {
+            try!(os.write_bool(9, v));
+        };
+        if let Some(v) = self.follower {
+            try!(os.write_bool(10, v));
+        };
+        if let Some(v) = self.data_init {
+            try!(os.write_bool(11, v));
+        };
+        if let Some(v) = self.vote.as_ref() {
+            try!(os.write_string(12, &v));
+        };
+        if let Some(v) = self.election {
+            try!(os.write_bool(13, v));
+        };
+        if let Some(v) = self.needs_write {
+            try!(os.write_bool(14, v));
+        };
+        if let Some(v) = self.initialized {
+            try!(os.write_bool(15, v));
+        };
+        if let Some(v) = self.keep_me {
+            try!(os.write_bool(16, v));
+        };
+        if let Some(v) = self.service.as_ref() {
+            try!(os.write_string(17, &v));
+        };
+        if let Some(v) = self.group.as_ref() {
+            try!(os.write_string(18, &v));
+        };
+        if let Some(v) = self.alive {
+            try!(os.write_bool(19, v));
+        };
+        if let Some(v) = self.suspect {
+            try!(os.write_bool(20, v));
+        };
+        if let Some(v) = self.confirmed {
+            try!(os.write_bool(21, v));
+        };
+        if let Some(v) = self.detached {
+            try!(os.write_bool(22, v));
+        };
+        if let Some(v) = self.update_pending {
+            try!(os.write_bool(23, v));
+        };
+        if let Some(v) = self.updating {
+            try!(os.write_bool(24, v));
+        };
+        if let Some(v) = self.health_check {
+            try!(os.write_enum(25, v.value()));
+        };
+        if let Some(v) = self.incarnation {
+            try!(os.write_uint64(26, v));
+        };
+        if let Some(v) = self.crash_loop {
+            try!(os.write_bool(27, v));
+        };
+        if let Some(v) = self.rolled_back.as_ref() {
+            try!(os.write_string(28, &v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<CensusEntry>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for CensusEntry {
+    fn new() -> CensusEntry {
+        CensusEntry::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<CensusEntry>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "id",
+                    CensusEntry::has_id,
+                    CensusEntry::get_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "member_id",
+                    CensusEntry::has_member_id,
+                    CensusEntry::get_member_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "hostname",
+                    CensusEntry::has_hostname,
+                    CensusEntry::get_hostname,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "ip",
+                    CensusEntry::has_ip,
+                    CensusEntry::get_ip,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "suitability",
+                    CensusEntry::has_suitability,
+                    CensusEntry::get_suitability,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "port",
+                    CensusEntry::has_port,
+                    CensusEntry::get_port,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_bool_accessor(
+                    "has_exposes",
+                    CensusEntry::has_has_exposes,
+                    CensusEntry::get_has_exposes,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_string_accessor(
+                    "exposes",
+                    CensusEntry::get_exposes,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_bool_accessor(
+                    "leader",
+                    CensusEntry::has_leader,
+                    CensusEntry::get_leader,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_bool_accessor(
+                    "follower",
+                    CensusEntry::has_follower,
+                    CensusEntry::get_follower,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_bool_accessor(
+                    "data_init",
+                    CensusEntry::has_data_init,
+                    CensusEntry::get_data_init,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "vote",
+                    CensusEntry::has_vote,
+                    CensusEntry::get_vote,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_bool_accessor(
+                    "election",
+                    CensusEntry::has_election,
+                    CensusEntry::get_election,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_bool_accessor(
+                    "needs_write",
+                    CensusEntry::has_needs_write,
+                    CensusEntry::get_needs_write,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_bool_accessor(
+                    "initialized",
+                    CensusEntry::has_initialized,
+                    CensusEntry::get_initialized,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_bool_accessor(
+                    "keep_me",
+                    CensusEntry::has_keep_me,
+                    CensusEntry::get_keep_me,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "service",
+                    CensusEntry::has_service,
+                    CensusEntry::get_service,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "group",
+                    CensusEntry::has_group,
+                    CensusEntry::get_group,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_bool_accessor(
+                    "alive",
+                    CensusEntry::has_alive,
+                    CensusEntry::get_alive,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_bool_accessor(
+                    "suspect",
+                    CensusEntry::has_suspect,
+                    CensusEntry::get_suspect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_bool_accessor(
+                    "confirmed",
+                    CensusEntry::has_confirmed,
+                    CensusEntry::get_confirmed,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_bool_accessor(
+                    "detached",
+                    CensusEntry::has_detached,
+                    CensusEntry::get_detached,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_bool_accessor(
+                    "update_pending",
+                    CensusEntry::has_update_pending,
+                    CensusEntry::get_update_pending,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_bool_accessor(
+                    "updating",
+                    CensusEntry::has_updating,
+                    CensusEntry::get_updating,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor(
+                    "health_check",
+                    CensusEntry::has_health_check,
+                    CensusEntry::get_health_check,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "incarnation",
+                    CensusEntry::has_incarnation,
+                    CensusEntry::get_incarnation,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_bool_accessor(
+                    "crash_loop",
+                    CensusEntry::has_crash_loop,
+                    CensusEntry::get_crash_loop,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "rolled_back",
+                    CensusEntry::has_rolled_back,
+                    CensusEntry::get_rolled_back,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<CensusEntry>(
+                    "CensusEntry",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for CensusEntry {
+    fn clear(&mut self) {
+        self.clear_id();
+        self.clear_member_id();
+        self.clear_hostname();
+        self.clear_ip();
+        self.clear_suitability();
+        self.clear_port();
+        self.clear_has_exposes();
+        self.clear_exposes();
+        self.clear_leader();
+        self.clear_follower();
+        self.clear_data_init();
+        self.clear_vote();
+        self.clear_election();
+        self.clear_needs_write();
+        self.clear_initialized();
+        self.clear_keep_me();
+        self.clear_service();
+        self.clear_group();
+        self.clear_alive();
+        self.clear_suspect();
+        self.clear_confirmed();
+        self.clear_detached();
+        self.clear_update_pending();
+        self.clear_updating();
+        self.clear_health_check();
+        self.clear_incarnation();
+        self.clear_crash_loop();
+        self.clear_rolled_back();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for CensusEntry {
+    fn eq(&self, other: &CensusEntry) -> bool {
+        self.id == other.id &&
+        self.member_id == other.member_id &&
+        self.hostname == other.hostname &&
+        self.ip == other.ip &&
+        self.suitability == other.suitability &&
+        self.port == other.port &&
+        self.has_exposes == other.has_exposes &&
+        self.exposes == other.exposes &&
+        self.leader == other.leader &&
+        self.follower == other.follower &&
+        self.data_init == other.data_init &&
+        self.vote == other.vote &&
+        self.election == other.election &&
+        self.needs_write == other.needs_write &&
+        self.initialized == other.initialized &&
+        self.keep_me == other.keep_me &&
+        self.service == other.service &&
+        self.group == other.group &&
+        self.alive == other.alive &&
+        self.suspect == other.suspect &&
+        self.confirmed == other.confirmed &&
+        self.detached == other.detached &&
+        self.update_pending == other.update_pending &&
+        self.updating == other.updating &&
+        self.health_check == other.health_check &&
+        self.incarnation == other.incarnation &&
+        self.crash_loop == other.crash_loop &&
+        self.rolled_back == other.rolled_back &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for CensusEntry {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum CensusEntry_HealthCheck {
+    OK = 1,
+    WARNING = 2,
+    CRITICAL = 3,
+    UNKNOWN = 4,
+}
+
+impl ::protobuf::ProtobufEnum for CensusEntry_HealthCheck {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<CensusEntry_HealthCheck> {
+        match value {
+            1 => ::std::option::Option::Some(CensusEntry_HealthCheck::OK),
+            2 => ::std::option::Option::Some(CensusEntry_HealthCheck::WARNING),
+            3 => ::std::option::Option::Some(CensusEntry_HealthCheck::CRITICAL),
+            4 => ::std::option::Option::Some(CensusEntry_HealthCheck::UNKNOWN),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [CensusEntry_HealthCheck] = &[
+            CensusEntry_HealthCheck::OK,
+            CensusEntry_HealthCheck::WARNING,
+            CensusEntry_HealthCheck::CRITICAL,
+            CensusEntry_HealthCheck::UNKNOWN,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static(_: Option<CensusEntry_HealthCheck>) -> &'static ::protobuf::reflect::EnumDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new("CensusEntry_HealthCheck", file_descriptor_proto())
+            })
+        }
+    }
+}
+
+impl ::std::marker::Copy for CensusEntry_HealthCheck {
+}
+
+#[derive(Clone,Default)]
+pub struct Election {
+    // message fields
+    id: ::protobuf::SingularField<::std::string::String>,
+    service: ::protobuf::SingularField<::std::string::String>,
+    group: ::protobuf::SingularField<::std::string::String>,
+    leader_id: ::protobuf::SingularField<::std::string::String>,
+    suitability: ::std::option::Option<u32>,
+    votes: ::protobuf::RepeatedField<::std::string::String>,
+    status: ::std::option::Option<Election_Status>,
+    term: ::std::option::Option<u32>,
+    excluded: ::protobuf::SingularField<::std::string::String>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for Election {}
+
+impl Election {
+    pub fn new() -> Election {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static Election {
+        static mut instance: ::protobuf::lazy::Lazy<Election> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Election,
+        };
+        unsafe {
+            instance.get(|| {
+                Election {
+                    id: ::protobuf::SingularField::none(),
+                    service: ::protobuf::SingularField::none(),
+                    group: ::protobuf::SingularField::none(),
+                    leader_id: ::protobuf::SingularField::none(),
+                    suitability: ::std::option::Option::None,
+                    votes: ::protobuf::RepeatedField::new(),
+                    status: ::std::option::Option::None,
+                    term: ::std::option::Option::None,
+                    excluded: ::protobuf::SingularField::none(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required string id = 1;
+
+    pub fn clear_id(&mut self) {
+        self.id.clear();
+    }
+
+    pub fn has_id(&self) -> bool {
+        self.id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: ::std::string::String) {
+        self.id = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+        if self.id.is_none() {
+            self.id.set_default();
+        };
+        self.id.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_id(&mut self) -> ::std::string::String {
+        self.id.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_id(&self) -> &str {
+        match self.id.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required string service = 2;
+
+    pub fn clear_service(&mut self) {
+        self.service.clear();
+    }
+
+    pub fn has_service(&self) -> bool {
+        self.service.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_service(&mut self, v: ::std::string::String) {
+        self.service = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_service(&mut self) -> &mut ::std::string::String {
+        if self.service.is_none() {
+            self.service.set_default();
+        };
+        self.service.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_service(&mut self) -> ::std::string::String {
+        self.service.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_service(&self) -> &str {
+        match self.service.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required string group = 3;
+
+    pub fn clear_group(&mut self) {
+        self.group.clear();
+    }
+
+    pub fn has_group(&self) -> bool {
+        self.group.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_group(&mut self, v: ::std::string::String) {
+        self.group = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_group(&mut self) -> &mut ::std::string::String {
+        if self.group.is_none() {
+            self.group.set_default();
+        };
+        self.group.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_group(&mut self) -> ::std::string::String {
+        self.group.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_group(&self) -> &str {
+        match self.group.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required string leader_id = 4;
+
+    pub fn clear_leader_id(&mut self) {
+        self.leader_id.clear();
+    }
+
+    pub fn has_leader_id(&self) -> bool {
+        self.leader_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_leader_id(&mut self, v: ::std::string::String) {
+        self.leader_id = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_leader_id(&mut self) -> &mut ::std::string::String {
+        if self.leader_id.is_none() {
+            self.leader_id.set_default();
+        };
+        self.leader_id.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_leader_id(&mut self) -> ::std::string::String {
+        self.leader_id.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_leader_id(&self) -> &str {
+        match self.leader_id.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // optional uint32 suitability = 5;
+
+    pub fn clear_suitability(&mut self) {
+        self.suitability = ::std::option::Option::None;
+    }
+
+    pub fn has_suitability(&self) -> bool {
+        self.suitability.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_suitability(&mut self, v: u32) {
+        self.suitability = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_suitability(&self) -> u32 {
+        self.suitability.unwrap_or(0)
+    }
+
+    // repeated string votes = 6;
+
+    pub fn clear_votes(&mut self) {
+        self.votes.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_votes(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.votes = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_votes(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.votes
+    }
+
+    // Take field
+    pub fn take_votes(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.votes, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_votes(&self) -> &[::std::string::String] {
+        &self.votes
+    }
+
+    // required .gossip.Election.Status status = 7;
+
+    pub fn clear_status(&mut self) {
+        self.status = ::std::option::Option::None;
+    }
+
+    pub fn has_status(&self) -> bool {
+        self.status.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_status(&mut self, v: Election_Status) {
+        self.status = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_status(&self) -> Election_Status {
+        self.status.unwrap_or(Election_Status::RUNNING)
+    }
+
+    // optional uint32 term = 8;
+
+    pub fn clear_term(&mut self) {
+        self.term = ::std::option::Option::None;
+    }
+
+    pub fn has_term(&self) -> bool {
+        self.term.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_term(&mut self, v: u32) {
+        self.term = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_term(&self) -> u32 {
+        self.term.unwrap_or(0)
+    }
+
+    // optional string excluded = 9;
+
+    pub fn clear_excluded(&mut self) {
+        self.excluded.clear();
+    }
+
+    pub fn has_excluded(&self) -> bool {
+        self.excluded.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_excluded(&mut self, v: ::std::string::String) {
+        self.excluded = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_excluded(&mut self) -> &mut ::std::string::String {
+        if self.excluded.is_none() {
+            self.excluded.set_default();
+        };
+        self.excluded.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_excluded(&mut self) -> ::std::string::String {
+        self.excluded.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_excluded(&self) -> &str {
+        match self.excluded.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+}
+
+impl ::protobuf::Message for Election {
+    fn is_initialized(&self) -> bool {
+        if self.id.is_none() {
+            return false;
+        };
+        if self.service.is_none() {
+            return false;
+        };
+        if self.group.is_none() {
+            return false;
+        };
+        if self.leader_id.is_none() {
+            return false;
+        };
+        if self.status.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.id));
+                },
+                2 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.service));
+                },
+                3 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.group));
+                },
+                4 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.leader_id));
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint32());
+                    self.suitability = ::std::option::Option::Some(tmp);
+                },
+                6 => {
+                    try!(::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.votes));
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_enum());
+                    self.status = ::std::option::Option::Some(tmp);
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint32());
+                    self.term = ::std::option::Option::Some(tmp);
+                },
+                9 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.excluded));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.id.iter() {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        for value in self.service.iter() {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        for value in self.group.iter() {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        for value in self.leader_id.iter() {
+            my_size += ::protobuf::rt::string_size(4, &value);
+        };
+        for value in self.suitability.iter() {
+            my_size += ::protobuf::rt::value_size(5, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.votes.iter() {
+            my_size += ::protobuf::rt::string_size(6, &value);
+        };
+        for value in self.status.iter() {
+            my_size += ::protobuf::rt::enum_size(7, *value);
+        };
+        for value in self.term.iter() {
+            my_size += ::protobuf::rt::value_size(8, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.excluded.iter() {
+            my_size += ::protobuf::rt::string_size(9, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.id.as_ref() {
+            try!(os.write_string(1, &v));
+        };
+        if let Some(v) = self.service.as_ref() {
+            try!(os.write_string(2, &v));
+        };
+        if let Some(v) = self.group.as_ref() {
+            try!(os.write_string(3, &v));
+        };
+        if let Some(v) = self.leader_id.as_ref() {
+            try!(os.write_string(4, &v));
+        };
+        if let Some(v) = self.suitability {
+            try!(os.write_uint32(5, v));
+        };
+        for v in self.votes.iter() {
+            try!(os.write_string(6, &v));
+        };
+        if let Some(v) = self.status {
+            try!(os.write_enum(7, v.value()));
+        };
+        if let Some(v) = self.term {
+            try!(os.write_uint32(8, v));
+        };
+        if let Some(v) = self.excluded.as_ref() {
+            try!(os.write_string(9, &v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<Election>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for Election {
+    fn new() -> Election {
+        Election::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<Election>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "id",
+                    Election::has_id,
+                    Election::get_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "service",
+                    Election::has_service,
+                    Election::get_service,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "group",
+                    Election::has_group,
+                    Election::get_group,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "leader_id",
+                    Election::has_leader_id,
+                    Election::get_leader_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u32_accessor(
+                    "suitability",
+                    Election::has_suitability,
+                    Election::get_suitability,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_string_accessor(
+                    "votes",
+                    Election::get_votes,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor(
+                    "status",
+                    Election::has_status,
+                    Election::get_status,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u32_accessor(
+                    "term",
+                    Election::has_term,
+                    Election::get_term,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "excluded",
+                    Election::has_excluded,
+                    Election::get_excluded,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Election>(
+                    "Election",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for Election {
+    fn clear(&mut self) {
+        self.clear_id();
+        self.clear_service();
+        self.clear_group();
+        self.clear_leader_id();
+        self.clear_suitability();
+        self.clear_votes();
+        self.clear_status();
+        self.clear_term();
+        self.clear_excluded();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for Election {
+    fn eq(&self, other: &Election) -> bool {
+        self.id == other.id &&
+        self.service == other.service &&
+        self.group == other.group &&
+        self.leader_id == other.leader_id &&
+        self.suitability == other.suitability &&
+        self.votes == other.votes &&
+        self.status == other.status &&
+        self.term == other.term &&
+        self.excluded == other.excluded &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for Election {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum Election_Status {
+    RUNNING = 1,
+    FINISHED = 2,
+}
+
+impl ::protobuf::ProtobufEnum for Election_Status {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<Election_Status> {
+        match value {
+            1 => ::std::option::Option::Some(Election_Status::RUNNING),
+            2 => ::std::option::Option::Some(Election_Status::FINISHED),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [Election_Status] = &[
+            Election_Status::RUNNING,
+            Election_Status::FINISHED,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static(_: Option<Election_Status>) -> &'static ::protobuf::reflect::EnumDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new("Election_Status", file_descriptor_proto())
+            })
+        }
+    }
+}
+
+impl ::std::marker::Copy for Election_Status {
+}
+
+#[derive(Clone,Default)]
+pub struct GossipFile {
+    // message fields
+    service_group: ::protobuf::SingularField<::std::string::String>,
+    file_name: ::protobuf::SingularField<::std::string::String>,
+    body: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    checksum: ::protobuf::SingularField<::std::string::String>,
+    version_number: ::std::option::Option<u64>,
+    encrypted: ::std::option::Option<bool>,
+    signature: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for GossipFile {}
+
+impl GossipFile {
+    pub fn new() -> GossipFile {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static GossipFile {
+        static mut instance: ::protobuf::lazy::Lazy<GossipFile> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const GossipFile,
+        };
+        unsafe {
+            instance.get(|| {
+                GossipFile {
+                    service_group: ::protobuf::SingularField::none(),
+                    file_name: ::protobuf::SingularField::none(),
+                    body: ::protobuf::SingularField::none(),
+                    checksum: ::protobuf::SingularField::none(),
+                    version_number: ::std::option::Option::None,
+                    encrypted: ::std::option::Option::None,
+                    signature: ::protobuf::SingularField::none(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required string service_group = 1;
+
+    pub fn clear_service_group(&mut self) {
+        self.service_group.clear();
+    }
+
+    pub fn has_service_group(&self) -> bool {
+        self.service_group.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_service_group(&mut self, v: ::std::string::String) {
+        self.service_group = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_service_group(&mut self) -> &mut ::std::string::String {
+        if self.service_group.is_none() {
+            self.service_group.set_default();
+        };
+        self.service_group.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_service_group(&mut self) -> ::std::string::String {
+        self.service_group.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_service_group(&self) -> &str {
+        match self.service_group.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required string file_name = 2;
+
+    pub fn clear_file_name(&mut self) {
+        self.file_name.clear();
+    }
+
+    pub fn has_file_name(&self) -> bool {
+        self.file_name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_file_name(&mut self, v: ::std::string::String) {
+        self.file_name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_file_name(&mut self) -> &mut ::std::string::String {
+        if self.file_name.is_none() {
+            self.file_name.set_default();
+        };
+        self.file_name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_file_name(&mut self) -> ::std::string::String {
+        self.file_name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_file_name(&self) -> &str {
+        match self.file_name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required bytes body = 3;
+
+    pub fn clear_body(&mut self) {
+        self.body.clear();
+    }
+
+    pub fn has_body(&self) -> bool {
+        self.body.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_body(&mut self, v: ::std::vec::Vec<u8>) {
+        self.body = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_body(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if self.body.is_none() {
+            self.body.set_default();
+        };
+        self.body.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_body(&mut self) -> ::std::vec::Vec<u8> {
+        self.body.take().unwrap_or_else(|| ::std::vec::Vec::new())
+    }
+
+    pub fn get_body(&self) -> &[u8] {
+        match self.body.as_ref() {
+            Some(v) => &v,
+            None => &[],
+        }
+    }
+
+    // required string checksum = 4;
+
+    pub fn clear_checksum(&mut self) {
+        self.checksum.clear();
+    }
+
+    pub fn has_checksum(&self) -> bool {
+        self.checksum.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_checksum(&mut self, v: ::std::string::String) {
+        self.checksum = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_checksum(&mut self) -> &mut ::std::string::String {
+        if self.checksum.is_none() {
+            self.checksum.set_default();
+        };
+        self.checksum.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_checksum(&mut self) -> ::std::string::String {
+        self.checksum.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_checksum(&self) -> &str {
+        match self.checksum.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required uint64 version_number = 5;
+
+    pub fn clear_version_number(&mut self) {
+        self.version_number = ::std::option::Option::None;
+    }
+
+    pub fn has_version_number(&self) -> bool {
+        self.version_number.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_version_number(&mut self, v: u64) {
+        self.version_number = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_version_number(&self) -> u64 {
+        self.version_number.unwrap_or(0)
+    }
+
+    // optional bool encrypted = 6;
+
+    pub fn clear_encrypted(&mut self) {
+        self.encrypted = ::std::option::Option::None;
+    }
+
+    pub fn has_encrypted(&self) -> bool {
+        self.encrypted.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_encrypted(&mut self, v: bool) {
+        self.encrypted = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_encrypted(&self) -> bool {
+        self.encrypted.unwrap_or(false)
+    }
+
+    // optional bytes signature = 7;
+
+    pub fn clear_signature(&mut self) {
+        self.signature.clear();
+    }
+
+    pub fn has_signature(&self) -> bool {
+        self.signature.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_signature(&mut self, v: ::std::vec::Vec<u8>) {
+        self.signature = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_signature(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if self.signature.is_none() {
+            self.signature.set_default();
+        };
+        self.signature.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_signature(&mut self) -> ::std::vec::Vec<u8> {
+        self.signature.take().unwrap_or_else(|| ::std::vec::Vec::new())
+    }
+
+    pub fn get_signature(&self) -> &[u8] {
+        match self.signature.as_ref() {
+            Some(v) => &v,
+            None => &[],
+        }
+    }
+}
+
+impl ::protobuf::Message for GossipFile {
+    fn is_initialized(&self) -> bool {
+        if self.service_group.is_none() {
+            return false;
+        };
+        if self.file_name.is_none() {
+            return false;
+        };
+        if self.body.is_none() {
+            return false;
+        };
+        if self.checksum.is_none() {
+            return false;
+        };
+        if self.version_number.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.service_group));
+                },
+                2 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.file_name));
+                },
+                3 => {
+                    try!(::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.body));
+                },
+                4 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.checksum));
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.version_number = ::std::option::Option::Some(tmp);
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_bool());
+                    self.encrypted = ::std::option::Option::Some(tmp);
+                },
+                7 => {
+                    try!(::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.signature));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.service_group.iter() {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        for value in self.file_name.iter() {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        for value in self.body.iter() {
+            my_size += ::protobuf::rt::bytes_size(3, &value);
+        };
+        for value in self.checksum.iter() {
+            my_size += ::protobuf::rt::string_size(4, &value);
+        };
+        for value in self.version_number.iter() {
+            my_size += ::protobuf::rt::value_size(5, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        if self.encrypted.is_some() {
+            my_size += 2;
+        };
+        for value in self.signature.iter() {
+            my_size += ::protobuf::rt::bytes_size(7, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.service_group.as_ref() {
+            try!(os.write_string(1, &v));
+        };
+        if let Some(v) = self.file_name.as_ref() {
+            try!(os.write_string(2, &v));
+        };
+        if let Some(v) = self.body.as_ref() {
+            try!(os.write_bytes(3, &v));
+        };
+        if let Some(v) = self.checksum.as_ref() {
+            try!(os.write_string(4, &v));
+        };
+        if let Some(v) = self.version_number {
+            try!(os.write_uint64(5, v));
+        };
+        if let Some(v) = self.encrypted {
+            try!(os.write_bool(6, v));
+        };
+        if let Some(v) = self.signature.as_ref() {
+            try!(os.write_bytes(7, &v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<GossipFile>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for GossipFile {
+    fn new() -> GossipFile {
+        GossipFile::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<GossipFile>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "service_group",
+                    GossipFile::has_service_group,
+                    GossipFile::get_service_group,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "file_name",
+                    GossipFile::has_file_name,
+                    GossipFile::get_file_name,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_bytes_accessor(
+                    "body",
+                    GossipFile::has_body,
+                    GossipFile::get_body,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "checksum",
+                    GossipFile::has_checksum,
+                    GossipFile::get_checksum,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "version_number",
+                    GossipFile::has_version_number,
+                    GossipFile::get_version_number,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_bool_accessor(
+                    "encrypted",
+                    GossipFile::has_encrypted,
+                    GossipFile::get_encrypted,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_bytes_accessor(
+                    "signature",
+                    GossipFile::has_signature,
+                    GossipFile::get_signature,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<GossipFile>(
+                    "GossipFile",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for GossipFile {
+    fn clear(&mut self) {
+        self.clear_service_group();
+        self.clear_file_name();
+        self.clear_body();
+        self.clear_checksum();
+        self.clear_version_number();
+        self.clear_encrypted();
+        self.clear_signature();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for GossipFile {
+    fn eq(&self, other: &GossipFile) -> bool {
+        self.service_group == other.service_group &&
+        self.file_name == other.file_name &&
+        self.body == other.body &&
+        self.checksum == other.checksum &&
+        self.version_number == other.version_number &&
+        self.encrypted == other.encrypted &&
+        self.signature == other.signature &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for GossipFile {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct KeyRoll {
+    // message fields
+    ring: ::protobuf::SingularField<::std::string::String>,
+    revision: ::protobuf::SingularField<::std::string::String>,
+    retire: ::std::option::Option<bool>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for KeyRoll {}
+
+impl KeyRoll {
+    pub fn new() -> KeyRoll {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static KeyRoll {
+        static mut instance: ::protobuf::lazy::Lazy<KeyRoll> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const KeyRoll,
+        };
+        unsafe {
+            instance.get(|| {
+                KeyRoll {
+                    ring: ::protobuf::SingularField::none(),
+                    revision: ::protobuf::SingularField::none(),
+                    retire: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required string ring = 1;
+
+    pub fn clear_ring(&mut self) {
+        self.ring.clear();
+    }
+
+    pub fn has_ring(&self) -> bool {
+        self.ring.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ring(&mut self, v: ::std::string::String) {
+        self.ring = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ring(&mut self) -> &mut ::std::string::String {
+        if self.ring.is_none() {
+            self.ring.set_default();
+        };
+        self.ring.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_ring(&mut self) -> ::std::string::String {
+        self.ring.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_ring(&self) -> &str {
+        match self.ring.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required string revision = 2;
+
+    pub fn clear_revision(&mut self) {
+        self.revision.clear();
+    }
+
+    pub fn has_revision(&self) -> bool {
+        self.revision.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_revision(&mut self, v: ::std::string::String) {
+        self.revision = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_revision(&mut self) -> &mut ::std::string::String {
+        if self.revision.is_none() {
+            self.revision.set_default();
+        };
+        self.revision.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_revision(&mut self) -> ::std::string::String {
+        self.revision.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_revision(&self) -> &str {
+        match self.revision.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // optional bool retire = 3;
+
+    pub fn clear_retire(&mut self) {
+        self.retire = ::std::option::Option::None;
+    }
+
+    pub fn has_retire(&self) -> bool {
+        self.retire.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_retire(&mut self, v: bool) {
+        self.retire = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_retire(&self) -> bool {
+        self.retire.unwrap_or(false)
+    }
+}
+
+impl ::protobuf::Message for KeyRoll {
+    fn is_initialized(&self) -> bool {
+        if self.ring.is_none() {
+            return false;
+        };
+        if self.revision.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.ring));
+                },
+                2 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.revision));
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_bool());
+                    self.retire = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.ring.iter() {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        for value in self.revision.iter() {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        if self.retire.is_some() {
+            my_size += 2;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.ring.as_ref() {
+            try!(os.write_string(1, &v));
+        };
+        if let Some(v) = self.revision.as_ref() {
+            try!(os.write_string(2, &v));
+        };
+        if let Some(v) = self.retire {
+            try!(os.write_bool(3, v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<KeyRoll>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for KeyRoll {
+    fn new() -> KeyRoll {
+        KeyRoll::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<KeyRoll>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "ring",
+                    KeyRoll::has_ring,
+                    KeyRoll::get_ring,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "revision",
+                    KeyRoll::has_revision,
+                    KeyRoll::get_revision,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_bool_accessor(
+                    "retire",
+                    KeyRoll::has_retire,
+                    KeyRoll::get_retire,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<KeyRoll>(
+                    "KeyRoll",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for KeyRoll {
+    fn clear(&mut self) {
+        self.clear_ring();
+        self.clear_revision();
+        self.clear_retire();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for KeyRoll {
+    fn eq(&self, other: &KeyRoll) -> bool {
+        self.ring == other.ring &&
+        self.revision == other.revision &&
+        self.retire == other.retire &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for KeyRoll {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = &[
+    0x0a, 0x16, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x63, 0x6f, 0x6c, 0x73, 0x2f, 0x67, 0x6f, 0x73, 0x73,
+    0x69, 0x70, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x12, 0x06, 0x67, 0x6f, 0x73, 0x73, 0x69, 0x70,
+    0x22, 0x5d, 0x0a, 0x08, 0x45, 0x6e, 0x76, 0x65, 0x6c, 0x6f, 0x70, 0x65, 0x12, 0x0f, 0x0a, 0x07,
+    0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x18, 0x01, 0x20, 0x02, 0x28, 0x0d, 0x12, 0x13, 0x0a,
+    0x0b, 0x6d, 0x69, 0x6e, 0x5f, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x18, 0x02, 0x20, 0x02,
+    0x28, 0x0d, 0x12, 0x0b, 0x0a, 0x03, 0x6b, 0x65, 0x79, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x12,
+    0x0d, 0x0a, 0x05, 0x6e, 0x6f, 0x6e, 0x63, 0x65, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0c, 0x12, 0x0f,
+    0x0a, 0x07, 0x70, 0x61, 0x79, 0x6c, 0x6f, 0x61, 0x64, 0x18, 0x05, 0x20, 0x02, 0x28, 0x0c, 0x22,
+    0x95, 0x01, 0x0a, 0x04, 0x53, 0x77, 0x69, 0x6d, 0x12, 0x1f, 0x0a, 0x04, 0x74, 0x79, 0x70, 0x65,
+    0x18, 0x01, 0x20, 0x02, 0x28, 0x0e, 0x32, 0x11, 0x2e, 0x67, 0x6f, 0x73, 0x73, 0x69, 0x70, 0x2e,
+    0x53, 0x77, 0x69, 0x6d, 0x2e, 0x54, 0x79, 0x70, 0x65, 0x12, 0x1a, 0x0a, 0x04, 0x70, 0x65, 0x65,
+    0x72, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0c, 0x2e, 0x67, 0x6f, 0x73, 0x73, 0x69, 0x70,
+    0x2e, 0x50, 0x65, 0x65, 0x72, 0x12, 0x0e, 0x0a, 0x06, 0x72, 0x75, 0x6d, 0x6f, 0x72, 0x73, 0x18,
+    0x03, 0x20, 0x03, 0x28, 0x0c, 0x22, 0x40, 0x0a, 0x04, 0x54, 0x79, 0x70, 0x65, 0x12, 0x08, 0x0a,
+    0x04, 0x50, 0x49, 0x4e, 0x47, 0x10, 0x01, 0x12, 0x07, 0x0a, 0x03, 0x41, 0x43, 0x4b, 0x10, 0x02,
+    0x12, 0x0b, 0x0a, 0x07, 0x50, 0x49, 0x4e, 0x47, 0x52, 0x45, 0x51, 0x10, 0x03, 0x12, 0x0a, 0x0a,
+    0x06, 0x49, 0x4e, 0x4a, 0x45, 0x43, 0x54, 0x10, 0x04, 0x12, 0x0c, 0x0a, 0x08, 0x50, 0x55, 0x53,
+    0x48, 0x50, 0x55, 0x4c, 0x4c, 0x10, 0x05, 0x22, 0x70, 0x0a, 0x04, 0x50, 0x65, 0x65, 0x72, 0x12,
+    0x11, 0x0a, 0x09, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02,
+    0x28, 0x09, 0x12, 0x14, 0x0a, 0x0c, 0x6c, 0x69, 0x73, 0x74, 0x65, 0x6e, 0x69, 0x6e, 0x67, 0x5f,
+    0x6f, 0x6e, 0x18, 0x02, 0x20, 0x02, 0x28, 0x09, 0x12, 0x15, 0x0a, 0x0d, 0x70, 0x72, 0x6f, 0x78,
+    0x79, 0x5f, 0x74, 0x68, 0x72, 0x6f, 0x75, 0x67, 0x68, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x12,
+    0x10, 0x0a, 0x08, 0x70, 0x72, 0x6f, 0x78, 0x79, 0x5f, 0x74, 0x6f, 0x18, 0x04, 0x20, 0x01, 0x28,
+    0x09, 0x12, 0x16, 0x0a, 0x0e, 0x67, 0x6f, 0x73, 0x73, 0x69, 0x70, 0x5f, 0x76, 0x65, 0x72, 0x73,
+    0x69, 0x6f, 0x6e, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0d, 0x22, 0xce, 0x01, 0x0a, 0x05, 0x52, 0x75,
+    0x6d, 0x6f, 0x72, 0x12, 0x0a, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x12,
+    0x1e, 0x0a, 0x06, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32,
+    0x0e, 0x2e, 0x67, 0x6f, 0x73, 0x73, 0x69, 0x70, 0x2e, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x12,
+    0x29, 0x0a, 0x0c, 0x63, 0x65, 0x6e, 0x73, 0x75, 0x73, 0x5f, 0x65, 0x6e, 0x74, 0x72, 0x79, 0x18,
+    0x03, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x13, 0x2e, 0x67, 0x6f, 0x73, 0x73, 0x69, 0x70, 0x2e, 0x43,
+    0x65, 0x6e, 0x73, 0x75, 0x73, 0x45, 0x6e, 0x74, 0x72, 0x79, 0x12, 0x22, 0x0a, 0x08, 0x65, 0x6c,
+    0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x10, 0x2e, 0x67,
+    0x6f, 0x73, 0x73, 0x69, 0x70, 0x2e, 0x45, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x27,
+    0x0a, 0x0b, 0x67, 0x6f, 0x73, 0x73, 0x69, 0x70, 0x5f, 0x66, 0x69, 0x6c, 0x65, 0x18, 0x05, 0x20,
+    0x01, 0x28, 0x0b, 0x32, 0x12, 0x2e, 0x67, 0x6f, 0x73, 0x73, 0x69, 0x70, 0x2e, 0x47, 0x6f, 0x73,
+    0x73, 0x69, 0x70, 0x46, 0x69, 0x6c, 0x65, 0x12, 0x21, 0x0a, 0x08, 0x6b, 0x65, 0x79, 0x5f, 0x72,
+    0x6f, 0x6c, 0x6c, 0x18, 0x06, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0f, 0x2e, 0x67, 0x6f, 0x73, 0x73,
+    0x69, 0x70, 0x2e, 0x4b, 0x65, 0x79, 0x52, 0x6f, 0x6c, 0x6c, 0x22, 0xef, 0x01, 0x0a, 0x06, 0x4d,
+    0x65, 0x6d, 0x62, 0x65, 0x72, 0x12, 0x0a, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28,
+    0x09, 0x12, 0x10, 0x0a, 0x08, 0x68, 0x6f, 0x73, 0x74, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x02, 0x20,
+    0x02, 0x28, 0x09, 0x12, 0x0a, 0x0a, 0x02, 0x69, 0x70, 0x18, 0x03, 0x20, 0x02, 0x28, 0x09, 0x12,
+    0x17, 0x0a, 0x0f, 0x67, 0x6f, 0x73, 0x73, 0x69, 0x70, 0x5f, 0x6c, 0x69, 0x73, 0x74, 0x65, 0x6e,
+    0x65, 0x72, 0x18, 0x04, 0x20, 0x02, 0x28, 0x09, 0x12, 0x13, 0x0a, 0x0b, 0x69, 0x6e, 0x63, 0x61,
+    0x72, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x05, 0x20, 0x02, 0x28, 0x04, 0x12, 0x25, 0x0a,
+    0x06, 0x68, 0x65, 0x61, 0x6c, 0x74, 0x68, 0x18, 0x06, 0x20, 0x02, 0x28, 0x0e, 0x32, 0x15, 0x2e,
+    0x67, 0x6f, 0x73, 0x73, 0x69, 0x70, 0x2e, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x2e, 0x48, 0x65,
+    0x61, 0x6c, 0x74, 0x68, 0x12, 0x11, 0x0a, 0x09, 0x70, 0x65, 0x72, 0x6d, 0x61, 0x6e, 0x65, 0x6e,
+    0x74, 0x18, 0x07, 0x20, 0x01, 0x28, 0x08, 0x12, 0x14, 0x0a, 0x0c, 0x73, 0x75, 0x73, 0x70, 0x65,
+    0x63, 0x74, 0x65, 0x64, 0x5f, 0x62, 0x79, 0x18, 0x08, 0x20, 0x01, 0x28, 0x09, 0x22, 0x3d, 0x0a,
+    0x06, 0x48, 0x65, 0x61, 0x6c, 0x74, 0x68, 0x12, 0x09, 0x0a, 0x05, 0x41, 0x4c, 0x49, 0x56, 0x45,
+    0x10, 0x01, 0x12, 0x0b, 0x0a, 0x07, 0x53, 0x55, 0x53, 0x50, 0x45, 0x43, 0x54, 0x10, 0x02, 0x12,
+    0x0d, 0x0a, 0x09, 0x43, 0x4f, 0x4e, 0x46, 0x49, 0x52, 0x4d, 0x45, 0x44, 0x10, 0x03, 0x12, 0x0c,
+    0x0a, 0x08, 0x44, 0x45, 0x50, 0x41, 0x52, 0x54, 0x45, 0x44, 0x10, 0x04, 0x22, 0xe6, 0x04, 0x0a,
+    0x0b, 0x43, 0x65, 0x6e, 0x73, 0x75, 0x73, 0x45, 0x6e, 0x74, 0x72, 0x79, 0x12, 0x0a, 0x0a, 0x02,
+    0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x12, 0x11, 0x0a, 0x09, 0x6d, 0x65, 0x6d, 0x62,
+    0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x02, 0x28, 0x09, 0x12, 0x10, 0x0a, 0x08, 0x68,
+    0x6f, 0x73, 0x74, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x03, 0x20, 0x02, 0x28, 0x09, 0x12, 0x0a, 0x0a,
+    0x02, 0x69, 0x70, 0x18, 0x04, 0x20, 0x02, 0x28, 0x09, 0x12, 0x13, 0x0a, 0x0b, 0x73, 0x75, 0x69,
+    0x74, 0x61, 0x62, 0x69, 0x6c, 0x69, 0x74, 0x79, 0x18, 0x05, 0x20, 0x01, 0x28, 0x04, 0x12, 0x0c,
+    0x0a, 0x04, 0x70, 0x6f, 0x72, 0x74, 0x18, 0x06, 0x20, 0x01, 0x28, 0x09, 0x12, 0x13, 0x0a, 0x0b,
+    0x68, 0x61, 0x73, 0x5f, 0x65, 0x78, 0x70, 0x6f, 0x73, 0x65, 0x73, 0x18, 0x07, 0x20, 0x01, 0x28,
+    0x08, 0x12, 0x0f, 0x0a, 0x07, 0x65, 0x78, 0x70, 0x6f, 0x73, 0x65, 0x73, 0x18, 0x08, 0x20, 0x03,
+    0x28, 0x09, 0x12, 0x0e, 0x0a, 0x06, 0x6c, 0x65, 0x61, 0x64, 0x65, 0x72, 0x18, 0x09, 0x20, 0x01,
+    0x28, 0x08, 0x12, 0x10, 0x0a, 0x08, 0x66, 0x6f, 0x6c, 0x6c, 0x6f, 0x77, 0x65, 0x72, 0x18, 0x0a,
+    0x20, 0x01, 0x28, 0x08, 0x12, 0x11, 0x0a, 0x09, 0x64, 0x61, 0x74, 0x61, 0x5f, 0x69, 0x6e, 0x69,
+    0x74, 0x18, 0x0b, 0x20, 0x01, 0x28, 0x08, 0x12, 0x0c, 0x0a, 0x04, 0x76, 0x6f, 0x74, 0x65, 0x18,
+    0x0c, 0x20, 0x01, 0x28, 0x09, 0x12, 0x10, 0x0a, 0x08, 0x65, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f,
+    0x6e, 0x18, 0x0d, 0x20, 0x01, 0x28, 0x08, 0x12, 0x13, 0x0a, 0x0b, 0x6e, 0x65, 0x65, 0x64, 0x73,
+    0x5f, 0x77, 0x72, 0x69, 0x74, 0x65, 0x18, 0x0e, 0x20, 0x01, 0x28, 0x08, 0x12, 0x13, 0x0a, 0x0b,
+    0x69, 0x6e, 0x69, 0x74, 0x69, 0x61, 0x6c, 0x69, 0x7a, 0x65, 0x64, 0x18, 0x0f, 0x20, 0x01, 0x28,
+    0x08, 0x12, 0x0f, 0x0a, 0x07, 0x6b, 0x65, 0x65, 0x70, 0x5f, 0x6d, 0x65, 0x18, 0x10, 0x20, 0x01,
+    0x28, 0x08, 0x12, 0x0f, 0x0a, 0x07, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x18, 0x11, 0x20,
+    0x02, 0x28, 0x09, 0x12, 0x0d, 0x0a, 0x05, 0x67, 0x72, 0x6f, 0x75, 0x70, 0x18, 0x12, 0x20, 0x02,
+    0x28, 0x09, 0x12, 0x0d, 0x0a, 0x05, 0x61, 0x6c, 0x69, 0x76, 0x65, 0x18, 0x13, 0x20, 0x01, 0x28,
+    0x08, 0x12, 0x0f, 0x0a, 0x07, 0x73, 0x75, 0x73, 0x70, 0x65, 0x63, 0x74, 0x18, 0x14, 0x20, 0x01,
+    0x28, 0x08, 0x12, 0x11, 0x0a, 0x09, 0x63, 0x6f, 0x6e, 0x66, 0x69, 0x72, 0x6d, 0x65, 0x64, 0x18,
+    0x15, 0x20, 0x01, 0x28, 0x08, 0x12, 0x10, 0x0a, 0x08, 0x64, 0x65, 0x74, 0x61, 0x63, 0x68, 0x65,
+    0x64, 0x18, 0x16, 0x20, 0x01, 0x28, 0x08, 0x12, 0x16, 0x0a, 0x0e, 0x75, 0x70, 0x64, 0x61, 0x74,
+    0x65, 0x5f, 0x70, 0x65, 0x6e, 0x64, 0x69, 0x6e, 0x67, 0x18, 0x17, 0x20, 0x01, 0x28, 0x08, 0x12,
+    0x10, 0x0a, 0x08, 0x75, 0x70, 0x64, 0x61, 0x74, 0x69, 0x6e, 0x67, 0x18, 0x18, 0x20, 0x01, 0x28,
+    0x08, 0x12, 0x35, 0x0a, 0x0c, 0x68, 0x65, 0x61, 0x6c, 0x74, 0x68, 0x5f, 0x63, 0x68, 0x65, 0x63,
+    0x6b, 0x18, 0x19, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x1f, 0x2e, 0x67, 0x6f, 0x73, 0x73, 0x69, 0x70,
+    0x2e, 0x43, 0x65, 0x6e, 0x73, 0x75, 0x73, 0x45, 0x6e, 0x74, 0x72, 0x79, 0x2e, 0x48, 0x65, 0x61,
+    0x6c, 0x74, 0x68, 0x43, 0x68, 0x65, 0x63, 0x6b, 0x12, 0x13, 0x0a, 0x0b, 0x69, 0x6e, 0x63, 0x61,
+    0x72, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x1a, 0x20, 0x02, 0x28, 0x04, 0x12, 0x12, 0x0a,
+    0x0a, 0x63, 0x72, 0x61, 0x73, 0x68, 0x5f, 0x6c, 0x6f, 0x6f, 0x70, 0x18, 0x1b, 0x20, 0x01, 0x28,
+    0x08, 0x12, 0x13, 0x0a, 0x0b, 0x72, 0x6f, 0x6c, 0x6c, 0x65, 0x64, 0x5f, 0x62, 0x61, 0x63, 0x6b,
+    0x18, 0x1c, 0x20, 0x01, 0x28, 0x09, 0x22, 0x3d, 0x0a, 0x0b, 0x48, 0x65, 0x61, 0x6c, 0x74, 0x68,
+    0x43, 0x68, 0x65, 0x63, 0x6b, 0x12, 0x06, 0x0a, 0x02, 0x4f, 0x4b, 0x10, 0x01, 0x12, 0x0b, 0x0a,
+    0x07, 0x57, 0x41, 0x52, 0x4e, 0x49, 0x4e, 0x47, 0x10, 0x02, 0x12, 0x0c, 0x0a, 0x08, 0x43, 0x52,
+    0x49, 0x54, 0x49, 0x43, 0x41, 0x4c, 0x10, 0x03, 0x12, 0x0b, 0x0a, 0x07, 0x55, 0x4e, 0x4b, 0x4e,
+    0x4f, 0x57, 0x4e, 0x10, 0x04, 0x22, 0xdb, 0x01, 0x0a, 0x08, 0x45, 0x6c, 0x65, 0x63, 0x74, 0x69,
+    0x6f, 0x6e, 0x12, 0x0a, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x12, 0x0f,
+    0x0a, 0x07, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x18, 0x02, 0x20, 0x02, 0x28, 0x09, 0x12,
+    0x0d, 0x0a, 0x05, 0x67, 0x72, 0x6f, 0x75, 0x70, 0x18, 0x03, 0x20, 0x02, 0x28, 0x09, 0x12, 0x11,
+    0x0a, 0x09, 0x6c, 0x65, 0x61, 0x64, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x04, 0x20, 0x02, 0x28,
+    0x09, 0x12, 0x13, 0x0a, 0x0b, 0x73, 0x75, 0x69, 0x74, 0x61, 0x62, 0x69, 0x6c, 0x69, 0x74, 0x79,
+    0x18, 0x05, 0x20, 0x01, 0x28, 0x0d, 0x12, 0x0d, 0x0a, 0x05, 0x76, 0x6f, 0x74, 0x65, 0x73, 0x18,
+    0x06, 0x20, 0x03, 0x28, 0x09, 0x12, 0x27, 0x0a, 0x06, 0x73, 0x74, 0x61, 0x74, 0x75, 0x73, 0x18,
+    0x07, 0x20, 0x02, 0x28, 0x0e, 0x32, 0x17, 0x2e, 0x67, 0x6f, 0x73, 0x73, 0x69, 0x70, 0x2e, 0x45,
+    0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x12, 0x0c,
+    0x0a, 0x04, 0x74, 0x65, 0x72, 0x6d, 0x18, 0x08, 0x20, 0x01, 0x28, 0x0d, 0x12, 0x10, 0x0a, 0x08,
+    0x65, 0x78, 0x63, 0x6c, 0x75, 0x64, 0x65, 0x64, 0x18, 0x09, 0x20, 0x01, 0x28, 0x09, 0x22, 0x23,
+    0x0a, 0x06, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x12, 0x0b, 0x0a, 0x07, 0x52, 0x55, 0x4e, 0x4e,
+    0x49, 0x4e, 0x47, 0x10, 0x01, 0x12, 0x0c, 0x0a, 0x08, 0x46, 0x49, 0x4e, 0x49, 0x53, 0x48, 0x45,
+    0x44, 0x10, 0x02, 0x22, 0x94, 0x01, 0x0a, 0x0a, 0x47, 0x6f, 0x73, 0x73, 0x69, 0x70, 0x46, 0x69,
+    0x6c, 0x65, 0x12, 0x15, 0x0a, 0x0d, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x5f, 0x67, 0x72,
+    0x6f, 0x75, 0x70, 0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x12, 0x11, 0x0a, 0x09, 0x66, 0x69, 0x6c,
+    0x65, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x02, 0x20, 0x02, 0x28, 0x09, 0x12, 0x0c, 0x0a, 0x04,
+    0x62, 0x6f, 0x64, 0x79, 0x18, 0x03, 0x20, 0x02, 0x28, 0x0c, 0x12, 0x10, 0x0a, 0x08, 0x63, 0x68,
+    0x65, 0x63, 0x6b, 0x73, 0x75, 0x6d, 0x18, 0x04, 0x20, 0x02, 0x28, 0x09, 0x12, 0x16, 0x0a, 0x0e,
+    0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x5f, 0x6e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x18, 0x05,
+    0x20, 0x02, 0x28, 0x04, 0x12, 0x11, 0x0a, 0x09, 0x65, 0x6e, 0x63, 0x72, 0x79, 0x70, 0x74, 0x65,
+    0x64, 0x18, 0x06, 0x20, 0x01, 0x28, 0x08, 0x12, 0x11, 0x0a, 0x09, 0x73, 0x69, 0x67, 0x6e, 0x61,
+    0x74, 0x75, 0x72, 0x65, 0x18, 0x07, 0x20, 0x01, 0x28, 0x0c, 0x22, 0x39, 0x0a, 0x07, 0x4b, 0x65,
+    0x79, 0x52, 0x6f, 0x6c, 0x6c, 0x12, 0x0c, 0x0a, 0x04, 0x72, 0x69, 0x6e, 0x67, 0x18, 0x01, 0x20,
+    0x02, 0x28, 0x09, 0x12, 0x10, 0x0a, 0x08, 0x72, 0x65, 0x76, 0x69, 0x73, 0x69, 0x6f, 0x6e, 0x18,
+    0x02, 0x20, 0x02, 0x28, 0x09, 0x12, 0x0e, 0x0a, 0x06, 0x72, 0x65, 0x74, 0x69, 0x72, 0x65, 0x18,
+    0x03, 0x20, 0x01, 0x28, 0x08, 0x4a, 0xff, 0x3b, 0x0a, 0x07, 0x12, 0x05, 0x00, 0x00, 0x92, 0x01,
+    0x01, 0x0a, 0x08, 0x0a, 0x01, 0x02, 0x12, 0x03, 0x00, 0x08, 0x0e, 0x0a, 0x8c, 0x04, 0x0a, 0x02,
+    0x04, 0x00, 0x12, 0x04, 0x0a, 0x00, 0x14, 0x01, 0x1a, 0x29, 0x20, 0x45, 0x76, 0x65, 0x72, 0x79,
+    0x20, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x20, 0x73, 0x65, 0x6e, 0x74, 0x20, 0x62, 0x65,
+    0x74, 0x77, 0x65, 0x65, 0x6e, 0x20, 0x73, 0x75, 0x70, 0x65, 0x72, 0x76, 0x69, 0x73, 0x6f, 0x72,
+    0x73, 0x2e, 0x0a, 0x32, 0xd4, 0x03, 0x20, 0x54, 0x68, 0x65, 0x20, 0x73, 0x63, 0x68, 0x65, 0x6d,
+    0x61, 0x20, 0x6f, 0x66, 0x20, 0x67, 0x6f, 0x73, 0x73, 0x69, 0x70, 0x20, 0x6d, 0x65, 0x73, 0x73,
+    0x61, 0x67, 0x65, 0x73, 0x20, 0x62, 0x65, 0x74, 0x77, 0x65, 0x65, 0x6e, 0x20, 0x73, 0x75, 0x70,
+    0x65, 0x72, 0x76, 0x69, 0x73, 0x6f, 0x72, 0x73, 0x2c, 0x20, 0x66, 0x72, 0x6f, 0x6d, 0x20, 0x76,
+    0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x20, 0x32, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x67, 0x6f, 0x73, 0x73, 0x69, 0x70, 0x20, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x63, 0x6f, 0x6c, 0x2e,
+    0x0a, 0x0a, 0x20, 0x46, 0x69, 0x65, 0x6c, 0x64, 0x73, 0x20, 0x6d, 0x61, 0x79, 0x20, 0x62, 0x65,
+    0x20, 0x61, 0x64, 0x64, 0x65, 0x64, 0x2c, 0x20, 0x62, 0x75, 0x74, 0x20, 0x6e, 0x65, 0x76, 0x65,
+    0x72, 0x20, 0x72, 0x65, 0x6e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x65, 0x64, 0x2c, 0x20, 0x72, 0x65,
+    0x74, 0x79, 0x70, 0x65, 0x64, 0x2c, 0x20, 0x6f, 0x72, 0x20, 0x72, 0x65, 0x75, 0x73, 0x65, 0x64,
+    0x20, 0x6f, 0x6e, 0x63, 0x65, 0x20, 0x72, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x64, 0x3b, 0x20, 0x72,
+    0x65, 0x61, 0x64, 0x65, 0x72, 0x73, 0x20, 0x73, 0x6b, 0x69, 0x70, 0x20, 0x66, 0x69, 0x65, 0x6c,
+    0x64, 0x73, 0x0a, 0x20, 0x74, 0x68, 0x65, 0x79, 0x20, 0x64, 0x6f, 0x20, 0x6e, 0x6f, 0x74, 0x20,
+    0x6b, 0x6e, 0x6f, 0x77, 0x2e, 0x20, 0x52, 0x65, 0x61, 0x64, 0x65, 0x72, 0x73, 0x20, 0x64, 0x72,
+    0x6f, 0x70, 0x20, 0x61, 0x20, 0x72, 0x75, 0x6d, 0x6f, 0x72, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20,
+    0x61, 0x6e, 0x20, 0x65, 0x6e, 0x75, 0x6d, 0x20, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x20, 0x74, 0x68,
+    0x65, 0x79, 0x20, 0x64, 0x6f, 0x20, 0x6e, 0x6f, 0x74, 0x20, 0x6b, 0x6e, 0x6f, 0x77, 0x2c, 0x20,
+    0x73, 0x6f, 0x20, 0x61, 0x20, 0x6e, 0x65, 0x77, 0x20, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x20, 0x6d,
+    0x75, 0x73, 0x74, 0x0a, 0x20, 0x62, 0x65, 0x20, 0x6f, 0x6e, 0x65, 0x20, 0x6f, 0x6c, 0x64, 0x65,
+    0x72, 0x20, 0x73, 0x75, 0x70, 0x65, 0x72, 0x76, 0x69, 0x73, 0x6f, 0x72, 0x73, 0x20, 0x63, 0x61,
+    0x6e, 0x20, 0x64, 0x6f, 0x20, 0x77, 0x69, 0x74, 0x68, 0x6f, 0x75, 0x74, 0x2e, 0x20, 0x41, 0x20,
+    0x63, 0x68, 0x61, 0x6e, 0x67, 0x65, 0x20, 0x6f, 0x6c, 0x64, 0x65, 0x72, 0x20, 0x73, 0x75, 0x70,
+    0x65, 0x72, 0x76, 0x69, 0x73, 0x6f, 0x72, 0x73, 0x20, 0x63, 0x61, 0x6e, 0x6e, 0x6f, 0x74, 0x20,
+    0x73, 0x61, 0x66, 0x65, 0x6c, 0x79, 0x20, 0x69, 0x67, 0x6e, 0x6f, 0x72, 0x65, 0x20, 0x6d, 0x75,
+    0x73, 0x74, 0x0a, 0x20, 0x72, 0x61, 0x69, 0x73, 0x65, 0x20, 0x6d, 0x69, 0x6e, 0x5f, 0x76, 0x65,
+    0x72, 0x73, 0x69, 0x6f, 0x6e, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x45, 0x6e, 0x76,
+    0x65, 0x6c, 0x6f, 0x70, 0x65, 0x2c, 0x20, 0x73, 0x6f, 0x20, 0x74, 0x68, 0x65, 0x79, 0x20, 0x72,
+    0x65, 0x66, 0x75, 0x73, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67,
+    0x65, 0x20, 0x72, 0x61, 0x74, 0x68, 0x65, 0x72, 0x20, 0x74, 0x68, 0x61, 0x6e, 0x20, 0x6d, 0x69,
+    0x73, 0x72, 0x65, 0x61, 0x64, 0x20, 0x69, 0x74, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00,
+    0x01, 0x12, 0x03, 0x0a, 0x08, 0x10, 0x0a, 0x43, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03,
+    0x0c, 0x02, 0x1e, 0x1a, 0x36, 0x20, 0x54, 0x68, 0x65, 0x20, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f,
+    0x6e, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x67, 0x6f, 0x73, 0x73, 0x69, 0x70, 0x20,
+    0x70, 0x72, 0x6f, 0x74, 0x6f, 0x63, 0x6f, 0x6c, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x65, 0x6e,
+    0x64, 0x65, 0x72, 0x20, 0x73, 0x70, 0x65, 0x61, 0x6b, 0x73, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x00, 0x02, 0x00, 0x04, 0x12, 0x03, 0x0c, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
+    0x00, 0x05, 0x12, 0x03, 0x0c, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x01,
+    0x12, 0x03, 0x0c, 0x12, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03,
+    0x0c, 0x1c, 0x1d, 0x0a, 0x53, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x0e, 0x02, 0x22,
+    0x1a, 0x46, 0x20, 0x54, 0x68, 0x65, 0x20, 0x6f, 0x6c, 0x64, 0x65, 0x73, 0x74, 0x20, 0x76, 0x65,
+    0x72, 0x73, 0x69, 0x6f, 0x6e, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x67, 0x6f, 0x73,
+    0x73, 0x69, 0x70, 0x20, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x63, 0x6f, 0x6c, 0x20, 0x74, 0x68, 0x61,
+    0x74, 0x20, 0x63, 0x61, 0x6e, 0x20, 0x72, 0x65, 0x61, 0x64, 0x20, 0x74, 0x68, 0x69, 0x73, 0x20,
+    0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01,
+    0x04, 0x12, 0x03, 0x0e, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x05, 0x12,
+    0x03, 0x0e, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x0e,
+    0x12, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x03, 0x12, 0x03, 0x0e, 0x20, 0x21,
+    0x0a, 0x64, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x10, 0x02, 0x1a, 0x1a, 0x57, 0x20,
+    0x54, 0x68, 0x65, 0x20, 0x6e, 0x61, 0x6d, 0x65, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x72, 0x65,
+    0x76, 0x69, 0x73, 0x69, 0x6f, 0x6e, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x72, 0x69,
+    0x6e, 0x67, 0x20, 0x6b, 0x65, 0x79, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x65, 0x6e, 0x63, 0x72,
+    0x79, 0x70, 0x74, 0x65, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x61, 0x79, 0x6c, 0x6f, 0x61,
+    0x64, 0x2c, 0x20, 0x69, 0x66, 0x20, 0x69, 0x74, 0x20, 0x69, 0x73, 0x20, 0x65, 0x6e, 0x63, 0x72,
+    0x79, 0x70, 0x74, 0x65, 0x64, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x04, 0x12,
+    0x03, 0x10, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x05, 0x12, 0x03, 0x10,
+    0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x10, 0x12, 0x15,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x03, 0x12, 0x03, 0x10, 0x18, 0x19, 0x0a, 0x0b,
+    0x0a, 0x04, 0x04, 0x00, 0x02, 0x03, 0x12, 0x03, 0x11, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x00, 0x02, 0x03, 0x04, 0x12, 0x03, 0x11, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
+    0x03, 0x05, 0x12, 0x03, 0x11, 0x0b, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x01,
+    0x12, 0x03, 0x11, 0x11, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x03, 0x12, 0x03,
+    0x11, 0x19, 0x1a, 0x0a, 0x36, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x04, 0x12, 0x03, 0x13, 0x02, 0x1d,
+    0x1a, 0x29, 0x20, 0x41, 0x20, 0x53, 0x77, 0x69, 0x6d, 0x20, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67,
+    0x65, 0x2c, 0x20, 0x65, 0x6e, 0x63, 0x72, 0x79, 0x70, 0x74, 0x65, 0x64, 0x20, 0x69, 0x66, 0x20,
+    0x6b, 0x65, 0x79, 0x20, 0x69, 0x73, 0x20, 0x73, 0x65, 0x74, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x00, 0x02, 0x04, 0x04, 0x12, 0x03, 0x13, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
+    0x04, 0x05, 0x12, 0x03, 0x13, 0x0b, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04, 0x01,
+    0x12, 0x03, 0x13, 0x11, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04, 0x03, 0x12, 0x03,
+    0x13, 0x1b, 0x1c, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x04, 0x16, 0x00, 0x23, 0x01, 0x0a,
+    0x0a, 0x0a, 0x03, 0x04, 0x01, 0x01, 0x12, 0x03, 0x16, 0x08, 0x0c, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
+    0x01, 0x04, 0x00, 0x12, 0x04, 0x17, 0x02, 0x1d, 0x03, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x04,
+    0x00, 0x01, 0x12, 0x03, 0x17, 0x07, 0x0b, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x01, 0x04, 0x00, 0x02,
+    0x00, 0x12, 0x03, 0x18, 0x04, 0x0d, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02, 0x00,
+    0x01, 0x12, 0x03, 0x18, 0x04, 0x08, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02, 0x00,
+    0x02, 0x12, 0x03, 0x18, 0x0b, 0x0c, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x01, 0x04, 0x00, 0x02, 0x01,
+    0x12, 0x03, 0x19, 0x04, 0x0c, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02, 0x01, 0x01,
+    0x12, 0x03, 0x19, 0x04, 0x07, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02, 0x01, 0x02,
+    0x12, 0x03, 0x19, 0x0a, 0x0b, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x01, 0x04, 0x00, 0x02, 0x02, 0x12,
+    0x03, 0x1a, 0x04, 0x10, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12,
+    0x03, 0x1a, 0x04, 0x0b, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02, 0x02, 0x02, 0x12,
+    0x03, 0x1a, 0x0e, 0x0f, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x01, 0x04, 0x00, 0x02, 0x03, 0x12, 0x03,
+    0x1b, 0x04, 0x0f, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02, 0x03, 0x01, 0x12, 0x03,
+    0x1b, 0x04, 0x0a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02, 0x03, 0x02, 0x12, 0x03,
+    0x1b, 0x0d, 0x0e, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x01, 0x04, 0x00, 0x02, 0x04, 0x12, 0x03, 0x1c,
+    0x04, 0x11, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02, 0x04, 0x01, 0x12, 0x03, 0x1c,
+    0x04, 0x0c, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02, 0x04, 0x02, 0x12, 0x03, 0x1c,
+    0x0f, 0x10, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x00, 0x12, 0x03, 0x1e, 0x02, 0x19, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x04, 0x12, 0x03, 0x1e, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x01, 0x02, 0x00, 0x06, 0x12, 0x03, 0x1e, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x1e, 0x10, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
+    0x00, 0x03, 0x12, 0x03, 0x1e, 0x17, 0x18, 0x0a, 0x21, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x01, 0x12,
+    0x03, 0x20, 0x02, 0x19, 0x1a, 0x14, 0x20, 0x4e, 0x6f, 0x74, 0x20, 0x73, 0x65, 0x74, 0x20, 0x66,
+    0x6f, 0x72, 0x20, 0x49, 0x4e, 0x4a, 0x45, 0x43, 0x54, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
+    0x02, 0x01, 0x04, 0x12, 0x03, 0x20, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01,
+    0x06, 0x12, 0x03, 0x20, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x01, 0x12,
+    0x03, 0x20, 0x10, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x03, 0x12, 0x03, 0x20,
+    0x17, 0x18, 0x0a, 0x5a, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x02, 0x12, 0x03, 0x22, 0x02, 0x1c, 0x1a,
+    0x4d, 0x20, 0x45, 0x61, 0x63, 0x68, 0x20, 0x61, 0x6e, 0x20, 0x65, 0x6e, 0x63, 0x6f, 0x64, 0x65,
+    0x64, 0x20, 0x52, 0x75, 0x6d, 0x6f, 0x72, 0x2c, 0x20, 0x73, 0x6f, 0x20, 0x74, 0x68, 0x61, 0x74,
+    0x20, 0x61, 0x20, 0x72, 0x75, 0x6d, 0x6f, 0x72, 0x20, 0x77, 0x65, 0x20, 0x63, 0x61, 0x6e, 0x6e,
+    0x6f, 0x74, 0x20, 0x72, 0x65, 0x61, 0x64, 0x20, 0x69, 0x73, 0x20, 0x64, 0x72, 0x6f, 0x70, 0x70,
+    0x65, 0x64, 0x20, 0x6f, 0x6e, 0x20, 0x69, 0x74, 0x73, 0x20, 0x6f, 0x77, 0x6e, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x04, 0x12, 0x03, 0x22, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x01, 0x02, 0x02, 0x05, 0x12, 0x03, 0x22, 0x0b, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
+    0x02, 0x02, 0x01, 0x12, 0x03, 0x22, 0x11, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02,
+    0x03, 0x12, 0x03, 0x22, 0x1a, 0x1b, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x02, 0x12, 0x04, 0x25, 0x00,
+    0x2c, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x02, 0x01, 0x12, 0x03, 0x25, 0x08, 0x0c, 0x0a, 0x0b,
+    0x0a, 0x04, 0x04, 0x02, 0x02, 0x00, 0x12, 0x03, 0x26, 0x02, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x02, 0x02, 0x00, 0x04, 0x12, 0x03, 0x26, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02,
+    0x00, 0x05, 0x12, 0x03, 0x26, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x01,
+    0x12, 0x03, 0x26, 0x12, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x03, 0x12, 0x03,
+    0x26, 0x1e, 0x1f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x01, 0x12, 0x03, 0x27, 0x02, 0x23,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x04, 0x12, 0x03, 0x27, 0x02, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x05, 0x12, 0x03, 0x27, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x02, 0x02, 0x01, 0x01, 0x12, 0x03, 0x27, 0x12, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02,
+    0x02, 0x01, 0x03, 0x12, 0x03, 0x27, 0x21, 0x22, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x02,
+    0x12, 0x03, 0x28, 0x02, 0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x02, 0x04, 0x12, 0x03,
+    0x28, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x02, 0x05, 0x12, 0x03, 0x28, 0x0b,
+    0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x02, 0x01, 0x12, 0x03, 0x28, 0x12, 0x1f, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x02, 0x03, 0x12, 0x03, 0x28, 0x22, 0x23, 0x0a, 0x0b, 0x0a,
+    0x04, 0x04, 0x02, 0x02, 0x03, 0x12, 0x03, 0x29, 0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02,
+    0x02, 0x03, 0x04, 0x12, 0x03, 0x29, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x03,
+    0x05, 0x12, 0x03, 0x29, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x03, 0x01, 0x12,
+    0x03, 0x29, 0x12, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x03, 0x03, 0x12, 0x03, 0x29,
+    0x1d, 0x1e, 0x0a, 0x4a, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x04, 0x12, 0x03, 0x2b, 0x02, 0x25, 0x1a,
+    0x3d, 0x20, 0x54, 0x68, 0x65, 0x20, 0x6e, 0x65, 0x77, 0x65, 0x73, 0x74, 0x20, 0x76, 0x65, 0x72,
+    0x73, 0x69, 0x6f, 0x6e, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x67, 0x6f, 0x73, 0x73,
+    0x69, 0x70, 0x20, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x63, 0x6f, 0x6c, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x20, 0x73, 0x70, 0x65, 0x61, 0x6b, 0x73, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x02, 0x02, 0x04, 0x04, 0x12, 0x03, 0x2b, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x02, 0x02, 0x04, 0x05, 0x12, 0x03, 0x2b, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02,
+    0x02, 0x04, 0x01, 0x12, 0x03, 0x2b, 0x12, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x04,
+    0x03, 0x12, 0x03, 0x2b, 0x23, 0x24, 0x0a, 0x5e, 0x0a, 0x02, 0x04, 0x03, 0x12, 0x04, 0x2f, 0x00,
+    0x36, 0x01, 0x1a, 0x52, 0x20, 0x41, 0x20, 0x72, 0x75, 0x6d, 0x6f, 0x72, 0x20, 0x63, 0x61, 0x72,
+    0x72, 0x69, 0x65, 0x73, 0x20, 0x61, 0x74, 0x20, 0x6d, 0x6f, 0x73, 0x74, 0x20, 0x6f, 0x6e, 0x65,
+    0x20, 0x70, 0x61, 0x79, 0x6c, 0x6f, 0x61, 0x64, 0x2e, 0x20, 0x41, 0x20, 0x72, 0x75, 0x6d, 0x6f,
+    0x72, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x6e, 0x6f, 0x20, 0x70, 0x61, 0x79, 0x6c, 0x6f, 0x61,
+    0x64, 0x20, 0x77, 0x65, 0x20, 0x6b, 0x6e, 0x6f, 0x77, 0x20, 0x69, 0x73, 0x20, 0x64, 0x72, 0x6f,
+    0x70, 0x70, 0x65, 0x64, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x03, 0x01, 0x12, 0x03, 0x2f,
+    0x08, 0x0d, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x00, 0x12, 0x03, 0x30, 0x02, 0x19, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x04, 0x12, 0x03, 0x30, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x03, 0x02, 0x00, 0x05, 0x12, 0x03, 0x30, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x03, 0x02, 0x00, 0x01, 0x12, 0x03, 0x30, 0x12, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02,
+    0x00, 0x03, 0x12, 0x03, 0x30, 0x17, 0x18, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x01, 0x12,
+    0x03, 0x31, 0x02, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x04, 0x12, 0x03, 0x31,
+    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x06, 0x12, 0x03, 0x31, 0x0b, 0x11,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x01, 0x12, 0x03, 0x31, 0x12, 0x18, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x03, 0x12, 0x03, 0x31, 0x1b, 0x1c, 0x0a, 0x0b, 0x0a, 0x04,
+    0x04, 0x03, 0x02, 0x02, 0x12, 0x03, 0x32, 0x02, 0x28, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02,
+    0x02, 0x04, 0x12, 0x03, 0x32, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x02, 0x06,
+    0x12, 0x03, 0x32, 0x0b, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x02, 0x01, 0x12, 0x03,
+    0x32, 0x17, 0x23, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x02, 0x03, 0x12, 0x03, 0x32, 0x26,
+    0x27, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x03, 0x12, 0x03, 0x33, 0x02, 0x21, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x03, 0x02, 0x03, 0x04, 0x12, 0x03, 0x33, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x03, 0x02, 0x03, 0x06, 0x12, 0x03, 0x33, 0x0b, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03,
+    0x02, 0x03, 0x01, 0x12, 0x03, 0x33, 0x14, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x03,
+    0x03, 0x12, 0x03, 0x33, 0x1f, 0x20, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x04, 0x12, 0x03,
+    0x34, 0x02, 0x26, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x04, 0x04, 0x12, 0x03, 0x34, 0x02,
+    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x04, 0x06, 0x12, 0x03, 0x34, 0x0b, 0x15, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x04, 0x01, 0x12, 0x03, 0x34, 0x16, 0x21, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x03, 0x02, 0x04, 0x03, 0x12, 0x03, 0x34, 0x24, 0x25, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
+    0x03, 0x02, 0x05, 0x12, 0x03, 0x35, 0x02, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x05,
+    0x04, 0x12, 0x03, 0x35, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x05, 0x06, 0x12,
+    0x03, 0x35, 0x0b, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x05, 0x01, 0x12, 0x03, 0x35,
+    0x13, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x05, 0x03, 0x12, 0x03, 0x35, 0x1e, 0x1f,
+    0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x04, 0x12, 0x04, 0x38, 0x00, 0x48, 0x01, 0x0a, 0x0a, 0x0a, 0x03,
+    0x04, 0x04, 0x01, 0x12, 0x03, 0x38, 0x08, 0x0e, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x04, 0x04, 0x00,
+    0x12, 0x04, 0x39, 0x02, 0x3e, 0x03, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x04, 0x00, 0x01, 0x12,
+    0x03, 0x39, 0x07, 0x0d, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03,
+    0x3a, 0x04, 0x0e, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x04, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03,
+    0x3a, 0x04, 0x09, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x04, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03,
+    0x3a, 0x0c, 0x0d, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x04, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x3b,
+    0x04, 0x10, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x04, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x3b,
+    0x04, 0x0b, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x04, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x3b,
+    0x0e, 0x0f, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x04, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x3c, 0x04,
+    0x12, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x04, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x3c, 0x04,
+    0x0d, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x04, 0x04, 0x00, 0x02, 0x02, 0x02, 0x12, 0x03, 0x3c, 0x10,
+    0x11, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x04, 0x04, 0x00, 0x02, 0x03, 0x12, 0x03, 0x3d, 0x04, 0x11,
+    0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x04, 0x04, 0x00, 0x02, 0x03, 0x01, 0x12, 0x03, 0x3d, 0x04, 0x0c,
+    0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x04, 0x04, 0x00, 0x02, 0x03, 0x02, 0x12, 0x03, 0x3d, 0x0f, 0x10,
+    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x00, 0x12, 0x03, 0x3f, 0x02, 0x19, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x04, 0x02, 0x00, 0x04, 0x12, 0x03, 0x3f, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x04, 0x02, 0x00, 0x05, 0x12, 0x03, 0x3f, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02,
+    0x00, 0x01, 0x12, 0x03, 0x3f, 0x12, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x03,
+    0x12, 0x03, 0x3f, 0x17, 0x18, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x01, 0x12, 0x03, 0x40,
+    0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x04, 0x12, 0x03, 0x40, 0x02, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x05, 0x12, 0x03, 0x40, 0x0b, 0x11, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x01, 0x12, 0x03, 0x40, 0x12, 0x1a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x04, 0x02, 0x01, 0x03, 0x12, 0x03, 0x40, 0x1d, 0x1e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04,
+    0x02, 0x02, 0x12, 0x03, 0x41, 0x02, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x02, 0x04,
+    0x12, 0x03, 0x41, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x02, 0x05, 0x12, 0x03,
+    0x41, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x02, 0x01, 0x12, 0x03, 0x41, 0x12,
+    0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x02, 0x03, 0x12, 0x03, 0x41, 0x17, 0x18, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x03, 0x12, 0x03, 0x42, 0x02, 0x26, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x04, 0x02, 0x03, 0x04, 0x12, 0x03, 0x42, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04,
+    0x02, 0x03, 0x05, 0x12, 0x03, 0x42, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x03,
+    0x01, 0x12, 0x03, 0x42, 0x12, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x03, 0x03, 0x12,
+    0x03, 0x42, 0x24, 0x25, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x04, 0x12, 0x03, 0x43, 0x02,
+    0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x04, 0x04, 0x12, 0x03, 0x43, 0x02, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x04, 0x05, 0x12, 0x03, 0x43, 0x0b, 0x11, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x04, 0x02, 0x04, 0x01, 0x12, 0x03, 0x43, 0x12, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x04, 0x02, 0x04, 0x03, 0x12, 0x03, 0x43, 0x20, 0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02,
+    0x05, 0x12, 0x03, 0x44, 0x02, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x05, 0x04, 0x12,
+    0x03, 0x44, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x05, 0x06, 0x12, 0x03, 0x44,
+    0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x05, 0x01, 0x12, 0x03, 0x44, 0x12, 0x18,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x05, 0x03, 0x12, 0x03, 0x44, 0x1b, 0x1c, 0x0a, 0x0b,
+    0x0a, 0x04, 0x04, 0x04, 0x02, 0x06, 0x12, 0x03, 0x45, 0x02, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x04, 0x02, 0x06, 0x04, 0x12, 0x03, 0x45, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02,
+    0x06, 0x05, 0x12, 0x03, 0x45, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x06, 0x01,
+    0x12, 0x03, 0x45, 0x10, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x06, 0x03, 0x12, 0x03,
+    0x45, 0x1c, 0x1d, 0x0a, 0x46, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x07, 0x12, 0x03, 0x47, 0x02, 0x23,
+    0x1a, 0x39, 0x20, 0x54, 0x68, 0x65, 0x20, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x20, 0x74, 0x68,
+    0x61, 0x74, 0x20, 0x73, 0x75, 0x73, 0x70, 0x65, 0x63, 0x74, 0x65, 0x64, 0x20, 0x74, 0x68, 0x69,
+    0x73, 0x20, 0x6f, 0x6e, 0x65, 0x2c, 0x20, 0x77, 0x68, 0x69, 0x6c, 0x65, 0x20, 0x69, 0x74, 0x20,
+    0x69, 0x73, 0x20, 0x73, 0x75, 0x73, 0x70, 0x65, 0x63, 0x74, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x04, 0x02, 0x07, 0x04, 0x12, 0x03, 0x47, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02,
+    0x07, 0x05, 0x12, 0x03, 0x47, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x07, 0x01,
+    0x12, 0x03, 0x47, 0x12, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x07, 0x03, 0x12, 0x03,
+    0x47, 0x21, 0x22, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x05, 0x12, 0x04, 0x4a, 0x00, 0x70, 0x01, 0x0a,
+    0x0a, 0x0a, 0x03, 0x04, 0x05, 0x01, 0x12, 0x03, 0x4a, 0x08, 0x13, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
+    0x05, 0x04, 0x00, 0x12, 0x04, 0x4b, 0x02, 0x50, 0x03, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x04,
+    0x00, 0x01, 0x12, 0x03, 0x4b, 0x07, 0x12, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x05, 0x04, 0x00, 0x02,
+    0x00, 0x12, 0x03, 0x4c, 0x04, 0x0b, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x05, 0x04, 0x00, 0x02, 0x00,
+    0x01, 0x12, 0x03, 0x4c, 0x04, 0x06, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x05, 0x04, 0x00, 0x02, 0x00,
+    0x02, 0x12, 0x03, 0x4c, 0x09, 0x0a, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x05, 0x04, 0x00, 0x02, 0x01,
+    0x12, 0x03, 0x4d, 0x04, 0x10, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x05, 0x04, 0x00, 0x02, 0x01, 0x01,
+    0x12, 0x03, 0x4d, 0x04, 0x0b, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x05, 0x04, 0x00, 0x02, 0x01, 0x02,
+    0x12, 0x03, 0x4d, 0x0e, 0x0f, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x05, 0x04, 0x00, 0x02, 0x02, 0x12,
+    0x03, 0x4e, 0x04, 0x11, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x05, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12,
+    0x03, 0x4e, 0x04, 0x0c, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x05, 0x04, 0x00, 0x02, 0x02, 0x02, 0x12,
+    0x03, 0x4e, 0x0f, 0x10, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x05, 0x04, 0x00, 0x02, 0x03, 0x12, 0x03,
+    0x4f, 0x04, 0x10, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x05, 0x04, 0x00, 0x02, 0x03, 0x01, 0x12, 0x03,
+    0x4f, 0x04, 0x0b, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x05, 0x04, 0x00, 0x02, 0x03, 0x02, 0x12, 0x03,
+    0x4f, 0x0e, 0x0f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x00, 0x12, 0x03, 0x51, 0x02, 0x19,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x04, 0x12, 0x03, 0x51, 0x02, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x05, 0x12, 0x03, 0x51, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x05, 0x02, 0x00, 0x01, 0x12, 0x03, 0x51, 0x12, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05,
+    0x02, 0x00, 0x03, 0x12, 0x03, 0x51, 0x17, 0x18, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x01,
+    0x12, 0x03, 0x52, 0x02, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x04, 0x12, 0x03,
+    0x52, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x05, 0x12, 0x03, 0x52, 0x0b,
+    0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x01, 0x12, 0x03, 0x52, 0x12, 0x1b, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x03, 0x12, 0x03, 0x52, 0x1e, 0x1f, 0x0a, 0x0b, 0x0a,
+    0x04, 0x04, 0x05, 0x02, 0x02, 0x12, 0x03, 0x53, 0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05,
+    0x02, 0x02, 0x04, 0x12, 0x03, 0x53, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x02,
+    0x05, 0x12, 0x03, 0x53, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x02, 0x01, 0x12,
+    0x03, 0x53, 0x12, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x02, 0x03, 0x12, 0x03, 0x53,
+    0x1d, 0x1e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x03, 0x12, 0x03, 0x54, 0x02, 0x19, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x03, 0x04, 0x12, 0x03, 0x54, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x05, 0x02, 0x03, 0x05, 0x12, 0x03, 0x54, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x05, 0x02, 0x03, 0x01, 0x12, 0x03, 0x54, 0x12, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02,
+    0x03, 0x03, 0x12, 0x03, 0x54, 0x17, 0x18, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x04, 0x12,
+    0x03, 0x55, 0x02, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x04, 0x04, 0x12, 0x03, 0x55,
+    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x04, 0x05, 0x12, 0x03, 0x55, 0x0b, 0x11,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x04, 0x01, 0x12, 0x03, 0x55, 0x12, 0x1d, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x05, 0x02, 0x04, 0x03, 0x12, 0x03, 0x55, 0x20, 0x21, 0x0a, 0x0b, 0x0a, 0x04,
+    0x04, 0x05, 0x02, 0x05, 0x12, 0x03, 0x56, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02,
+    0x05, 0x04, 0x12, 0x03, 0x56, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x05, 0x05,
+    0x12, 0x03, 0x56, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x05, 0x01, 0x12, 0x03,
+    0x56, 0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x05, 0x03, 0x12, 0x03, 0x56, 0x19,
+    0x1a, 0x0a, 0x47, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x06, 0x12, 0x03, 0x58, 0x02, 0x20, 0x1a, 0x3a,
+    0x20, 0x53, 0x65, 0x74, 0x2c, 0x20, 0x70, 0x6f, 0x73, 0x73, 0x69, 0x62, 0x6c, 0x79, 0x20, 0x74,
+    0x6f, 0x20, 0x6e, 0x6f, 0x74, 0x68, 0x69, 0x6e, 0x67, 0x2c, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x20, 0x65, 0x78, 0x70, 0x6f,
+    0x73, 0x65, 0x73, 0x20, 0x70, 0x6f, 0x72, 0x74, 0x73, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05,
+    0x02, 0x06, 0x04, 0x12, 0x03, 0x58, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x06,
+    0x05, 0x12, 0x03, 0x58, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x06, 0x01, 0x12,
+    0x03, 0x58, 0x10, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x06, 0x03, 0x12, 0x03, 0x58,
+    0x1e, 0x1f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x07, 0x12, 0x03, 0x59, 0x02, 0x1e, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x07, 0x04, 0x12, 0x03, 0x59, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x05, 0x02, 0x07, 0x05, 0x12, 0x03, 0x59, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x05, 0x02, 0x07, 0x01, 0x12, 0x03, 0x59, 0x12, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02,
+    0x07, 0x03, 0x12, 0x03, 0x59, 0x1c, 0x1d, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x08, 0x12,
+    0x03, 0x5a, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x08, 0x04, 0x12, 0x03, 0x5a,
+    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x08, 0x05, 0x12, 0x03, 0x5a, 0x0b, 0x0f,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x08, 0x01, 0x12, 0x03, 0x5a, 0x10, 0x16, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x05, 0x02, 0x08, 0x03, 0x12, 0x03, 0x5a, 0x19, 0x1a, 0x0a, 0x0b, 0x0a, 0x04,
+    0x04, 0x05, 0x02, 0x09, 0x12, 0x03, 0x5b, 0x02, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02,
+    0x09, 0x04, 0x12, 0x03, 0x5b, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x09, 0x05,
+    0x12, 0x03, 0x5b, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x09, 0x01, 0x12, 0x03,
+    0x5b, 0x10, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x09, 0x03, 0x12, 0x03, 0x5b, 0x1b,
+    0x1d, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x0a, 0x12, 0x03, 0x5c, 0x02, 0x1f, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x05, 0x02, 0x0a, 0x04, 0x12, 0x03, 0x5c, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x05, 0x02, 0x0a, 0x05, 0x12, 0x03, 0x5c, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05,
+    0x02, 0x0a, 0x01, 0x12, 0x03, 0x5c, 0x10, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x0a,
+    0x03, 0x12, 0x03, 0x5c, 0x1c, 0x1e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x0b, 0x12, 0x03,
+    0x5d, 0x02, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x0b, 0x04, 0x12, 0x03, 0x5d, 0x02,
+    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x0b, 0x05, 0x12, 0x03, 0x5d, 0x0b, 0x11, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x0b, 0x01, 0x12, 0x03, 0x5d, 0x12, 0x16, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x05, 0x02, 0x0b, 0x03, 0x12, 0x03, 0x5d, 0x19, 0x1b, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
+    0x05, 0x02, 0x0c, 0x12, 0x03, 0x5e, 0x02, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x0c,
+    0x04, 0x12, 0x03, 0x5e, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x0c, 0x05, 0x12,
+    0x03, 0x5e, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x0c, 0x01, 0x12, 0x03, 0x5e,
+    0x10, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x0c, 0x03, 0x12, 0x03, 0x5e, 0x1b, 0x1d,
+    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x0d, 0x12, 0x03, 0x5f, 0x02, 0x21, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x05, 0x02, 0x0d, 0x04, 0x12, 0x03, 0x5f, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x05, 0x02, 0x0d, 0x05, 0x12, 0x03, 0x5f, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02,
+    0x0d, 0x01, 0x12, 0x03, 0x5f, 0x10, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x0d, 0x03,
+    0x12, 0x03, 0x5f, 0x1e, 0x20, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x0e, 0x12, 0x03, 0x60,
+    0x02, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x0e, 0x04, 0x12, 0x03, 0x60, 0x02, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x0e, 0x05, 0x12, 0x03, 0x60, 0x0b, 0x0f, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x05, 0x02, 0x0e, 0x01, 0x12, 0x03, 0x60, 0x10, 0x1b, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x05, 0x02, 0x0e, 0x03, 0x12, 0x03, 0x60, 0x1e, 0x20, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05,
+    0x02, 0x0f, 0x12, 0x03, 0x61, 0x02, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x0f, 0x04,
+    0x12, 0x03, 0x61, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x0f, 0x05, 0x12, 0x03,
+    0x61, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x0f, 0x01, 0x12, 0x03, 0x61, 0x10,
+    0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x0f, 0x03, 0x12, 0x03, 0x61, 0x1a, 0x1c, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x10, 0x12, 0x03, 0x62, 0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x05, 0x02, 0x10, 0x04, 0x12, 0x03, 0x62, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05,
+    0x02, 0x10, 0x05, 0x12, 0x03, 0x62, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x10,
+    0x01, 0x12, 0x03, 0x62, 0x12, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x10, 0x03, 0x12,
+    0x03, 0x62, 0x1c, 0x1e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x11, 0x12, 0x03, 0x63, 0x02,
+    0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x11, 0x04, 0x12, 0x03, 0x63, 0x02, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x11, 0x05, 0x12, 0x03, 0x63, 0x0b, 0x11, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x05, 0x02, 0x11, 0x01, 0x12, 0x03, 0x63, 0x12, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x05, 0x02, 0x11, 0x03, 0x12, 0x03, 0x63, 0x1a, 0x1c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02,
+    0x12, 0x12, 0x03, 0x64, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x12, 0x04, 0x12,
+    0x03, 0x64, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x12, 0x05, 0x12, 0x03, 0x64,
+    0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x12, 0x01, 0x12, 0x03, 0x64, 0x10, 0x15,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x12, 0x03, 0x12, 0x03, 0x64, 0x18, 0x1a, 0x0a, 0x0b,
+    0x0a, 0x04, 0x04, 0x05, 0x02, 0x13, 0x12, 0x03, 0x65, 0x02, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x05, 0x02, 0x13, 0x04, 0x12, 0x03, 0x65, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02,
+    0x13, 0x05, 0x12, 0x03, 0x65, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x13, 0x01,
+    0x12, 0x03, 0x65, 0x10, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x13, 0x03, 0x12, 0x03,
+    0x65, 0x1a, 0x1c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x14, 0x12, 0x03, 0x66, 0x02, 0x1f,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x14, 0x04, 0x12, 0x03, 0x66, 0x02, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x05, 0x02, 0x14, 0x05, 0x12, 0x03, 0x66, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x05, 0x02, 0x14, 0x01, 0x12, 0x03, 0x66, 0x10, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05,
+    0x02, 0x14, 0x03, 0x12, 0x03, 0x66, 0x1c, 0x1e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x15,
+    0x12, 0x03, 0x67, 0x02, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x15, 0x04, 0x12, 0x03,
+    0x67, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x15, 0x05, 0x12, 0x03, 0x67, 0x0b,
+    0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x15, 0x01, 0x12, 0x03, 0x67, 0x10, 0x18, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x15, 0x03, 0x12, 0x03, 0x67, 0x1b, 0x1d, 0x0a, 0x0b, 0x0a,
+    0x04, 0x04, 0x05, 0x02, 0x16, 0x12, 0x03, 0x68, 0x02, 0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05,
+    0x02, 0x16, 0x04, 0x12, 0x03, 0x68, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x16,
+    0x05, 0x12, 0x03, 0x68, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x16, 0x01, 0x12,
+    0x03, 0x68, 0x10, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x16, 0x03, 0x12, 0x03, 0x68,
+    0x21, 0x23, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x17, 0x12, 0x03, 0x69, 0x02, 0x1e, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x17, 0x04, 0x12, 0x03, 0x69, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x05, 0x02, 0x17, 0x05, 0x12, 0x03, 0x69, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x05, 0x02, 0x17, 0x01, 0x12, 0x03, 0x69, 0x10, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02,
+    0x17, 0x03, 0x12, 0x03, 0x69, 0x1b, 0x1d, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x18, 0x12,
+    0x03, 0x6a, 0x02, 0x29, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x18, 0x04, 0x12, 0x03, 0x6a,
+    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x18, 0x06, 0x12, 0x03, 0x6a, 0x0b, 0x16,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x18, 0x01, 0x12, 0x03, 0x6a, 0x17, 0x23, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x05, 0x02, 0x18, 0x03, 0x12, 0x03, 0x6a, 0x26, 0x28, 0x0a, 0x0b, 0x0a, 0x04,
+    0x04, 0x05, 0x02, 0x19, 0x12, 0x03, 0x6b, 0x02, 0x23, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02,
+    0x19, 0x04, 0x12, 0x03, 0x6b, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x19, 0x05,
+    0x12, 0x03, 0x6b, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x19, 0x01, 0x12, 0x03,
+    0x6b, 0x12, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x19, 0x03, 0x12, 0x03, 0x6b, 0x20,
+    0x22, 0x0a, 0x51, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x1a, 0x12, 0x03, 0x6d, 0x02, 0x20, 0x1a, 0x44,
+    0x20, 0x53, 0x65, 0x74, 0x20, 0x77, 0x68, 0x69, 0x6c, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73,
+    0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x27, 0x73, 0x20, 0x70, 0x72, 0x6f, 0x63, 0x65, 0x73, 0x73,
+    0x20, 0x6b, 0x65, 0x65, 0x70, 0x73, 0x20, 0x65, 0x78, 0x69, 0x74, 0x69, 0x6e, 0x67, 0x20, 0x73,
+    0x6f, 0x6f, 0x6e, 0x20, 0x61, 0x66, 0x74, 0x65, 0x72, 0x20, 0x69, 0x74, 0x20, 0x73, 0x74, 0x61,
+    0x72, 0x74, 0x73, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x1a, 0x04, 0x12, 0x03, 0x6d,
+    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x1a, 0x05, 0x12, 0x03, 0x6d, 0x0b, 0x0f,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x1a, 0x01, 0x12, 0x03, 0x6d, 0x10, 0x1a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x05, 0x02, 0x1a, 0x03, 0x12, 0x03, 0x6d, 0x1d, 0x1f, 0x0a, 0x46, 0x0a, 0x04,
+    0x04, 0x05, 0x02, 0x1b, 0x12, 0x03, 0x6f, 0x02, 0x23, 0x1a, 0x39, 0x20, 0x54, 0x68, 0x65, 0x20,
+    0x72, 0x65, 0x6c, 0x65, 0x61, 0x73, 0x65, 0x20, 0x74, 0x68, 0x69, 0x73, 0x20, 0x6d, 0x65, 0x6d,
+    0x62, 0x65, 0x72, 0x20, 0x6c, 0x61, 0x73, 0x74, 0x20, 0x72, 0x6f, 0x6c, 0x6c, 0x65, 0x64, 0x20,
+    0x61, 0x6e, 0x20, 0x75, 0x70, 0x64, 0x61, 0x74, 0x65, 0x20, 0x62, 0x61, 0x63, 0x6b, 0x20, 0x66,
+    0x72, 0x6f, 0x6d, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x1b, 0x04, 0x12, 0x03, 0x6f,
+    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x1b, 0x05, 0x12, 0x03, 0x6f, 0x0b, 0x11,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x1b, 0x01, 0x12, 0x03, 0x6f, 0x12, 0x1d, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x05, 0x02, 0x1b, 0x03, 0x12, 0x03, 0x6f, 0x20, 0x22, 0x0a, 0x0b, 0x0a, 0x02,
+    0x04, 0x06, 0x12, 0x05, 0x72, 0x00, 0x80, 0x01, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x06, 0x01,
+    0x12, 0x03, 0x72, 0x08, 0x10, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x06, 0x04, 0x00, 0x12, 0x04, 0x73,
+    0x02, 0x76, 0x03, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x04, 0x00, 0x01, 0x12, 0x03, 0x73, 0x07,
+    0x0d, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x06, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x74, 0x04, 0x10,
+    0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x06, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x74, 0x04, 0x0b,
+    0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x06, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x74, 0x0e, 0x0f,
+    0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x06, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x75, 0x04, 0x11, 0x0a,
+    0x0e, 0x0a, 0x07, 0x04, 0x06, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x75, 0x04, 0x0c, 0x0a,
+    0x0e, 0x0a, 0x07, 0x04, 0x06, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x75, 0x0f, 0x10, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x00, 0x12, 0x03, 0x77, 0x02, 0x19, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x06, 0x02, 0x00, 0x04, 0x12, 0x03, 0x77, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06,
+    0x02, 0x00, 0x05, 0x12, 0x03, 0x77, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00,
+    0x01, 0x12, 0x03, 0x77, 0x12, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x03, 0x12,
+    0x03, 0x77, 0x17, 0x18, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x01, 0x12, 0x03, 0x78, 0x02,
+    0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x04, 0x12, 0x03, 0x78, 0x02, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x05, 0x12, 0x03, 0x78, 0x0b, 0x11, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x06, 0x02, 0x01, 0x01, 0x12, 0x03, 0x78, 0x12, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x06, 0x02, 0x01, 0x03, 0x12, 0x03, 0x78, 0x1c, 0x1d, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02,
+    0x02, 0x12, 0x03, 0x79, 0x02, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x02, 0x04, 0x12,
+    0x03, 0x79, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x02, 0x05, 0x12, 0x03, 0x79,
+    0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x02, 0x01, 0x12, 0x03, 0x79, 0x12, 0x17,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x02, 0x03, 0x12, 0x03, 0x79, 0x1a, 0x1b, 0x0a, 0x0b,
+    0x0a, 0x04, 0x04, 0x06, 0x02, 0x03, 0x12, 0x03, 0x7a, 0x02, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x06, 0x02, 0x03, 0x04, 0x12, 0x03, 0x7a, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02,
+    0x03, 0x05, 0x12, 0x03, 0x7a, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x03, 0x01,
+    0x12, 0x03, 0x7a, 0x12, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x03, 0x03, 0x12, 0x03,
+    0x7a, 0x1e, 0x1f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x04, 0x12, 0x03, 0x7b, 0x02, 0x22,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x04, 0x04, 0x12, 0x03, 0x7b, 0x02, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x06, 0x02, 0x04, 0x05, 0x12, 0x03, 0x7b, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x06, 0x02, 0x04, 0x01, 0x12, 0x03, 0x7b, 0x12, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06,
+    0x02, 0x04, 0x03, 0x12, 0x03, 0x7b, 0x20, 0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x05,
+    0x12, 0x03, 0x7c, 0x02, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x05, 0x04, 0x12, 0x03,
+    0x7c, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x05, 0x05, 0x12, 0x03, 0x7c, 0x0b,
+    0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x05, 0x01, 0x12, 0x03, 0x7c, 0x12, 0x17, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x05, 0x03, 0x12, 0x03, 0x7c, 0x1a, 0x1b, 0x0a, 0x0b, 0x0a,
+    0x04, 0x04, 0x06, 0x02, 0x06, 0x12, 0x03, 0x7d, 0x02, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06,
+    0x02, 0x06, 0x04, 0x12, 0x03, 0x7d, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x06,
+    0x06, 0x12, 0x03, 0x7d, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x06, 0x01, 0x12,
+    0x03, 0x7d, 0x12, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x06, 0x03, 0x12, 0x03, 0x7d,
+    0x1b, 0x1c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x07, 0x12, 0x03, 0x7e, 0x02, 0x1b, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x07, 0x04, 0x12, 0x03, 0x7e, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x06, 0x02, 0x07, 0x05, 0x12, 0x03, 0x7e, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x06, 0x02, 0x07, 0x01, 0x12, 0x03, 0x7e, 0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02,
+    0x07, 0x03, 0x12, 0x03, 0x7e, 0x19, 0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x08, 0x12,
+    0x03, 0x7f, 0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x08, 0x04, 0x12, 0x03, 0x7f,
+    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x08, 0x05, 0x12, 0x03, 0x7f, 0x0b, 0x11,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x08, 0x01, 0x12, 0x03, 0x7f, 0x12, 0x1a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x06, 0x02, 0x08, 0x03, 0x12, 0x03, 0x7f, 0x1d, 0x1e, 0x0a, 0x68, 0x0a, 0x02,
+    0x04, 0x07, 0x12, 0x06, 0x83, 0x01, 0x00, 0x8b, 0x01, 0x01, 0x1a, 0x5a, 0x20, 0x57, 0x68, 0x65,
+    0x74, 0x68, 0x65, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x66, 0x69, 0x6c, 0x65, 0x20, 0x68, 0x61,
+    0x73, 0x20, 0x62, 0x65, 0x65, 0x6e, 0x20, 0x77, 0x72, 0x69, 0x74, 0x74, 0x65, 0x6e, 0x20, 0x6f,
+    0x75, 0x74, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x72, 0x65, 0x63, 0x65, 0x69, 0x76,
+    0x65, 0x72, 0x27, 0x73, 0x20, 0x6f, 0x77, 0x6e, 0x20, 0x62, 0x75, 0x73, 0x69, 0x6e, 0x65, 0x73,
+    0x73, 0x2c, 0x20, 0x73, 0x6f, 0x20, 0x69, 0x74, 0x20, 0x69, 0x73, 0x20, 0x6e, 0x6f, 0x74, 0x20,
+    0x73, 0x65, 0x6e, 0x74, 0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x07, 0x01, 0x12, 0x04, 0x83,
+    0x01, 0x08, 0x12, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x00, 0x12, 0x04, 0x84, 0x01, 0x02,
+    0x24, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x04, 0x12, 0x04, 0x84, 0x01, 0x02, 0x0a,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x05, 0x12, 0x04, 0x84, 0x01, 0x0b, 0x11, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x01, 0x12, 0x04, 0x84, 0x01, 0x12, 0x1f, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x03, 0x12, 0x04, 0x84, 0x01, 0x22, 0x23, 0x0a, 0x0c, 0x0a,
+    0x04, 0x04, 0x07, 0x02, 0x01, 0x12, 0x04, 0x85, 0x01, 0x02, 0x20, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x07, 0x02, 0x01, 0x04, 0x12, 0x04, 0x85, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x07,
+    0x02, 0x01, 0x05, 0x12, 0x04, 0x85, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x07, 0x02,
+    0x01, 0x01, 0x12, 0x04, 0x85, 0x01, 0x12, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01,
+    0x03, 0x12, 0x04, 0x85, 0x01, 0x1e, 0x1f, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x02, 0x12,
+    0x04, 0x86, 0x01, 0x02, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x02, 0x04, 0x12, 0x04,
+    0x86, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x02, 0x05, 0x12, 0x04, 0x86,
+    0x01, 0x0b, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x02, 0x01, 0x12, 0x04, 0x86, 0x01,
+    0x11, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x02, 0x03, 0x12, 0x04, 0x86, 0x01, 0x18,
+    0x19, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x03, 0x12, 0x04, 0x87, 0x01, 0x02, 0x1f, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x03, 0x04, 0x12, 0x04, 0x87, 0x01, 0x02, 0x0a, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x07, 0x02, 0x03, 0x05, 0x12, 0x04, 0x87, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x07, 0x02, 0x03, 0x01, 0x12, 0x04, 0x87, 0x01, 0x12, 0x1a, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x07, 0x02, 0x03, 0x03, 0x12, 0x04, 0x87, 0x01, 0x1d, 0x1e, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
+    0x07, 0x02, 0x04, 0x12, 0x04, 0x88, 0x01, 0x02, 0x25, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x07, 0x02,
+    0x04, 0x04, 0x12, 0x04, 0x88, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x04,
+    0x05, 0x12, 0x04, 0x88, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x04, 0x01,
+    0x12, 0x04, 0x88, 0x01, 0x12, 0x20, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x04, 0x03, 0x12,
+    0x04, 0x88, 0x01, 0x23, 0x24, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x05, 0x12, 0x04, 0x89,
+    0x01, 0x02, 0x1e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x05, 0x04, 0x12, 0x04, 0x89, 0x01,
+    0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x05, 0x05, 0x12, 0x04, 0x89, 0x01, 0x0b,
+    0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x05, 0x01, 0x12, 0x04, 0x89, 0x01, 0x10, 0x19,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x05, 0x03, 0x12, 0x04, 0x89, 0x01, 0x1c, 0x1d, 0x0a,
+    0x0c, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x06, 0x12, 0x04, 0x8a, 0x01, 0x02, 0x1f, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x07, 0x02, 0x06, 0x04, 0x12, 0x04, 0x8a, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x07, 0x02, 0x06, 0x05, 0x12, 0x04, 0x8a, 0x01, 0x0b, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x07, 0x02, 0x06, 0x01, 0x12, 0x04, 0x8a, 0x01, 0x11, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x07,
+    0x02, 0x06, 0x03, 0x12, 0x04, 0x8a, 0x01, 0x1d, 0x1e, 0x0a, 0x71, 0x0a, 0x02, 0x04, 0x08, 0x12,
+    0x06, 0x8e, 0x01, 0x00, 0x92, 0x01, 0x01, 0x1a, 0x63, 0x20, 0x54, 0x68, 0x65, 0x20, 0x72, 0x69,
+    0x6e, 0x67, 0x20, 0x73, 0x65, 0x6e, 0x64, 0x73, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x61, 0x20,
+    0x72, 0x65, 0x76, 0x69, 0x73, 0x69, 0x6f, 0x6e, 0x20, 0x6f, 0x66, 0x20, 0x69, 0x74, 0x73, 0x20,
+    0x6b, 0x65, 0x79, 0x2c, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x69, 0x66, 0x20, 0x72, 0x65, 0x74, 0x69,
+    0x72, 0x65, 0x20, 0x69, 0x73, 0x20, 0x73, 0x65, 0x74, 0x2c, 0x20, 0x72, 0x65, 0x66, 0x75, 0x73,
+    0x65, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x72, 0x65, 0x76, 0x69, 0x73, 0x69, 0x6f, 0x6e, 0x73,
+    0x20, 0x62, 0x65, 0x66, 0x6f, 0x72, 0x65, 0x20, 0x69, 0x74, 0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03,
+    0x04, 0x08, 0x01, 0x12, 0x04, 0x8e, 0x01, 0x08, 0x0f, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x08, 0x02,
+    0x00, 0x12, 0x04, 0x8f, 0x01, 0x02, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x04,
+    0x12, 0x04, 0x8f, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x05, 0x12,
+    0x04, 0x8f, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x01, 0x12, 0x04,
+    0x8f, 0x01, 0x12, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x03, 0x12, 0x04, 0x8f,
+    0x01, 0x19, 0x1a, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x01, 0x12, 0x04, 0x90, 0x01, 0x02,
+    0x1f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x04, 0x12, 0x04, 0x90, 0x01, 0x02, 0x0a,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x05, 0x12, 0x04, 0x90, 0x01, 0x0b, 0x11, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x01, 0x12, 0x04, 0x90, 0x01, 0x12, 0x1a, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x03, 0x12, 0x04, 0x90, 0x01, 0x1d, 0x1e, 0x0a, 0x0c, 0x0a,
+    0x04, 0x04, 0x08, 0x02, 0x02, 0x12, 0x04, 0x91, 0x01, 0x02, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x08, 0x02, 0x02, 0x04, 0x12, 0x04, 0x91, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08,
+    0x02, 0x02, 0x05, 0x12, 0x04, 0x91, 0x01, 0x0b, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08, 0x02,
+    0x02, 0x01, 0x12, 0x04, 0x91, 0x01, 0x10, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x02,
+    0x03, 0x12, 0x04, 0x91, 0x01, 0x19, 0x1a,
+];
+
+static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
+    lock: ::protobuf::lazy::ONCE_INIT,
+    ptr: 0 as *const ::protobuf::descriptor::FileDescriptorProto,
+};
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    unsafe {
+        file_descriptor_proto_lazy.get(|| {
+            parse_descriptor_proto()
+        })
+    }
+}
@@ -33,16 +33,22 @@
 use std::collections::{HashMap, BTreeMap};
 use std::fmt;
 use std::ops::{Deref, DerefMut};
+use std::str::FromStr;
 use std::sync::{Arc, RwLock};
 use std::thread;
 use std::time::Duration;
 
+use hcore::package::PackageIdent;
+use protobuf::RepeatedField;
+use sup_protocol::gossip as proto;
 use toml;
 use uuid::Uuid;
 
 use gossip::member::{MemberId, MemberList, Health};
 use gossip::lamport_clock::LamportClock;
+use gossip::wire;
 use error::{Error, Result};
+use health_check;
 use util;
 
 static LOGKEY: &'static str = "CN";
@@ -74,6 +80,17 @@ pub struct CensusEntry {
     pub suspect: bool,
     pub confirmed: bool,
     pub detached: bool,
+    // These are options, like `election` and `needs_write`, so entries from Supervisors that
+    // predate rolling updates still decode
+    pub update_pending: Option<bool>,
+    pub updating: Option<bool>,
+    pub health_check: Option<health_check::Status>,
+    /// Whether the service's process keeps exiting soon after it starts; an option so entries
+    /// from Supervisors that predate crash loop reporting still decode
+    pub crash_loop: Option<bool>,
+    /// The release we last rolled an update back from, so the rest of the service group never
+    /// updates to it either
+    pub rolled_back: Option<String>,
     pub incarnation: LamportClock,
 }
 
@@ -102,6 +119,11 @@ impl CensusEntry {
             suspect: false,
             confirmed: false,
             detached: false,
+            update_pending: None,
+            updating: None,
+            health_check: None,
+            crash_loop: None,
+            rolled_back: None,
             service: service.into(),
             group: group.into(),
             incarnation: LamportClock::new(),
@@ -182,6 +204,72 @@ impl CensusEntry {
         self.needs_write = Some(true);
     }
 
+    /// Set whether we have downloaded an update and are waiting for our turn to apply it.
+    pub fn update_pending(&mut self, update_pending: bool) {
+        if self.is_update_pending() != update_pending {
+            self.update_pending = Some(update_pending);
+            self.incarnation.increment();
+            self.needs_write = Some(true);
+        }
+    }
+
+    /// Set whether we are in the middle of applying an update.
+    pub fn updating(&mut self, updating: bool) {
+        if self.is_updating() != updating {
+            self.updating = Some(updating);
+            self.incarnation.increment();
+            self.needs_write = Some(true);
+        }
+    }
+
+    /// Record that we rolled back an update to `ident`.
+    pub fn rolled_back(&mut self, ident: &PackageIdent) {
+        let ident = ident.to_string();
+        if self.rolled_back.as_ref() != Some(&ident) {
+            self.rolled_back = Some(ident);
+            self.incarnation.increment();
+            self.needs_write = Some(true);
+        }
+    }
+
+    /// Have we downloaded an update, and are we waiting for our turn to apply it?
+    pub fn is_update_pending(&self) -> bool {
+        self.update_pending.unwrap_or(false)
+    }
+
+    /// Are we in the middle of applying an update?
+    pub fn is_updating(&self) -> bool {
+        self.updating.unwrap_or(false)
+    }
+
+    /// Set the status of our last health check.
+    pub fn health_check(&mut self, health_check: Option<health_check::Status>) {
+        self.health_check = health_check;
+        self.incarnation.increment();
+        self.needs_write = Some(true);
+    }
+
+    /// Set whether the service's process is crash looping.
+    pub fn crash_loop(&mut self, crash_loop: bool) {
+        if self.is_crash_looping() != crash_loop {
+            self.crash_loop = Some(crash_loop);
+            self.incarnation.increment();
+            self.needs_write = Some(true);
+        }
+    }
+
+    /// Is the service's process crash looping?
+    pub fn is_crash_looping(&self) -> bool {
+        self.crash_loop.unwrap_or(false)
+    }
+
+    /// Is this member alive, with a process that is not crash looping and a health check that is
+    /// not critical? Members that have not run a health check yet are healthy.
+    pub fn is_healthy(&self) -> bool {
+        self.alive && !self.is_crash_looping() &&
+        self.health_check != Some(health_check::Status::Critical)
+    }
+
     /// Set us to alive.
     pub fn set_alive(&mut self) {
         self.alive = true;
@@ -246,6 +334,139 @@ impl CensusEntry {
     pub fn written(&mut self) {
         self.needs_write = None;
     }
+
+    /// The entry as the `CensusEntry` message of the gossip wire format.
+    pub fn to_proto(&self) -> proto::CensusEntry {
+        let mut msg = proto::CensusEntry::new();
+        msg.set_id(self.id.to_string());
+        msg.set_member_id(self.member_id.to_string());
+        msg.set_hostname(self.hostname.clone());
+        msg.set_ip(self.ip.clone());
+        msg.set_suitability(self.suitability);
+        if let Some(ref port) = self.port {
+            msg.set_port(port.clone());
+        }
+        if let Some(ref exposes) = self.exposes {
+            msg.set_has_exposes(true);
+            msg.set_exposes(RepeatedField::from_vec(exposes.clone()));
+        }
+        msg.set_leader(self.leader);
+        msg.set_follower(self.follower);
+        msg.set_data_init(self.data_init);
+        if let Some(ref vote) = self.vote {
+            msg.set_vote(vote.clone());
+        }
+        if let Some(election) = self.election {
+            msg.set_election(election);
+        }
+        if let Some(needs_write) = self.needs_write {
+            msg.set_needs_write(needs_write);
+        }
+        msg.set_initialized(self.initialized);
+        msg.set_keep_me(self.keep_me);
+        msg.set_service(self.service.clone());
+        msg.set_group(self.group.clone());
+        msg.set_alive(self.alive);
+        msg.set_suspect(self.suspect);
+        msg.set_confirmed(self.confirmed);
+        msg.set_detached(self.detached);
+        if let Some(update_pending) = self.update_pending {
+            msg.set_update_pending(update_pending);
+        }
+        if let Some(updating) = self.updating {
+            msg.set_updating(updating);
+        }
+        if let Some(ref status) = self.health_check {
+            msg.set_health_check(match *status {
+                health_check::Status::Ok => proto::CensusEntry_HealthCheck::OK,
+                health_check::Status::Warning => proto::CensusEntry_HealthCheck::WARNING,
+                health_check::Status::Critical => proto::CensusEntry_HealthCheck::CRITICAL,
+                health_check::Status::Unknown => proto::CensusEntry_HealthCheck::UNKNOWN,
+            });
+        }
+        msg.set_incarnation(self.incarnation.counter);
+        if let Some(crash_loop) = self.crash_loop {
+            msg.set_crash_loop(crash_loop);
+        }
+        if let Some(ref rolled_back) = self.rolled_back {
+            msg.set_rolled_back(rolled_back.clone());
+        }
+        msg
+    }
+
+    /// The entry a `CensusEntry` message of the gossip wire format describes.
+    ///
+    /// # Failures
+    ///
+    /// * A required field is missing, or an id is not a Uuid
+    pub fn from_proto(mut msg: proto::CensusEntry) -> Result<CensusEntry> {
+        try!(wire::initialized(&msg));
+        let mut ce = CensusEntry {
+            id: try!(Uuid::parse_str(msg.get_id())),
+            member_id: try!(Uuid::parse_str(msg.get_member_id())),
+            hostname: msg.take_hostname(),
+            ip: msg.take_ip(),
+            suitability: msg.get_suitability(),
+            port: None,
+            exposes: None,
+            leader: msg.get_leader(),
+            follower: msg.get_follower(),
+            data_init: msg.get_data_init(),
+            vote: None,
+            election: None,
+            needs_write: None,
+            initialized: msg.get_initialized(),
+            keep_me: msg.get_keep_me(),
+            service: msg.take_service(),
+            group: msg.take_group(),
+            alive: msg.get_alive(),
+            suspect: msg.get_suspect(),
+            confirmed: msg.get_confirmed(),
+            detached: msg.get_detached(),
+            update_pending: None,
+            updating: None,
+            health_check: None,
+            crash_loop: None,
+            rolled_back: None,
+            incarnation: LamportClock { counter: msg.get_incarnation() },
+        };
+        if msg.has_port() {
+            ce.port = Some(msg.take_port());
+        }
+        if msg.get_has_exposes() {
+            ce.exposes = Some(msg.take_exposes().into_vec());
+        }
+        if msg.has_vote() {
+            ce.vote = Some(msg.take_vote());
+        }
+        if msg.has_election() {
+            ce.election = Some(msg.get_election());
+        }
+        if msg.has_needs_write() {
+            ce.needs_write = Some(msg.get_needs_write());
+        }
+        if msg.has_update_pending() {
+            ce.update_pending = Some(msg.get_update_pending());
+        }
+        if msg.has_updating() {
+            ce.updating = Some(msg.get_updating());
+        }
+        if msg.has_health_check() {
+            ce.health_check = Some(match msg.get_health_check() {
+                proto::CensusEntry_HealthCheck::OK => health_check::Status::Ok,
+                proto::CensusEntry_HealthCheck::WARNING => health_check::Status::Warning,
+                proto::CensusEntry_HealthCheck::CRITICAL => health_check::Status::Critical,
+                proto::CensusEntry_HealthCheck::UNKNOWN => health_check::Status::Unknown,
+            });
+        }
+        if msg.has_crash_loop() {
+            ce.crash_loop = Some(msg.get_crash_loop());
+        }
+        if msg.has_rolled_back() {
+            ce.rolled_back = Some(msg.take_rolled_back());
+        }
+        Ok(ce)
+    }
 }
 
 impl PartialEq for CensusEntry {
@@ -275,6 +496,16 @@ impl PartialEq for CensusEntry {
             false
         } else if self.election != other.election {
             false
+        } else if self.update_pending != other.update_pending {
+            false
+        } else if self.updating != other.updating {
+            false
+        } else if self.health_check != other.health_check {
+            false
+        } else if self.crash_loop != other.crash_loop {
+            false
+        } else if self.rolled_back != other.rolled_back {
+            false
         } else if self.service != other.service {
             false
         } else if self.group != other.group {
@@ -378,6 +609,25 @@ impl Census {
         self.population.insert(ce.id, ce);
     }
 
+    /// Remove the entries of a member that has departed the ring. Our own entry is never removed.
+    /// Returns the ids of the entries we removed.
+    pub fn remove_member(&mut self, member_id: &MemberId) -> Vec<CensusEntryId> {
+        let me = self.me.clone();
+        let departed: Vec<CensusEntryId> = self.population
+            .iter()
+            .filter(|&(id, ce)| *id != me && ce.member_id == *member_id)
+            .map(|(id, _ce)| id.clone())
+            .collect();
+        for id in departed.iter() {
+            self.population.remove(id);
+        }
+        if !departed.is_empty() {
+            // Our entry is the only one left we can flag, so the census gets written again
+            self.me_mut().needs_write = Some(true);
+        }
+        departed
+    }
+
     /// Set whether we are in an event
     pub fn in_event(&mut self, status: bool) {
         self.in_event = status;
@@ -649,17 +899,57 @@ impl Census {
                    never be met, and no leader will be elected. Add another instance to the \
                    service group!");
         }
-        let alive_population = self.alive_population() as f32;
-        let total_pop = total_population as f32;
-        let percent_alive: usize = ((alive_population.round() / total_pop.round()) * 100.0)
-            .round() as usize;
-        if percent_alive > 50 {
-            true
-        } else {
-            false
+        is_quorum(self.alive_population(), total_population)
+    }
+
+    /// Returns true if more than half the census is alive as far as our own failure detector can
+    /// tell. Census rumors can still claim members on the far side of a partition are alive, long
+    /// after we have stopped hearing from them; the member list can't be fooled that way, so a
+    /// leader uses this to notice it is stranded in a minority.
+    pub fn has_quorum_in(&self, member_list: &MemberList) -> bool {
+        let alive_population = self.population
+            .values()
+            .filter(|ce| {
+                ce.id == self.me || member_list.is_alive(&ce.member_id)
+            })
+            .count();
+        is_quorum(alive_population, self.total_population())
+    }
+
+    /// Is it our turn to apply an update during a rolling update? Only one member of the census
+    /// updates at a time, so it is our turn if:
+    ///
+    /// * No other living member is currently updating
+    /// * Of the living members with an update pending, our `id` sorts first lexicographically
+    pub fn rolling_update_turn(&self) -> bool {
+        let other_updating = self.population
+            .values()
+            .any(|ce| ce.id != self.me && ce.alive && ce.is_updating());
+        if other_updating {
+            return false;
+        }
+        let next = self.population
+            .values()
+            .filter(|ce| ce.alive && ce.is_update_pending())
+            .min_by_key(|ce| ce.id.simple().to_string());
+        match next {
+            Some(ce) => ce.id == self.me,
+            None => false,
         }
     }
 
+    /// The releases any member of the census has rolled an update back from.
+    pub fn rolled_back_updates(&self) -> Vec<PackageIdent> {
+        let mut idents: Vec<PackageIdent> = self.population
+            .values()
+            .filter_map(|ce| ce.rolled_back.as_ref())
+            .filter_map(|ident| PackageIdent::from_str(ident).ok())
+            .collect();
+        idents.sort();
+        idents.dedup();
+        idents
+    }
+
     pub fn no_leaders_allowed(&mut self) {
         for (_id, ce) in self.population.iter_mut() {
             ce.leader = false;
@@ -749,6 +1039,16 @@ impl CensusList {
         return true;
     }
 
+    /// Remove the entries of a member that has departed the ring from every census. Returns the
+    /// ids of the entries we removed.
+    pub fn remove_member(&mut self, member_id: &MemberId) -> Vec<CensusEntryId> {
+        let mut departed = Vec::new();
+        for (_sg, mut census) in self.censuses.iter_mut() {
+            departed.extend(census.remove_member(member_id));
+        }
+        departed
+    }
+
     pub fn written(&mut self) {
         for (_sg, mut census) in self.censuses.iter_mut() {
             census.written();
@@ -779,6 +1079,19 @@ impl DerefMut for CensusList {
     }
 }
 
+/// More than half of the population must be alive to have quorum.
+fn is_quorum(alive_population: usize, total_population: usize) -> bool {
+    let alive_population = alive_population as f32;
+    let total_pop = total_population as f32;
+    let percent_alive: usize = ((alive_population.round() / total_pop.round()) * 100.0)
+        .round() as usize;
+    if percent_alive > 50 {
+        true
+    } else {
+        false
+    }
+}
+
 pub fn start_health_adjuster(census_list: Arc<RwLock<CensusList>>,
                              member_list: Arc<RwLock<MemberList>>) {
     outputln!("Starting census health adjuster");
@@ -803,7 +1116,7 @@ pub fn start_health_adjuster(census_list: Arc<RwLock<CensusList>>,
                                         census_entry.set_suspect();
                                     }
                                 }
-                                Health::Confirmed => {
+                                Health::Confirmed | Health::Departed => {
                                     if census_entry.confirmed == false {
                                         census_entry.set_confirmed();
                                     }
@@ -825,7 +1138,12 @@ pub fn start_health_adjuster(census_list: Arc<RwLock<CensusList>>,
 #[cfg(test)]
 mod test {
     mod census {
-        use gossip::member::MemberId;
+        use std::str::FromStr;
+
+        use hcore::package::PackageIdent;
+        use rustc_serialize::json::{self, Json};
+
+        use gossip::member::{Member, MemberId, MemberList};
         use census::{Census, CensusEntry};
 
         fn generate_ce() -> CensusEntry {
@@ -882,6 +1200,53 @@ mod test {
             assert_eq!(census.has_quorum(), false);
         }
 
+        #[test]
+        fn has_quorum_in_uses_our_view_of_the_ring() {
+            let mut census = generate_census();
+            add_entries(&mut census, 4);
+            let mut me = Member::new("me".to_string(),
+                                     "10.0.0.1".to_string(),
+                                     "10.0.0.1:9634".to_string(),
+                                     false);
+            me.id = census.me().member_id;
+            let mut member_list = MemberList::new(me);
+            assert_eq!(census.has_quorum_in(&member_list), false);
+
+            let others: Vec<MemberId> = census.values()
+                .filter(|ce| ce.id != census.me)
+                .map(|ce| ce.member_id)
+                .collect();
+            for id in others.iter().take(2) {
+                let mut member = Member::new("peer".to_string(),
+                                             "10.0.0.2".to_string(),
+                                             "10.0.0.2:9634".to_string(),
+                                             false);
+                member.id = *id;
+                member_list.insert(member);
+            }
+            assert_eq!(census.has_quorum_in(&member_list), true);
+
+            member_list.suspect(&others[0]);
+            assert_eq!(census.has_quorum_in(&member_list), false);
+            assert_eq!(census.has_quorum(), true);
+        }
+
+        #[test]
+        fn remove_member_keeps_our_entry() {
+            let mut census = generate_census();
+            add_entries(&mut census, 2);
+            let departed = census.values()
+                .find(|ce| ce.id != census.me)
+                .unwrap()
+                .clone();
+            assert_eq!(census.remove_member(&departed.member_id), vec![departed.id]);
+            assert_eq!(census.total_population(), 2);
+
+            let my_member_id = census.me().member_id;
+            assert!(census.remove_member(&my_member_id).is_empty());
+            assert_eq!(census.total_population(), 2);
+        }
+
         #[test]
         fn minimum_quorum() {
             let mut census = generate_census();
@@ -906,5 +1271,60 @@ mod test {
             fail_the_leader(&mut census);
             assert_eq!(census.has_leader(), false);
         }
+
+        #[test]
+        fn rolling_update_turn() {
+            let mut census = generate_census();
+            add_entries(&mut census, 2);
+            // Nobody has an update pending, so it is nobody's turn
+            assert_eq!(census.rolling_update_turn(), false);
+
+            for (_id, mut ce) in census.iter_mut() {
+                ce.update_pending(true);
+            }
+            let first = census.values().map(|ce| ce.id.simple().to_string()).min().unwrap();
+            let me_first = census.me().id.simple().to_string() == first;
+            assert_eq!(census.rolling_update_turn(), me_first);
+
+            // Once someone else is updating, we must wait for them
+            let me = census.me.clone();
+            for (_id, mut ce) in census.iter_mut().filter(|&(id, ref _ce)| *id != me).take(1) {
+                ce.update_pending(false);
+                ce.updating(true);
+            }
+            assert_eq!(census.rolling_update_turn(), false);
+        }
+
+        #[test]
+        fn rolled_back_updates() {
+            let mut census = generate_census();
+            add_entries(&mut census, 2);
+            assert!(census.rolled_back_updates().is_empty());
+
+            let bad = PackageIdent::from_str("core/redis/3.2.1/20160612000000").unwrap();
+            for (_id, mut ce) in census.iter_mut() {
+                ce.rolled_back(&bad);
+            }
+            assert_eq!(census.rolled_back_updates(), vec![bad]);
+        }
+
+        #[test]
+        fn decodes_entries_from_older_supervisors() {
+            let mut ce = generate_ce();
+            ce.updating(true);
+            let mut encoded = match Json::from_str(&json::encode(&ce).unwrap()).unwrap() {
+                Json::Object(object) => object,
+                _ => panic!("census entries encode as objects"),
+            };
+            encoded.remove("update_pending");
+            encoded.remove("updating");
+            encoded.remove("crash_loop");
+            encoded.remove("rolled_back");
+            let decoded: CensusEntry = json::decode(&Json::Object(encoded).to_string()).unwrap();
+            assert!(!decoded.is_update_pending());
+            assert!(!decoded.is_updating());
+            assert!(!decoded.is_crash_looping());
+            assert!(decoded.rolled_back.is_none());
+        }
     }
 }
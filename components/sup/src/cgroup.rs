@@ -0,0 +1,317 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Run a service's processes in a cgroup of their own.
+//!
+//! A service that declares resource limits, in its plan or when it is started, runs in a cgroup
+//! v2 group of its own under `/sys/fs/cgroup/habitat`, named after its service group. The kernel
+//! holds every process the service starts to the limits, and we kill all of them, not just the
+//! one we started, when the service stops.
+
+use std::ffi::{CStr, CString};
+use std::fs::{self, File};
+use std::io;
+use std::io::prelude::*;
+use std::os::unix::ffi::OsStrExt;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::Duration;
+
+use hcore::package::PackageInstall;
+use libc;
+use time::SteadyTime;
+use time::Duration as TimeDuration;
+
+use error::{Error, Result, SupError};
+use util::{self, signals};
+
+static LOGKEY: &'static str = "CG";
+/// Where the cgroup v2 hierarchy is mounted
+const CGROUP_ROOT: &'static str = "/sys/fs/cgroup";
+/// The cgroup we create each service's cgroup in
+const CGROUP_PARENT: &'static str = "habitat";
+/// The period we give a service's share of CPU time over, in microseconds
+const CPU_PERIOD: u64 = 100000;
+/// How long we keep killing a service's processes before giving up, in seconds
+const KILL_TIMEOUT: i64 = 5;
+
+/// The resources a service's processes may use between them. A limit of `None` is unlimited.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Limits {
+    /// The most memory, in bytes
+    pub memory_max: Option<u64>,
+    /// The most CPU time, in thousandths of a CPU
+    pub cpu_max: Option<u64>,
+    /// The most processes at once
+    pub pids_max: Option<u64>,
+    /// Whether to run the service in a cgroup even without limits, so we can kill every process
+    /// it starts
+    pub cgroup: bool,
+}
+
+impl Limits {
+    /// The limits declared in a package's plan.
+    ///
+    /// # Failures
+    ///
+    /// * A limit's metadata cannot be read, or is not a valid limit
+    pub fn from_package(pkg_install: &PackageInstall) -> Result<Limits> {
+        let mut limits = Limits::default();
+        if let Some(memory) = try!(pkg_install.svc_memory_max()) {
+            limits.memory_max = Some(try!(parse_memory(&memory)));
+        }
+        if let Some(cpu) = try!(pkg_install.svc_cpu_max()) {
+            limits.cpu_max = Some(try!(parse_cpu(&cpu)));
+        }
+        if let Some(pids) = try!(pkg_install.svc_pids_max()) {
+            limits.pids_max = Some(try!(parse_pids(&pids)));
+        }
+        Ok(limits)
+    }
+
+    /// These limits, falling back to `other` for any we do not set.
+    pub fn or(&self, other: &Limits) -> Limits {
+        Limits {
+            memory_max: self.memory_max.or(other.memory_max),
+            cpu_max: self.cpu_max.or(other.cpu_max),
+            pids_max: self.pids_max.or(other.pids_max),
+            cgroup: self.cgroup || other.cgroup,
+        }
+    }
+
+    /// Whether the service runs in a cgroup of its own.
+    pub fn needs_cgroup(&self) -> bool {
+        self.cgroup || self.memory_max.is_some() || self.cpu_max.is_some() ||
+        self.pids_max.is_some()
+    }
+}
+
+/// Parse an amount of memory, in bytes or with a `K`, `M` or `G` suffix.
+pub fn parse_memory(memory: &str) -> Result<u64> {
+    match util::parse_bytes(memory) {
+        Some(bytes) if bytes > 0 => Ok(bytes),
+        _ => Err(sup_error!(Error::InvalidResourceLimit(format!("memory {}", memory)))),
+    }
+}
+
+/// Parse a number of CPUs, which may be a fraction, into thousandths of a CPU.
+pub fn parse_cpu(cpu: &str) -> Result<u64> {
+    let invalid = || sup_error!(Error::InvalidResourceLimit(format!("cpu {}", cpu)));
+    let cpus: f64 = try!(cpu.parse().map_err(|_| invalid()));
+    let thousandths = (cpus * 1000.0).round();
+    if !thousandths.is_finite() || thousandths < 1.0 {
+        return Err(invalid());
+    }
+    Ok(thousandths as u64)
+}
+
+/// Parse a number of processes.
+pub fn parse_pids(pids: &str) -> Result<u64> {
+    match pids.parse() {
+        Ok(pids) if pids > 0 => Ok(pids),
+        _ => Err(sup_error!(Error::InvalidResourceLimit(format!("pids {}", pids)))),
+    }
+}
+
+/// A service's cgroup.
+#[derive(Debug)]
+pub struct Cgroup {
+    path: PathBuf,
+}
+
+impl Cgroup {
+    /// Create the cgroup for a service group, if it does not exist, and hold it to `limits`.
+    ///
+    /// # Failures
+    ///
+    /// * The cgroup v2 hierarchy is not mounted, or we may not create a cgroup in it
+    /// * The kernel lacks a controller a limit needs
+    pub fn create(service_group: &str, limits: &Limits) -> Result<Cgroup> {
+        let root = Path::new(CGROUP_ROOT);
+        if !root.join("cgroup.controllers").is_file() {
+            return Err(sup_error!(Error::Cgroup(format!("no cgroup v2 hierarchy is mounted \
+                                                          at {}",
+                                                         CGROUP_ROOT))));
+        }
+        let mut controllers = Vec::new();
+        if limits.memory_max.is_some() {
+            controllers.push("memory");
+        }
+        if limits.cpu_max.is_some() {
+            controllers.push("cpu");
+        }
+        if limits.pids_max.is_some() {
+            controllers.push("pids");
+        }
+        let parent = root.join(CGROUP_PARENT);
+        try!(make_dir(&parent));
+        try!(enable_controllers(root, &controllers));
+        try!(enable_controllers(&parent, &controllers));
+
+        let cgroup = Cgroup { path: parent.join(service_group) };
+        try!(make_dir(&cgroup.path));
+        let cpu_max = limits.cpu_max
+            .map(|cpu| format!("{} {}", cpu * CPU_PERIOD / 1000, CPU_PERIOD));
+        try!(cgroup.set_limit("memory.max", limits.memory_max.map(|m| m.to_string())));
+        try!(cgroup.set_limit("cpu.max", cpu_max));
+        try!(cgroup.set_limit("pids.max", limits.pids_max.map(|p| p.to_string())));
+        outputln!("Running {} in cgroup {}", service_group, cgroup.path.display());
+        Ok(cgroup)
+    }
+
+    /// The path of the cgroup's `cgroup.procs`, for a process starting a service to `join` the
+    /// cgroup with.
+    pub fn procs_path(&self) -> Result<CString> {
+        let path = self.path.join("cgroup.procs");
+        CString::new(path.as_os_str().as_bytes()).map_err(|e| cgroup_error(&path, e))
+    }
+
+    /// The processes in the cgroup.
+    pub fn procs(&self) -> Result<Vec<u32>> {
+        let path = self.path.join("cgroup.procs");
+        let mut procs = String::new();
+        let mut file = try!(File::open(&path).map_err(|e| cgroup_error(&path, e)));
+        try!(file.read_to_string(&mut procs).map_err(|e| cgroup_error(&path, e)));
+        Ok(procs.lines().filter_map(|pid| pid.trim().parse().ok()).collect())
+    }
+
+    /// Kill every process in the cgroup, and wait for them to go.
+    ///
+    /// # Failures
+    ///
+    /// * Processes remain in the cgroup after `KILL_TIMEOUT`
+    pub fn kill(&self) -> Result<()> {
+        let deadline = SteadyTime::now() + TimeDuration::seconds(KILL_TIMEOUT);
+        // Kernels from 5.14 on kill the whole cgroup at once; on older ones, we kill each
+        // process we find until none are left, in case they fork as we go
+        let kill_all = write_file(&self.path.join("cgroup.kill"), "1").is_ok();
+        loop {
+            let procs = try!(self.procs());
+            if procs.is_empty() {
+                return Ok(());
+            }
+            if SteadyTime::now() > deadline {
+                return Err(sup_error!(Error::Cgroup(format!("{} processes remain in {} after \
+                                                              killing them",
+                                                             procs.len(),
+                                                             self.path.display()))));
+            }
+            if !kill_all {
+                for pid in procs {
+                    debug!("Killing process {} in {}", pid, self.path.display());
+                    // The process may have exited already
+                    let _ = signals::send_signal_to_pid(pid, signals::Signal::SIGKILL);
+                }
+            }
+            thread::sleep(Duration::from_millis(10));
+        }
+    }
+
+    fn set_limit(&self, file: &str, limit: Option<String>) -> Result<()> {
+        let path = self.path.join(file);
+        // A controller we have not enabled has no files; we have nothing to limit with it
+        if limit.is_none() && !path.exists() {
+            return Ok(());
+        }
+        write_file(&path, &limit.unwrap_or(String::from("max")))
+    }
+}
+
+/// Move the calling process into the cgroup whose `cgroup.procs` is at `procs`. The processes it
+/// starts from then on are in the cgroup too. We call this in the child between fork and exec,
+/// so it makes system calls and nothing else: no allocating, and no locks.
+pub fn join(procs: &CStr) -> io::Result<()> {
+    unsafe {
+        let fd = libc::open(procs.as_ptr(), libc::O_WRONLY | libc::O_CLOEXEC);
+        if fd < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        // Writing 0 moves the process that writes it
+        let written = libc::write(fd, b"0".as_ptr() as *const libc::c_void, 1);
+        let err = io::Error::last_os_error();
+        libc::close(fd);
+        if written != 1 {
+            return Err(err);
+        }
+    }
+    Ok(())
+}
+
+fn make_dir(path: &Path) -> Result<()> {
+    match fs::create_dir(path) {
+        Ok(()) => Ok(()),
+        Err(ref e) if path.is_dir() => {
+            debug!("Using existing cgroup {}: {}", path.display(), e);
+            Ok(())
+        }
+        Err(e) => Err(cgroup_error(path, e)),
+    }
+}
+
+/// Let the cgroups under `path` use the controllers.
+fn enable_controllers(path: &Path, controllers: &[&str]) -> Result<()> {
+    for controller in controllers {
+        try!(write_file(&path.join("cgroup.subtree_control"),
+                        &format!("+{}", controller)));
+    }
+    Ok(())
+}
+
+fn write_file(path: &Path, contents: &str) -> Result<()> {
+    let mut file = try!(fs::OpenOptions::new()
+        .write(true)
+        .open(path)
+        .map_err(|e| cgroup_error(path, e)));
+    file.write_all(contents.as_bytes()).map_err(|e| cgroup_error(path, e))
+}
+
+fn cgroup_error<E: ToString>(path: &Path, err: E) -> SupError {
+    sup_error!(Error::Cgroup(format!("{}: {}", path.display(), err.to_string())))
+}
+
+#[cfg(test)]
+mod test {
+    use cgroup::{Limits, parse_cpu, parse_memory, parse_pids};
+
+    #[test]
+    fn parses_limits() {
+        assert_eq!(parse_memory("512M").unwrap(), 512 * 1024 * 1024);
+        assert_eq!(parse_memory("2g").unwrap(), 2 * 1024 * 1024 * 1024);
+        assert_eq!(parse_memory("65536").unwrap(), 65536);
+        assert!(parse_memory("lots").is_err());
+        assert!(parse_memory("0").is_err());
+        assert_eq!(parse_cpu("0.5").unwrap(), 500);
+        assert_eq!(parse_cpu("2").unwrap(), 2000);
+        assert!(parse_cpu("0").is_err());
+        assert!(parse_cpu("half").is_err());
+        assert_eq!(parse_pids("100").unwrap(), 100);
+        assert!(parse_pids("-1").is_err());
+    }
+
+    #[test]
+    fn overrides_fall_back_to_the_package() {
+        let package = Limits {
+            memory_max: Some(1024),
+            pids_max: Some(10),
+            ..Limits::default()
+        };
+        let overrides = Limits { pids_max: Some(20), ..Limits::default() };
+        assert!(!Limits::default().needs_cgroup());
+        let limits = overrides.or(&package);
+        assert_eq!(limits.memory_max, Some(1024));
+        assert_eq!(limits.pids_max, Some(20));
+        assert_eq!(limits.cpu_max, None);
+        assert!(limits.needs_cgroup());
+    }
+}
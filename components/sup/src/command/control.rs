@@ -0,0 +1,116 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Controls the process of a service run by another Supervisor, through its sidecar. Actually the
+//! `up`, `down`, `restart`, `signal` and `step-down` commands.
+//!
+//! # Examples
+//!
+//! ```bash
+//! $ hab-sup restart 172.17.0.2:9631
+//! ```
+//!
+//! Will restart the process of the first service the Supervisor at 172.17.0.2 runs.
+//!
+//! ```bash
+//! $ hab-sup signal 172.17.0.2:9631 HUP --service-group haproxy.default
+//! ```
+//!
+//! Will send `SIGHUP` to the process of the haproxy.default service.
+//!
+//! ```bash
+//! $ hab-sup step-down 172.17.0.2:9631 --service-group redis.default
+//! ```
+//!
+//! Will make the Supervisor at 172.17.0.2 hand leadership of redis.default to another member, and
+//! stop its own redis once the new leader is promoted.
+//!
+//! The Supervisor only accepts the request if the `HAB_SUP_CONTROL_TOKEN` environment variable
+//! holds the same token on both sides.
+
+use std::io::Read;
+
+use hcore::env as henv;
+use hyper::client::Client;
+use hyper::header::{Authorization, Bearer};
+use hyper::status::StatusCode;
+
+use config::gconfig;
+use error::{Error, Result};
+use sidecar::CONTROL_TOKEN_ENVVAR;
+
+static LOGKEY: &'static str = "CT";
+
+/// Start the service's process, and keep it up.
+pub fn up() -> Result<()> {
+    send("up")
+}
+
+/// Stop the service's process, and keep it down.
+pub fn down() -> Result<()> {
+    send("down")
+}
+
+/// Restart the service's process.
+pub fn restart() -> Result<()> {
+    send("restart")
+}
+
+/// Send a Unix signal to the service's process.
+pub fn signal() -> Result<()> {
+    send(&format!("signal/{}", gconfig().signal()))
+}
+
+/// Step down as the leader of the service group.
+pub fn step_down() -> Result<()> {
+    send("step-down")
+}
+
+/// Ask the sidecar to carry out a control action, and print the status it responds with.
+///
+/// # Failures
+///
+/// * The control token is not set
+/// * The sidecar cannot be reached
+/// * The sidecar refuses or fails the request
+fn send(action: &str) -> Result<()> {
+    let token = match henv::var(CONTROL_TOKEN_ENVVAR) {
+        Ok(token) => token,
+        Err(_) => return Err(sup_error!(Error::ControlTokenNotSet)),
+    };
+    let url = match gconfig().service_group() {
+        "" => format!("http://{}/control/{}", gconfig().sidecar_addr(), action),
+        sg => {
+            format!("http://{}/services/{}/control/{}",
+                    gconfig().sidecar_addr(),
+                    sg,
+                    action)
+        }
+    };
+    debug!("Sending control request to {}", url);
+    let client = Client::new();
+    let mut res = try!(client.post(&url).header(Authorization(Bearer { token: token })).send());
+    let mut body = String::new();
+    try!(res.read_to_string(&mut body));
+    match res.status {
+        StatusCode::Ok | StatusCode::Accepted => {
+            outputln!("{}", body.trim());
+            Ok(())
+        }
+        status => {
+            outputln!("{}", body.trim());
+            Err(sup_error!(Error::HTTP(status)))
+        }
+    }
+}
@@ -0,0 +1,84 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Shows the output of a service run by another Supervisor, through its sidecar.
+//!
+//! # Examples
+//!
+//! ```bash
+//! $ hab-sup logs 172.17.0.2:9631
+//! ```
+//!
+//! Will show the most recent output of the first service the Supervisor at 172.17.0.2 runs.
+//!
+//! ```bash
+//! $ hab-sup logs 172.17.0.2:9631 -f --service-group redis.default
+//! ```
+//!
+//! Will show the most recent output of redis.default, then keep showing its output as it is
+//! written, until interrupted.
+
+use std::io::{BufRead, BufReader, Read};
+
+use hyper::client::Client;
+use hyper::status::StatusCode;
+
+use config::gconfig;
+use error::{Error, Result};
+
+static LOGKEY: &'static str = "LO";
+
+/// Print the service's most recent output, and follow it if we were asked to.
+///
+/// # Failures
+///
+/// * The sidecar cannot be reached
+/// * The sidecar is not running the service
+pub fn logs() -> Result<()> {
+    let path = match gconfig().service_group() {
+        "" => String::from("/logs"),
+        sg => format!("/services/{}/logs", sg),
+    };
+    let mut query = Vec::new();
+    if let Some(lines) = gconfig().log_lines() {
+        query.push(format!("lines={}", lines));
+    }
+    if gconfig().follow() {
+        query.push(String::from("follow=true"));
+    }
+    let url = format!("http://{}{}?{}",
+                      gconfig().sidecar_addr(),
+                      path,
+                      query.join("&"));
+    debug!("Requesting logs from {}", url);
+    let client = Client::new();
+    let mut res = try!(client.get(&url).send());
+    if res.status != StatusCode::Ok {
+        let mut body = String::new();
+        try!(res.read_to_string(&mut body));
+        outputln!("{}", body.trim());
+        return Err(sup_error!(Error::HTTP(res.status)));
+    }
+    // The lines are already formatted by the Supervisor that printed them; while we follow, it
+    // sends blank lines to check we are still here
+    let mut reader = BufReader::new(res);
+    let mut line = String::new();
+    while try!(reader.read_line(&mut line)) > 0 {
+        if line != "\n" {
+            print!("{}", line);
+        }
+        line.clear();
+    }
+    Ok(())
+}
@@ -21,3 +21,5 @@
 pub mod start;
 pub mod configure;
 pub mod shell;
+pub mod control;
+pub mod logs;
@@ -53,10 +53,16 @@
 //! Will start the `redis` service using the `leader` topology in the `production` group, while
 //! watching the `haproxy` `default` group's configuration.
 //!
+//! ```bash
+//! $ hab-sup start acme/redis --service acme/haproxy,bind=backend:redis.default
+//! ```
+//!
+//! Will start the `redis` service, and alongside it in the same Supervisor, the `haproxy` service
+//! bound to it. Each `--service` may set its own `topology=`, `group=` and `bind=`.
+//!
 //! See the [documentation on topologies](../topology) for a deeper discussion of how they function.
 //!
 
-use std::env;
 use std::path::Path;
 
 use ansi_term::Colour::Yellow;
@@ -65,18 +71,18 @@ use common::ui::UI;
 use depot_client::Client;
 use hcore::crypto::default_cache_key_path;
 use hcore::fs::{cache_artifact_path, FS_ROOT_PATH};
-use hcore::package::PackageIdent;
+use hcore::package::{Identifiable, PackageIdent};
 
 use {PRODUCT, VERSION};
 use error::Result;
-use config::{gconfig, UpdateStrategy};
-use package::Package;
-use topology::{self, Topology};
+use config::{gconfig, ServiceSpec, UpdateStrategy};
+use package::{updater, Package};
+use topology;
 
 static LOGKEY: &'static str = "CS";
 
-/// Creates a [Package](../../pkg/struct.Package.html), then passes it to the run method of the
-/// selected [topology](../../topology).
+/// Creates a [Package](../../pkg/struct.Package.html) for each service we were asked to run, then
+/// passes them to the [topologies](../../topology) to run.
 ///
 /// # Failures
 ///
@@ -84,27 +90,45 @@ static LOGKEY: &'static str = "CS";
 /// * Fails if the `run` method for the topology fails
 /// * Fails if an unknown topology was specified on the command line
 pub fn package() -> Result<()> {
+    let mut services = Vec::new();
+    for (i, spec) in gconfig().services().into_iter().enumerate() {
+        // Only the package given on the command line may be a local artifact
+        let local_artifact = if i == 0 {
+            gconfig().local_artifact()
+        } else {
+            None
+        };
+        let package = try!(load_package(&spec.package, local_artifact));
+        services.push((package, spec));
+    }
+    start_packages(services)
+}
+
+/// Loads a package, first installing it if it is not installed, or updating it if we follow an
+/// update strategy and there is a newer release in the depot.
+fn load_package(ident: &PackageIdent, local_artifact: Option<&str>) -> Result<Package> {
     let mut ui = UI::default();
-    match Package::load(gconfig().package(), None) {
+    match Package::load(&installed_ident(ident), None) {
         Ok(mut package) => {
             let update_strategy = gconfig().update_strategy();
             match update_strategy {
                 UpdateStrategy::None => {}
                 _ => {
-                    let url = gconfig().url();
+                    let depot_url = gconfig().depot_url();
+                    let url = depot_url.as_str();
                     outputln!("Checking Depot for newer versions...");
-                    // It is important to pass `gconfig().package()` to `show_package()` instead
-                    // of the package identifier of the loaded package. This will ensure that
-                    // if the operator starts a package while specifying a version number, they
-                    // will only automaticaly receive release updates for the started package.
+                    // It is important to pass the identifier we were started with to
+                    // `latest_ident()` instead of the package identifier of the loaded package.
+                    // This will ensure that if the operator starts a package while specifying a
+                    // version number, or a version prefix, they will only automaticaly receive
+                    // release updates for the started package.
                     //
                     // If the operator does not specify a version number they will
                     // automatically receive updates for any releases, regardless of version
                     // number, for the started  package.
                     let depot_client = try!(Client::new(url, PRODUCT, VERSION, None));
-                    let latest_pkg_data =
-                        try!(depot_client.show_package((*gconfig().package()).clone()));
-                    let latest_ident: PackageIdent = latest_pkg_data.get_ident().clone().into();
+                    let latest = try!(updater::latest_ident(&depot_client, ident));
+                    let latest_ident = latest.unwrap_or(package.ident().clone());
                     if &latest_ident > package.ident() {
                         outputln!("Downloading latest version from Depot: {}", latest_ident);
                         let new_pkg_data = try!(install::start(&mut ui,
@@ -121,13 +145,14 @@ pub fn package() -> Result<()> {
                     };
                 }
             }
-            start_package(package)
+            Ok(package)
         }
         Err(_) => {
             outputln!("{} is not installed",
-                      Yellow.bold().paint(gconfig().package().to_string()));
-            let url = gconfig().url();
-            let new_pkg_data = match gconfig().local_artifact() {
+                      Yellow.bold().paint(ident.to_string()));
+            let depot_url = gconfig().depot_url();
+            let url = depot_url.as_str();
+            let new_pkg_data = match local_artifact {
                 Some(artifact) => {
                     try!(install::start(&mut ui,
                                         url,
@@ -140,11 +165,12 @@ pub fn package() -> Result<()> {
                 }
                 None => {
                     outputln!("Searching for {} in remote {}",
-                              Yellow.bold().paint(gconfig().package().to_string()),
+                              Yellow.bold().paint(ident.to_string()),
                               url);
+                    let ident = try!(pinned_ident(url, ident));
                     try!(install::start(&mut ui,
                                         url,
-                                        &gconfig().package().to_string(),
+                                        &ident.to_string(),
                                         PRODUCT,
                                         VERSION,
                                         Path::new(FS_ROOT_PATH),
@@ -152,19 +178,35 @@ pub fn package() -> Result<()> {
                                         &default_cache_key_path(None)))
                 }
             };
-            let package = try!(Package::load(&new_pkg_data, None));
-            start_package(package)
+            Package::load(&new_pkg_data, None)
         }
     }
 }
 
-fn start_package(package: Package) -> Result<()> {
-    let run_path = try!(package.run_path());
-    debug!("Setting the PATH to {}", run_path);
-    env::set_var("PATH", &run_path);
-    match *gconfig().topology() {
-        Topology::Standalone => topology::standalone::run(package),
-        Topology::Leader => topology::leader::run(package),
-        Topology::Initializer => topology::initializer::run(package),
+/// The package identifier to load from the installed packages. A version on the started package
+/// may be a prefix, which `Package::load` does not match, so we resolve it against the installed
+/// releases first. We only go to the depot when no installed release matches.
+fn installed_ident(ident: &PackageIdent) -> PackageIdent {
+    if ident.version.is_none() || ident.fully_qualified() {
+        return ident.clone();
     }
+    updater::latest_installed(ident).unwrap_or(ident.clone())
+}
+
+/// The package identifier to install. A version on the started package may be a prefix, which
+/// the depot does not know about, so we resolve it to the latest release within it first.
+fn pinned_ident(url: &str, ident: &PackageIdent) -> Result<PackageIdent> {
+    if ident.version.is_none() || ident.fully_qualified() {
+        return Ok(ident.clone());
+    }
+    let depot_client = try!(Client::new(url, PRODUCT, VERSION, None));
+    match try!(updater::latest_ident(&depot_client, ident)) {
+        Some(latest) => Ok(latest),
+        None => Ok(ident.clone()),
+    }
+}
+
+fn start_packages(services: Vec<(Package, ServiceSpec)>) -> Result<()> {
+    // Each service's process and hooks run with the package's own run path as their PATH
+    topology::run(services)
 }
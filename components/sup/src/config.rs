@@ -26,9 +26,15 @@ use std::mem;
 
 use hcore::package::PackageIdent;
 
+use cgroup::{self, Limits};
 use error::{Error, SupError};
+use gossip::detector::Timing;
 use gossip::server::GOSSIP_DEFAULT_PORT;
+use gossip::transport::TransportConfig;
+use logs::{self, LogPolicy};
+use supervisor::{Restart, RestartPolicy};
 use topology::Topology;
+use util::signals::Signal;
 
 static LOGKEY: &'static str = "CFG";
 
@@ -65,12 +71,19 @@ pub enum Command {
     Start,
     ShellBash,
     ShellSh,
+    Up,
+    Down,
+    Restart,
+    Signal,
+    StepDown,
+    Logs,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum UpdateStrategy {
     None,
     AtOnce,
+    Rolling,
 }
 
 impl UpdateStrategy {
@@ -78,6 +91,7 @@ impl UpdateStrategy {
         match strategy {
             "none" => UpdateStrategy::None,
             "at-once" => UpdateStrategy::AtOnce,
+            "rolling" => UpdateStrategy::Rolling,
             s => panic!("Invalid update strategy {}", s),
         }
     }
@@ -96,6 +110,12 @@ impl FromStr for Command {
             "bash" => Ok(Command::ShellBash),
             "sh" => Ok(Command::ShellSh),
             "start" => Ok(Command::Start),
+            "up" => Ok(Command::Up),
+            "down" => Ok(Command::Down),
+            "restart" => Ok(Command::Restart),
+            "signal" => Ok(Command::Signal),
+            "step-down" => Ok(Command::StepDown),
+            "logs" => Ok(Command::Logs),
             _ => Err(sup_error!(Error::CommandNotImplemented)),
         }
     }
@@ -108,6 +128,113 @@ impl Default for Command {
     }
 }
 
+/// A service for the Supervisor to run, along with the settings that can differ between the
+/// services one Supervisor runs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ServiceSpec {
+    pub package: PackageIdent,
+    pub topology: Topology,
+    pub group: String,
+    pub bind: Vec<String>,
+    /// The user keys allowed to change the service group's config and files; anyone may when
+    /// there are none
+    pub trusted_users: Vec<String>,
+    /// How we restart the service's process after it exits on its own
+    pub restart: RestartPolicy,
+    /// Resource limits that override those in the package's plan
+    pub limits: Limits,
+    /// The signal that stops the service's process, overriding the package's plan
+    pub stop_signal: Option<Signal>,
+    /// How long the process may take to stop, in seconds, overriding the package's plan
+    pub stop_timeout: Option<u64>,
+    /// When we rotate the service's log files
+    pub logs: LogPolicy,
+}
+
+impl ServiceSpec {
+    /// Parse a service given as `origin/name[/version[/release]]`, optionally followed by
+    /// comma-separated settings: `topology=`, `group=`, any number of `bind=` and `trust=`, the
+    /// restart policy's `restart=`, `restart-retries=`, `restart-backoff=` and
+    /// `restart-backoff-max=`, the resource limits `memory-max=`, `cpu-max=`, `pids-max=` and
+    /// `cgroup=`, `stop-signal=` and `stop-timeout=`, and the log policy's `log-max-size=`,
+    /// `log-max-age=` and `log-keep=`. For example,
+    /// `core/haproxy,group=production,bind=backend:redis.production,restart=on-failure`.
+    pub fn from_spec(spec: &str, default_group: &str) -> Result<ServiceSpec, SupError> {
+        let mut parts = spec.split(',');
+        let ident = parts.next().unwrap_or("");
+        let mut service = ServiceSpec {
+            package: try!(PackageIdent::from_str(ident)),
+            topology: Topology::default(),
+            group: default_group.to_string(),
+            bind: Vec::new(),
+            trusted_users: Vec::new(),
+            restart: RestartPolicy::default(),
+            limits: Limits::default(),
+            stop_signal: None,
+            stop_timeout: None,
+            logs: LogPolicy::default(),
+        };
+        let invalid = || sup_error!(Error::InvalidServiceSpec(spec.to_string()));
+        for part in parts {
+            let mut kv = part.splitn(2, '=');
+            match (kv.next(), kv.next()) {
+                (Some("topology"), Some(topology)) => {
+                    service.topology = try!(Topology::from_str(topology))
+                }
+                (Some("group"), Some(group)) => service.group = group.to_string(),
+                (Some("bind"), Some(bind)) => service.bind.push(bind.to_string()),
+                (Some("trust"), Some(user)) => service.trusted_users.push(user.to_string()),
+                (Some("restart"), Some(restart)) => {
+                    service.restart.restart = try!(Restart::from_str(restart))
+                }
+                (Some("restart-retries"), Some(retries)) => {
+                    service.restart.max_retries = Some(try!(retries.parse().map_err(|_| invalid())))
+                }
+                (Some("restart-backoff"), Some(secs)) => {
+                    service.restart.backoff = try!(secs.parse().map_err(|_| invalid()))
+                }
+                (Some("restart-backoff-max"), Some(secs)) => {
+                    service.restart.backoff_max = try!(secs.parse().map_err(|_| invalid()))
+                }
+                (Some("memory-max"), Some(memory)) => {
+                    service.limits.memory_max = Some(try!(cgroup::parse_memory(memory)))
+                }
+                (Some("cpu-max"), Some(cpu)) => {
+                    service.limits.cpu_max = Some(try!(cgroup::parse_cpu(cpu)))
+                }
+                (Some("pids-max"), Some(pids)) => {
+                    service.limits.pids_max = Some(try!(cgroup::parse_pids(pids)))
+                }
+                (Some("cgroup"), Some(cgroup)) => {
+                    service.limits.cgroup = try!(cgroup.parse().map_err(|_| invalid()))
+                }
+                (Some("stop-signal"), Some(signal)) => {
+                    service.stop_signal = Some(try!(Signal::from_str(signal)))
+                }
+                (Some("stop-timeout"), Some(secs)) => {
+                    service.stop_timeout = Some(try!(secs.parse().map_err(|_| invalid())))
+                }
+                (Some("log-max-size"), Some(size)) => {
+                    service.logs.max_size = Some(try!(logs::parse_size(size)))
+                }
+                (Some("log-max-age"), Some(secs)) => {
+                    service.logs.max_age = Some(try!(secs.parse().map_err(|_| invalid())))
+                }
+                (Some("log-keep"), Some(keep)) => {
+                    service.logs.keep = try!(keep.parse().map_err(|_| invalid()))
+                }
+                _ => return Err(invalid()),
+            }
+        }
+        Ok(service)
+    }
+
+    /// Return the service group this service runs in
+    pub fn service_group(&self) -> String {
+        format!("{}.{}", self.package.name, self.group)
+    }
+}
+
 /// Holds our configuration options.
 #[derive(Default, Debug, PartialEq, Eq)]
 pub struct Config {
@@ -115,11 +242,18 @@ pub struct Config {
     package: PackageIdent,
     local_artifact: Option<String>,
     url: String,
+    channel: Option<String>,
     topology: Topology,
     group: String,
     path: String,
     archive: String,
     bind: Vec<String>,
+    trusted_users: Vec<String>,
+    restart_policy: RestartPolicy,
+    limits: Limits,
+    stop_signal: Option<Signal>,
+    stop_timeout: Option<u64>,
+    log_policy: LogPolicy,
     key: String,
     email: Option<String>,
     expire_days: Option<u16>,
@@ -127,13 +261,22 @@ pub struct Config {
     gossip_listen_port: u16,
     http_listen_ip: String,
     http_listen_port: u16,
+    sidecar_addr: String,
+    signal: String,
+    log_lines: Option<usize>,
+    follow: bool,
     userkey: Option<String>,
     servicekey: Option<String>,
     infile: Option<String>,
     outfile: Option<String>,
     gossip_peer: Vec<String>,
     gossip_permanent: bool,
+    gossip_timing: Timing,
+    gossip_transport: TransportConfig,
+    services: Vec<ServiceSpec>,
     update_strategy: UpdateStrategy,
+    update_grace: u64,
+    health_check_interval: u64,
     service_group: String,
     file_path: String,
     version_number: u64,
@@ -170,6 +313,17 @@ impl Config {
         &self.archive
     }
 
+    /// Set how often, in seconds, to run the health check; 0 turns off periodic health checks
+    pub fn set_health_check_interval(&mut self, interval: u64) -> &mut Config {
+        self.health_check_interval = interval;
+        self
+    }
+
+    /// Return how often, in seconds, to run the health check
+    pub fn health_check_interval(&self) -> u64 {
+        self.health_check_interval
+    }
+
     pub fn set_update_strategy(&mut self, strat: UpdateStrategy) -> &mut Config {
         self.update_strategy = strat;
         self
@@ -180,6 +334,17 @@ impl Config {
         self.update_strategy.clone()
     }
 
+    /// Set how long, in seconds, after an update a critical health check rolls it back
+    pub fn set_update_grace(&mut self, grace: u64) -> &mut Config {
+        self.update_grace = grace;
+        self
+    }
+
+    /// Return how long, in seconds, after an update a critical health check rolls it back
+    pub fn update_grace(&self) -> u64 {
+        self.update_grace
+    }
+
     /// Set the `Command` we used
     pub fn set_command(&mut self, command: Command) -> &mut Config {
         self.command = command;
@@ -311,6 +476,28 @@ impl Config {
         &self.url
     }
 
+    /// Set the depot channel
+    pub fn set_channel(&mut self, channel: Option<String>) -> &mut Config {
+        self.channel = channel;
+        self
+    }
+
+    /// Return the depot channel
+    pub fn channel(&self) -> Option<&String> {
+        self.channel.as_ref()
+    }
+
+    /// Return the url packages are installed and updated from; the depot channel, if we are
+    /// following one, otherwise the depot url itself
+    pub fn depot_url(&self) -> String {
+        match self.channel {
+            Some(ref channel) => {
+                format!("{}/channels/{}", self.url.trim_right_matches('/'), channel)
+            }
+            None => self.url.clone(),
+        }
+    }
+
     /// Set the topology
     pub fn set_topology(&mut self, topology: Topology) -> &mut Config {
         self.topology = topology;
@@ -358,6 +545,51 @@ impl Config {
         self
     }
 
+    /// Set the address of the sidecar to send control requests to
+    pub fn set_sidecar_addr(&mut self, addr: String) -> &mut Config {
+        self.sidecar_addr = addr;
+        self
+    }
+
+    /// Return the address of the sidecar to send control requests to
+    pub fn sidecar_addr(&self) -> &str {
+        &self.sidecar_addr
+    }
+
+    /// Set the signal to send to a service's process
+    pub fn set_signal(&mut self, signal: String) -> &mut Config {
+        self.signal = signal;
+        self
+    }
+
+    /// Return the signal to send to a service's process
+    pub fn signal(&self) -> &str {
+        &self.signal
+    }
+
+    /// Set how many of a service's most recent lines of output to show
+    pub fn set_log_lines(&mut self, lines: usize) -> &mut Config {
+        self.log_lines = Some(lines);
+        self
+    }
+
+    /// Return how many of a service's most recent lines of output to show, if not the sidecar's
+    /// default
+    pub fn log_lines(&self) -> Option<usize> {
+        self.log_lines
+    }
+
+    /// Set whether to keep showing a service's output as it is written
+    pub fn set_follow(&mut self, follow: bool) -> &mut Config {
+        self.follow = follow;
+        self
+    }
+
+    /// Return whether to keep showing a service's output as it is written
+    pub fn follow(&self) -> bool {
+        self.follow
+    }
+
     pub fn gossip_permanent(&self) -> bool {
         self.gossip_permanent
     }
@@ -367,6 +599,28 @@ impl Config {
         self
     }
 
+    /// Return the timings of the gossip failure detector
+    pub fn gossip_timing(&self) -> &Timing {
+        &self.gossip_timing
+    }
+
+    /// Set the timings of the gossip failure detector
+    pub fn set_gossip_timing(&mut self, timing: Timing) -> &mut Config {
+        self.gossip_timing = timing;
+        self
+    }
+
+    /// Return how we gossip with other members
+    pub fn gossip_transport(&self) -> &TransportConfig {
+        &self.gossip_transport
+    }
+
+    /// Set how we gossip with other members
+    pub fn set_gossip_transport(&mut self, transport: TransportConfig) -> &mut Config {
+        self.gossip_transport = transport;
+        self
+    }
+
     pub fn gossip_peer(&self) -> &[String] {
         &self.gossip_peer
     }
@@ -423,6 +677,98 @@ impl Config {
         &self.package
     }
 
+    /// Set the services to run alongside the package
+    pub fn set_services(&mut self, services: Vec<ServiceSpec>) -> &mut Config {
+        self.services = services;
+        self
+    }
+
+    /// Set the user keys allowed to change the package's service group config and files
+    pub fn set_trusted_users(&mut self, trusted_users: Vec<String>) -> &mut Config {
+        self.trusted_users = trusted_users;
+        self
+    }
+
+    /// Return the user keys allowed to change the package's service group config and files
+    pub fn trusted_users(&self) -> &[String] {
+        &self.trusted_users
+    }
+
+    /// Set how we restart the package's process after it exits on its own
+    pub fn set_restart_policy(&mut self, restart_policy: RestartPolicy) -> &mut Config {
+        self.restart_policy = restart_policy;
+        self
+    }
+
+    /// Return how we restart the package's process after it exits on its own
+    pub fn restart_policy(&self) -> &RestartPolicy {
+        &self.restart_policy
+    }
+
+    /// Set resource limits for the package's service, overriding those in its plan
+    pub fn set_limits(&mut self, limits: Limits) -> &mut Config {
+        self.limits = limits;
+        self
+    }
+
+    /// Return the resource limits for the package's service that override those in its plan
+    pub fn limits(&self) -> &Limits {
+        &self.limits
+    }
+
+    /// Set the signal that stops the package's process, overriding its plan
+    pub fn set_stop_signal(&mut self, signal: Option<Signal>) -> &mut Config {
+        self.stop_signal = signal;
+        self
+    }
+
+    /// Return the signal that stops the package's process, if it overrides its plan
+    pub fn stop_signal(&self) -> Option<&Signal> {
+        self.stop_signal.as_ref()
+    }
+
+    /// Set how long the package's process may take to stop, overriding its plan
+    pub fn set_stop_timeout(&mut self, timeout: Option<u64>) -> &mut Config {
+        self.stop_timeout = timeout;
+        self
+    }
+
+    /// Return how long the package's process may take to stop, if it overrides its plan
+    pub fn stop_timeout(&self) -> Option<u64> {
+        self.stop_timeout
+    }
+
+    /// Set when we rotate the package's log files
+    pub fn set_log_policy(&mut self, log_policy: LogPolicy) -> &mut Config {
+        self.log_policy = log_policy;
+        self
+    }
+
+    /// Return when we rotate the package's log files
+    pub fn log_policy(&self) -> &LogPolicy {
+        &self.log_policy
+    }
+
+    /// Return every service to run: the package, with the topology, group, bindings, trusted
+    /// users, restart policy, resource limits, stop settings and log policy we were started with,
+    /// followed by any others we were given
+    pub fn services(&self) -> Vec<ServiceSpec> {
+        let mut services = vec![ServiceSpec {
+                                    package: self.package.clone(),
+                                    topology: self.topology,
+                                    group: self.group.clone(),
+                                    bind: self.bind.clone(),
+                                    trusted_users: self.trusted_users.clone(),
+                                    restart: self.restart_policy.clone(),
+                                    limits: self.limits.clone(),
+                                    stop_signal: self.stop_signal.clone(),
+                                    stop_timeout: self.stop_timeout,
+                                    logs: self.log_policy.clone(),
+                                }];
+        services.extend(self.services.iter().cloned());
+        services
+    }
+
     pub fn set_local_artifact(&mut self, artifact: String) -> &mut Config {
         self.local_artifact = Some(artifact);
         self
@@ -455,8 +801,11 @@ impl Config {
 
 #[cfg(test)]
 mod tests {
-    use super::{Config, Command};
+    use super::{Config, Command, ServiceSpec};
+    use logs::LogPolicy;
+    use supervisor::Restart;
     use topology::Topology;
+    use util::signals::Signal;
 
     #[test]
     fn new() {
@@ -492,10 +841,89 @@ mod tests {
         assert_eq!(c.url(), "http://foolio.com");
     }
 
+    #[test]
+    fn depot_url() {
+        let mut c = Config::new();
+        c.set_url(String::from("http://foolio.com/v1/depot"));
+        assert_eq!(c.depot_url(), "http://foolio.com/v1/depot");
+        c.set_channel(Some(String::from("stable")));
+        assert_eq!(c.depot_url(), "http://foolio.com/v1/depot/channels/stable");
+    }
+
     #[test]
     fn topology() {
         let mut c = Config::new();
         c.set_topology(Topology::Leader);
         assert_eq!(*c.topology(), Topology::Leader);
     }
+
+    #[test]
+    fn service_spec() {
+        let spec = ServiceSpec::from_spec("core/haproxy,topology=leader,bind=backend:redis.prod,\
+                                           bind=cache:memcached.prod,trust=dave",
+                                          "default")
+            .unwrap();
+        assert_eq!(spec.package.to_string(), "core/haproxy");
+        assert_eq!(spec.topology, Topology::Leader);
+        assert_eq!(spec.service_group(), "haproxy.default");
+        assert_eq!(spec.bind,
+                   vec![String::from("backend:redis.prod"), String::from("cache:memcached.prod")]);
+        assert_eq!(spec.trusted_users, vec![String::from("dave")]);
+        assert_eq!(spec.restart.restart, Restart::Always);
+        assert!(ServiceSpec::from_spec("core/haproxy,frobnicate", "default").is_err());
+    }
+
+    #[test]
+    fn service_spec_restart_policy() {
+        let spec = ServiceSpec::from_spec("core/redis,restart=on-failure,restart-retries=5,\
+                                           restart-backoff=2,restart-backoff-max=30",
+                                          "default")
+            .unwrap();
+        assert_eq!(spec.restart.restart, Restart::OnFailure);
+        assert_eq!(spec.restart.max_retries, Some(5));
+        assert_eq!(spec.restart.backoff, 2);
+        assert_eq!(spec.restart.backoff_max, 30);
+        assert!(ServiceSpec::from_spec("core/redis,restart=sometimes", "default").is_err());
+        assert!(ServiceSpec::from_spec("core/redis,restart-retries=lots", "default").is_err());
+    }
+
+    #[test]
+    fn service_spec_limits() {
+        let spec = ServiceSpec::from_spec("core/redis,memory-max=512M,cpu-max=0.5,pids-max=100",
+                                          "default")
+            .unwrap();
+        assert_eq!(spec.limits.memory_max, Some(512 * 1024 * 1024));
+        assert_eq!(spec.limits.cpu_max, Some(500));
+        assert_eq!(spec.limits.pids_max, Some(100));
+        assert!(!spec.limits.cgroup);
+        let spec = ServiceSpec::from_spec("core/redis,cgroup=true", "default").unwrap();
+        assert!(spec.limits.needs_cgroup());
+        assert!(ServiceSpec::from_spec("core/redis,memory-max=lots", "default").is_err());
+        assert!(ServiceSpec::from_spec("core/redis,cgroup=sure", "default").is_err());
+    }
+
+    #[test]
+    fn service_spec_stop() {
+        let spec = ServiceSpec::from_spec("core/postgresql,stop-signal=INT,stop-timeout=120",
+                                          "default")
+            .unwrap();
+        assert_eq!(spec.stop_signal, Some(Signal::SIGINT));
+        assert_eq!(spec.stop_timeout, Some(120));
+        assert!(ServiceSpec::from_spec("core/redis,stop-signal=NAP", "default").is_err());
+        assert!(ServiceSpec::from_spec("core/redis,stop-timeout=soon", "default").is_err());
+    }
+
+    #[test]
+    fn service_spec_logs() {
+        let spec = ServiceSpec::from_spec("core/redis,log-max-size=1M,log-max-age=86400,log-keep=2",
+                                          "default")
+            .unwrap();
+        assert_eq!(spec.logs.max_size, Some(1024 * 1024));
+        assert_eq!(spec.logs.max_age, Some(86400));
+        assert_eq!(spec.logs.keep, 2);
+        let spec = ServiceSpec::from_spec("core/redis", "default").unwrap();
+        assert_eq!(spec.logs, LogPolicy::default());
+        assert!(ServiceSpec::from_spec("core/redis,log-max-size=huge", "default").is_err());
+        assert!(ServiceSpec::from_spec("core/redis,log-keep=-1", "default").is_err());
+    }
 }
@@ -24,6 +24,10 @@
 //! its supervisor id first".
 //!
 //! The `leader` topology then evaluates these rumors.
+//!
+//! A leader that steps down starts a new term with itself `excluded`. Every member that meets an
+//! election for that term carries the exclusion along, and never lets the excluded member win, so
+//! leadership passes to someone else.
 
 use std::collections::hash_map::HashMap;
 use std::collections::hash_set::HashSet;
@@ -31,10 +35,13 @@ use std::cmp::PartialEq;
 use std::fmt;
 use std::ops::{Deref, DerefMut};
 
+use sup_protocol::gossip as proto;
 use uuid::Uuid;
 
+use error::Result;
 use gossip::member::MemberId;
 use gossip::rumor::{RumorList, Rumor};
+use gossip::wire;
 
 pub type ElectionId = Uuid;
 
@@ -66,6 +73,8 @@ pub struct Election {
     pub votes: HashSet<MemberId>,
     pub status: ElectionStatus,
     pub term: u32,
+    /// A member that may not win this term, because it is stepping down as leader
+    pub excluded: Option<MemberId>,
 }
 
 impl fmt::Display for Election {
@@ -87,7 +96,8 @@ impl PartialEq for Election {
     fn eq(&self, other: &Election) -> bool {
         self.service == other.service && self.group == other.group &&
         self.leader_id == other.leader_id && self.suitability == other.suitability &&
-        self.votes == other.votes && self.status == other.status && self.term == other.term
+        self.votes == other.votes && self.status == other.status && self.term == other.term &&
+        self.excluded == other.excluded
     }
 }
 
@@ -109,9 +119,15 @@ impl Election {
             term: term,
             suitability: suitability,
             status: ElectionStatus::Running,
+            excluded: None,
         }
     }
 
+    /// Returns true if the member may not win this election.
+    pub fn is_excluded(&self, member_id: &MemberId) -> bool {
+        self.excluded.as_ref() == Some(member_id)
+    }
+
     /// Returns true if the election is finished.
     pub fn finished(&self) -> bool {
         self.status == ElectionStatus::Finished
@@ -124,15 +140,19 @@ impl Election {
 
     /// Returns true if the alive population and the number of votes are equivalent
     pub fn should_finish(&self, member_id: &MemberId, alive_population: usize) -> bool {
-        self.votes.len() == alive_population && self.leader_id == *member_id
+        self.votes.len() == alive_population && self.leader_id == *member_id &&
+        !self.is_excluded(member_id)
     }
 
     /// Updates this struct via another. If true, we have changed the election, and the rumor
     /// should stay hot. If false, we have not changed, and the rumor can start going cold.
     ///
-    /// * If we are the same, return false
+    /// * If the terms are the same and either side excludes a member, both sides exclude it
+    /// * If we are the same, return false (or true, if we just took their exclusion)
     /// * If we are running and the inbound is finsihed, and our term is the same, take the remote
     /// and return true
+    /// * If only their leader is excluded, keep ours, add their votes, and return true
+    /// * If only our leader is excluded, take theirs, add our votes, and return true
     /// * If our suitability is higher then theirs, vote for them, and return true
     /// * If their suitability is higher than ours, vote for them, and return true
     /// * If we are suitablely equal, but our string is higher, vote for ourselves with the remote
@@ -140,46 +160,45 @@ impl Election {
     /// * Otherwise, vote for them, and take theirs - return true
     /// * Otherwise, we are the same, but our votes are different - add all our votes and return
     /// true
-    pub fn update_via(&mut self, remote_election: Election) -> bool {
+    pub fn update_via(&mut self, mut remote_election: Election) -> bool {
+        let mut took_exclusion = false;
+        if self.term == remote_election.term {
+            if self.excluded.is_none() && remote_election.excluded.is_some() {
+                self.excluded = remote_election.excluded;
+                took_exclusion = true;
+            }
+            remote_election.excluded = self.excluded;
+        }
+        let ours_excluded = self.is_excluded(&self.leader_id);
+        let theirs_excluded = self.is_excluded(&remote_election.leader_id);
+
         if *self == remote_election {
-            false
+            took_exclusion
         } else if self.status == ElectionStatus::Running &&
            remote_election.status == ElectionStatus::Finished &&
            self.term == remote_election.term {
             *self = remote_election;
             true
+        } else if theirs_excluded && !ours_excluded {
+            self.keep_ours(remote_election);
+            true
+        } else if ours_excluded && !theirs_excluded {
+            self.take_theirs(remote_election);
+            true
         } else if self.suitability > remote_election.suitability {
-            for x in remote_election.votes.iter() {
-                self.votes.insert(*x);
-            }
-            self.votes.insert(remote_election.leader_id);
+            self.keep_ours(remote_election);
             true
         } else if remote_election.suitability > self.suitability {
-            let old_votes = self.votes.clone();
-            let old_id = self.leader_id.clone();
-            *self = remote_election;
-            for x in old_votes.iter() {
-                self.votes.insert(*x);
-            }
-            self.votes.insert(old_id);
+            self.take_theirs(remote_election);
             true
         } else {
             if self.leader_id.simple().to_string() >
                remote_election.leader_id.simple().to_string() {
-                for x in remote_election.votes.iter() {
-                    self.votes.insert(*x);
-                }
-                self.votes.insert(remote_election.leader_id);
+                self.keep_ours(remote_election);
                 true
             } else if self.leader_id.simple().to_string() <
                remote_election.leader_id.simple().to_string() {
-                let old_votes = self.votes.clone();
-                let old_id = self.leader_id.clone();
-                *self = remote_election;
-                for x in old_votes.iter() {
-                    self.votes.insert(*x);
-                }
-                self.votes.insert(old_id);
+                self.take_theirs(remote_election);
                 true
             } else {
                 let votes = self.votes.clone();
@@ -197,12 +216,85 @@ impl Election {
                         self.status = ElectionStatus::Finished;
                         true
                     } else {
-                        false
+                        took_exclusion
                     }
                 }
             }
         }
     }
+
+    /// Keep our leader, and add the remote's votes to ours.
+    fn keep_ours(&mut self, remote_election: Election) {
+        for x in remote_election.votes.iter() {
+            self.votes.insert(*x);
+        }
+        self.votes.insert(remote_election.leader_id);
+    }
+
+    /// Take the remote's leader, and add our votes to theirs.
+    fn take_theirs(&mut self, remote_election: Election) {
+        let old_votes = self.votes.clone();
+        let old_id = self.leader_id.clone();
+        *self = remote_election;
+        for x in old_votes.iter() {
+            self.votes.insert(*x);
+        }
+        self.votes.insert(old_id);
+    }
+
+    /// The election as the `Election` message of the gossip wire format.
+    pub fn to_proto(&self) -> proto::Election {
+        let mut msg = proto::Election::new();
+        msg.set_id(self.id.to_string());
+        msg.set_service(self.service.clone());
+        msg.set_group(self.group.clone());
+        msg.set_leader_id(self.leader_id.to_string());
+        msg.set_suitability(self.suitability);
+        for vote in self.votes.iter() {
+            msg.mut_votes().push(vote.to_string());
+        }
+        msg.set_status(match self.status {
+            ElectionStatus::Running => proto::Election_Status::RUNNING,
+            ElectionStatus::Finished => proto::Election_Status::FINISHED,
+        });
+        msg.set_term(self.term);
+        if let Some(ref excluded) = self.excluded {
+            msg.set_excluded(excluded.to_string());
+        }
+        msg
+    }
+
+    /// The election an `Election` message of the gossip wire format describes.
+    ///
+    /// # Failures
+    ///
+    /// * A required field is missing, or an id is not a Uuid
+    pub fn from_proto(mut msg: proto::Election) -> Result<Election> {
+        try!(wire::initialized(&msg));
+        let mut votes = HashSet::new();
+        for vote in msg.get_votes().iter() {
+            votes.insert(try!(Uuid::parse_str(vote)));
+        }
+        let excluded = if msg.has_excluded() {
+            Some(try!(Uuid::parse_str(msg.get_excluded())))
+        } else {
+            None
+        };
+        Ok(Election {
+            id: try!(Uuid::parse_str(msg.get_id())),
+            service: msg.take_service(),
+            group: msg.take_group(),
+            leader_id: try!(Uuid::parse_str(msg.get_leader_id())),
+            suitability: msg.get_suitability(),
+            votes: votes,
+            status: match msg.get_status() {
+                proto::Election_Status::RUNNING => ElectionStatus::Running,
+                proto::Election_Status::FINISHED => ElectionStatus::Finished,
+            },
+            term: msg.get_term(),
+            excluded: excluded,
+        })
+    }
 }
 
 /// The election list tracks elections across service groups.
@@ -323,6 +415,28 @@ impl ElectionList {
         rumor_list.add_rumor(election_rumor);
         rumor_list
     }
+
+    /// Generate a rumor list for a new election term in which we step down: we vote in it, but
+    /// cannot win it, so leadership passes to another member.
+    pub fn step_down_rumor_list_for(&self, service: String, group: String) -> RumorList {
+        let mut election = self.generate_election_for(service, group);
+        election.term = self.current_term_for(&election) + 1;
+        election.excluded = Some(self.member_id);
+        let election_rumor = Rumor::election(election.clone());
+        let mut rumor_list = RumorList::new();
+        rumor_list.add_rumor(election_rumor);
+        rumor_list
+    }
+
+    /// Returns the term of our service group's election, once it has finished.
+    pub fn finished_term(&self) -> Option<u32> {
+        self.election().and_then(|e| if e.finished() { Some(e.term) } else { None })
+    }
+
+    /// Returns true if our service group is in an election called by a leader stepping down.
+    pub fn in_step_down(&self) -> bool {
+        self.election().map_or(false, |e| !e.finished() && e.excluded.is_some())
+    }
 }
 
 impl Deref for ElectionList {
@@ -352,8 +466,10 @@ mod test {
     }
 
     mod election {
+        use protobuf::{self, Message};
+
         use gossip::member::MemberId;
-        use election::Election;
+        use election::{Election, ElectionStatus};
         use super::generate_election;
 
         #[test]
@@ -481,6 +597,40 @@ mod test {
             assert!(a_election == d_election);
             assert!(a_election == e_election);
         }
+
+        #[test]
+        fn update_via_never_elects_an_excluded_member() {
+            let (local_id, mut local_election) = generate_election();
+            let (remote_id, mut remote_election) = generate_election();
+
+            local_election.suitability = 100;
+            local_election.excluded = Some(local_id);
+            assert!(remote_election.update_via(local_election.clone()));
+            assert_eq!(remote_election.leader_id, remote_id);
+            assert_eq!(remote_election.excluded, Some(local_id));
+            assert!(remote_election.votes.contains(&local_id));
+
+            assert!(local_election.update_via(remote_election.clone()));
+            assert_eq!(local_election.leader_id, remote_id);
+            assert_eq!(local_election, remote_election);
+            assert!(!local_election.should_finish(&local_id, 2));
+            assert!(remote_election.should_finish(&remote_id, 2));
+        }
+
+        #[test]
+        fn round_trips_through_the_wire_format() {
+            let (local_id, mut election) = generate_election();
+            let (remote_id, _) = generate_election();
+            election.votes.insert(remote_id);
+            election.excluded = Some(local_id);
+            election.status = ElectionStatus::Finished;
+
+            let bytes = election.to_proto().write_to_bytes().unwrap();
+            let msg = protobuf::parse_from_bytes(&bytes).unwrap();
+            let decoded = Election::from_proto(msg).unwrap();
+            assert_eq!(decoded.id, election.id);
+            assert_eq!(decoded, election);
+        }
     }
 
 }
@@ -50,6 +50,7 @@ use ansi_term::Colour::Red;
 use handlebars;
 use hcore::package::Identifiable;
 use hyper;
+use protobuf;
 use rustc_serialize::json;
 use toml;
 use uuid;
@@ -98,11 +99,17 @@ impl SupError {
 #[derive(Debug)]
 pub enum Error {
     ActorError(actor::ActorError),
+    Cgroup(String),
     CommandNotImplemented,
+    ConfigVersionNotFound(u64),
+    ControlTokenNotSet,
+    ControlUnauthorized,
     DbInvalidPath,
     DepotClient(depot_client::Error),
     ExecCommandNotFound(String),
     FileNotFound(String),
+    GossipDecode(String),
+    GossipMessageTooLarge(usize, usize),
     HabitatCommon(common::Error),
     HabitatCore(hcore::Error),
     HandlebarsTemplateFileError(handlebars::TemplateFileError),
@@ -113,9 +120,17 @@ pub enum Error {
     /// TODO: once discovery/etcd.rs is purged, this error can be removed
     HyperError(hyper::error::Error),
     InvalidBinding(String),
+    InvalidConfigVersion(String),
+    InvalidGossipTiming(String),
+    InvalidGossipTransport(String),
     InvalidKeyParameter(String),
+    InvalidLogLines(String),
+    InvalidLogPolicy(String),
     InvalidPidFile,
+    InvalidResourceLimit(String),
     InvalidServiceGroupString(String),
+    InvalidServiceSpec(String),
+    InvalidStopTimeout(String),
     Io(io::Error),
     IPFailed,
     JsonDecode(json::DecoderError),
@@ -123,12 +138,17 @@ pub enum Error {
     KeyNotFound(String),
     MetaFileIO(io::Error),
     NetParseError(net::AddrParseError),
+    NoLeaderCandidate(String),
     NoRunFile,
+    NotLeader(String),
     NulError(ffi::NulError),
     PackageArchiveMalformed(String),
     PackageNotFound(package::PackageIdent),
     Permissions(String),
+    Protobuf(protobuf::ProtobufError),
     RemotePackageNotFound(package::PackageIdent),
+    ServiceFailed(String),
+    ServiceNotStarted(String),
     SignalFailed,
     SignalNotifierStarted,
     StrFromUtf8Error(str::Utf8Error),
@@ -136,8 +156,12 @@ pub enum Error {
     TomlEncode(toml::Error),
     TomlParser(Vec<toml::ParserError>),
     TryRecvError(mpsc::TryRecvError),
+    UnknownRestartPolicy(String),
+    UnknownService(String),
+    UnknownSignal(String),
     UnknownTopology(String),
     UnpackFailed,
+    UnsupportedGossipVersion(u32),
     UuidParseError(uuid::ParseError),
 }
 
@@ -154,14 +178,27 @@ impl fmt::Display for SupError {
                 format!("`{}' was not found on the filesystem or in PATH", c)
             }
             Error::Permissions(ref err) => format!("{}", err),
+            Error::Protobuf(ref err) => format!("{}", err),
             Error::HabitatCommon(ref err) => format!("{}", err),
             Error::HabitatCore(ref err) => format!("{}", err),
             Error::HandlebarsTemplateFileError(ref err) => format!("{:?}", err),
             Error::HandlebarsRenderError(ref err) => format!("{}", err),
+            Error::Cgroup(ref e) => format!("Cannot run the service in its cgroup: {}", e),
             Error::CommandNotImplemented => format!("Command is not yet implemented!"),
+            Error::ConfigVersionNotFound(ref v) => {
+                format!("Configuration version {} is not in the history", v)
+            }
+            Error::ControlTokenNotSet => {
+                format!("The control API is disabled; set HAB_SUP_CONTROL_TOKEN to enable it")
+            }
+            Error::ControlUnauthorized => format!("The control token was missing or wrong"),
             Error::DbInvalidPath => format!("Invalid filepath to internal datastore"),
             Error::DepotClient(ref err) => format!("{}", err),
             Error::FileNotFound(ref e) => format!("File not found at: {}", e),
+            Error::GossipDecode(ref e) => format!("Failed to decode a gossip message: {}", e),
+            Error::GossipMessageTooLarge(size, max) => {
+                format!("Gossip message is {} bytes, but the most we allow is {}", size, max)
+            }
             Error::HealthCheck(ref e) => format!("Health Check failed: {}", e),
             Error::HookFailed(ref t, ref e, ref o) => {
                 format!("Hook failed to run: {}, {}, {}", t, e, o)
@@ -171,13 +208,25 @@ impl fmt::Display for SupError {
             Error::InvalidBinding(ref binding) => {
                 format!("Invalid binding - must be ':' delimited: {}", binding)
             }
+            Error::InvalidConfigVersion(ref v) => {
+                format!("Invalid configuration version number: {}", v)
+            }
+            Error::InvalidGossipTiming(ref e) => format!("Invalid gossip timing: {}", e),
+            Error::InvalidGossipTransport(ref e) => format!("Invalid gossip transport: {}", e),
             Error::InvalidKeyParameter(ref e) => {
                 format!("Invalid parameter for key generation: {:?}", e)
             }
+            Error::InvalidLogLines(ref e) => format!("Invalid number of log lines: {}", e),
+            Error::InvalidLogPolicy(ref e) => format!("Invalid log policy: {}", e),
             Error::InvalidPidFile => format!("Invalid child process PID file"),
+            Error::InvalidResourceLimit(ref e) => format!("Invalid resource limit: {}", e),
             Error::InvalidServiceGroupString(ref e) => {
                 format!("Invalid service group string: {}", e)
             }
+            Error::InvalidServiceSpec(ref e) => format!("Invalid service: {}", e),
+            Error::InvalidStopTimeout(ref e) => {
+                format!("Invalid stop timeout {}; use a number of seconds", e)
+            }
             Error::Io(ref err) => format!("{}", err),
             Error::IPFailed => format!("Failed to discover this hosts outbound IP address"),
             Error::JsonDecode(ref e) => format!("JSON decoding error: {}", e),
@@ -185,10 +234,14 @@ impl fmt::Display for SupError {
             Error::KeyNotFound(ref e) => format!("Key not found in key cache: {}", e),
             Error::MetaFileIO(ref e) => format!("IO error while accessing MetaFile: {:?}", e),
             Error::NetParseError(ref e) => format!("Can't parse ip:port: {}", e),
+            Error::NoLeaderCandidate(ref e) => {
+                format!("No other alive member of {} can take over as the leader", e)
+            }
             Error::NoRunFile => {
                 format!("No run file is present for this package; specify a run hook or \
                          $pkg_svc_run in your plan")
             }
+            Error::NotLeader(ref e) => format!("This member is not the leader of {}", e),
             Error::NulError(ref e) => format!("{}", e),
             Error::PackageArchiveMalformed(ref e) => {
                 format!("Package archive was unreadable or contained unexpected contents: {:?}",
@@ -208,6 +261,10 @@ impl fmt::Display for SupError {
                     format!("Cannot find a release of package in any sources: {}", pkg)
                 }
             }
+            Error::ServiceFailed(ref s) => format!("Stopped because of an error: {}", s),
+            Error::ServiceNotStarted(ref s) => {
+                format!("{} has not been started by its topology yet", s)
+            }
             Error::SignalFailed => format!("Failed to send a signal to the child process"),
             Error::SignalNotifierStarted => {
                 format!("Only one instance of a Signal Notifier may be running")
@@ -219,8 +276,17 @@ impl fmt::Display for SupError {
                 format!("Failed to parse toml:\n{}", toml_parser_string(errs))
             }
             Error::TryRecvError(ref err) => format!("{}", err),
+            Error::UnknownRestartPolicy(ref r) => {
+                format!("Unknown restart policy {}; use always, on-failure or never", r)
+            }
+            Error::UnknownService(ref s) => format!("This Supervisor is not running {}", s),
+            Error::UnknownSignal(ref s) => format!("Unknown signal {}", s),
             Error::UnknownTopology(ref t) => format!("Unknown topology {}!", t),
             Error::UnpackFailed => format!("Failed to unpack a package"),
+            Error::UnsupportedGossipVersion(v) => {
+                format!("Gossip message uses version {} of the protocol, which we do not support",
+                        v)
+            }
             Error::UuidParseError(ref e) => format!("Uuid Parse Error: {:?}", e),
         };
         let cstring = Red.bold().paint(content).to_string();
@@ -245,20 +311,37 @@ impl error::Error for SupError {
             Error::HandlebarsTemplateFileError(ref err) => err.description(),
             Error::HabitatCommon(ref err) => err.description(),
             Error::HabitatCore(ref err) => err.description(),
+            Error::Cgroup(_) => "Cannot run the service in its cgroup",
             Error::CommandNotImplemented => "Command is not yet implemented!",
+            Error::ConfigVersionNotFound(_) => "Configuration version is not in the history",
+            Error::ControlTokenNotSet => "The control API token is not set",
+            Error::ControlUnauthorized => "The control token was missing or wrong",
             Error::DbInvalidPath => "A bad filepath was provided for an internal datastore",
             Error::DepotClient(ref err) => err.description(),
             Error::FileNotFound(_) => "File not found",
+            Error::GossipDecode(_) => "Failed to decode a gossip message",
+            Error::GossipMessageTooLarge(_, _) => "Gossip message is too large",
             Error::HealthCheck(_) => "Health Check returned an unknown status code",
             Error::HookFailed(_, _, _) => "Hook failed to run",
             Error::HTTP(_) => "Received an HTTP error",
             Error::HyperError(ref err) => err.description(),
             Error::InvalidBinding(_) => "Invalid binding parameter",
+            Error::InvalidConfigVersion(_) => "Configuration versions must be positive integers",
+            Error::InvalidGossipTiming(_) => "Invalid gossip timing",
+            Error::InvalidGossipTransport(_) => "Invalid gossip transport",
             Error::InvalidKeyParameter(_) => "Key parameter error",
+            Error::InvalidLogLines(_) => "The number of log lines must be a whole number",
+            Error::InvalidLogPolicy(_) => "Invalid log policy",
             Error::InvalidPidFile => "Invalid child process PID file",
+            Error::InvalidResourceLimit(_) => "Invalid resource limit",
             Error::InvalidServiceGroupString(_) => {
                 "Service group strings must be in service.group format (example: redis.default)"
             }
+            Error::InvalidServiceSpec(_) => {
+                "Services must be given as origin/name, optionally followed by ,topology=, \
+                 ,group= or ,bind= settings"
+            }
+            Error::InvalidStopTimeout(_) => "Stop timeouts must be a number of seconds",
             Error::Io(ref err) => err.description(),
             Error::IPFailed => "Failed to discover the outbound IP address",
             Error::JsonDecode(_) => "JSON decoding error: {:?}",
@@ -266,10 +349,12 @@ impl error::Error for SupError {
             Error::KeyNotFound(_) => "Key not found in key cache",
             Error::MetaFileIO(_) => "MetaFile could not be read or written to",
             Error::NetParseError(_) => "Can't parse IP:port",
+            Error::NoLeaderCandidate(_) => "No other alive member can take over as the leader",
             Error::NoRunFile => {
                 "No run file is present for this package; specify a run hook or $pkg_svc_run \
                  in your plan"
             }
+            Error::NotLeader(_) => "This member is not the leader of the service group",
             Error::NulError(_) => {
                 "An attempt was made to build a CString with a null byte inside it"
             }
@@ -278,7 +363,10 @@ impl error::Error for SupError {
             }
             Error::PackageNotFound(_) => "Cannot find a package",
             Error::Permissions(_) => "File system permissions error",
+            Error::Protobuf(ref err) => err.description(),
             Error::RemotePackageNotFound(_) => "Cannot find a package in any sources",
+            Error::ServiceFailed(_) => "A service stopped because of an error",
+            Error::ServiceNotStarted(_) => "The service has not been started yet",
             Error::SignalFailed => "Failed to send a signal to the child process",
             Error::SignalNotifierStarted => "Only one instance of a Signal Notifier may be running",
             Error::StrFromUtf8Error(_) => "Failed to convert a str from a &[u8] as UTF-8",
@@ -286,8 +374,12 @@ impl error::Error for SupError {
             Error::TomlEncode(_) => "Failed to encode toml!",
             Error::TomlParser(_) => "Failed to parse toml!",
             Error::TryRecvError(_) => "A channel failed to recieve a response",
+            Error::UnknownRestartPolicy(_) => "Unknown restart policy",
+            Error::UnknownService(_) => "This Supervisor is not running that service",
+            Error::UnknownSignal(_) => "Unknown signal",
             Error::UnknownTopology(_) => "Unknown topology",
             Error::UnpackFailed => "Failed to unpack a package",
+            Error::UnsupportedGossipVersion(_) => "Unsupported gossip protocol version",
             Error::UuidParseError(_) => "Uuid Parse Error",
         }
     }
@@ -326,6 +418,12 @@ impl From<handlebars::TemplateFileError> for SupError {
     }
 }
 
+impl From<protobuf::ProtobufError> for SupError {
+    fn from(err: protobuf::ProtobufError) -> SupError {
+        sup_error!(Error::Protobuf(err))
+    }
+}
+
 impl From<hcore::Error> for SupError {
     fn from(err: hcore::Error) -> SupError {
         sup_error!(Error::HabitatCore(err))
@@ -14,48 +14,79 @@
 
 //! The Gossip Client.
 //!
-//! This module takes a `UtpSocket`, and lets you send and receive messages with it. Messages are
-//! encoded with json.
+//! This module lets you send and receive messages with another member, over whichever transport
+//! we gossip with. Messages are encoded in the version of the gossip protocol the client was made
+//! to speak.
 //!
 
-use std::net::ToSocketAddrs;
-use std::str;
+use std::net::{SocketAddr, ToSocketAddrs, UdpSocket};
+use std::usize;
+use std::sync::Arc;
 
-use common::wire_message::WireMessage;
-use hcore::crypto::SymKey;
-use rustc_serialize::json;
 use utp::UtpSocket;
 
-use error::Result;
+use error::{Error, Result};
+use gossip::member::MemberId;
+use gossip::ring_keys::RingKeys;
 use gossip::rumor::{Protocol, Peer, RumorList};
+use gossip::transport::{self, Transport, JSON_GOSSIP_VERSION};
+
+static LOGKEY: &'static str = "GC";
 
 pub const BUFFER_SIZE: usize = 10000;
 
+/// How a client reaches the other member.
+enum Connection {
+    Utp(UtpSocket),
+    Udp(Arc<UdpSocket>, SocketAddr, usize),
+}
+
 /// A Gossip Client.
 pub struct Client<'a> {
-    pub socket: UtpSocket,
-    ring_key: Option<&'a SymKey>,
+    connection: Connection,
+    ring_keys: &'a RingKeys,
+    gossip_version: u8,
 }
 
 impl<'a> Client<'a> {
-    /// Create a new client from anything that can become a `SocketAddr`.
+    /// Create a new client for anything that can become a `SocketAddr`, over the given transport,
+    /// which writes messages in the given version of the gossip protocol.
     ///
     /// # Errors
     ///
     /// * If we cannot connect the UTP socket
-    pub fn new<A: ToSocketAddrs>(dst: A, ring_key: Option<&'a SymKey>) -> Result<Client> {
-        let socket = try!(UtpSocket::connect(dst));
+    /// * If we cannot resolve the address to send UDP datagrams to
+    pub fn new<A: ToSocketAddrs>(transport: &Transport,
+                                 dst: A,
+                                 ring_keys: &'a RingKeys,
+                                 gossip_version: u8)
+                                 -> Result<Client<'a>> {
+        let connection = match *transport {
+            Transport::Utp => Connection::Utp(try!(UtpSocket::connect(dst))),
+            Transport::Udp(ref socket, max_datagram) => {
+                let addr = match try!(dst.to_socket_addrs()).next() {
+                    Some(addr) => addr,
+                    None => {
+                        let msg = "the member's address did not resolve".to_string();
+                        return Err(sup_error!(Error::InvalidGossipTransport(msg)));
+                    }
+                };
+                Connection::Udp(socket.clone(), addr, max_datagram)
+            }
+        };
         Ok(Client {
-            socket: socket,
-            ring_key: ring_key,
+            connection: connection,
+            ring_keys: ring_keys,
+            gossip_version: gossip_version,
         })
     }
 
-    /// Create a new client from a `UtpSocket`
-    pub fn from_socket(socket: UtpSocket, ring_key: Option<&'a SymKey>) -> Client {
+    /// Create a new client from a `UtpSocket`. It writes JSON, which every member reads.
+    pub fn from_socket(socket: UtpSocket, ring_keys: &'a RingKeys) -> Client {
         Client {
-            socket: socket,
-            ring_key: ring_key,
+            connection: Connection::Utp(socket),
+            ring_keys: ring_keys,
+            gossip_version: JSON_GOSSIP_VERSION,
         }
     }
 
@@ -94,34 +125,67 @@ impl<'a> Client<'a> {
         Ok(())
     }
 
-    /// Receives a message.
+    /// Get the hot rumors to send a member in one message; `message` makes the message from the
+    /// rumors. Over uTP, that is all of them. Over UDP, it is as many as fit in a datagram,
+    /// hottest first.
+    pub fn hot_rumors_for<F>(&self,
+                             rumor_list: &RumorList,
+                             member_id: &MemberId,
+                             message: F)
+                             -> RumorList
+        where F: Fn(RumorList) -> Protocol
+    {
+        match self.connection {
+            Connection::Utp(_) => rumor_list.hot_rumors_for(member_id),
+            Connection::Udp(_, _, max_datagram) => {
+                rumor_list.hot_rumors_within(member_id,
+                                             max_datagram,
+                                             |rumors| self.datagram_size(&message(rumors.clone())))
+            }
+        }
+    }
+
+    /// The size of the datagram a message would take; as good as infinite, if we cannot encode
+    /// it.
+    fn datagram_size(&self, msg: &Protocol) -> usize {
+        match transport::encode(msg, self.ring_keys, self.gossip_version) {
+            Ok(encoded) => transport::datagram(&encoded, self.gossip_version).len(),
+            Err(_) => usize::MAX,
+        }
+    }
+
+    /// Receives a message. Only clients made from a `UtpSocket` receive; datagrams sent to us over
+    /// UDP all arrive at the inbound listener.
     ///
     /// # Errors
     ///
     /// * We cannot receive the data from the socket
     /// * We cannot decode the data into a `gossip::message::Protocol`
     pub fn recv_message(&mut self) -> Result<Protocol> {
+        let socket = match self.connection {
+            Connection::Utp(ref mut socket) => socket,
+            Connection::Udp(..) => {
+                let msg = "UDP clients only send messages".to_string();
+                return Err(sup_error!(Error::InvalidGossipTransport(msg)));
+            }
+        };
         let mut buf = [0u8; BUFFER_SIZE];
-        let mut json_str = String::new();
+        let mut encoded = Vec::new();
         let mut keep_reading_buffer = true;
 
         while keep_reading_buffer {
-            let (amt, _src) = try!(self.socket.recv_from(&mut buf));
+            let (amt, _src) = try!(socket.recv_from(&mut buf));
             match amt {
                 0 => keep_reading_buffer = false,
-                amt => {
-                    let partial_str = try!(str::from_utf8(&buf[..amt]));
-                    json_str.push_str(partial_str);
-                }
+                amt => encoded.extend_from_slice(&buf[..amt]),
             }
         }
 
         debug!("Received protocol ({:?}): {}",
-               self.socket.peer_addr(),
-               json_str);
+               socket.peer_addr(),
+               String::from_utf8_lossy(&encoded));
 
-        let wire_msg: WireMessage = try!(json::decode(&json_str));
-        Ok(try!(wire_msg.msg(self.ring_key)))
+        transport::decode(&encoded, self.ring_keys)
     }
 
     /// Send a message.
@@ -130,16 +194,23 @@ impl<'a> Client<'a> {
     ///
     /// * We cannot encode the `Message`
     /// * We fail to send the encoded buffer to the remote
+    /// * The message does not fit in a datagram
     pub fn send_message(&mut self, msg: Protocol) -> Result<()> {
-        let encoded = {
-            let wire_msg = match self.ring_key.as_ref() {
-                Some(key) => try!(WireMessage::encrypted(&msg, &key)),
-                None => try!(WireMessage::plain(&msg)),
-            };
-            try!(json::encode(&wire_msg))
-        };
-        debug!("Encoded message {:#?}", encoded);
-        try!(self.socket.send_to(encoded.as_bytes()));
+        let encoded = try!(transport::encode(&msg, self.ring_keys, self.gossip_version));
+        debug!("Encoded message {}", String::from_utf8_lossy(&encoded));
+        match self.connection {
+            Connection::Utp(ref mut socket) => {
+                try!(socket.send_to(&encoded));
+            }
+            Connection::Udp(ref socket, ref addr, max_datagram) => {
+                let datagram = transport::datagram(&encoded, self.gossip_version);
+                if datagram.len() > max_datagram {
+                    return Err(sup_error!(Error::GossipMessageTooLarge(datagram.len(),
+                                                                       max_datagram)));
+                }
+                try!(socket.send_to(&datagram, addr));
+            }
+        }
         debug!("Sent protocol: {:?}", msg);
         Ok(())
     }
@@ -16,20 +16,130 @@
 //!
 //! This module tracks the status of all our outbound connections, and takes care of marking them
 //! as suspect or confirmed.
+//!
+//! How long we wait at each step is set by a `Timing`. Once a member is suspect, we wait for the
+//! suspicion timeout before confirming it. Like Lifeguard, that timeout starts out longer (by the
+//! suspicion multiplier), and shrinks towards the minimum as other members tell us they suspect it
+//! too; a member we alone suspect is more likely to be a problem on our end.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use rustc_serialize::{Encodable, Encoder};
 use time::{SteadyTime, Duration};
 
+use error::{Error, Result};
 use gossip::member::MemberId;
 
+static LOGKEY: &'static str = "FD";
+
 /// Failure time in milliseconds
 pub static REQUEST_FAILURE_TIME: i64 = 5000;
 
 /// How long before we declare this member all the way gone
 pub static REQUEST_CONFIRM_TIME: i64 = 10000;
 
+/// How often we send an outbound ping, in milliseconds
+pub static PING_INTERVAL: u64 = 200;
+
+/// How many members we ask to ping a member that did not answer us
+pub static PINGREQ_MEMBERS: usize = 5;
+
+/// How many independent suspicions bring the suspicion timeout all the way down to its minimum
+pub static SUSPICION_CONFIRMATIONS: usize = 3;
+
+/// How often we swap every rumor with a member when gossiping over UDP, in milliseconds
+pub static PUSH_PULL_INTERVAL: u64 = 30000;
+
+/// How long a member stays confirmed dead before we remove it from the ring, in milliseconds
+pub static DEPARTURE_TIMEOUT: u64 = 72 * 60 * 60 * 1000;
+
+/// The timings of the failure detector. The defaults suit a LAN.
+#[derive(Debug, Clone, PartialEq, Eq, RustcEncodable)]
+pub struct Timing {
+    /// How often we send an outbound ping, in milliseconds
+    pub ping_interval: u64,
+    /// How many members we ask to ping a member that did not answer us
+    pub pingreq_fanout: usize,
+    /// How long we wait for an ack, and then for a ping-req, before we suspect a member, in
+    /// milliseconds
+    pub ping_timeout: u64,
+    /// The shortest time a member stays suspect before we confirm it, in milliseconds
+    pub suspicion_timeout: u64,
+    /// How many times the suspicion timeout we wait when no other member suspects it too
+    pub suspicion_multiplier: u64,
+    /// How long a member stays confirmed dead before it departs the ring, in milliseconds
+    pub departure_timeout: u64,
+    /// How often we swap every rumor with a member when gossiping over UDP, in milliseconds
+    pub push_pull_interval: u64,
+}
+
+impl Default for Timing {
+    fn default() -> Timing {
+        Timing {
+            ping_interval: PING_INTERVAL,
+            pingreq_fanout: PINGREQ_MEMBERS,
+            ping_timeout: REQUEST_FAILURE_TIME as u64,
+            suspicion_timeout: REQUEST_CONFIRM_TIME as u64,
+            suspicion_multiplier: 1,
+            departure_timeout: DEPARTURE_TIMEOUT,
+            push_pull_interval: PUSH_PULL_INTERVAL,
+        }
+    }
+}
+
+impl Timing {
+    /// Check the timings make sense together.
+    ///
+    /// # Failures
+    ///
+    /// * Any of them is zero
+    /// * We would give up on a ping before the next one is sent
+    /// * The longest suspicion timeout is too long to count in milliseconds
+    /// * A member could depart before it would have been confirmed
+    pub fn validate(&self) -> Result<()> {
+        let longest_suspicion = self.suspicion_timeout.checked_mul(self.suspicion_multiplier);
+        let problem = if self.ping_interval == 0 {
+            Some("the ping interval must be more than 0ms")
+        } else if self.pingreq_fanout == 0 {
+            Some("the ping-req fan-out must be at least 1")
+        } else if self.ping_timeout <= self.ping_interval {
+            Some("the ping timeout must be longer than the ping interval")
+        } else if self.suspicion_timeout == 0 {
+            Some("the suspicion timeout must be more than 0ms")
+        } else if self.suspicion_multiplier == 0 {
+            Some("the suspicion multiplier must be at least 1")
+        } else if longest_suspicion.is_none() {
+            Some("the suspicion timeout times the multiplier is too long")
+        } else if Some(self.departure_timeout) <= longest_suspicion {
+            Some("the departure timeout must be longer than the longest suspicion timeout")
+        } else if self.push_pull_interval == 0 {
+            Some("the push-pull interval must be more than 0ms")
+        } else {
+            None
+        };
+        match problem {
+            Some(problem) => Err(sup_error!(Error::InvalidGossipTiming(problem.to_string()))),
+            None => Ok(()),
+        }
+    }
+
+    /// How long a member stays suspect before we confirm it, in milliseconds, when `suspicions`
+    /// other members have told us they suspect it too. Starts at the suspicion timeout times the
+    /// multiplier, and falls logarithmically to the suspicion timeout.
+    pub fn suspicion_timeout_for(&self, suspicions: usize) -> u64 {
+        let min = self.suspicion_timeout as f64;
+        let max = min * self.suspicion_multiplier as f64;
+        let fraction = ((suspicions as f64) + 1.0).ln() /
+                       ((SUSPICION_CONFIRMATIONS as f64) + 1.0).ln();
+        let timeout = max - (max - min) * fraction;
+        if timeout < min {
+            self.suspicion_timeout
+        } else {
+            timeout.round() as u64
+        }
+    }
+}
+
 /// The status of a request
 #[derive(Debug, RustcEncodable, RustcDecodable, PartialEq, Eq)]
 pub enum Status {
@@ -44,16 +154,33 @@ pub enum Status {
 pub struct RequestState {
     pub status: Status,
     pub timeout: SteadyTime,
+    /// When the request entered its current status
+    pub since: SteadyTime,
 }
 
 impl RequestState {
     /// Create a new RequestState. Starts out in 'Running'.
     pub fn new() -> RequestState {
+        RequestState::with_timeout(REQUEST_FAILURE_TIME as u64)
+    }
+
+    /// Create a new RequestState in 'Running', that times out after `timeout` milliseconds.
+    pub fn with_timeout(timeout: u64) -> RequestState {
+        let now = SteadyTime::now();
         RequestState {
             status: Status::Running,
-            timeout: SteadyTime::now() + Duration::milliseconds(REQUEST_FAILURE_TIME),
+            timeout: now + Duration::milliseconds(timeout as i64),
+            since: now,
         }
     }
+
+    /// Move to a new status, timing out after `timeout` milliseconds.
+    fn set(&mut self, status: Status, timeout: u64) {
+        let now = SteadyTime::now();
+        self.status = status;
+        self.timeout = now + Duration::milliseconds(timeout as i64);
+        self.since = now;
+    }
 }
 
 // We have to hand-write the serialization due to our use of SteadyTime.
@@ -72,12 +199,24 @@ impl Encodable for RequestState {
 #[derive(Debug, RustcEncodable)]
 pub struct Detector {
     pub open_requests: HashMap<MemberId, RequestState>,
+    /// The members that have told us they suspect a member
+    pub suspicions: HashMap<MemberId, HashSet<MemberId>>,
+    pub timing: Timing,
 }
 
 impl Detector {
-    /// Create a new failure detector.
+    /// Create a new failure detector, with the default timings.
     pub fn new() -> Detector {
-        Detector { open_requests: HashMap::new() }
+        Detector::with_timing(Timing::default())
+    }
+
+    /// Create a new failure detector with the given timings.
+    pub fn with_timing(timing: Timing) -> Detector {
+        Detector {
+            open_requests: HashMap::new(),
+            suspicions: HashMap::new(),
+            timing: timing,
+        }
     }
 
     /// Returns true if a member has an open request
@@ -87,36 +226,68 @@ impl Detector {
 
     /// Start a new request
     pub fn start(&mut self, member_id: MemberId) {
-        self.open_requests.insert(member_id, RequestState::new());
+        self.open_requests.insert(member_id, RequestState::with_timeout(self.timing.ping_timeout));
     }
 
     /// Set a requests state to PingReq
     pub fn pingreq(&mut self, member_id: &MemberId) {
         if let Some(rs) = self.open_requests.get_mut(member_id) {
-            rs.status = Status::PingReq;
-            rs.timeout = SteadyTime::now() + Duration::milliseconds(REQUEST_FAILURE_TIME);
+            rs.set(Status::PingReq, self.timing.ping_timeout);
         }
     }
 
     /// Sets a requests state to AwaitingAck
     pub fn awaiting_ack(&mut self, member_id: &MemberId) {
         if let Some(rs) = self.open_requests.get_mut(member_id) {
-            rs.status = Status::AwaitingAck;
-            rs.timeout = SteadyTime::now() + Duration::milliseconds(REQUEST_FAILURE_TIME);
+            rs.set(Status::AwaitingAck, self.timing.ping_timeout);
         }
     }
 
     /// Sets a requests state to Failed
     pub fn failed(&mut self, member_id: &MemberId) {
+        let timeout = self.suspicion_timeout(member_id);
         if let Some(rs) = self.open_requests.get_mut(member_id) {
-            rs.status = Status::Failed;
-            rs.timeout = SteadyTime::now() + Duration::milliseconds(REQUEST_CONFIRM_TIME);
+            rs.set(Status::Failed, timeout);
         }
     }
 
+    /// Record that another member told us it suspects a member. If we suspect it too, the more
+    /// members that agree, the sooner we confirm it.
+    pub fn suspected_by(&mut self, member_id: &MemberId, by: MemberId) {
+        let added = self.suspicions
+            .entry(member_id.clone())
+            .or_insert(HashSet::new())
+            .insert(by);
+        if !added {
+            return;
+        }
+        let timeout = self.suspicion_timeout(member_id);
+        if let Some(rs) = self.open_requests.get_mut(member_id) {
+            if rs.status == Status::Failed {
+                rs.timeout = rs.since + Duration::milliseconds(timeout as i64);
+            }
+        }
+    }
+
+    /// How long we keep a member suspect before confirming it, given who else suspects it.
+    fn suspicion_timeout(&self, member_id: &MemberId) -> u64 {
+        let suspicions = self.suspicions.get(member_id).map_or(0, |s| s.len());
+        self.timing.suspicion_timeout_for(suspicions)
+    }
+
     /// On success, we remove the request from the detector
     pub fn success(&mut self, member_id: &MemberId) {
         self.open_requests.remove(member_id);
+        self.suspicions.remove(member_id);
+    }
+
+    /// Forget everything about a member that has departed the ring.
+    pub fn departed(&mut self, member_id: &MemberId) {
+        self.open_requests.remove(member_id);
+        self.suspicions.remove(member_id);
+        for (_suspect, mut suspected_by) in self.suspicions.iter_mut() {
+            suspected_by.remove(member_id);
+        }
     }
 
     /// Checks the timeout of connections. Returns a tuple of (suspect, confirmed) members.
@@ -140,6 +311,7 @@ impl Detector {
         }
         for member_id in confirmed_members.iter() {
             self.open_requests.remove(member_id);
+            self.suspicions.remove(member_id);
         }
         (pingreq_members, failed_members, confirmed_members)
     }
@@ -159,9 +331,53 @@ mod test {
 
     }
 
+    mod timing {
+        use gossip::detector::Timing;
+
+        #[test]
+        fn default_is_valid() {
+            assert!(Timing::default().validate().is_ok());
+        }
+
+        #[test]
+        fn validate_rejects_a_ping_timeout_shorter_than_the_interval() {
+            let mut timing = Timing::default();
+            timing.ping_timeout = timing.ping_interval;
+            assert!(timing.validate().is_err());
+        }
+
+        #[test]
+        fn validate_rejects_an_overflowing_suspicion_timeout() {
+            let mut timing = Timing::default();
+            timing.suspicion_multiplier = u64::max_value();
+            timing.departure_timeout = u64::max_value();
+            assert!(timing.validate().is_err());
+        }
+
+        #[test]
+        fn validate_rejects_departing_before_confirmation() {
+            let mut timing = Timing::default();
+            timing.departure_timeout = timing.suspicion_timeout;
+            assert!(timing.validate().is_err());
+        }
+
+        #[test]
+        fn suspicion_timeout_shrinks_with_independent_suspicions() {
+            let mut timing = Timing::default();
+            timing.suspicion_timeout = 1000;
+            timing.suspicion_multiplier = 5;
+            assert_eq!(timing.suspicion_timeout_for(0), 5000);
+            let one = timing.suspicion_timeout_for(1);
+            assert!(one < 5000 && one > 1000);
+            assert!(timing.suspicion_timeout_for(2) < one);
+            assert_eq!(timing.suspicion_timeout_for(3), 1000);
+            assert_eq!(timing.suspicion_timeout_for(10), 1000);
+        }
+    }
+
     mod detector {
         use gossip::member::MemberId;
-        use gossip::detector::{Detector, Status};
+        use gossip::detector::{Detector, Status, Timing};
 
         #[test]
         fn start() {
@@ -180,5 +396,20 @@ mod test {
             assert!(d.open_requests.contains_key(&id));
             assert_eq!(d.open_requests.get(&id).unwrap().status, Status::PingReq);
         }
+
+        #[test]
+        fn suspected_by_brings_the_confirmation_forward() {
+            let mut timing = Timing::default();
+            timing.suspicion_multiplier = 4;
+            let mut d = Detector::with_timing(timing);
+            let id = MemberId::new_v4();
+            d.start(id);
+            d.failed(&id);
+            let alone = d.open_requests.get(&id).unwrap().timeout;
+            d.suspected_by(&id, MemberId::new_v4());
+            assert!(d.open_requests.get(&id).unwrap().timeout < alone);
+            d.success(&id);
+            assert!(!d.suspicions.contains_key(&id));
+        }
     }
 }
@@ -23,23 +23,26 @@ use std::fmt;
 use std::ops::Deref;
 
 use rand::{thread_rng, Rng};
+use sup_protocol::gossip as proto;
+use time;
 use uuid::Uuid;
 
+use error::Result;
 use gossip::lamport_clock::LamportClock;
-
-// How many members do we send a PingReq to for a failed node?
-static PINGREQ_MEMBERS: usize = 5;
+use gossip::transport;
+use gossip::wire;
 
 /// Every time we receive a Suspect or Confirmed message about our own entry in the MemberList, we
 /// update our Incarnation.
 pub type Incarnation = LamportClock;
 
-/// The health of the Member.
+/// The health of the Member. A member that is shutting down announces that it is `Departed`.
 #[derive(Clone, Debug, PartialEq, Eq, RustcDecodable, RustcEncodable)]
 pub enum Health {
     Alive,
     Suspect,
     Confirmed,
+    Departed,
 }
 
 /// Each member has a Uuid.
@@ -56,6 +59,9 @@ pub struct Member {
     pub incarnation: Incarnation,
     pub health: Health,
     pub permanent: bool,
+    /// The member that suspected this one, while it is suspect. Each member that suspects it
+    /// counts once towards confirming it, however many members pass the rumor on.
+    pub suspected_by: Option<MemberId>,
 }
 
 impl Member {
@@ -69,6 +75,7 @@ impl Member {
             incarnation: Incarnation::new(),
             health: Health::Alive,
             permanent: permanent,
+            suspected_by: None,
         }
     }
 
@@ -79,7 +86,8 @@ impl Member {
     /// * If the rhs has a higher incarnation, use the rhs
     /// * If the lhs has a higher incarnation, use the lhs
     /// * If the incarnation is equal, and health is equal, use the lhs
-    /// * If the incarnation is equal, and the rhs health is confirmed, used rhs
+    /// * If the incarnation is equal, and the lhs has departed, use the lhs
+    /// * If the incarnation is equal, and the rhs health is confirmed or departed, used rhs
     /// * If the incarnation is equal, and the lhs is alive, and the rhs is suspect, use rhs
     /// * If the incarnation is equal, and the lhs is confirmed, and the rhs is suspect or alive, use the lhs
     pub fn update_via(&mut self, my_id: &MemberId, rhs: Member) -> bool {
@@ -92,7 +100,10 @@ impl Member {
             if self.health == rhs.health {
                 return false;
             }
-            if rhs.health == Health::Confirmed {
+            if self.health == Health::Departed {
+                return false;
+            }
+            if rhs.health == Health::Confirmed || rhs.health == Health::Departed {
                 if self.id == *my_id {
                     self.incarnation.increment();
                     self.health = Health::Alive;
@@ -120,6 +131,56 @@ impl Member {
         }
         return false;
     }
+
+    /// The member as the `Member` message of the gossip wire format.
+    pub fn to_proto(&self) -> proto::Member {
+        let mut msg = proto::Member::new();
+        msg.set_id(self.id.to_string());
+        msg.set_hostname(self.hostname.clone());
+        msg.set_ip(self.ip.clone());
+        msg.set_gossip_listener(self.gossip_listener.clone());
+        msg.set_incarnation(self.incarnation.counter);
+        msg.set_health(match self.health {
+            Health::Alive => proto::Member_Health::ALIVE,
+            Health::Suspect => proto::Member_Health::SUSPECT,
+            Health::Confirmed => proto::Member_Health::CONFIRMED,
+            Health::Departed => proto::Member_Health::DEPARTED,
+        });
+        msg.set_permanent(self.permanent);
+        if let Some(ref suspected_by) = self.suspected_by {
+            msg.set_suspected_by(suspected_by.to_string());
+        }
+        msg
+    }
+
+    /// The member a `Member` message of the gossip wire format describes.
+    ///
+    /// # Failures
+    ///
+    /// * A required field is missing, or an id is not a Uuid
+    pub fn from_proto(mut msg: proto::Member) -> Result<Member> {
+        try!(wire::initialized(&msg));
+        let suspected_by = if msg.has_suspected_by() {
+            Some(try!(Uuid::parse_str(msg.get_suspected_by())))
+        } else {
+            None
+        };
+        Ok(Member {
+            id: try!(Uuid::parse_str(msg.get_id())),
+            hostname: msg.take_hostname(),
+            ip: msg.take_ip(),
+            gossip_listener: msg.take_gossip_listener(),
+            incarnation: LamportClock { counter: msg.get_incarnation() },
+            health: match msg.get_health() {
+                proto::Member_Health::ALIVE => Health::Alive,
+                proto::Member_Health::SUSPECT => Health::Suspect,
+                proto::Member_Health::CONFIRMED => Health::Confirmed,
+                proto::Member_Health::DEPARTED => Health::Departed,
+            },
+            permanent: msg.get_permanent(),
+            suspected_by: suspected_by,
+        })
+    }
 }
 
 impl fmt::Display for Member {
@@ -128,14 +189,32 @@ impl fmt::Display for Member {
     }
 }
 
+/// What we remember about a member that has departed the ring.
+#[derive(Clone, Debug, PartialEq, Eq, RustcDecodable, RustcEncodable)]
+pub struct Tombstone {
+    /// The incarnation the member had when it departed; only gossip from a later incarnation
+    /// brings it back
+    pub incarnation: Incarnation,
+    /// When it departed, in milliseconds since the epoch
+    pub departed_at: u64,
+}
+
 /// A list of members. Keeps track of both the members themselves, and provides an order to iterate
 /// through them via the `next()` function.
+///
+/// Members that stay confirmed dead for long enough depart the ring; they are removed from the
+/// list, and a tombstone keeps stale gossip about them from bringing them back.
 #[derive(Debug, RustcDecodable, RustcEncodable)]
 pub struct MemberList {
     members: HashMap<MemberId, Member>,
     position: usize,
     order: Vec<Uuid>,
     pub my_id: MemberId,
+    /// When each confirmed member was confirmed, in milliseconds since the epoch
+    confirmed_at: HashMap<MemberId, u64>,
+    departed: HashMap<MemberId, Tombstone>,
+    /// The newest version of the gossip protocol each member told us it speaks
+    gossip_versions: HashMap<MemberId, u32>,
 }
 
 impl MemberList {
@@ -146,6 +225,9 @@ impl MemberList {
             position: 0,
             order: Vec::new(),
             my_id: my_member.id.clone(),
+            confirmed_at: HashMap::new(),
+            departed: HashMap::new(),
+            gossip_versions: HashMap::new(),
         };
         ml.insert(my_member);
         ml
@@ -155,8 +237,9 @@ impl MemberList {
     pub fn insert(&mut self, member: Member) {
         let oid = member.id.clone();
         if let None = self.members.insert(member.id.clone(), member) {
-            self.order.push(oid);
+            self.order.push(oid.clone());
         }
+        self.track_confirmation(&oid);
     }
 
     /// Return a reference to the next member in the list. If the list is empty, we return `None`.
@@ -185,11 +268,28 @@ impl MemberList {
     /// so we insert them.
     ///
     /// Either way, we return true if we added a new member or mutated an existing one; false if we
-    /// did nothing.
+    /// did nothing. Members that have departed stay gone, unless the gossip is about a later
+    /// incarnation.
     pub fn process(&mut self, remote_member: Member) -> bool {
-        // This is strange - rust won't let this be an else.
-        if let Some(mut current_member) = self.members.get_mut(&remote_member.id) {
-            return current_member.update_via(&self.my_id, remote_member);
+        let id = remote_member.id.clone();
+        if let Some(tombstone) = self.departed.get(&id) {
+            if remote_member.incarnation <= tombstone.incarnation {
+                debug!("Ignoring gossip about departed member {}", id);
+                return false;
+            }
+        }
+        if self.departed.remove(&id).is_some() {
+            warn!("Member {} has rejoined", id);
+        }
+        if self.members.contains_key(&id) {
+            let changed = self.members
+                .get_mut(&id)
+                .unwrap()
+                .update_via(&self.my_id, remote_member);
+            if changed {
+                self.track_confirmation(&id);
+            }
+            return changed;
         }
         match remote_member.health {
             Health::Alive => warn!("Member {} is alive", remote_member.id),
@@ -213,33 +313,142 @@ impl MemberList {
     /// Set a members health to Alive
     pub fn alive(&mut self, member_id: &MemberId) {
         if let Some(mut member) = self.members.get_mut(member_id) {
-            member.health = Health::Alive;
+            if member.health != Health::Departed {
+                member.health = Health::Alive;
+                member.suspected_by = None;
+            }
         }
+        self.track_confirmation(member_id);
     }
 
-    /// Set a members health to Suspect.
+    /// Set a members health to Suspect, on our own suspicion.
     pub fn suspect(&mut self, member_id: &MemberId) {
         if let Some(mut member) = self.members.get_mut(member_id) {
-            if member.health != Health::Confirmed {
+            if member.health != Health::Confirmed && member.health != Health::Departed {
                 warn!("Member {} is suspect", member_id);
                 member.health = Health::Suspect;
+                member.suspected_by = Some(self.my_id);
             }
         }
+        self.track_confirmation(member_id);
     }
 
     /// Set a members health to Confirmed.
     pub fn confirm(&mut self, member_id: &MemberId) {
         if let Some(mut member) = self.members.get_mut(member_id) {
-            if member.health != Health::Confirmed {
+            if member.health != Health::Confirmed && member.health != Health::Departed {
                 warn!("Member {} is confirmed dead", member_id);
                 member.health = Health::Confirmed;
             }
         }
+        self.track_confirmation(member_id);
+    }
+
+    /// Mark ourselves as Departed, with a new incarnation so it overrides anything else that is
+    /// said about us. Returns our updated entry.
+    pub fn depart(&mut self) -> Member {
+        let my_id = self.my_id.clone();
+        {
+            let me = self.members.get_mut(&my_id).unwrap();
+            me.incarnation.increment();
+            me.health = Health::Departed;
+        }
+        self.track_confirmation(&my_id);
+        self.members.get(&my_id).unwrap().clone()
+    }
+
+    /// Start the departure clock for a member that has just been confirmed dead or announced it
+    /// departed, or stop it for one that is back.
+    fn track_confirmation(&mut self, member_id: &MemberId) {
+        let confirmed = self.members
+            .get(member_id)
+            .map_or(false,
+                    |m| m.health == Health::Confirmed || m.health == Health::Departed);
+        if confirmed {
+            self.confirmed_at.entry(member_id.clone()).or_insert(now_millis());
+        } else {
+            self.confirmed_at.remove(member_id);
+        }
+    }
+
+    /// Remove the members that have been confirmed dead for longer than `departure_timeout`
+    /// milliseconds, or that announced they departed that long ago, leaving a tombstone in their
+    /// place. We never remove ourselves, or a permanent member that has not departed. Tombstones
+    /// are kept for as long again, which is plenty of time for the rest of the ring to have
+    /// departed the member too.
+    ///
+    /// Returns the members that departed.
+    pub fn expire_departed(&mut self, departure_timeout: u64) -> Vec<MemberId> {
+        let now = now_millis();
+        let forgotten: Vec<MemberId> = self.departed
+            .iter()
+            .filter(|&(_id, t)| now.saturating_sub(t.departed_at) >= departure_timeout)
+            .map(|(id, _t)| id.clone())
+            .collect();
+        for member_id in forgotten.iter() {
+            self.departed.remove(member_id);
+        }
+
+        let departing: Vec<MemberId> = self.confirmed_at
+            .iter()
+            .filter(|&(id, at)| {
+                *id != self.my_id && now.saturating_sub(*at) >= departure_timeout &&
+                self.members
+                    .get(id)
+                    .map_or(false, |m| m.health == Health::Departed || !m.permanent)
+            })
+            .map(|(id, _at)| id.clone())
+            .collect();
+        for member_id in departing.iter() {
+            self.confirmed_at.remove(member_id);
+            self.gossip_versions.remove(member_id);
+            if let Some(member) = self.members.remove(member_id) {
+                warn!("Member {} has departed", member_id);
+                self.departed.insert(member_id.clone(),
+                                     Tombstone {
+                                         incarnation: member.incarnation,
+                                         departed_at: now,
+                                     });
+            }
+            if let Some(index) = self.order.iter().position(|id| id == member_id) {
+                self.order.remove(index);
+                if index < self.position {
+                    self.position = self.position - 1;
+                }
+            }
+        }
+        departing
+    }
+
+    /// Remember the newest version of the gossip protocol a member told us it speaks; one that
+    /// does not say only speaks JSON, as it would after being rolled back to an older release.
+    /// Only members in the list are remembered, until they depart.
+    pub fn speaks(&mut self, member_id: &MemberId, gossip_version: Option<u32>) {
+        if self.members.contains_key(member_id) {
+            let version = gossip_version.unwrap_or(transport::JSON_GOSSIP_VERSION as u32);
+            self.gossip_versions.insert(member_id.clone(), version);
+        }
+    }
+
+    /// The version of the gossip protocol to write to a member; JSON, until it tells us it speaks
+    /// a newer one.
+    pub fn gossip_version_for(&self, member_id: &MemberId) -> u8 {
+        transport::version_for(self.gossip_versions.get(member_id).cloned())
+    }
+
+    /// Return true if this member has departed the ring, or announced that it is departing.
+    pub fn is_departed(&self, member_id: &MemberId) -> bool {
+        self.departed.contains_key(member_id) ||
+        self.members.get(member_id).map_or(false, |m| m.health == Health::Departed)
     }
 
-    /// Selects PINGREQ_MEMBERS number of members to use as targets for a PingReq. The members are
-    /// chosen completely randomly.
-    pub fn pingreq_targets(&self, myself: &MemberId, target: &MemberId) -> Vec<Member> {
+    /// Selects `count` members to use as targets for a PingReq. The members are chosen completely
+    /// randomly.
+    pub fn pingreq_targets(&self,
+                           myself: &MemberId,
+                           target: &MemberId,
+                           count: usize)
+                           -> Vec<Member> {
         let mut rng = thread_rng();
         let mut values: Vec<&Member> = self.members
             .values()
@@ -247,12 +456,23 @@ impl MemberList {
             .collect();
         rng.shuffle(&mut values);
         let mut results: Vec<Member> = Vec::new();
-        for member in values.into_iter().take(PINGREQ_MEMBERS) {
+        for member in values.into_iter().take(count) {
             results.push(member.clone());
         }
         results
     }
 
+    /// Selects up to `count` random members, other than ourselves, that we believe are alive.
+    pub fn alive_targets(&self, myself: &MemberId, count: usize) -> Vec<Member> {
+        let mut rng = thread_rng();
+        let mut values: Vec<&Member> = self.members
+            .values()
+            .filter(|m| &m.id != myself && m.health == Health::Alive)
+            .collect();
+        rng.shuffle(&mut values);
+        values.into_iter().take(count).map(|m| m.clone()).collect()
+    }
+
     /// Return an reference to a given member, if it exists in the MemberList.
     pub fn get(&self, member_id: &MemberId) -> Option<&Member> {
         self.members.get(member_id)
@@ -269,12 +489,13 @@ impl MemberList {
         usual_suspects
     }
 
-    /// Return true if all members other than the provided id are Confirmed.
+    /// Return true if all members other than the provided id are Confirmed or Departed.
     pub fn isolated(&self, myself: &MemberId) -> bool {
         self.members.iter().fold(true, |acc, (id, m)| {
             if id == myself {
                 acc
-            } else if m.health == Health::Confirmed && acc != false {
+            } else if (m.health == Health::Confirmed || m.health == Health::Departed) &&
+                      acc != false {
                 true
             } else {
                 false
@@ -283,6 +504,12 @@ impl MemberList {
     }
 }
 
+/// The wall clock time, in milliseconds since the epoch.
+fn now_millis() -> u64 {
+    let now = time::get_time();
+    (now.sec as u64) * 1000 + (now.nsec as u64) / 1_000_000
+}
+
 impl Deref for MemberList {
     type Target = HashMap<MemberId, Member>;
 
@@ -405,11 +632,29 @@ mod test {
             assert_eq!(bobo.health, Health::Confirmed);
             assert_eq!(r, false);
         }
+
+        #[test]
+        fn update_via_equal_keeps_departed() {
+            let my_id = MemberId::new_v4();
+            let mut bobo = bobo();
+            let mut other_bobo = bobo.clone();
+            other_bobo.health = Health::Departed;
+            assert_eq!(bobo.update_via(&my_id, other_bobo), true);
+            assert_eq!(bobo.health, Health::Departed);
+
+            for health in vec![Health::Alive, Health::Suspect, Health::Confirmed] {
+                let mut tclown = bobo.clone();
+                tclown.health = health;
+                assert_eq!(bobo.update_via(&my_id, tclown), false);
+                assert_eq!(bobo.health, Health::Departed);
+            }
+        }
     }
 
     mod member_list {
         use uuid::Uuid;
         use gossip::member::{Member, MemberList, Health};
+        use gossip::transport::{GOSSIP_VERSION, JSON_GOSSIP_VERSION};
 
         fn new_member_list() -> MemberList {
             let james = Member::new(String::from("james"),
@@ -497,7 +742,7 @@ mod test {
             ml.insert(dead_member);
 
             // With fewer than 6 members (excluding the target), use them all
-            assert_eq!(ml.pingreq_targets(&my_id, &dead_id).len(), 5);
+            assert_eq!(ml.pingreq_targets(&my_id, &dead_id, 5).len(), 5);
 
             let newbie = Member::new(String::from("newbie.foo.com"),
                                      String::from("192.168.1.1"),
@@ -505,7 +750,7 @@ mod test {
                                      false);
             ml.insert(newbie);
             // With 5 members, use them all
-            assert_eq!(ml.pingreq_targets(&my_id, &dead_id).len(), 5);
+            assert_eq!(ml.pingreq_targets(&my_id, &dead_id, 5).len(), 5);
 
             let oldie = Member::new(String::from("oldie.foo.com"),
                                     String::from("192.168.1.1"),
@@ -513,7 +758,7 @@ mod test {
                                     false);
             ml.insert(oldie);
             // With more than 5 members, use them all
-            assert_eq!(ml.pingreq_targets(&my_id, &dead_id).len(), 5);
+            assert_eq!(ml.pingreq_targets(&my_id, &dead_id, 5).len(), 5);
         }
 
         #[test]
@@ -552,5 +797,103 @@ mod test {
             // One member who is not us is alive, so we are not isolated
             assert_eq!(ml.isolated(&my_id), false);
         }
+
+        #[test]
+        fn expire_departed() {
+            let mut ml = new_member_list();
+            let my_id = ml.my_id.clone();
+            let dead = Member::new(String::from("dead.foo.com"),
+                                   String::from("192.168.1.2"),
+                                   String::from("192.168.1.2:4312"),
+                                   false);
+            let dead_id = dead.id.clone();
+            let mut permanent = Member::new(String::from("permanent.foo.com"),
+                                            String::from("192.168.1.3"),
+                                            String::from("192.168.1.3:4312"),
+                                            true);
+            let permanent_id = permanent.id.clone();
+            ml.insert(dead.clone());
+            permanent.health = Health::Confirmed;
+            ml.insert(permanent);
+
+            // Nobody departs before they have been confirmed for the whole timeout
+            ml.confirm(&dead_id);
+            assert!(ml.expire_departed(60000).is_empty());
+
+            // Permanent members and ourselves never depart
+            ml.confirm(&my_id);
+            assert_eq!(ml.expire_departed(0), vec![dead_id.clone()]);
+            assert!(ml.get(&dead_id).is_none());
+            assert!(ml.get(&permanent_id).is_some());
+            assert!(ml.get(&my_id).is_some());
+            assert_eq!(ml.order.len(), 2);
+
+            // Stale gossip cannot bring the member back, but a later incarnation can
+            assert_eq!(ml.process(dead.clone()), false);
+            assert!(ml.is_departed(&dead_id));
+            let mut rejoined = dead;
+            rejoined.incarnation.increment();
+            assert_eq!(ml.process(rejoined), true);
+            assert!(!ml.is_departed(&dead_id));
+            assert!(ml.is_alive(&dead_id));
+        }
+
+        #[test]
+        fn depart() {
+            let mut ml = new_member_list();
+            let my_id = ml.my_id.clone();
+            let me = ml.depart();
+            assert_eq!(me.health, Health::Departed);
+            assert_eq!(*me.incarnation, 1);
+            assert!(ml.is_departed(&my_id));
+
+            // Nothing we knew about ourselves beforehand can undo it
+            let mut stale = me.clone();
+            stale.health = Health::Alive;
+            stale.incarnation = me.incarnation.clone();
+            assert_eq!(ml.process(stale), false);
+            ml.suspect(&my_id);
+            ml.confirm(&my_id);
+            ml.alive(&my_id);
+            assert_eq!(ml.get(&my_id).unwrap().health, Health::Departed);
+        }
+
+        #[test]
+        fn suspect_records_who_suspected_the_member() {
+            let mut ml = new_member_list();
+            let my_id = ml.my_id.clone();
+            let bobo = Member::new("bobo".to_string(),
+                                   "10.0.0.9".to_string(),
+                                   "10.0.0.9:9634".to_string(),
+                                   false);
+            let bobo_id = bobo.id.clone();
+            ml.insert(bobo);
+            ml.suspect(&bobo_id);
+            assert_eq!(ml.get(&bobo_id).unwrap().suspected_by, Some(my_id));
+            ml.alive(&bobo_id);
+            assert_eq!(ml.get(&bobo_id).unwrap().suspected_by, None);
+        }
+
+        #[test]
+        fn gossip_version_for_members_that_told_us() {
+            let mut ml = new_member_list();
+            let bobo = Member::new("bobo".to_string(),
+                                   "10.0.0.9".to_string(),
+                                   "10.0.0.9:9634".to_string(),
+                                   false);
+            let bobo_id = bobo.id.clone();
+            ml.insert(bobo);
+            assert_eq!(ml.gossip_version_for(&bobo_id), JSON_GOSSIP_VERSION);
+            ml.speaks(&bobo_id, Some(GOSSIP_VERSION as u32));
+            assert_eq!(ml.gossip_version_for(&bobo_id), GOSSIP_VERSION);
+            // Rolled back to a release that does not say
+            ml.speaks(&bobo_id, None);
+            assert_eq!(ml.gossip_version_for(&bobo_id), JSON_GOSSIP_VERSION);
+
+            // Strangers are not remembered
+            let stranger = Uuid::new_v4();
+            ml.speaks(&stranger, Some(GOSSIP_VERSION as u32));
+            assert_eq!(ml.gossip_version_for(&stranger), JSON_GOSSIP_VERSION);
+        }
     }
 }
@@ -22,3 +22,6 @@ pub mod member;
 pub mod server;
 pub mod client;
 pub mod detector;
+pub mod transport;
+pub mod wire;
+pub mod ring_keys;
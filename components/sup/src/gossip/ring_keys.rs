@@ -0,0 +1,325 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The revisions of the ring key we gossip with.
+//!
+//! We encrypt every message with one revision, the sending revision, and decrypt messages
+//! encrypted with any revision of the ring's key we have not retired. A revision we have not
+//! loaded yet is read from the key cache when a message first needs it, and kept from then on,
+//! so installing a new revision on a member is enough for it to read messages encrypted with
+//! that revision.
+//!
+//! At start, we send with the revision we were given: the newest in the key cache for `--ring`,
+//! or the one in `HAB_RING_KEY`. Rolling the ring onto a new
+//! revision without downtime takes three steps:
+//!
+//! 1. Install the new revision in the key cache of every member
+//! 2. Gossip a `KeyRoll` rumor saying to send with the new revision
+//! 3. Once every member sends with it, gossip a `KeyRoll` rumor that also retires the revisions
+//!    before it, so members refuse messages encrypted with them
+//!
+//! `hab ring key roll` takes the last two steps, once it has checked every member can read
+//! messages encrypted with the new revision.
+
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use hcore::crypto::SymKey;
+use hcore::crypto::keys::parse_name_with_rev;
+use sup_protocol::gossip as proto;
+
+use error::{Error, Result};
+use gossip::wire;
+
+static LOGKEY: &'static str = "RK";
+
+/// The revisions of a ring key we accept, and the one we send with.
+#[derive(Clone)]
+pub struct RingKeys {
+    /// The name of the ring key; `None` when we gossip in the clear
+    name: Option<String>,
+    /// The revisions we have loaded, newest first
+    keys: Vec<SymKey>,
+    /// The revisions we have read from the key cache since, shared between our clones
+    loaded: Arc<Mutex<Vec<SymKey>>>,
+    /// The revision we encrypt with
+    sending: Option<String>,
+    /// We refuse revisions older than this one
+    retired_before: Option<String>,
+    /// Where we look for revisions we have not loaded
+    cache: PathBuf,
+    /// The latest roll we took part in
+    roll: Option<KeyRoll>,
+}
+
+impl RingKeys {
+    /// Gossip in the clear.
+    pub fn none() -> RingKeys {
+        RingKeys {
+            name: None,
+            keys: Vec::new(),
+            loaded: Arc::new(Mutex::new(Vec::new())),
+            sending: None,
+            retired_before: None,
+            cache: PathBuf::new(),
+            roll: None,
+        }
+    }
+
+    /// Load every revision of the ring key `name` in the key cache, sending with the newest. Use
+    /// `send_with` to send with another.
+    ///
+    /// # Failures
+    ///
+    /// * There is no revision of the key in the cache
+    pub fn load(name: &str, cache: &Path) -> Result<RingKeys> {
+        let keys = try!(SymKey::get_pairs_for(name, cache));
+        if keys.is_empty() {
+            return Err(sup_error!(Error::KeyNotFound(name.to_string())));
+        }
+        Ok(RingKeys {
+            name: Some(name.to_string()),
+            sending: Some(keys[0].rev.clone()),
+            keys: keys,
+            loaded: Arc::new(Mutex::new(Vec::new())),
+            retired_before: None,
+            cache: cache.to_path_buf(),
+            roll: None,
+        })
+    }
+
+    /// The key we encrypt with, if we encrypt at all.
+    pub fn sending(&self) -> Option<&SymKey> {
+        match self.sending {
+            Some(ref rev) => self.keys.iter().find(|k| &k.rev == rev),
+            None => None,
+        }
+    }
+
+    /// Whether we encrypt our messages.
+    pub fn is_encrypted(&self) -> bool {
+        self.name.is_some()
+    }
+
+    /// The key for a revision we accept, by its name with revision. Revisions we have not loaded
+    /// are read from the key cache, once.
+    pub fn get(&self, name_with_rev: &str) -> Option<SymKey> {
+        if let Some(key) = self.keys.iter().find(|k| k.name_with_rev() == name_with_rev) {
+            return Some(key.clone());
+        }
+        let rev = match parse_name_with_rev(name_with_rev) {
+            Ok((ref name, ref rev)) if self.name.as_ref() == Some(name) => rev.clone(),
+            _ => return None,
+        };
+        if self.is_retired(&rev) {
+            debug!("Refusing retired ring key {}", name_with_rev);
+            return None;
+        }
+        let mut loaded = self.loaded.lock().unwrap();
+        if let Some(key) = loaded.iter().find(|k| k.rev == rev) {
+            return Some(key.clone());
+        }
+        match SymKey::get_pair_for(name_with_rev, &self.cache) {
+            Ok(key) => {
+                loaded.push(key.clone());
+                Some(key)
+            }
+            Err(e) => {
+                debug!("Cannot load ring key {}: {}", name_with_rev, e);
+                None
+            }
+        }
+    }
+
+    /// The names with revision of the keys we have loaded, newest first.
+    pub fn revisions(&self) -> Vec<String> {
+        self.keys.iter().map(|k| k.name_with_rev()).collect()
+    }
+
+    /// Send with another revision of the ring key we accept.
+    ///
+    /// # Failures
+    ///
+    /// * We do not have the revision, or it is retired
+    pub fn send_with(&mut self, rev: &str) -> Result<()> {
+        let name_with_rev = match self.name {
+            Some(ref name) => format!("{}-{}", name, rev),
+            None => return Err(sup_error!(Error::KeyNotFound(rev.to_string()))),
+        };
+        let key = match self.get(&name_with_rev) {
+            Some(key) => key,
+            None => return Err(sup_error!(Error::KeyNotFound(name_with_rev))),
+        };
+        if !self.keys.iter().any(|k| k.rev == key.rev) {
+            self.keys.push(key);
+            self.keys.sort_by(|a, b| b.rev.cmp(&a.rev));
+        }
+        self.sending = Some(rev.to_string());
+        Ok(())
+    }
+
+    /// Take part in a roll of the ring onto a revision of its key. Returns true if we had not
+    /// heard of the roll, and took part; false if it is for another ring, or older news.
+    ///
+    /// # Failures
+    ///
+    /// * We do not have the revision the ring rolls onto
+    pub fn roll(&mut self, roll: &KeyRoll) -> Result<bool> {
+        if self.name.as_ref() != Some(&roll.ring) {
+            return Ok(false);
+        }
+        if let Some(ref current) = self.roll {
+            if !roll.is_newer_than(current) {
+                return Ok(false);
+            }
+        }
+        try!(self.send_with(&roll.revision));
+        if roll.retire {
+            self.keys.retain(|k| k.rev >= roll.revision);
+            self.loaded.lock().unwrap().retain(|k| k.rev >= roll.revision);
+            self.retired_before = Some(roll.revision.clone());
+        }
+        self.roll = Some(roll.clone());
+        Ok(true)
+    }
+
+    fn is_retired(&self, rev: &str) -> bool {
+        match self.retired_before {
+            Some(ref retired_before) => rev < &retired_before[..],
+            None => false,
+        }
+    }
+}
+
+/// A rumor that the ring sends with a revision of its key, and perhaps that it has retired the
+/// revisions before it. A later revision is newer news; so is retiring, at the same revision.
+#[derive(Debug, Clone, PartialEq, Eq, RustcEncodable, RustcDecodable)]
+pub struct KeyRoll {
+    /// The name of the ring key
+    pub ring: String,
+    /// The revision to send with
+    pub revision: String,
+    /// Whether the revisions before it are retired
+    pub retire: bool,
+}
+
+impl KeyRoll {
+    pub fn new(ring: String, revision: String, retire: bool) -> KeyRoll {
+        KeyRoll {
+            ring: ring,
+            revision: revision,
+            retire: retire,
+        }
+    }
+
+    pub fn is_newer_than(&self, other: &KeyRoll) -> bool {
+        (&self.revision, self.retire) > (&other.revision, other.retire)
+    }
+
+    /// The roll as the `KeyRoll` message of the gossip wire format.
+    pub fn to_proto(&self) -> proto::KeyRoll {
+        let mut msg = proto::KeyRoll::new();
+        msg.set_ring(self.ring.clone());
+        msg.set_revision(self.revision.clone());
+        msg.set_retire(self.retire);
+        msg
+    }
+
+    /// The roll a `KeyRoll` message of the gossip wire format describes.
+    ///
+    /// # Failures
+    ///
+    /// * A required field is missing
+    pub fn from_proto(mut msg: proto::KeyRoll) -> Result<KeyRoll> {
+        try!(wire::initialized(&msg));
+        Ok(KeyRoll {
+            ring: msg.take_ring(),
+            revision: msg.take_revision(),
+            retire: msg.get_retire(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+    use std::thread;
+    use std::time::Duration;
+
+    use hcore::crypto::SymKey;
+    use protobuf::{self, Message};
+    use tempdir::TempDir;
+
+    use gossip::ring_keys::{KeyRoll, RingKeys};
+
+    /// Generate a revision of the ring key newer than any in the cache; revisions are timestamps
+    /// to the second.
+    fn next_revision(cache: &TempDir) -> SymKey {
+        thread::sleep(Duration::from_millis(1100));
+        SymKey::generate_pair_for_ring("beyonce", cache.path()).unwrap()
+    }
+
+    #[test]
+    fn sends_with_the_newest_and_accepts_every_revision() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let old = SymKey::generate_pair_for_ring("beyonce", cache.path()).unwrap();
+        let new = next_revision(&cache);
+        let keys = RingKeys::load("beyonce", cache.path()).unwrap();
+        assert_eq!(keys.sending().unwrap().rev, new.rev);
+        assert!(keys.get(&old.name_with_rev()).is_some());
+        assert!(keys.get("jayz-20160504220722").is_none());
+    }
+
+    #[test]
+    fn reads_revisions_installed_later_from_the_cache() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let old = SymKey::generate_pair_for_ring("beyonce", cache.path()).unwrap();
+        let keys = RingKeys::load("beyonce", cache.path()).unwrap();
+        let new = next_revision(&cache);
+        assert_eq!(keys.sending().unwrap().rev, old.rev);
+        assert!(keys.get(&new.name_with_rev()).is_some());
+        // Once read, the revision no longer needs the cache
+        fs::remove_dir_all(cache.path()).unwrap();
+        assert!(keys.clone().get(&new.name_with_rev()).is_some());
+    }
+
+    #[test]
+    fn rolls_onto_a_revision_then_retires_the_older_ones() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let old = SymKey::generate_pair_for_ring("beyonce", cache.path()).unwrap();
+        let new = next_revision(&cache);
+        let mut keys = RingKeys::load("beyonce", cache.path()).unwrap();
+        keys.send_with(&old.rev).unwrap();
+
+        let roll = KeyRoll::new("beyonce".to_string(), new.rev.clone(), false);
+        assert!(keys.roll(&roll).unwrap());
+        assert!(!keys.roll(&roll).unwrap());
+        assert_eq!(keys.sending().unwrap().rev, new.rev);
+        assert!(keys.get(&old.name_with_rev()).is_some());
+
+        // The retired revision stays in the cache, but we no longer read it
+        let retire = KeyRoll::new("beyonce".to_string(), new.rev.clone(), true);
+        assert!(keys.roll(&retire).unwrap());
+        assert!(keys.get(&old.name_with_rev()).is_none());
+        assert_eq!(keys.revisions(), vec![new.name_with_rev()]);
+    }
+
+    #[test]
+    fn round_trips_through_the_wire_format() {
+        let roll = KeyRoll::new("beyonce".to_string(), "20160504220722".to_string(), true);
+        let bytes = roll.to_proto().write_to_bytes().unwrap();
+        assert_eq!(KeyRoll::from_proto(protobuf::parse_from_bytes(&bytes).unwrap()).unwrap(),
+                   roll);
+    }
+}
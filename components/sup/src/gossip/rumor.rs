@@ -30,6 +30,8 @@ use election::Election;
 pub const COLD_AFTER: usize = 3;
 
 use gossip::member::{Member, MemberId};
+use gossip::ring_keys::KeyRoll;
+use gossip::transport::GOSSIP_VERSION;
 
 /// A Peer is a representation of a member; it tracks how to contact the member, and whether this
 /// request is actually being proxied during a PingReq operation.
@@ -39,27 +41,33 @@ pub struct Peer {
     pub listening_on: String,
     pub proxy_through: Option<String>,
     pub proxy_to: Option<String>,
+    /// The newest version of the gossip protocol the member speaks; members that only speak
+    /// version 1 do not say
+    pub gossip_version: Option<u32>,
 }
 
 impl Peer {
-    /// Create a new peer.
+    /// Create a new peer, which speaks our version of the gossip protocol.
     pub fn new(member_id: MemberId, listening_on: String) -> Peer {
         Peer {
             member_id: member_id,
             listening_on: listening_on,
             proxy_through: None,
             proxy_to: None,
+            gossip_version: Some(GOSSIP_VERSION as u32),
         }
     }
 }
 
-/// The SWIM Protocol.
+/// The SWIM Protocol. `PushPull` is only ever sent over a push-pull stream, where it carries every
+/// rumor the sender knows.
 #[derive(Debug, RustcDecodable, RustcEncodable)]
 pub enum Protocol {
     Ping(Peer, RumorList),
     Ack(Peer, RumorList),
     PingReq(Peer, RumorList),
     Inject(RumorList),
+    PushPull(Peer, RumorList),
 }
 
 /// Rumors contain Messages as their payload, which are then processed by the correct internal
@@ -70,6 +78,7 @@ pub enum Message {
     CensusEntry(CensusEntry),
     Election(Election),
     GossipFile(GossipFile),
+    KeyRoll(KeyRoll),
     Blank,
 }
 
@@ -116,6 +125,14 @@ impl Rumor {
         }
     }
 
+    /// Create a new rumor with a `Message::KeyRoll` payload.
+    pub fn key_roll(roll: KeyRoll) -> Rumor {
+        Rumor {
+            id: Uuid::new_v4(),
+            payload: Message::KeyRoll(roll),
+        }
+    }
+
     /// Create a new rumor with a 'Blank' payload.
     pub fn blank() -> Rumor {
         Rumor {
@@ -238,6 +255,52 @@ impl RumorList {
         hot_rumors
     }
 
+    /// Get the hot rumors for a member, hottest first, that fit in a message of `max` bytes;
+    /// `size` gives the size of the message holding some rumors. Each rumor adds about its own
+    /// size to the message, so we measure each once rather than the whole message as it grows,
+    /// and skip any that would take the message past `max`.
+    pub fn hot_rumors_within<F>(&self, member_id: &MemberId, max: usize, size: F) -> RumorList
+        where F: Fn(&RumorList) -> usize
+    {
+        let mut hot_rumors: Vec<(usize, &Rumor)> = self.rumors
+            .values()
+            .map(|rumor| (self.heat_for(member_id, &rumor.id), rumor))
+            .filter(|&(heat, _rumor)| heat <= COLD_AFTER)
+            .collect();
+        hot_rumors.sort_by_key(|&(heat, _rumor)| heat);
+        let mut packed = RumorList::new();
+        let mut packed_ids = Vec::new();
+        let empty = size(&packed);
+        let mut total = empty;
+        for (_heat, rumor) in hot_rumors.into_iter() {
+            let mut alone = RumorList::new();
+            alone.add_rumor(rumor.clone());
+            let added = size(&alone).saturating_sub(empty);
+            if total.saturating_add(added) <= max {
+                packed.add_rumor(rumor.clone());
+                packed_ids.push(rumor.id);
+                total += added;
+            }
+        }
+        // The sizes may not add up exactly, such as when the message is encrypted; if we were
+        // off, we leave out the coldest rumors we packed until the message fits
+        while size(&packed) > max {
+            match packed_ids.pop() {
+                Some(id) => packed.remove_rumor(&id),
+                None => break,
+            }
+        }
+        packed
+    }
+
+    /// Get every rumor we know, without our record of who we shared them with.
+    pub fn without_heat(&self) -> RumorList {
+        RumorList {
+            rumors: self.rumors.clone(),
+            heat: HashMap::new(),
+        }
+    }
+
     /// Return the heat for a given member and rumor.
     pub fn heat_for(&self, member_id: &MemberId, rumor_id: &RumorId) -> usize {
         if self.heat.contains_key(member_id) {
@@ -288,6 +351,18 @@ impl RumorList {
         }
     }
 
+    /// Remove every rumor about rolling the ring key; only the latest roll matters.
+    pub fn prune_key_rolls(&mut self) {
+        let mut prune_list: Vec<RumorId> = Vec::new();
+        for (rid, rumor) in self.rumors.iter() {
+            if let Message::KeyRoll(_) = rumor.payload {
+                prune_list.push(rid.clone());
+            }
+        }
+        for rid in prune_list.iter() {
+            self.rumors.remove(&rid);
+        }
+    }
 
     pub fn remove_rumor(&mut self, rumor_id: &RumorId) {
         self.rumors.remove(rumor_id);
@@ -342,5 +417,27 @@ mod test {
             let fake_member = MemberId::new_v4();
             assert_eq!(0, rl.heat_for(&fake_member, &rumor_id));
         }
+
+        #[test]
+        fn hot_rumors_within_packs_the_hottest_first() {
+            let mut rl = RumorList::new();
+            let member_id = MemberId::new_v4();
+            let hot = Rumor::blank();
+            let warm = Rumor::blank();
+            let cold = Rumor::blank();
+            let (hot_id, warm_id, cold_id) = (hot.id, warm.id, cold.id);
+            rl.add_rumor(hot);
+            rl.add_rumor(warm);
+            rl.add_rumor(cold);
+            rl.increment_heat_for(&member_id, &warm_id);
+            for _ in 0..3 {
+                rl.increment_heat_for(&member_id, &cold_id);
+            }
+
+            let packed = rl.hot_rumors_within(&member_id, 2, |rumors| rumors.len());
+            assert_eq!(packed.len(), 2);
+            assert!(packed.contains_key(&hot_id));
+            assert!(packed.contains_key(&warm_id));
+        }
     }
 }
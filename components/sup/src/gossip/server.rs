@@ -20,38 +20,56 @@
 //! * An inbound listener, which receives SWIM Protocol messages.
 //! * An outbound distributor, which initiates outbound pings to members
 //! * A failure detector, which tracks outbound connections and times them out
+//!
+//! When we gossip over UDP, there is also a push-pull listener, and a thread that periodically
+//! swaps every rumor we know with another member; see the transport module.
 
 use threadpool::ThreadPool;
 
 use std::thread;
-use std::ops::Deref;
 use std::time::Duration;
-use std::sync::{Arc, RwLock};
-use std::net;
+use std::sync::{mpsc, Arc, RwLock};
+use std::net::{self, TcpListener, TcpStream, UdpSocket};
 
 use common::gossip_file::GossipFileList;
-use hcore::crypto::{default_cache_key_path, SymKey};
+use hcore::crypto::default_cache_key_path;
+use hcore::crypto::keys::parse_name_with_rev;
 use hcore::service::ServiceGroup;
+use time::{self, SteadyTime};
 use utp::{UtpListener, UtpSocket};
 
 use gossip::client::Client;
-use gossip::member::{Member, MemberList, Health};
+use gossip::member::{Member, MemberId, MemberList, Health};
+use gossip::ring_keys::RingKeys;
 use gossip::rumor::{Peer, Protocol, Rumor, RumorList, Message};
-use gossip::detector::Detector;
+use gossip::detector::{Detector, Timing};
+use gossip::transport::{self, Transport, TransportConfig, JSON_GOSSIP_VERSION, MAX_DATAGRAM};
 use election::ElectionList;
 use census::{Census, CensusEntry, CensusList};
-use error::Result;
+use error::{Error, Result};
 use util;
 
 static LOGKEY: &'static str = "GS";
-/// How often do we send an outbound request, in milliseconds
-static OUTBOUND_INTERVAL: u64 = 200;
 /// How many outbound threads do we allow?
 static OUTBOUND_MAX_THREADS: usize = 5;
 /// How many inbound threads do we allow?
 static INBOUND_MAX_THREADS: usize = 5;
 /// The default port for the Gossip protocol
 pub static GOSSIP_DEFAULT_PORT: usize = 9634;
+/// How long we spend telling the ring we are departing before we give up on the members that
+/// have not answered, in milliseconds
+const DEPART_TIMEOUT_MS: i64 = 2000;
+
+/// The census, election and gossip file lists for one of the services this supervisor runs.
+#[derive(Clone)]
+pub struct ServiceLists {
+    /// Our list of censuses, with this service's census as the local one
+    pub census_list: Arc<RwLock<CensusList>>,
+    /// The list of elections, with this service's election as our own
+    pub election_list: Arc<RwLock<ElectionList>>,
+    /// The list of gossip files for this service's group
+    pub gossip_file_list: Arc<RwLock<GossipFileList>>,
+}
 
 /// A gossip server
 pub struct Server {
@@ -61,33 +79,32 @@ pub struct Server {
     pub member_list: Arc<RwLock<MemberList>>,
     /// Our list of rumors to share
     pub rumor_list: Arc<RwLock<RumorList>>,
-    /// Our list of censuses
-    pub census_list: Arc<RwLock<CensusList>>,
     /// The failure detector
     pub detector: Arc<RwLock<Detector>>,
-    /// The list of elections
-    pub election_list: Arc<RwLock<ElectionList>>,
-    /// The list of gossip files
-    pub gossip_file_list: Arc<RwLock<GossipFileList>>,
+    /// The lists of every service we run; rumors we receive are processed for each of them
+    pub services: Arc<RwLock<Vec<ServiceLists>>>,
     /// Our 'peer' entry, used to generate SWIM protocol messages.
     pub peer: Peer,
-    /// An optional ring key used to encrypt messages with peers
-    ring_key: Arc<Option<SymKey>>,
+    /// The revisions of the ring key we encrypt messages with peers with, if any
+    ring_keys: Arc<RwLock<RingKeys>>,
+    /// How we send messages to peers
+    transport: Transport,
 }
 
 impl Server {
-    /// Creates a new Server. Creates our own entry in the census and membership lists, and writes
-    /// a rumor that this server is alive.
+    /// Creates a new Server. Creates our own entry in the membership list, and writes a rumor
+    /// that this server is alive. Services are added with `add_service`.
+    ///
+    /// # Errors
+    ///
+    /// * We gossip over UDP, and cannot bind the socket
     pub fn new(listen_ip: String,
                listen_port: u16,
                permanent: bool,
                ring_name_with_rev: Option<String>,
-               service: String,
-               group: String,
-               organization: Option<String>,
-               exposes: Option<Vec<String>>,
-               port: Option<String>)
-               -> Server {
+               timing: Timing,
+               transport: &TransportConfig)
+               -> Result<Server> {
 
         let hostname = util::sys::hostname().unwrap_or(String::from("unknown"));
         let listen = format!("{}:{}", listen_ip, listen_port);
@@ -95,36 +112,32 @@ impl Server {
         let peer_listen2 = peer_listen.clone();
 
         let member = Member::new(hostname, listen_ip, peer_listen2, permanent);
-
-        let service_group = format!("{}.{}", service, group);
-        let mut ce = CensusEntry::new(service.clone(), group.clone(), member.id.clone());
-        ce.exposes = exposes;
-        ce.port = port;
         let my_id = member.id.clone();
-        let leader_id = member.id.clone();
         outputln!("Supervisor {}", member);
-        outputln!("Census {}", ce);
 
-        let census_list = CensusList::new(Census::new(ce.clone()));
-
-        let ring_key = match ring_name_with_rev {
-            Some(rnwr) => Some(SymKey::get_pair_for(&rnwr, &default_cache_key_path(None)).unwrap()),
-            None => None,
+        // We accept every revision of the ring key we have, and send with the one we were given
+        // until the ring rolls onto another
+        let ring_keys = match ring_name_with_rev {
+            Some(rnwr) => {
+                let (ring, rev) = try!(parse_name_with_rev(&rnwr));
+                let mut ring_keys = try!(RingKeys::load(&ring, &default_cache_key_path(None)));
+                try!(ring_keys.send_with(&rev));
+                ring_keys
+            }
+            None => RingKeys::none(),
         };
 
+        let transport = try!(Transport::bind(transport, &listen));
+
         let server = Server {
             listen: listen,
             member_list: Arc::new(RwLock::new(MemberList::new(member.clone()))),
             rumor_list: Arc::new(RwLock::new(RumorList::new())),
-            census_list: Arc::new(RwLock::new(census_list)),
             peer: Peer::new(my_id, peer_listen),
-            detector: Arc::new(RwLock::new(Detector::new())),
-            election_list: Arc::new(RwLock::new(ElectionList::new(service_group, leader_id))),
-            gossip_file_list:
-                Arc::new(RwLock::new(GossipFileList::new(ServiceGroup::new(service,
-                                                                           group,
-                                                                           organization)))),
-            ring_key: Arc::new(ring_key),
+            detector: Arc::new(RwLock::new(Detector::with_timing(timing))),
+            services: Arc::new(RwLock::new(Vec::new())),
+            ring_keys: Arc::new(RwLock::new(ring_keys)),
+            transport: transport,
         };
 
         // Write our Alive Rumor
@@ -134,62 +147,251 @@ impl Server {
             rl.add_rumor(rumor);
         }
 
+        Ok(server)
+    }
+
+    /// Adds a service we run to the server. Creates our entry in its census, and writes a rumor
+    /// for it.
+    ///
+    /// Our services never hear about one another through gossip, so each new service starts with
+    /// the censuses we already know, and our other services are given its census entry.
+    pub fn add_service(&self,
+                       service: String,
+                       group: String,
+                       organization: Option<String>,
+                       exposes: Option<Vec<String>>,
+                       port: Option<String>)
+                       -> ServiceLists {
+        let my_id = self.peer.member_id.clone();
+        let service_group = format!("{}.{}", service, group);
+        let mut ce = CensusEntry::new(service.clone(), group.clone(), my_id.clone());
+        ce.exposes = exposes;
+        ce.port = port;
+        outputln!("Census {}", ce);
+
+        let mut census_list = CensusList::new(Census::new(ce.clone()));
+        let mut services = self.services.write().unwrap();
+        if let Some(first) = services.first() {
+            let cl = first.census_list.read().unwrap();
+            for (_service_group, census) in cl.iter() {
+                for (_id, census_entry) in census.iter() {
+                    census_list.insert_entry(census_entry.clone());
+                }
+            }
+        }
+        for other in services.iter() {
+            let mut cl = other.census_list.write().unwrap();
+            cl.insert_entry(ce.clone());
+        }
+
+        let lists = ServiceLists {
+            census_list: Arc::new(RwLock::new(census_list)),
+            election_list: Arc::new(RwLock::new(ElectionList::new(service_group, my_id))),
+            gossip_file_list:
+                Arc::new(RwLock::new(GossipFileList::new(ServiceGroup::new(service,
+                                                                           group,
+                                                                           organization)))),
+        };
+        services.push(lists.clone());
+
         // Write our Census Entry Rumor
         {
             let rumor = Rumor::census_entry(ce);
-            let mut rl = server.rumor_list.write().unwrap();
+            let mut rl = self.rumor_list.write().unwrap();
             rl.add_rumor(rumor);
         }
 
-        server
+        lists
     }
 
-    /// Starts the inbound gossip listener.
+    /// Shares a change to one of our own census entries: writes its rumor, and hands it to our
+    /// other services.
+    pub fn publish_census_entry(&self, ce: CensusEntry) {
+        let service_group = ce.service_group();
+        {
+            let mut rl = self.rumor_list.write().unwrap();
+            rl.add_rumor(Rumor::census_entry(ce.clone()));
+        }
+        let services = self.services.read().unwrap();
+        for service in services.iter() {
+            let mut cl = service.census_list.write().unwrap();
+            if cl.local_census().service_group() != service_group {
+                cl.process(ce.clone());
+            }
+        }
+    }
+
+    /// Processes a list of rumors we generated ourselves, as though we had received them.
+    pub fn process_rumors(&self, rumors: RumorList) {
+        process_rumors(rumors,
+                       self.ring_keys.clone(),
+                       self.rumor_list.clone(),
+                       self.member_list.clone(),
+                       self.services.clone(),
+                       self.detector.clone());
+    }
+
+    /// Announces that we are leaving the ring. We mark ourselves Departed, and tell a few of the
+    /// members we believe are alive directly, rather than waiting for the next rounds of gossip;
+    /// they spread the news from there.
+    pub fn depart(&self) {
+        outputln!("Departing the gossip ring");
+        let me = {
+            let mut ml = self.member_list.write().unwrap();
+            ml.depart()
+        };
+        let rumor = Rumor::member(me);
+        {
+            let mut rl = self.rumor_list.write().unwrap();
+            rl.update_rumor(rumor.clone());
+        }
+        let mut announcement = RumorList::new();
+        announcement.add_rumor(rumor);
+
+        let fanout = self.detector.read().unwrap().timing.pingreq_fanout;
+        let targets = {
+            let ml = self.member_list.read().unwrap();
+            ml.alive_targets(&self.peer.member_id, fanout)
+        };
+        let ring_keys = self.ring_keys.read().unwrap().clone();
+        // We tell each member on a thread of its own, so an unreachable member only costs us the
+        // time we are willing to wait for all of them
+        let (tx, rx) = mpsc::channel();
+        let mut telling = 0;
+        for member in targets.into_iter() {
+            let transport = self.transport.clone();
+            let ring_keys = ring_keys.clone();
+            let gossip_version = self.member_list.read().unwrap().gossip_version_for(&member.id);
+            let announcement = announcement.clone();
+            let tx = tx.clone();
+            let spawned = thread::Builder::new()
+                .name(String::from("gossip-depart"))
+                .spawn(move || {
+                    let mut c = match Client::new(&transport,
+                                                  &member.gossip_listener[..],
+                                                  &ring_keys,
+                                                  gossip_version) {
+                        Ok(c) => c,
+                        Err(e) => {
+                            debug!("Failed to create a gossip connection for {}; not telling it \
+                                    we departed: {}",
+                                   member.id,
+                                   e);
+                            let _ = tx.send(());
+                            return;
+                        }
+                    };
+                    match c.inject(announcement) {
+                        Ok(_) => {}
+                        Err(e) => debug!("Failed to tell {} we departed: {:?}", member.id, e),
+                    }
+                    let _ = tx.send(());
+                });
+            if spawned.is_ok() {
+                telling += 1;
+            }
+        }
+        let deadline = SteadyTime::now() + time::Duration::milliseconds(DEPART_TIMEOUT_MS);
+        while telling > 0 && SteadyTime::now() < deadline {
+            while let Ok(()) = rx.try_recv() {
+                telling -= 1;
+            }
+            thread::sleep(Duration::from_millis(10));
+        }
+        if telling > 0 {
+            debug!("Gave up waiting for {} members to hear we departed", telling);
+        }
+    }
+
+    /// Starts the inbound gossip listener. When we gossip over UDP, we also start the push-pull
+    /// listener.
     ///
     /// # Errors
     ///
     /// * If we cannot bind to the listener
     pub fn start_inbound(&self) -> Result<()> {
         outputln!("Starting inbound gossip listener");
-        let key = self.ring_key.clone();
+        let transport = self.transport.clone();
+        let key = self.ring_keys.clone();
         let ml = self.member_list.clone();
         let rl = self.rumor_list.clone();
-        let cl = self.census_list.clone();
+        let services = self.services.clone();
         let my_peer = self.peer.clone();
         let detector = self.detector.clone();
-        let el = self.election_list.clone();
-        let gfl = self.gossip_file_list.clone();
-        let listener = try!(UtpListener::bind(&self.listen[..]));
-        let _t = thread::Builder::new()
-            .name("inbound".to_string())
-            .spawn(move || inbound(listener, key, my_peer, ml, rl, cl, detector, el, gfl));
+        match self.transport {
+            Transport::Utp => {
+                let listener = try!(UtpListener::bind(&self.listen[..]));
+                let _t = thread::Builder::new().name("inbound".to_string()).spawn(move || {
+                    inbound(listener, transport, key, my_peer, ml, rl, services, detector)
+                });
+            }
+            Transport::Udp(ref socket, _) => {
+                let socket = socket.clone();
+                let _t = thread::Builder::new().name("inbound".to_string()).spawn(move || {
+                    inbound_udp(socket, transport, key, my_peer, ml, rl, services, detector)
+                });
+                try!(self.start_push_pull_inbound());
+            }
+        }
         Ok(())
     }
 
-    /// Starts the outbound gossip distributor.
+    /// Starts the push-pull listener, on the TCP port with the same number as our gossip port.
+    fn start_push_pull_inbound(&self) -> Result<()> {
+        outputln!("Starting push-pull listener");
+        let key = self.ring_keys.clone();
+        let ml = self.member_list.clone();
+        let rl = self.rumor_list.clone();
+        let services = self.services.clone();
+        let my_peer = self.peer.clone();
+        let detector = self.detector.clone();
+        let listener = try!(TcpListener::bind(&self.listen[..]));
+        let _t = thread::Builder::new().name("push_pull_inbound".to_string()).spawn(move || {
+            push_pull_inbound(listener, key, my_peer, ml, rl, services, detector)
+        });
+        Ok(())
+    }
+
+    /// Starts the outbound gossip distributor. When we gossip over UDP, we also start swapping
+    /// rumors with a member every push-pull interval.
     pub fn start_outbound(&self) {
         outputln!("Starting outbound gossip distributor");
-        let key = self.ring_key.clone();
+        let transport = self.transport.clone();
+        let key = self.ring_keys.clone();
         let ml = self.member_list.clone();
         let rl = self.rumor_list.clone();
         let my_peer = self.peer.clone();
         let detector = self.detector.clone();
         let _t = thread::Builder::new()
             .name("outbound".to_string())
-            .spawn(move || outbound(key, my_peer, ml, rl, detector));
+            .spawn(move || outbound(transport, key, my_peer, ml, rl, detector));
+
+        if let Transport::Udp(..) = self.transport {
+            let key = self.ring_keys.clone();
+            let ml = self.member_list.clone();
+            let rl = self.rumor_list.clone();
+            let services = self.services.clone();
+            let my_peer = self.peer.clone();
+            let detector = self.detector.clone();
+            let _t = thread::Builder::new()
+                .name("push_pull".to_string())
+                .spawn(move || push_pull(key, my_peer, ml, rl, services, detector));
+        }
     }
 
     /// Starts the failure detector.
     pub fn start_failure_detector(&self) {
         outputln!("Starting gossip failure detector");
-        let key = self.ring_key.clone();
+        let transport = self.transport.clone();
+        let key = self.ring_keys.clone();
         let my_peer = self.peer.clone();
         let ml = self.member_list.clone();
         let rl = self.rumor_list.clone();
+        let services = self.services.clone();
         let detector = self.detector.clone();
-        let _t = thread::Builder::new()
-            .name("failure_detector".to_string())
-            .spawn(move || failure_detector(key, my_peer, ml, rl, detector));
+        let _t = thread::Builder::new().name("failure_detector".to_string()).spawn(move || {
+            failure_detector(transport, key, my_peer, ml, rl, services, detector)
+        });
     }
 
     /// Sends blocking SWIM requests to our initial gossip peers.
@@ -217,11 +419,32 @@ impl Server {
         Ok(())
     }
 
+    /// Over UDP, we join by swapping every rumor we know with each peer, since they will not fit
+    /// in a ping. We do not know which version of the gossip protocol a peer speaks until it
+    /// answers, so we join in JSON.
     fn try_peers(&self, peer_listeners: &[String]) -> bool {
         let mut initialized = false;
         for to in peer_listeners {
             outputln!("Joining gossip peer at {}", to);
-            let mut c = match Client::new(&to[..], self.ring_key.deref().as_ref()) {
+            if let Transport::Udp(..) = self.transport {
+                match push_pull_with(&to[..],
+                                     JSON_GOSSIP_VERSION,
+                                     &self.ring_keys,
+                                     &self.peer,
+                                     &self.member_list,
+                                     &self.rumor_list,
+                                     &self.services,
+                                     &self.detector) {
+                    Ok(()) => initialized = true,
+                    Err(e) => outputln!("Failed to push-pull with {:?}: {}", to, e),
+                }
+                continue;
+            }
+            let ring_keys = self.ring_keys.read().unwrap().clone();
+            let mut c = match Client::new(&self.transport,
+                                          &to[..],
+                                          &ring_keys,
+                                          JSON_GOSSIP_VERSION) {
                 Ok(c) => c,
                 Err(e) => {
                     debug!("Error creating gossip client - {:?}", e);
@@ -254,14 +477,13 @@ impl Server {
 ///
 /// New requests are handled by passing them to `receive`.
 pub fn inbound(listener: UtpListener,
-               ring_key: Arc<Option<SymKey>>,
+               transport: Transport,
+               ring_keys: Arc<RwLock<RingKeys>>,
                my_peer: Peer,
                member_list: Arc<RwLock<MemberList>>,
                rumor_list: Arc<RwLock<RumorList>>,
-               census_list: Arc<RwLock<CensusList>>,
-               detector: Arc<RwLock<Detector>>,
-               election_list: Arc<RwLock<ElectionList>>,
-               gossip_file_list: Arc<RwLock<GossipFileList>>) {
+               services: Arc<RwLock<Vec<ServiceLists>>>,
+               detector: Arc<RwLock<Detector>>) {
     let pool = ThreadPool::new(INBOUND_MAX_THREADS);
     for connection in listener.incoming() {
         loop {
@@ -281,26 +503,255 @@ pub fn inbound(listener: UtpListener,
                        pool.active_count(),
                        pool.max_count());
 
-                let key = ring_key.clone();
+                let transport = transport.clone();
+                let key = ring_keys.clone();
                 let my_peer = my_peer.clone();
                 let ml = member_list.clone();
                 let rl = rumor_list.clone();
-                let cl = census_list.clone();
+                let services = services.clone();
                 let d1 = detector.clone();
-                let el = election_list.clone();
-                let gfl = gossip_file_list.clone();
 
-                pool.execute(move || receive(socket, src, key, my_peer, ml, rl, cl, d1, el, gfl));
+                pool.execute(move || {
+                    receive(socket, src, transport, key, my_peer, ml, rl, services, d1)
+                });
             }
             _ => {}
         }
     }
 }
 
-/// Receives a message from the inbound listener.
+/// Listens for incoming UDP datagrams, and hands each to a thread in the pool. Handles at most
+/// INBOUND_MAX_THREADS concurrent messages.
 ///
-/// Tries to receive a protocol message on the socket we were passed, then handles it according to what part
-/// of the SWIM protocol we received.
+/// Messages are handled by `handle_message`, just as they are for uTP.
+pub fn inbound_udp(socket: Arc<UdpSocket>,
+                   transport: Transport,
+                   ring_keys: Arc<RwLock<RingKeys>>,
+                   my_peer: Peer,
+                   member_list: Arc<RwLock<MemberList>>,
+                   rumor_list: Arc<RwLock<RumorList>>,
+                   services: Arc<RwLock<Vec<ServiceLists>>>,
+                   detector: Arc<RwLock<Detector>>) {
+    let pool = ThreadPool::new(INBOUND_MAX_THREADS);
+    let mut buf = vec![0u8; MAX_DATAGRAM];
+    loop {
+        let (amt, src) = match socket.recv_from(&mut buf) {
+            Ok(received) => received,
+            Err(e) => {
+                debug!("Failed to receive a datagram: {}", e);
+                continue;
+            }
+        };
+        while pool.active_count() == pool.max_count() {
+            info!("{} of {} inbound threads full; delaying this round",
+                  pool.active_count(),
+                  pool.max_count());
+            thread::sleep(Duration::from_millis(10));
+        }
+        let datagram = buf[..amt].to_vec();
+
+        let transport = transport.clone();
+        let key = ring_keys.clone();
+        let my_peer = my_peer.clone();
+        let ml = member_list.clone();
+        let rl = rumor_list.clone();
+        let services = services.clone();
+        let d1 = detector.clone();
+
+        pool.execute(move || {
+            let msg = match transport::open_datagram(&datagram)
+                .and_then(|encoded| transport::decode(encoded, &key.read().unwrap())) {
+                Ok(msg) => msg,
+                Err(e) => {
+                    debug!("Failed to receive a message from {:?}: {}", src, e);
+                    return;
+                }
+            };
+            handle_message(msg, src, transport, key, my_peer, ml, rl, services, d1)
+        });
+    }
+}
+
+/// Listens for push-pull connections, and swaps rumors with each member that connects, on a
+/// thread for each. Rumors injected over TCP, which are too large for a datagram, arrive here too.
+pub fn push_pull_inbound(listener: TcpListener,
+                         ring_keys: Arc<RwLock<RingKeys>>,
+                         my_peer: Peer,
+                         member_list: Arc<RwLock<MemberList>>,
+                         rumor_list: Arc<RwLock<RumorList>>,
+                         services: Arc<RwLock<Vec<ServiceLists>>>,
+                         detector: Arc<RwLock<Detector>>) {
+    for stream in listener.incoming() {
+        match stream {
+            Ok(stream) => {
+                let ring_keys = ring_keys.clone();
+                let my_peer = my_peer.clone();
+                let member_list = member_list.clone();
+                let rumor_list = rumor_list.clone();
+                let services = services.clone();
+                let detector = detector.clone();
+                let _t = thread::Builder::new().name("push_pull".to_string()).spawn(move || {
+                    match receive_push_pull(stream,
+                                            &ring_keys,
+                                            &my_peer,
+                                            &member_list,
+                                            &rumor_list,
+                                            &services,
+                                            &detector) {
+                        Ok(()) => {}
+                        Err(e) => debug!("Failed push-pull: {}", e),
+                    }
+                });
+            }
+            Err(e) => debug!("Failed to accept a push-pull connection: {}", e),
+        }
+    }
+}
+
+/// Answers a push-pull with every rumor we know, then processes the rumors we were sent.
+fn receive_push_pull(mut stream: TcpStream,
+                     ring_keys: &Arc<RwLock<RingKeys>>,
+                     my_peer: &Peer,
+                     member_list: &Arc<RwLock<MemberList>>,
+                     rumor_list: &Arc<RwLock<RumorList>>,
+                     services: &Arc<RwLock<Vec<ServiceLists>>>,
+                     detector: &Arc<RwLock<Detector>>)
+                     -> Result<()> {
+    try!(transport::set_timeouts(&stream));
+    let keys = ring_keys.read().unwrap().clone();
+    match try!(transport::read_message(&mut stream, &keys)) {
+        Protocol::PushPull(from_peer, remote_rumor_list) => {
+            debug!("Push-pull from {:?}", from_peer);
+            heard_from(&from_peer, member_list);
+            let our_rumors = rumor_list.read().unwrap().without_heat();
+            try!(transport::write_message(&mut stream,
+                                          &Protocol::PushPull(my_peer.clone(), our_rumors),
+                                          &keys,
+                                          transport::version_for(from_peer.gossip_version)));
+            process_rumors(remote_rumor_list,
+                           ring_keys.clone(),
+                           rumor_list.clone(),
+                           member_list.clone(),
+                           services.clone(),
+                           detector.clone());
+        }
+        Protocol::Inject(remote_rumor_list) => {
+            debug!("Incoming rumor injection over TCP: {:?}", remote_rumor_list);
+            process_rumors(remote_rumor_list,
+                           ring_keys.clone(),
+                           rumor_list.clone(),
+                           member_list.clone(),
+                           services.clone(),
+                           detector.clone());
+        }
+        msg => debug!("Ignoring a message we only take over SWIM: {:?}", msg),
+    }
+    Ok(())
+}
+
+/// Every push-pull interval, swaps every rumor we know with one alive member. This is how rumors
+/// too large for a datagram spread when we gossip over UDP.
+pub fn push_pull(ring_keys: Arc<RwLock<RingKeys>>,
+                 my_peer: Peer,
+                 member_list: Arc<RwLock<MemberList>>,
+                 rumor_list: Arc<RwLock<RumorList>>,
+                 services: Arc<RwLock<Vec<ServiceLists>>>,
+                 detector: Arc<RwLock<Detector>>) {
+    let push_pull_interval = detector.read().unwrap().timing.push_pull_interval;
+    loop {
+        thread::sleep(Duration::from_millis(push_pull_interval));
+
+        let target = {
+            let ml = member_list.read().unwrap();
+            ml.alive_targets(&my_peer.member_id, 1).into_iter().next()
+        };
+        if let Some(member) = target {
+            debug!("Push-pull with {}", member.id);
+            let gossip_version = member_list.read().unwrap().gossip_version_for(&member.id);
+            match push_pull_with(&member.gossip_listener[..],
+                                 gossip_version,
+                                 &ring_keys,
+                                 &my_peer,
+                                 &member_list,
+                                 &rumor_list,
+                                 &services,
+                                 &detector) {
+                Ok(()) => {}
+                Err(e) => debug!("Failed to push-pull with {}: {}", member.id, e),
+            }
+        }
+    }
+}
+
+/// Swaps every rumor we know with the member listening on `addr`, writing in the given version of
+/// the gossip protocol, and processes the rumors it sends back.
+///
+/// # Errors
+///
+/// * We cannot reach the member, or it does not answer with its own rumors
+fn push_pull_with(addr: &str,
+                  gossip_version: u8,
+                  ring_keys: &Arc<RwLock<RingKeys>>,
+                  my_peer: &Peer,
+                  member_list: &Arc<RwLock<MemberList>>,
+                  rumor_list: &Arc<RwLock<RumorList>>,
+                  services: &Arc<RwLock<Vec<ServiceLists>>>,
+                  detector: &Arc<RwLock<Detector>>)
+                  -> Result<()> {
+    let our_rumors = rumor_list.read().unwrap().without_heat();
+    let msg = Protocol::PushPull(my_peer.clone(), our_rumors);
+    let keys = ring_keys.read().unwrap().clone();
+    match try!(transport::push_pull(addr, &msg, &keys, gossip_version)) {
+        Protocol::PushPull(from_peer, remote_rumor_list) => {
+            heard_from(&from_peer, member_list);
+            process_rumors(remote_rumor_list,
+                           ring_keys.clone(),
+                           rumor_list.clone(),
+                           member_list.clone(),
+                           services.clone(),
+                           detector.clone());
+            Ok(())
+        }
+        msg => {
+            let msg = format!("expected a push-pull in reply, got {:?}", msg);
+            Err(sup_error!(Error::InvalidGossipTransport(msg)))
+        }
+    }
+}
+
+/// Receives a message from the inbound uTP listener, and handles it.
+fn receive(socket: UtpSocket,
+           src: net::SocketAddr,
+           transport: Transport,
+           ring_keys: Arc<RwLock<RingKeys>>,
+           my_peer: Peer,
+           member_list: Arc<RwLock<MemberList>>,
+           rumor_list: Arc<RwLock<RumorList>>,
+           services: Arc<RwLock<Vec<ServiceLists>>>,
+           detector: Arc<RwLock<Detector>>) {
+    let msg = {
+        let keys = ring_keys.read().unwrap().clone();
+        let mut client = Client::from_socket(socket, &keys);
+        match client.recv_message() {
+            Ok(msg) => msg,
+            Err(e) => {
+                debug!("Failed to receive a message: {:#?} {:#?}", src, e);
+                return;
+            }
+        }
+    };
+    handle_message(msg,
+                   src,
+                   transport,
+                   ring_keys,
+                   my_peer,
+                   member_list,
+                   rumor_list,
+                   services,
+                   detector);
+}
+
+/// Handles a message we received, according to what part of the SWIM protocol it is.
 ///
 /// ## Ping(Peer, RumorList)
 /// * Respond immediately with an Ack of hot rumors for the requesting Peer, or its Proxy.
@@ -315,45 +766,40 @@ pub fn inbound(listener: UtpListener,
 /// ## PingReq(Peer, RumorList)
 /// * Create a connection to the requested Peer
 /// * Forward along the RumorList to that Peer as a Proxy Ping.
-fn receive(socket: UtpSocket,
-           src: net::SocketAddr,
-           ring_key: Arc<Option<SymKey>>,
-           my_peer: Peer,
-           member_list: Arc<RwLock<MemberList>>,
-           rumor_list: Arc<RwLock<RumorList>>,
-           census_list: Arc<RwLock<CensusList>>,
-           detector: Arc<RwLock<Detector>>,
-           election_list: Arc<RwLock<ElectionList>>,
-           gossip_file_list: Arc<RwLock<GossipFileList>>) {
-    let mut client = Client::from_socket(socket, ring_key.deref().as_ref());
-    let msg = match client.recv_message() {
-        Ok(msg) => msg,
-        Err(e) => {
-            debug!("Failed to receive a message: {:#?} {:#?}",
-                   client.socket.peer_addr(),
-                   e);
-            return;
-        }
-    };
-
+///
+/// ## PushPull(Peer, RumorList)
+/// * Only taken by the push-pull listener; ignored here.
+fn handle_message(msg: Protocol,
+                  src: net::SocketAddr,
+                  transport: Transport,
+                  ring_keys: Arc<RwLock<RingKeys>>,
+                  my_peer: Peer,
+                  member_list: Arc<RwLock<MemberList>>,
+                  rumor_list: Arc<RwLock<RumorList>>,
+                  services: Arc<RwLock<Vec<ServiceLists>>>,
+                  detector: Arc<RwLock<Detector>>) {
     debug!("#{:?} protocol {:?}", src, msg);
 
     match msg {
         Protocol::Ping(from_peer, remote_rumor_list) => {
             debug!("Ping from {:?}", from_peer);
+            heard_from(&from_peer, &member_list);
 
-            // Who are we responding to? The peer, or are we proxied through someone else?
-            let respond_to = {
+            // Who are we responding to? The peer, or are we proxied through someone else? We do
+            // not know which version of the protocol a proxy speaks, so we answer it in JSON.
+            let (respond_to, gossip_version) = {
                 if let Some(ref proxy_through) = from_peer.proxy_through {
                     debug!("Proxy ping for {:?}", from_peer);
-                    proxy_through.clone()
+                    (proxy_through.clone(), JSON_GOSSIP_VERSION)
                 } else {
-                    from_peer.listening_on.clone()
+                    (from_peer.listening_on.clone(),
+                     transport::version_for(from_peer.gossip_version))
                 }
             };
 
             // Create a client for that peer
-            let mut c = match Client::new(&respond_to[..], ring_key.deref().as_ref()) {
+            let keys = ring_keys.read().unwrap().clone();
+            let mut c = match Client::new(&transport, &respond_to[..], &keys, gossip_version) {
                 Ok(c) => c,
                 Err(e) => {
                     debug!("Failed to create a gossip client for {:?}; aborting: {}",
@@ -363,12 +809,6 @@ fn receive(socket: UtpSocket,
                 }
             };
 
-            // Generate our Ack rumors
-            let ack_rumors = {
-                let rl = rumor_list.read().unwrap();
-                rl.hot_rumors_for(&from_peer.member_id)
-            };
-
             // If we're Ack-ing through a proxy, make sure our reply is correct
             let ack_peer = if from_peer.proxy_to.is_some() {
                 let mut ack_peer = Peer::new(my_peer.member_id.clone(),
@@ -379,14 +819,23 @@ fn receive(socket: UtpSocket,
                 my_peer
             };
 
+            // Generate our Ack rumors
+            let ack_rumors = {
+                let rl = rumor_list.read().unwrap();
+                c.hot_rumors_for(&rl,
+                                 &from_peer.member_id,
+                                 |rumors| Protocol::Ack(ack_peer.clone(), rumors))
+            };
+
             // Send our Ack
             match c.ack(ack_peer, ack_rumors.clone()) {
                 Ok(_) => {}
                 Err(e) => debug!("Failed to send Ack to {:?}: {:?}", from_peer, e),
             }
 
-            // Update the heat for the rumors we shared
-            {
+            // Update the heat for the rumors we shared. Observers, and members we have not heard of
+            // yet, leave no heat behind; they get every rumor until they are in our member list.
+            if member_list.read().unwrap().get(&from_peer.member_id).is_some() {
                 let mut rl = rumor_list.write().unwrap();
                 rl.update_heat_for(&from_peer.member_id, &ack_rumors);
             }
@@ -394,18 +843,23 @@ fn receive(socket: UtpSocket,
             // Update our rumors
             debug!("Updating rumors from {:#?}", from_peer);
             process_rumors(remote_rumor_list,
+                           ring_keys,
                            rumor_list,
                            member_list,
-                           census_list,
-                           election_list,
-                           gossip_file_list);
+                           services,
+                           detector);
         }
         Protocol::Ack(mut from_peer, remote_rumor_list) => {
+            heard_from(&from_peer, &member_list);
             // If this is a proxy ack, forward the results on
             if from_peer.proxy_to.is_some() {
                 debug!("Proxy Ack for {:?}", from_peer);
                 let forward_to = from_peer.proxy_to.take().unwrap();
-                let mut c = match Client::new(&forward_to[..], ring_key.deref().as_ref()) {
+                let keys = ring_keys.read().unwrap().clone();
+                let mut c = match Client::new(&transport,
+                                              &forward_to[..],
+                                              &keys,
+                                              JSON_GOSSIP_VERSION) {
                     Ok(c) => c,
                     Err(e) => {
                         debug!("Failed to create a gossip client to forward for {:?}; aborting: \
@@ -428,15 +882,16 @@ fn receive(socket: UtpSocket,
                 // Update our rumors
                 debug!("Updating rumors via ack from {:#?} ", from_peer);
                 process_rumors(remote_rumor_list,
+                               ring_keys,
                                rumor_list,
                                member_list,
-                               census_list,
-                               election_list,
-                               gossip_file_list);
+                               services,
+                               detector);
             }
         }
         Protocol::PingReq(from_peer, remote_rumor_list) => {
             debug!("PingReq from {:?}", from_peer);
+            heard_from(&from_peer, &member_list);
             let proxy_to = match from_peer.proxy_to {
                 Some(ref proxy_to) => proxy_to.clone(),
                 None => {
@@ -444,7 +899,8 @@ fn receive(socket: UtpSocket,
                     return;
                 }
             };
-            let mut c = match Client::new(&proxy_to[..], ring_key.deref().as_ref()) {
+            let keys = ring_keys.read().unwrap().clone();
+            let mut c = match Client::new(&transport, &proxy_to[..], &keys, JSON_GOSSIP_VERSION) {
                 Ok(c) => c,
                 Err(e) => {
                     debug!("Failed to create a gossip connection for sending ping-req to {} for \
@@ -466,25 +922,48 @@ fn receive(socket: UtpSocket,
         Protocol::Inject(remote_rumor_list) => {
             debug!("Incoming rumor injection: {:?}", remote_rumor_list);
             process_rumors(remote_rumor_list,
+                           ring_keys,
                            rumor_list,
                            member_list,
-                           census_list,
-                           election_list,
-                           gossip_file_list);
+                           services,
+                           detector);
+        }
+        Protocol::PushPull(from_peer, _) => {
+            debug!("Ignoring push-pull from {:?}; it belongs on the push-pull listener",
+                   from_peer);
         }
     }
 }
 
+/// Remembers the version of the gossip protocol a peer told us its member speaks, so we can write
+/// to the member in it.
+fn heard_from(peer: &Peer, member_list: &Arc<RwLock<MemberList>>) {
+    member_list.write().unwrap().speaks(&peer.member_id, peer.gossip_version);
+}
+
+/// Processes the rumors we received, for the member list and for each of the services we run.
+///
+/// A rumor that a member is suspect counts as a suspicion of the member that first suspected it,
+/// for the failure detector; relaying the rumor does not make another. A rumor that the ring
+/// rolled onto a revision of its key changes the revision we send with.
 pub fn process_rumors(remote_rumors: RumorList,
+                      ring_keys: Arc<RwLock<RingKeys>>,
                       rumor_list: Arc<RwLock<RumorList>>,
                       member_list: Arc<RwLock<MemberList>>,
-                      census_list: Arc<RwLock<CensusList>>,
-                      election_list: Arc<RwLock<ElectionList>>,
-                      gossip_file_list: Arc<RwLock<GossipFileList>>) {
+                      services: Arc<RwLock<Vec<ServiceLists>>>,
+                      detector: Arc<RwLock<Detector>>) {
+    let services = services.read().unwrap();
     for (id, remote_rumor) in remote_rumors.rumors.into_iter() {
         match remote_rumor.payload {
             Message::Member(m) => {
                 debug!("Processing member {:#?}", m);
+                if let Some(by) = m.suspected_by {
+                    let my_id = member_list.read().unwrap().my_id;
+                    if m.health == Health::Suspect && m.id != by && m.id != my_id &&
+                       by != my_id {
+                        detector.write().unwrap().suspected_by(&m.id, by);
+                    }
+                }
                 let processed = {
                     let mut ml = member_list.write().unwrap();
                     ml.process(m)
@@ -494,6 +973,12 @@ pub fn process_rumors(remote_rumors: RumorList,
                         let ml = member_list.read().unwrap();
                         ml.get(&id).unwrap().clone()
                     };
+                    // A member that announced it departed is dropped from the census right away
+                    let my_id = member_list.read().unwrap().my_id;
+                    if member.health == Health::Departed && member.id != my_id {
+                        outputln!("Member {} has departed the ring", member.id);
+                        forget_census_entries(&member.id, &services, &rumor_list);
+                    }
 
                     // The internals of the object might have changed, but not by
                     // replacement. Hence, we don't take the rumor as given - we have to go
@@ -506,10 +991,17 @@ pub fn process_rumors(remote_rumors: RumorList,
             }
             Message::CensusEntry(ce) => {
                 debug!("Processing Census Entry {:#?}", ce);
-                let processed = {
-                    let mut cl = census_list.write().unwrap();
-                    cl.process(ce.clone())
-                };
+                if member_list.read().unwrap().is_departed(&ce.member_id) {
+                    debug!("Ignoring Census Entry for departed member {}", ce.member_id);
+                    continue;
+                }
+                let mut processed = false;
+                for service in services.iter() {
+                    let mut cl = service.census_list.write().unwrap();
+                    if cl.process(ce.clone()) {
+                        processed = true;
+                    }
+                }
                 if processed {
                     let mut rl = rumor_list.write().unwrap();
                     // If we changed, by definition we took the other side.
@@ -520,18 +1012,22 @@ pub fn process_rumors(remote_rumors: RumorList,
             // destroy the un-neccessary rumors
             Message::Election(election) => {
                 debug!("Processing Election {}", election);
-                let processed = {
-                    let mut el = election_list.write().unwrap();
-                    el.process(election.clone())
-                };
-                if processed {
+                // If the election is for one of our services, its list has the election we
+                // share; otherwise any list that took the election will do.
+                let mut elector = None;
+                for service in services.iter() {
+                    let mut el = service.election_list.write().unwrap();
+                    if el.process(election.clone()) {
+                        let ours = el.election()
+                            .map_or(false, |e| e.service_group() == election.service_group());
+                        if ours || elector.is_none() {
+                            elector = el.get(&election.service_group()).cloned();
+                        }
+                    }
+                }
+                if let Some(elector) = elector {
                     debug!("We processed Election {}", election);
 
-                    let elector = {
-                        let el = election_list.read().unwrap();
-                        el.get(&election.service_group()).unwrap().clone()
-                    };
-
                     let mut rl = rumor_list.write().unwrap();
                     rl.prune_elections_for(&elector.service_group());
                     rl.add_rumor(Rumor::election(elector));
@@ -539,36 +1035,82 @@ pub fn process_rumors(remote_rumors: RumorList,
             }
             Message::GossipFile(gossip_file) => {
                 debug!("Processing Gossip File {}", gossip_file);
-                let processed = {
-                    let mut gf = gossip_file_list.write().unwrap();
-                    gf.process(gossip_file.clone())
-                };
+                let mut processed = false;
+                for service in services.iter() {
+                    let mut gf = service.gossip_file_list.write().unwrap();
+                    if gf.process(gossip_file.clone()) {
+                        processed = true;
+                    }
+                }
                 if processed {
                     let mut rl = rumor_list.write().unwrap();
                     rl.prune_gossip_files_for(&gossip_file);
                     rl.add_rumor(Rumor::gossip_file(gossip_file));
                 }
             }
+            Message::KeyRoll(roll) => {
+                debug!("Processing Key Roll {:?}", roll);
+                let rolled = ring_keys.write().unwrap().roll(&roll);
+                match rolled {
+                    Ok(true) => {
+                        if roll.retire {
+                            outputln!("Sending with ring key {}-{}; retired the revisions \
+                                       before it",
+                                      roll.ring,
+                                      roll.revision);
+                        } else {
+                            outputln!("Sending with ring key {}-{}", roll.ring, roll.revision);
+                        }
+                        let mut rl = rumor_list.write().unwrap();
+                        rl.prune_key_rolls();
+                        rl.add_rumor(Rumor::key_roll(roll));
+                    }
+                    Ok(false) => {}
+                    Err(e) => {
+                        outputln!("Cannot roll onto ring key {}-{}: {}",
+                                  roll.ring,
+                                  roll.revision,
+                                  e)
+                    }
+                }
+            }
             Message::Blank => {}
         }
     }
 }
 
 
-/// The outbound distributor. Every OUTBOUND_INTERVAL in milliseconds, it spawns a new connection
-/// to the next member.
+/// Removes the entries of a member that has departed the ring from the census of each of our
+/// services, along with the rumors about them.
+fn forget_census_entries(member_id: &MemberId,
+                         services: &[ServiceLists],
+                         rumor_list: &Arc<RwLock<RumorList>>) {
+    let mut census_entry_ids = Vec::new();
+    for service in services.iter() {
+        let mut cl = service.census_list.write().unwrap();
+        census_entry_ids.extend(cl.remove_member(member_id));
+    }
+    let mut rl = rumor_list.write().unwrap();
+    for census_entry_id in census_entry_ids.iter() {
+        rl.remove_rumor(census_entry_id);
+    }
+}
+
+/// The outbound distributor. Every ping interval, it spawns a new connection to the next member.
 ///
 /// Like inbound, it is backed by a thread pool - if we have more than OUTBOUND_MAX_THREADS running
 /// at once, we delay the next outbound message until a thread is free.
-pub fn outbound(ring_key: Arc<Option<SymKey>>,
+pub fn outbound(transport: Transport,
+                ring_keys: Arc<RwLock<RingKeys>>,
                 my_peer: Peer,
                 member_list: Arc<RwLock<MemberList>>,
                 rumor_list: Arc<RwLock<RumorList>>,
                 detector: Arc<RwLock<Detector>>) {
     let pool = ThreadPool::new(OUTBOUND_MAX_THREADS);
+    let ping_interval = detector.read().unwrap().timing.ping_interval;
     loop {
         // Pretty chimpy, but will work for now
-        thread::sleep(Duration::from_millis(OUTBOUND_INTERVAL));
+        thread::sleep(Duration::from_millis(ping_interval));
 
         if pool.active_count() == pool.max_count() {
             info!("{} of {} outbound threads full; delaying this round",
@@ -598,12 +1140,18 @@ pub fn outbound(ring_key: Arc<Option<SymKey>>,
             continue;
         }
 
+        // Members that told us they departed are gone for good
+        if member.health == Health::Departed {
+            continue;
+        }
+
         let running_request = {
             detector.read().unwrap().exists(&member.id)
         };
 
         if !running_request {
-            let key1 = ring_key.clone();
+            let transport1 = transport.clone();
+            let key1 = ring_keys.clone();
             let rl1 = rumor_list.clone();
             let ml1 = member_list.clone();
             let mp1 = my_peer.clone();
@@ -613,7 +1161,7 @@ pub fn outbound(ring_key: Arc<Option<SymKey>>,
                    member,
                    pool.active_count(),
                    pool.max_count());
-            pool.execute(move || send_outbound(key1, mp1, member, rl1, ml1, d1));
+            pool.execute(move || send_outbound(transport1, key1, mp1, member, rl1, ml1, d1));
         } else {
             debug!("Skipping ping of {} due to already running request",
                    member.id)
@@ -622,7 +1170,8 @@ pub fn outbound(ring_key: Arc<Option<SymKey>>,
 }
 
 /// Send an outbound Ping. If we fail to send, we initiate a PingReq.
-pub fn send_outbound(ring_key: Arc<Option<SymKey>>,
+pub fn send_outbound(transport: Transport,
+                     ring_keys: Arc<RwLock<RingKeys>>,
                      my_peer: Peer,
                      member: Member,
                      rumor_list: Arc<RwLock<RumorList>>,
@@ -633,13 +1182,16 @@ pub fn send_outbound(ring_key: Arc<Option<SymKey>>,
         d.start(member.id.clone());
     }
 
-    let mut c = match Client::new(&member.gossip_listener[..], ring_key.deref().as_ref()) {
+    let keys = ring_keys.read().unwrap().clone();
+    let gossip_version = member_list.read().unwrap().gossip_version_for(&member.id);
+    let mut c = match Client::new(&transport, &member.gossip_listener[..], &keys, gossip_version) {
         Ok(c) => c,
         Err(e) => {
             debug!("Failed to create a gossip connection for {}; sending ping-req: {}",
                    member.id,
                    e);
-            send_pingreq(ring_key.clone(),
+            send_pingreq(transport.clone(),
+                         ring_keys.clone(),
                          my_peer,
                          member,
                          rumor_list,
@@ -651,7 +1203,9 @@ pub fn send_outbound(ring_key: Arc<Option<SymKey>>,
 
     let ping_rumors = {
         let rl = rumor_list.read().unwrap();
-        rl.hot_rumors_for(&member.id)
+        c.hot_rumors_for(&rl,
+                         &member.id,
+                         |rumors| Protocol::Ping(my_peer.clone(), rumors))
     };
 
     debug!("Preparing hot rumors for {:?}: {:#?}", member, ping_rumors);
@@ -664,7 +1218,8 @@ pub fn send_outbound(ring_key: Arc<Option<SymKey>>,
         Ok(_) => {}
         Err(e) => {
             debug!("Failed to ping {:?}: {:?}", my_peer, e);
-            send_pingreq(ring_key.clone(),
+            send_pingreq(transport.clone(),
+                         ring_keys.clone(),
                          my_peer,
                          member,
                          rumor_list,
@@ -682,7 +1237,8 @@ pub fn send_outbound(ring_key: Arc<Option<SymKey>>,
 
 /// Send a PingReq for a failed Ping. We pick targets from the Member List, and then send a PingReq
 /// to each of them, with our information filled in.
-pub fn send_pingreq(ring_key: Arc<Option<SymKey>>,
+pub fn send_pingreq(transport: Transport,
+                    ring_keys: Arc<RwLock<RingKeys>>,
                     my_peer: Peer,
                     member: Member,
                     rumor_list: Arc<RwLock<RumorList>>,
@@ -692,22 +1248,22 @@ pub fn send_pingreq(ring_key: Arc<Option<SymKey>>,
         detector.write().unwrap().pingreq(&member.id);
     }
 
-    let ping_rumors = {
-        let rl = rumor_list.read().unwrap();
-        rl.hot_rumors_for(&member.id)
-    };
-
+    let fanout = detector.read().unwrap().timing.pingreq_fanout;
     let pingreq_targets = {
         let ml = member_list.read().unwrap();
-        ml.pingreq_targets(&my_peer.member_id, &member.id)
+        ml.pingreq_targets(&my_peer.member_id, &member.id, fanout)
     };
 
+    let keys = ring_keys.read().unwrap().clone();
     for pingreq_member in pingreq_targets.into_iter() {
         debug!("Sending pingreq to {} through {}",
                member.id,
                pingreq_member.id);
-        let mut c = match Client::new(&pingreq_member.gossip_listener[..],
-                                      ring_key.deref().as_ref()) {
+        let gossip_version = member_list.read().unwrap().gossip_version_for(&pingreq_member.id);
+        let mut c = match Client::new(&transport,
+                                      &pingreq_member.gossip_listener[..],
+                                      &keys,
+                                      gossip_version) {
             Ok(c) => c,
             Err(e) => {
                 debug!("Failed to create a gossip connection for {}; aborting ping-req request: \
@@ -722,7 +1278,14 @@ pub fn send_pingreq(ring_key: Arc<Option<SymKey>>,
         proxy_peer.proxy_through = Some(pingreq_member.gossip_listener.clone());
         proxy_peer.proxy_to = Some(member.gossip_listener.clone());
 
-        match c.pingreq(proxy_peer, ping_rumors.clone()) {
+        let ping_rumors = {
+            let rl = rumor_list.read().unwrap();
+            c.hot_rumors_for(&rl,
+                             &member.id,
+                             |rumors| Protocol::PingReq(proxy_peer.clone(), rumors))
+        };
+
+        match c.pingreq(proxy_peer, ping_rumors) {
             Ok(_) => {}
             Err(e) => {
                 debug!("Failed to pingreq {:?}: {:?}; aborting ping-req request",
@@ -736,11 +1299,17 @@ pub fn send_pingreq(ring_key: Arc<Option<SymKey>>,
 /// The failure detector. Every 100ms, we check for any failed for confirmed timeouts within the
 /// detector. If we find a timeout, we update our rumor and the members entry. Additionally, if we
 /// mark a member as Suspect through a rumor we were passed, we set up its entry in the detector.
-pub fn failure_detector(ring_key: Arc<Option<SymKey>>,
+///
+/// Members that have been confirmed for longer than the departure timeout depart the ring; we
+/// forget them, their census entries, and every rumor about them.
+pub fn failure_detector(transport: Transport,
+                        ring_keys: Arc<RwLock<RingKeys>>,
                         my_peer: Peer,
                         member_list: Arc<RwLock<MemberList>>,
                         rumor_list: Arc<RwLock<RumorList>>,
+                        services: Arc<RwLock<Vec<ServiceLists>>>,
                         detector: Arc<RwLock<Detector>>) {
+    let departure_timeout = detector.read().unwrap().timing.departure_timeout;
     loop {
         // Get a list of all our suspected and confirmed members
         let (pingreq, failed, confirmed) = {
@@ -780,6 +1349,20 @@ pub fn failure_detector(ring_key: Arc<Option<SymKey>>,
             }
         }
 
+        // For each member confirmed for long enough, remove it from the ring
+        let departed = {
+            let mut ml = member_list.write().unwrap();
+            ml.expire_departed(departure_timeout)
+        };
+        for member_id in departed.iter() {
+            outputln!("Member {} has been removed from the ring", member_id);
+            detector.write().unwrap().departed(member_id);
+            forget_census_entries(member_id, &services.read().unwrap(), &rumor_list);
+            let mut rl = rumor_list.write().unwrap();
+            rl.remove_rumor(member_id);
+            rl.heat.remove(member_id);
+        }
+
         // Account for any suspects who come to us via rumors
         {
             let ml = member_list.read().unwrap();
@@ -800,7 +1383,8 @@ pub fn failure_detector(ring_key: Arc<Option<SymKey>>,
         for member_id in pingreq.iter() {
             let ml = member_list.read().unwrap();
             let member = ml.get(&member_id).unwrap().clone();
-            send_pingreq(ring_key.clone(),
+            send_pingreq(transport.clone(),
+                         ring_keys.clone(),
                          my_peer.clone(),
                          member,
                          rumor_list.clone(),
@@ -0,0 +1,538 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! How gossip messages travel between members.
+//!
+//! By default, members gossip over uTP, with a connection for each message, which carries every
+//! hot rumor no matter how large. They can gossip over plain UDP instead, where each message is a
+//! single datagram. We put as many rumors in a datagram as fit, hottest first; rumors that do not
+//! fit, like large gossip files, reach every member through push-pull. Every push-pull interval,
+//! and when we join the ring, we open a TCP connection to a member and swap every rumor we know
+//! with it.
+//!
+//! Datagrams and TCP frames start with the version of the gossip protocol they were written in, so
+//! a member can tell a message it does not understand from a corrupt one.
+//!
+//! Version 1 of the protocol writes messages as JSON, and version 2 in the binary format of the
+//! wire module. We read both. Supervisors that only speak version 1 cannot read the binary format,
+//! so we write JSON to a member until it tells us, in the peer of a message it sends us, that it
+//! speaks version 2. A ring can then be upgraded one member at a time. Those Supervisors also fail
+//! a whole JSON message on a rumor they do not know the shape of, so the JSON we write only holds
+//! rumors they can read.
+
+use std::cmp;
+use std::collections::HashMap;
+use std::fmt;
+use std::io::{self, Read, Write};
+use std::net::{TcpStream, UdpSocket};
+use std::str::{self, FromStr};
+use std::sync::Arc;
+use std::time::Duration;
+
+use common::gossip_frame::{self, MAX_FRAME};
+use common::wire_message::WireMessage;
+use rustc_serialize::json;
+
+use error::{Error, Result, SupError};
+use gossip::member::Health;
+use gossip::ring_keys::RingKeys;
+use gossip::rumor::{Message, Protocol, Rumor, RumorList};
+use gossip::wire;
+
+static LOGKEY: &'static str = "GT";
+
+/// The newest version of the gossip protocol we speak
+pub const GOSSIP_VERSION: u8 = 2;
+
+/// The version of the gossip protocol we write to members that have not told us they speak a
+/// newer one. Its messages are JSON, which every supervisor reads.
+pub const JSON_GOSSIP_VERSION: u8 = 1;
+
+/// The largest datagram we send over UDP, by default
+pub const DEFAULT_MAX_DATAGRAM: usize = 8192;
+
+/// The smallest datagram size we allow; any smaller and a member rumor might not fit
+pub const MIN_DATAGRAM: usize = 1024;
+
+/// The most a UDP datagram can carry
+pub const MAX_DATAGRAM: usize = 65507;
+
+/// How long we wait on a push-pull connection before giving up, in milliseconds
+pub const PUSH_PULL_TIMEOUT: u64 = 10000;
+
+/// The transports we can gossip over.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, RustcEncodable)]
+pub enum TransportKind {
+    Utp,
+    Udp,
+}
+
+impl Default for TransportKind {
+    fn default() -> TransportKind {
+        TransportKind::Utp
+    }
+}
+
+impl FromStr for TransportKind {
+    type Err = SupError;
+
+    fn from_str(value: &str) -> Result<TransportKind> {
+        match value {
+            "utp" => Ok(TransportKind::Utp),
+            "udp" => Ok(TransportKind::Udp),
+            _ => {
+                let msg = format!("{} is not a transport; use utp or udp", value);
+                Err(sup_error!(Error::InvalidGossipTransport(msg)))
+            }
+        }
+    }
+}
+
+impl fmt::Display for TransportKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            TransportKind::Utp => write!(f, "utp"),
+            TransportKind::Udp => write!(f, "udp"),
+        }
+    }
+}
+
+/// How we gossip, as configured when the supervisor starts.
+#[derive(Debug, Clone, PartialEq, Eq, RustcEncodable)]
+pub struct TransportConfig {
+    /// The transport for SWIM messages
+    pub kind: TransportKind,
+    /// The largest datagram we send over UDP, in bytes
+    pub max_datagram: usize,
+}
+
+impl Default for TransportConfig {
+    fn default() -> TransportConfig {
+        TransportConfig {
+            kind: TransportKind::default(),
+            max_datagram: DEFAULT_MAX_DATAGRAM,
+        }
+    }
+}
+
+impl TransportConfig {
+    /// Check the datagram size is one we can work with.
+    ///
+    /// # Failures
+    ///
+    /// * The datagram size is too small to hold a member rumor, or too large for UDP
+    pub fn validate(&self) -> Result<()> {
+        if self.max_datagram < MIN_DATAGRAM || self.max_datagram > MAX_DATAGRAM {
+            let msg = format!("the datagram size must be between {} and {} bytes",
+                              MIN_DATAGRAM,
+                              MAX_DATAGRAM);
+            return Err(sup_error!(Error::InvalidGossipTransport(msg)));
+        }
+        Ok(())
+    }
+}
+
+/// A transport ready to send gossip, shared by everything in the server that sends messages.
+#[derive(Debug, Clone)]
+pub enum Transport {
+    /// A new uTP connection for each message
+    Utp,
+    /// Datagrams from our one UDP socket, of no more than the given number of bytes. The inbound
+    /// listener receives on the same socket.
+    Udp(Arc<UdpSocket>, usize),
+}
+
+impl Transport {
+    /// Set up the transport for a server that listens on `listen`.
+    ///
+    /// # Failures
+    ///
+    /// * We are gossiping over UDP, and cannot bind the socket
+    pub fn bind(config: &TransportConfig, listen: &str) -> Result<Transport> {
+        match config.kind {
+            TransportKind::Utp => Ok(Transport::Utp),
+            TransportKind::Udp => {
+                let socket = try!(UdpSocket::bind(listen));
+                Ok(Transport::Udp(Arc::new(socket), config.max_datagram))
+            }
+        }
+    }
+}
+
+/// The version of the gossip protocol to write to a member that told us it speaks
+/// `gossip_version`: the newest one we both speak, or JSON if it has not told us.
+pub fn version_for(gossip_version: Option<u32>) -> u8 {
+    match gossip_version {
+        Some(version) if version > JSON_GOSSIP_VERSION as u32 => {
+            cmp::min(version, GOSSIP_VERSION as u32) as u8
+        }
+        _ => JSON_GOSSIP_VERSION,
+    }
+}
+
+/// Encode a message in the given version of the gossip protocol, encrypting it with our sending
+/// ring key if we have one.
+///
+/// # Failures
+///
+/// * The message cannot be encoded, or encrypted
+pub fn encode(msg: &Protocol, ring_keys: &RingKeys, version: u8) -> Result<Vec<u8>> {
+    if version > JSON_GOSSIP_VERSION {
+        return wire::encode(msg, ring_keys);
+    }
+    let msg = for_version_one(msg);
+    let wire_msg = match ring_keys.sending() {
+        Some(key) => try!(WireMessage::encrypted(&msg, key)),
+        None => try!(WireMessage::plain(&msg)),
+    };
+    Ok(try!(json::encode(&wire_msg)).into_bytes())
+}
+
+/// The message as a Supervisor that only speaks version 1 can decode it. Those Supervisors do not
+/// know that members depart, or that rings roll their keys, so we tell them departed members are
+/// confirmed dead, and leave key rolls out.
+fn for_version_one(msg: &Protocol) -> Protocol {
+    match *msg {
+        Protocol::Ping(ref peer, ref rumors) => {
+            Protocol::Ping(peer.clone(), rumors_for_version_one(rumors))
+        }
+        Protocol::Ack(ref peer, ref rumors) => {
+            Protocol::Ack(peer.clone(), rumors_for_version_one(rumors))
+        }
+        Protocol::PingReq(ref peer, ref rumors) => {
+            Protocol::PingReq(peer.clone(), rumors_for_version_one(rumors))
+        }
+        Protocol::Inject(ref rumors) => Protocol::Inject(rumors_for_version_one(rumors)),
+        Protocol::PushPull(ref peer, ref rumors) => {
+            Protocol::PushPull(peer.clone(), rumors_for_version_one(rumors))
+        }
+    }
+}
+
+fn rumors_for_version_one(rumor_list: &RumorList) -> RumorList {
+    let mut rumors = HashMap::new();
+    for (id, rumor) in rumor_list.rumors.iter() {
+        let payload = match rumor.payload {
+            Message::KeyRoll(_) => continue,
+            Message::Member(ref member) if member.health == Health::Departed => {
+                let mut member = member.clone();
+                member.health = Health::Confirmed;
+                Message::Member(member)
+            }
+            ref payload => payload.clone(),
+        };
+        rumors.insert(id.clone(),
+                      Rumor {
+                          id: rumor.id.clone(),
+                          payload: payload,
+                      });
+    }
+    RumorList {
+        rumors: rumors,
+        heat: HashMap::new(),
+    }
+}
+
+/// Decode a message, decrypting it with the ring key revision it was encrypted with. A binary
+/// envelope never starts with a `{`, so we can tell the JSON messages of version 1 apart from it.
+///
+/// # Failures
+///
+/// * The bytes are not a message, or it was encrypted with a revision we do not accept
+/// * The message needs a newer version of the gossip protocol than ours
+pub fn decode(bytes: &[u8], ring_keys: &RingKeys) -> Result<Protocol> {
+    if bytes.first() == Some(&b'{') {
+        let json_str = try!(str::from_utf8(bytes));
+        let wire_msg: WireMessage = try!(json::decode(json_str));
+        let ring_key = match wire_msg.key() {
+            Some(key_name) => ring_keys.get(key_name),
+            None => None,
+        };
+        Ok(try!(wire_msg.msg(ring_key.as_ref())))
+    } else {
+        wire::decode(bytes, ring_keys)
+    }
+}
+
+/// Put a message encoded in the given version of the protocol in a datagram.
+pub fn datagram(encoded: &[u8], version: u8) -> Vec<u8> {
+    let mut datagram = Vec::with_capacity(encoded.len() + 1);
+    datagram.push(version);
+    datagram.extend_from_slice(encoded);
+    datagram
+}
+
+/// Take the encoded message out of a datagram.
+///
+/// # Failures
+///
+/// * The datagram is written in a version of the protocol we do not know
+pub fn open_datagram(datagram: &[u8]) -> Result<&[u8]> {
+    match datagram.split_first() {
+        Some((&version, encoded)) => {
+            try!(check_version(version));
+            Ok(encoded)
+        }
+        None => Err(sup_error!(Error::UnsupportedGossipVersion(0))),
+    }
+}
+
+/// Write a message to a push-pull stream, in the given version of the protocol.
+///
+/// # Failures
+///
+/// * The message cannot be encoded, or is larger than a frame can be
+/// * We cannot write to the stream
+pub fn write_message<W: Write>(stream: &mut W,
+                               msg: &Protocol,
+                               ring_keys: &RingKeys,
+                               version: u8)
+                               -> Result<()> {
+    let encoded = try!(encode(msg, ring_keys, version));
+    if encoded.len() > MAX_FRAME {
+        return Err(sup_error!(Error::GossipMessageTooLarge(encoded.len(), MAX_FRAME)));
+    }
+    try!(stream.write_all(&gossip_frame::header(version, encoded.len() as u32)));
+    try!(stream.write_all(&encoded));
+    try!(stream.flush());
+    Ok(())
+}
+
+/// Read a message from a push-pull stream. The length in a frame's header is whatever the other
+/// end says it is, so we only take as much memory as the bytes that actually arrive.
+///
+/// # Failures
+///
+/// * We cannot read from the stream
+/// * The frame is in a version of the protocol we do not know, or is too large
+/// * The message cannot be decoded
+pub fn read_message<R: Read>(stream: &mut R, ring_keys: &RingKeys) -> Result<Protocol> {
+    let mut header = [0u8; gossip_frame::HEADER_LEN];
+    try!(stream.read_exact(&mut header));
+    let (version, len) = gossip_frame::parse_header(&header);
+    try!(check_version(version));
+    if len > MAX_FRAME {
+        return Err(sup_error!(Error::GossipMessageTooLarge(len, MAX_FRAME)));
+    }
+    let mut encoded = Vec::new();
+    try!(stream.by_ref().take(len as u64).read_to_end(&mut encoded));
+    if encoded.len() < len {
+        return Err(sup_error!(Error::Io(io::Error::new(io::ErrorKind::UnexpectedEof,
+                                                       "the push-pull frame ended early"))));
+    }
+    decode(&encoded, ring_keys)
+}
+
+/// Send a message to the member listening on `addr`, in the given version of the protocol, and
+/// return the message it answers with.
+///
+/// # Failures
+///
+/// * We cannot connect to the member, or it takes too long to answer
+/// * We cannot write our message, or read its answer
+pub fn push_pull(addr: &str,
+                 msg: &Protocol,
+                 ring_keys: &RingKeys,
+                 version: u8)
+                 -> Result<Protocol> {
+    let mut stream = try!(TcpStream::connect(addr));
+    try!(set_timeouts(&stream));
+    try!(write_message(&mut stream, msg, ring_keys, version));
+    read_message(&mut stream, ring_keys)
+}
+
+/// Make sure a push-pull stream cannot hang a thread forever.
+pub fn set_timeouts(stream: &TcpStream) -> Result<()> {
+    let timeout = Some(Duration::from_millis(PUSH_PULL_TIMEOUT));
+    try!(stream.set_read_timeout(timeout));
+    try!(stream.set_write_timeout(timeout));
+    Ok(())
+}
+
+/// We read any version of the protocol up to our own.
+fn check_version(version: u8) -> Result<()> {
+    if version == 0 || version > GOSSIP_VERSION {
+        Err(sup_error!(Error::UnsupportedGossipVersion(version as u32)))
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Cursor;
+
+    use common::gossip_frame;
+    use common::wire_message::WireMessage;
+    use rustc_serialize::json;
+
+    use std::collections::HashMap;
+
+    use gossip::member::{Health, Member};
+    use gossip::ring_keys::{KeyRoll, RingKeys};
+    use gossip::rumor::{Protocol, Rumor, RumorList};
+    use gossip::transport::{self, TransportConfig, TransportKind, GOSSIP_VERSION,
+                            JSON_GOSSIP_VERSION};
+
+    fn inject() -> Protocol {
+        let member = Member::new("sam".to_string(),
+                                 "10.0.0.2".to_string(),
+                                 "10.0.0.2:9634".to_string(),
+                                 false);
+        let mut rumors = RumorList::new();
+        rumors.add_rumor(Rumor::member(member));
+        Protocol::Inject(rumors)
+    }
+
+    #[test]
+    fn transport_kind_from_str() {
+        assert_eq!("udp".parse::<TransportKind>().unwrap(), TransportKind::Udp);
+        assert_eq!("utp".parse::<TransportKind>().unwrap(), TransportKind::Utp);
+        assert!("tcp".parse::<TransportKind>().is_err());
+    }
+
+    #[test]
+    fn validate_rejects_tiny_datagrams() {
+        let mut config = TransportConfig::default();
+        assert!(config.validate().is_ok());
+        config.max_datagram = 100;
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn writes_json_until_a_member_speaks_our_version() {
+        assert_eq!(transport::version_for(None), JSON_GOSSIP_VERSION);
+        assert_eq!(transport::version_for(Some(1)), JSON_GOSSIP_VERSION);
+        assert_eq!(transport::version_for(Some(2)), GOSSIP_VERSION);
+        assert_eq!(transport::version_for(Some(99)), GOSSIP_VERSION);
+
+        let json = transport::encode(&inject(), &RingKeys::none(), JSON_GOSSIP_VERSION).unwrap();
+        assert_eq!(json[0], b'{');
+        let binary = transport::encode(&inject(), &RingKeys::none(), GOSSIP_VERSION).unwrap();
+        assert!(binary[0] != b'{');
+        for encoded in vec![json, binary] {
+            match transport::decode(&encoded, &RingKeys::none()).unwrap() {
+                Protocol::Inject(rumors) => assert_eq!(rumors.len(), 1),
+                msg => panic!("Expected an Inject, got {:?}", msg),
+            }
+        }
+    }
+
+    // The shapes a Supervisor from before version 2 decodes messages into
+    #[derive(Debug, PartialEq, RustcDecodable)]
+    enum BaselineHealth {
+        Alive,
+        Suspect,
+        Confirmed,
+    }
+
+    #[derive(RustcDecodable)]
+    struct BaselineMember {
+        health: BaselineHealth,
+    }
+
+    #[derive(RustcDecodable)]
+    enum BaselineMessage {
+        Member(BaselineMember),
+        CensusEntry(()),
+        Election(()),
+        GossipFile(()),
+        Blank,
+    }
+
+    #[derive(RustcDecodable)]
+    struct BaselineRumor {
+        payload: BaselineMessage,
+    }
+
+    #[derive(RustcDecodable)]
+    struct BaselineRumorList {
+        rumors: HashMap<String, BaselineRumor>,
+    }
+
+    #[derive(RustcDecodable)]
+    enum BaselineProtocol {
+        Inject(BaselineRumorList),
+    }
+
+    #[test]
+    fn writes_json_that_supervisors_before_version_two_read() {
+        let mut member = Member::new("sam".to_string(),
+                                     "10.0.0.2".to_string(),
+                                     "10.0.0.2:9634".to_string(),
+                                     false);
+        member.health = Health::Departed;
+        let mut rumors = RumorList::new();
+        rumors.add_rumor(Rumor::member(member));
+        rumors.add_rumor(Rumor::key_roll(KeyRoll::new("ring".to_string(),
+                                                      "20160101010101".to_string(),
+                                                      false)));
+
+        let encoded = transport::encode(&Protocol::Inject(rumors),
+                                        &RingKeys::none(),
+                                        JSON_GOSSIP_VERSION)
+            .unwrap();
+        let wire_msg: WireMessage = json::decode(::std::str::from_utf8(&encoded).unwrap())
+            .unwrap();
+        let BaselineProtocol::Inject(rumor_list) = wire_msg.msg(None).unwrap();
+        assert_eq!(rumor_list.rumors.len(), 1);
+        for rumor in rumor_list.rumors.values() {
+            match rumor.payload {
+                BaselineMessage::Member(ref m) => assert_eq!(m.health, BaselineHealth::Confirmed),
+                _ => panic!("Expected a member rumor"),
+            }
+        }
+    }
+
+    #[test]
+    fn datagrams_are_versioned() {
+        let encoded = transport::encode(&inject(), &RingKeys::none(), GOSSIP_VERSION).unwrap();
+        let mut datagram = transport::datagram(&encoded, GOSSIP_VERSION);
+        assert_eq!(datagram[0], GOSSIP_VERSION);
+        assert_eq!(transport::open_datagram(&datagram).unwrap(), &encoded[..]);
+
+        datagram[0] = GOSSIP_VERSION + 1;
+        assert!(transport::open_datagram(&datagram).is_err());
+        assert!(transport::open_datagram(&[]).is_err());
+    }
+
+    #[test]
+    fn reads_version_one_json_messages() {
+        let wire_msg = WireMessage::plain(&inject()).unwrap();
+        let encoded = json::encode(&wire_msg).unwrap().into_bytes();
+        match transport::decode(&encoded, &RingKeys::none()).unwrap() {
+            Protocol::Inject(rumors) => assert_eq!(rumors.len(), 1),
+            msg => panic!("Expected an Inject, got {:?}", msg),
+        }
+    }
+
+    #[test]
+    fn messages_round_trip_through_a_stream() {
+        let mut stream = Cursor::new(Vec::new());
+        transport::write_message(&mut stream, &inject(), &RingKeys::none(), GOSSIP_VERSION)
+            .unwrap();
+        stream.set_position(0);
+        match transport::read_message(&mut stream, &RingKeys::none()).unwrap() {
+            Protocol::Inject(rumors) => assert_eq!(rumors.len(), 1),
+            msg => panic!("Expected an Inject, got {:?}", msg),
+        }
+    }
+
+    #[test]
+    fn refuses_frames_that_end_early() {
+        let mut frame = gossip_frame::header(GOSSIP_VERSION, 1024).to_vec();
+        frame.extend_from_slice(b"{}");
+        assert!(transport::read_message(&mut Cursor::new(frame), &RingKeys::none()).is_err());
+    }
+}
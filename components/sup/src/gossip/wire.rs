@@ -0,0 +1,361 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The binary wire format for gossip.
+//!
+//! Messages are encoded as the protocol buffers defined in `gossip.proto`, in the
+//! `habitat_sup_protocol` crate. Each payload type converts itself with `to_proto` and
+//! `from_proto`, next to its definition; this module wraps them in the envelope, the SWIM message,
+//! peers and rumors.
+//!
+//! Readers skip any field they do not know, and drop any rumor they cannot read, so new fields and
+//! rumor types can be added without breaking older supervisors. Every message carries the version
+//! of the gossip protocol it was written in, and the oldest version that can read it; a supervisor
+//! refuses messages it is too old to read.
+
+use protobuf::{CodedInputStream, Message as ProtobufMessage, MessageStatic, RepeatedField};
+use sup_protocol::gossip as proto;
+use uuid::Uuid;
+
+use common::gossip_file::GossipFile;
+use census::CensusEntry;
+use election::Election;
+use error::{Error, Result, SupError};
+use gossip::member::Member;
+use gossip::ring_keys::{KeyRoll, RingKeys};
+use gossip::rumor::{Message, Peer, Protocol, Rumor, RumorList};
+use gossip::transport::GOSSIP_VERSION;
+
+static LOGKEY: &'static str = "GW";
+
+/// The oldest version of the gossip protocol that can read the messages we write
+pub const MIN_GOSSIP_VERSION: u32 = 2;
+
+/// Encode a message in an envelope, encrypting it with our sending ring key if we have one.
+///
+/// # Failures
+///
+/// * The message cannot be encoded, or encrypted
+pub fn encode(msg: &Protocol, ring_keys: &RingKeys) -> Result<Vec<u8>> {
+    let swim = try!(try!(encode_swim(msg)).write_to_bytes());
+    let mut envelope = proto::Envelope::new();
+    envelope.set_version(GOSSIP_VERSION as u32);
+    envelope.set_min_version(MIN_GOSSIP_VERSION);
+    match ring_keys.sending() {
+        Some(key) => {
+            let (nonce, ciphertext) = try!(key.encrypt(&swim));
+            envelope.set_key(key.name_with_rev());
+            envelope.set_nonce(nonce);
+            envelope.set_payload(ciphertext);
+        }
+        None => envelope.set_payload(swim),
+    }
+    Ok(try!(envelope.write_to_bytes()))
+}
+
+/// Decode a message from its envelope, decrypting it if it is encrypted.
+///
+/// # Failures
+///
+/// * The message needs a newer version of the gossip protocol than ours
+/// * The message is encrypted with a ring key revision we do not have, or have retired
+/// * The message cannot be decoded
+pub fn decode(bytes: &[u8], ring_keys: &RingKeys) -> Result<Protocol> {
+    let mut envelope: proto::Envelope = try!(parse(bytes));
+    if envelope.get_min_version() > GOSSIP_VERSION as u32 {
+        return Err(sup_error!(Error::UnsupportedGossipVersion(envelope.get_min_version())));
+    }
+    let swim = if envelope.has_key() {
+        let ring_key = match ring_keys.get(envelope.get_key()) {
+            Some(ring_key) => ring_key,
+            None => {
+                let msg = format!("the message is encrypted with {}, but we accept {}",
+                                  envelope.get_key(),
+                                  accepted(ring_keys));
+                return Err(sup_error!(Error::GossipDecode(msg)));
+            }
+        };
+        if !envelope.has_nonce() {
+            return Err(missing("Envelope", "nonce"));
+        }
+        try!(ring_key.decrypt(envelope.get_nonce(), envelope.get_payload()))
+    } else {
+        envelope.take_payload()
+    };
+    decode_swim(try!(parse(&swim)))
+}
+
+/// The ring key revisions we accept, for error messages.
+fn accepted(ring_keys: &RingKeys) -> String {
+    let revisions = ring_keys.revisions();
+    if revisions.is_empty() {
+        "no ring key".to_string()
+    } else {
+        revisions.join(", ")
+    }
+}
+
+fn encode_swim(msg: &Protocol) -> Result<proto::Swim> {
+    let (kind, peer, rumors) = match *msg {
+        Protocol::Ping(ref peer, ref rumors) => (proto::Swim_Type::PING, Some(peer), rumors),
+        Protocol::Ack(ref peer, ref rumors) => (proto::Swim_Type::ACK, Some(peer), rumors),
+        Protocol::PingReq(ref peer, ref rumors) => (proto::Swim_Type::PINGREQ, Some(peer), rumors),
+        Protocol::Inject(ref rumors) => (proto::Swim_Type::INJECT, None, rumors),
+        Protocol::PushPull(ref peer, ref rumors) => {
+            (proto::Swim_Type::PUSHPULL, Some(peer), rumors)
+        }
+    };
+    let mut swim = proto::Swim::new();
+    swim.set_field_type(kind);
+    if let Some(peer) = peer {
+        swim.set_peer(encode_peer(peer));
+    }
+    let mut encoded_rumors = Vec::with_capacity(rumors.len());
+    for rumor in rumors.rumors.values() {
+        if let Some(encoded) = try!(encode_rumor(rumor)) {
+            encoded_rumors.push(encoded);
+        }
+    }
+    swim.set_rumors(RepeatedField::from_vec(encoded_rumors));
+    Ok(swim)
+}
+
+fn decode_swim(mut swim: proto::Swim) -> Result<Protocol> {
+    let mut rumor_list = RumorList::new();
+    for bytes in swim.get_rumors().iter() {
+        match decode_rumor(bytes) {
+            Ok(Some(rumor)) => {
+                rumor_list.rumors.insert(rumor.id, rumor);
+            }
+            Ok(None) => debug!("Dropping a rumor with no payload we know"),
+            Err(e) => debug!("Dropping a rumor we cannot decode: {}", e),
+        }
+    }
+    let peer = if swim.has_peer() {
+        Some(try!(decode_peer(swim.take_peer())))
+    } else {
+        None
+    };
+    match (swim.get_field_type(), peer) {
+        (proto::Swim_Type::INJECT, _) => Ok(Protocol::Inject(rumor_list)),
+        (proto::Swim_Type::PING, Some(peer)) => Ok(Protocol::Ping(peer, rumor_list)),
+        (proto::Swim_Type::ACK, Some(peer)) => Ok(Protocol::Ack(peer, rumor_list)),
+        (proto::Swim_Type::PINGREQ, Some(peer)) => Ok(Protocol::PingReq(peer, rumor_list)),
+        (proto::Swim_Type::PUSHPULL, Some(peer)) => Ok(Protocol::PushPull(peer, rumor_list)),
+        (_, None) => Err(missing("Swim", "peer")),
+    }
+}
+
+fn encode_peer(peer: &Peer) -> proto::Peer {
+    let mut msg = proto::Peer::new();
+    msg.set_member_id(peer.member_id.to_string());
+    msg.set_listening_on(peer.listening_on.clone());
+    if let Some(ref proxy_through) = peer.proxy_through {
+        msg.set_proxy_through(proxy_through.clone());
+    }
+    if let Some(ref proxy_to) = peer.proxy_to {
+        msg.set_proxy_to(proxy_to.clone());
+    }
+    if let Some(gossip_version) = peer.gossip_version {
+        msg.set_gossip_version(gossip_version);
+    }
+    msg
+}
+
+fn decode_peer(mut msg: proto::Peer) -> Result<Peer> {
+    try!(initialized(&msg));
+    let mut peer = Peer::new(try!(Uuid::parse_str(msg.get_member_id())),
+                             msg.take_listening_on());
+    if msg.has_proxy_through() {
+        peer.proxy_through = Some(msg.take_proxy_through());
+    }
+    if msg.has_proxy_to() {
+        peer.proxy_to = Some(msg.take_proxy_to());
+    }
+    peer.gossip_version = if msg.has_gossip_version() {
+        Some(msg.get_gossip_version())
+    } else {
+        None
+    };
+    Ok(peer)
+}
+
+/// Blank rumors carry nothing, so they are not sent at all.
+fn encode_rumor(rumor: &Rumor) -> Result<Option<Vec<u8>>> {
+    let mut msg = proto::Rumor::new();
+    msg.set_id(rumor.id.to_string());
+    match rumor.payload {
+        Message::Member(ref member) => msg.set_member(member.to_proto()),
+        Message::CensusEntry(ref ce) => msg.set_census_entry(ce.to_proto()),
+        Message::Election(ref election) => msg.set_election(election.to_proto()),
+        Message::GossipFile(ref gossip_file) => msg.set_gossip_file(gossip_file.to_proto()),
+        Message::KeyRoll(ref roll) => msg.set_key_roll(roll.to_proto()),
+        Message::Blank => return Ok(None),
+    }
+    Ok(Some(try!(msg.write_to_bytes())))
+}
+
+fn decode_rumor(bytes: &[u8]) -> Result<Option<Rumor>> {
+    let mut msg: proto::Rumor = try!(parse(bytes));
+    let payload = if msg.has_member() {
+        Message::Member(try!(Member::from_proto(msg.take_member())))
+    } else if msg.has_census_entry() {
+        Message::CensusEntry(try!(CensusEntry::from_proto(msg.take_census_entry())))
+    } else if msg.has_election() {
+        Message::Election(try!(Election::from_proto(msg.take_election())))
+    } else if msg.has_gossip_file() {
+        Message::GossipFile(try!(GossipFile::from_proto(msg.take_gossip_file())))
+    } else if msg.has_key_roll() {
+        Message::KeyRoll(try!(KeyRoll::from_proto(msg.take_key_roll())))
+    } else {
+        return Ok(None);
+    };
+    Ok(Some(Rumor {
+        id: try!(Uuid::parse_str(msg.get_id())),
+        payload: payload,
+    }))
+}
+
+/// Parse a message of the gossip wire format.
+///
+/// # Failures
+///
+/// * The message cannot be decoded, or is missing a required field
+pub fn parse<M: ProtobufMessage + MessageStatic>(bytes: &[u8]) -> Result<M> {
+    let mut msg: M = MessageStatic::new();
+    try!(msg.merge_from(&mut CodedInputStream::from_bytes(bytes)));
+    try!(initialized(&msg));
+    Ok(msg)
+}
+
+/// Fail unless the message has all its required fields. Parsing leaves the messages inside
+/// another unchecked, and rust-protobuf panics rather than fail on a message without them.
+pub fn initialized<M: ProtobufMessage>(msg: &M) -> Result<()> {
+    if msg.is_initialized() {
+        Ok(())
+    } else {
+        let msg = format!("{} is missing a required field", msg.descriptor().full_name());
+        Err(sup_error!(Error::GossipDecode(msg)))
+    }
+}
+
+/// The error for a message that is missing a field.
+fn missing(message: &str, field: &str) -> SupError {
+    sup_error!(Error::GossipDecode(format!("{} has no {}", message, field)))
+}
+
+#[cfg(test)]
+mod test {
+    use gossip::member::{Health, Member, MemberId};
+    use gossip::ring_keys::RingKeys;
+    use gossip::rumor::{Peer, Protocol, Rumor, RumorList};
+    use gossip::transport::GOSSIP_VERSION;
+    use gossip::wire;
+    use protobuf::{CodedOutputStream, Message};
+    use sup_protocol::gossip as proto;
+
+    fn member() -> Member {
+        let mut member = Member::new("frodo".to_string(),
+                                     "10.0.0.1".to_string(),
+                                     "10.0.0.1:9634".to_string(),
+                                     true);
+        member.health = Health::Suspect;
+        member.suspected_by = Some(MemberId::new_v4());
+        member
+    }
+
+    fn ping(member: &Member) -> Protocol {
+        let mut rumors = RumorList::new();
+        rumors.add_rumor(Rumor::member(member.clone()));
+        rumors.add_rumor(Rumor::blank());
+        Protocol::Ping(Peer::new(member.id, member.gossip_listener.clone()), rumors)
+    }
+
+    #[test]
+    fn round_trips_a_ping() {
+        let member = member();
+        let encoded = wire::encode(&ping(&member), &RingKeys::none()).unwrap();
+        match wire::decode(&encoded, &RingKeys::none()).unwrap() {
+            Protocol::Ping(peer, rumors) => {
+                assert_eq!(peer.member_id, member.id);
+                assert_eq!(peer.listening_on, "10.0.0.1:9634");
+                assert_eq!(peer.gossip_version, Some(GOSSIP_VERSION as u32));
+                // The blank rumor is not sent
+                assert_eq!(rumors.len(), 1);
+                assert_eq!(rumors.rumors[&member.id], Rumor::member(member));
+            }
+            msg => panic!("Expected a Ping, got {:?}", msg),
+        }
+    }
+
+    #[test]
+    fn skips_unknown_fields() {
+        let mut encoded = wire::encode(&ping(&member()), &RingKeys::none()).unwrap();
+        {
+            let mut os = CodedOutputStream::vec(&mut encoded);
+            os.write_string(42, "from the future").unwrap();
+            os.write_uint64(43, 42).unwrap();
+            os.flush().unwrap();
+        }
+        assert!(wire::decode(&encoded, &RingKeys::none()).is_ok());
+    }
+
+    #[test]
+    fn drops_only_the_rumors_it_cannot_read() {
+        let member = member();
+        let mut known = proto::Rumor::new();
+        known.set_id(member.id.to_string());
+        known.set_member(member.to_proto());
+        // A health from the future fails the rumor that carries it, and nothing else
+        let mut future = member.to_proto().write_to_bytes().unwrap();
+        let mut unknown = Vec::new();
+        {
+            let mut os = CodedOutputStream::vec(&mut future);
+            os.write_enum(6, 99).unwrap();
+            os.flush().unwrap();
+        }
+        {
+            let mut os = CodedOutputStream::vec(&mut unknown);
+            os.write_string(1, &MemberId::new_v4().to_string()).unwrap();
+            os.write_bytes(2, &future).unwrap();
+            os.flush().unwrap();
+        }
+        let mut swim = proto::Swim::new();
+        swim.set_field_type(proto::Swim_Type::INJECT);
+        swim.mut_rumors().push(known.write_to_bytes().unwrap());
+        swim.mut_rumors().push(unknown);
+        let mut envelope = proto::Envelope::new();
+        envelope.set_version(2);
+        envelope.set_min_version(2);
+        envelope.set_payload(swim.write_to_bytes().unwrap());
+        let encoded = envelope.write_to_bytes().unwrap();
+        match wire::decode(&encoded, &RingKeys::none()).unwrap() {
+            Protocol::Inject(rumors) => {
+                assert_eq!(rumors.len(), 1);
+                assert_eq!(rumors.rumors[&member.id], Rumor::member(member));
+            }
+            msg => panic!("Expected an Inject, got {:?}", msg),
+        }
+    }
+
+    #[test]
+    fn refuses_messages_we_are_too_old_to_read() {
+        let mut envelope = proto::Envelope::new();
+        envelope.set_version(99);
+        envelope.set_min_version(99);
+        envelope.set_payload(Vec::new());
+        let encoded = envelope.write_to_bytes().unwrap();
+        assert!(wire::decode(&encoded, &RingKeys::none()).is_err());
+    }
+}
@@ -12,9 +12,25 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::VecDeque;
 use std::fmt::{self, Display, Formatter};
+use std::result;
+use std::sync::{Arc, RwLock};
+use std::thread;
+use std::time::Duration as StdDuration;
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+use rustc_serialize::{Encodable, Encoder};
+use time::{self, Duration, SteadyTime, Tm};
+
+use census::CensusList;
+use error::Result;
+use package::Package;
+use service_config::ServiceConfig;
+use supervisor::Supervisor;
+
+static LOGKEY: &'static str = "HC";
+
+#[derive(Debug, Clone, PartialEq, Eq, RustcEncodable, RustcDecodable)]
 pub enum Status {
     Ok,
     Warning,
@@ -22,7 +38,7 @@ pub enum Status {
     Unknown,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, RustcEncodable)]
 pub struct CheckResult {
     pub status: Status,
     pub output: String,
@@ -64,3 +80,146 @@ impl Display for CheckResult {
         write!(f, "{} - {}", status_code, self.output)
     }
 }
+
+/// How many health check results we keep in a `History`.
+pub const HISTORY_SIZE: usize = 20;
+
+/// A health check result, along with when the check ran and how long it took.
+#[derive(Debug, Clone)]
+pub struct CheckRecord {
+    pub result: CheckResult,
+    pub checked_at: Tm,
+    pub duration: Duration,
+}
+
+// We have to hand-write the serialization due to our use of Tm and Duration.
+impl Encodable for CheckRecord {
+    fn encode<S: Encoder>(&self, s: &mut S) -> result::Result<(), S::Error> {
+        try!(s.emit_struct("CheckRecord", 3, |s| {
+            try!(s.emit_struct_field("result", 0, |s| self.result.encode(s)));
+            try!(s.emit_struct_field("checked_at",
+                                     1,
+                                     |s| format!("{}", self.checked_at.rfc3339()).encode(s)));
+            try!(s.emit_struct_field("duration_ms",
+                                     2,
+                                     |s| self.duration.num_milliseconds().encode(s)));
+            Ok(())
+        }));
+        Ok(())
+    }
+}
+
+/// The most recent health check results, oldest first.
+#[derive(Debug, RustcEncodable)]
+pub struct History {
+    records: VecDeque<CheckRecord>,
+}
+
+impl History {
+    pub fn new() -> History {
+        History { records: VecDeque::with_capacity(HISTORY_SIZE) }
+    }
+
+    /// Add a result to the history, forgetting the oldest one if the history is full.
+    pub fn push(&mut self, record: CheckRecord) {
+        if self.records.len() >= HISTORY_SIZE {
+            self.records.pop_front();
+        }
+        self.records.push_back(record);
+    }
+
+    /// The most recent result, if we have run a health check yet.
+    pub fn last(&self) -> Option<&CheckRecord> {
+        self.records.back()
+    }
+
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+}
+
+/// Run the package's health check, and record the result in the history. The hook runs with
+/// copies of the package and its configuration, so we hold none of the locks while it runs.
+///
+/// # Failures
+///
+/// * If the health_check cannot be run
+pub fn run(package: &RwLock<Package>,
+           config: &RwLock<ServiceConfig>,
+           supervisor: &RwLock<Supervisor>,
+           history: &RwLock<History>)
+           -> Result<CheckResult> {
+    let package = package.read().unwrap().clone();
+    let config = config.read().unwrap().clone();
+    let process_status = supervisor.read().unwrap().status();
+    let checked_at = time::now_utc();
+    let start = SteadyTime::now();
+    let result = try!(package.health_check(&config, process_status));
+    let record = CheckRecord {
+        result: result.clone(),
+        checked_at: checked_at,
+        duration: SteadyTime::now() - start,
+    };
+    history.write().unwrap().push(record);
+    Ok(result)
+}
+
+/// Run the health check every `interval` seconds, and gossip the status of each check as part of
+/// our census entry.
+pub fn start_health_checker(interval: u64,
+                            package: Arc<RwLock<Package>>,
+                            config: Arc<RwLock<ServiceConfig>>,
+                            supervisor: Arc<RwLock<Supervisor>>,
+                            history: Arc<RwLock<History>>,
+                            census_list: Arc<RwLock<CensusList>>) {
+    outputln!("Starting health checks every {} seconds", interval);
+    let _t = thread::Builder::new().name("health_checker".to_string()).spawn(move || {
+        loop {
+            thread::sleep(StdDuration::from_secs(interval));
+            // A check that fails to run counts as critical, as it does after an update, so our
+            // peers see the failure rather than the last status we gossiped.
+            let status = match run(&package, &config, &supervisor, &history) {
+                Ok(result) => {
+                    debug!("Health check: {}", result);
+                    result.status
+                }
+                Err(e) => {
+                    outputln!("Health check failed to run: {}", e);
+                    Status::Critical
+                }
+            };
+            let mut cl = census_list.write().unwrap();
+            let me = cl.me_mut();
+            if me.health_check.as_ref() != Some(&status) {
+                me.health_check(Some(status));
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod test {
+    use time::{self, Duration};
+
+    use health_check::{CheckRecord, CheckResult, History, HISTORY_SIZE};
+
+    fn record(output: &str) -> CheckRecord {
+        CheckRecord {
+            result: CheckResult::ok(output.to_string()),
+            checked_at: time::now_utc(),
+            duration: Duration::milliseconds(10),
+        }
+    }
+
+    #[test]
+    fn history_is_bounded() {
+        let mut history = History::new();
+        assert!(history.last().is_none());
+        for x in 0..HISTORY_SIZE + 5 {
+            history.push(record(&x.to_string()));
+        }
+        assert_eq!(history.len(), HISTORY_SIZE);
+        assert_eq!(history.last().unwrap().result.output,
+                   (HISTORY_SIZE + 4).to_string());
+    }
+}
@@ -42,6 +42,7 @@
 extern crate habitat_core as hcore;
 extern crate habitat_common as common;
 extern crate habitat_depot_client as depot_client;
+extern crate habitat_sup_protocol as sup_protocol;
 extern crate handlebars;
 #[macro_use]
 extern crate hyper;
@@ -64,6 +65,7 @@ extern crate utp;
 extern crate rand;
 extern crate threadpool;
 extern crate openssl;
+extern crate protobuf;
 #[macro_use]
 extern crate lazy_static;
 
@@ -271,9 +273,12 @@ pub mod topology;
 pub mod state_machine;
 pub mod sidecar;
 pub mod health_check;
+pub mod logs;
+pub mod metrics;
 pub mod config;
 pub mod service_config;
 pub mod census;
+pub mod cgroup;
 pub mod gossip;
 pub mod election;
 pub mod supervisor;
@@ -0,0 +1,429 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Capture the output of a service's process.
+//!
+//! Each line the process writes to stdout or stderr is printed with the Supervisor's own output,
+//! and written to a log file for its stream in the `logs` directory of the service's var path:
+//! `stdout.log` and `stderr.log`. Lines are written as the process wrote them, or as JSON lines
+//! when the Supervisor's output is JSON.
+//!
+//! A log file is rotated once it grows past the policy's size, or gets older than its age. The
+//! current file moves to `stdout.log.1`, the one before that to `stdout.log.2`, and so on; we
+//! remove the oldest once there are more than the policy keeps. We record when each current file
+//! was started next to it, in `stdout.log.started`, so a file keeps its age across restarts.
+//!
+//! We also keep each service's most recent lines in memory, as the Supervisor printed them, so
+//! the sidecar can serve them, and follow new ones as they come in.
+
+use std::collections::VecDeque;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufReader};
+use std::io::prelude::*;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Condvar, Mutex};
+use std::time::{Duration as StdDuration, SystemTime, UNIX_EPOCH};
+
+use time::{Duration, SteadyTime};
+
+use error::{Error, Result};
+use output::{self, StructuredOutput};
+use util;
+
+static LOGKEY: &'static str = "LG";
+/// The logkey of lines of output from a service's process
+const OUTPUT_LOGKEY: &'static str = "O";
+/// The directory under a service's var path we keep its logs in
+pub const LOG_DIR: &'static str = "logs";
+/// How many of a service's most recent lines we keep in memory
+const BUFFER_LINES: usize = 1000;
+
+/// When we rotate a service's log files, and how many rotated files we keep.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogPolicy {
+    /// Rotate a log file once it grows past this many bytes; `None` never rotates for size
+    pub max_size: Option<u64>,
+    /// Rotate a log file once it is this many seconds old; `None` never rotates for age
+    pub max_age: Option<u64>,
+    /// How many rotated files to keep for each stream
+    pub keep: u32,
+}
+
+impl Default for LogPolicy {
+    fn default() -> LogPolicy {
+        LogPolicy {
+            max_size: Some(10 * 1024 * 1024),
+            max_age: None,
+            keep: 5,
+        }
+    }
+}
+
+/// Parse a log file size, in bytes or with a `K`, `M` or `G` suffix.
+pub fn parse_size(size: &str) -> Result<u64> {
+    match util::parse_bytes(size) {
+        Some(bytes) if bytes > 0 => Ok(bytes),
+        _ => Err(sup_error!(Error::InvalidLogPolicy(format!("log size {}", size)))),
+    }
+}
+
+/// The path of a service's log file for a stream, `stdout` or `stderr`.
+pub fn log_path(var_path: &Path, stream: &str) -> PathBuf {
+    var_path.join(LOG_DIR).join(format!("{}.log", stream))
+}
+
+/// Where a service's output goes, and what it is labelled with.
+#[derive(Debug, Clone)]
+pub struct ServiceLogs {
+    pub service_group: String,
+    pub member_id: String,
+    pub policy: LogPolicy,
+    /// The most recent lines of both streams
+    pub buffer: LogBuffer,
+    /// The service's var path
+    var_path: PathBuf,
+}
+
+impl ServiceLogs {
+    pub fn new(service_group: String,
+               member_id: String,
+               policy: LogPolicy,
+               var_path: PathBuf)
+               -> ServiceLogs {
+        ServiceLogs {
+            service_group: service_group,
+            member_id: member_id,
+            policy: policy,
+            buffer: LogBuffer::new(BUFFER_LINES),
+            var_path: var_path,
+        }
+    }
+
+    /// Print each line from a stream of the process, and write it to the stream's log file,
+    /// until the process closes it. Lines still print when we cannot write the log file.
+    pub fn capture<R: Read>(&self, preamble: &str, stream: &str, reader: R) -> Result<()> {
+        let mut log = match LogFile::open(log_path(&self.var_path, stream), self.policy.clone()) {
+            Ok(log) => Some(log),
+            Err(e) => {
+                outputln!("Cannot log {} of {}: {}", stream, self.service_group, e);
+                None
+            }
+        };
+        let mut reader = BufReader::new(reader);
+        let mut buffer = Vec::new();
+        // The process may write output that is not UTF-8, so we read bytes and convert each line
+        while try!(reader.read_until(b'\n', &mut buffer)) > 0 {
+            let line = String::from_utf8_lossy(&buffer)
+                .trim_right_matches(|c| c == '\n' || c == '\r')
+                .to_string();
+            let mut so = StructuredOutput::new(preamble,
+                                               OUTPUT_LOGKEY,
+                                               line!(),
+                                               file!(),
+                                               column!(),
+                                               &line);
+            so.service_group = Some(&self.service_group);
+            so.member_id = Some(&self.member_id);
+            so.stream = Some(stream);
+            println!("{}", so);
+            so.color = Some(false);
+            let formatted = so.to_string();
+            // Once we fail to write the log file, we stop trying
+            if let Some(mut file) = log.take() {
+                let written = if output::is_json() {
+                    file.write_line(&formatted)
+                } else {
+                    file.write_line(&line)
+                };
+                match written {
+                    Ok(()) => log = Some(file),
+                    Err(e) => outputln!("Cannot log {} of {}: {}", stream, self.service_group, e),
+                }
+            }
+            self.buffer.push(formatted);
+            buffer.clear();
+        }
+        Ok(())
+    }
+}
+
+/// A service's most recent lines of output, shared between the threads that capture them and
+/// the sidecar. Each line has a sequence number, so a reader following along can ask for the
+/// lines after the last one it saw.
+#[derive(Debug, Clone)]
+pub struct LogBuffer {
+    inner: Arc<(Mutex<Lines>, Condvar)>,
+}
+
+#[derive(Debug)]
+struct Lines {
+    lines: VecDeque<String>,
+    capacity: usize,
+    /// The sequence number of the oldest line we still have
+    first: u64,
+}
+
+impl Lines {
+    /// The sequence number the next line will get.
+    fn next(&self) -> u64 {
+        self.first + self.lines.len() as u64
+    }
+
+    /// The lines from sequence number `from` on; lines we no longer have are skipped.
+    fn since(&self, from: u64) -> Vec<String> {
+        let skip = from.saturating_sub(self.first) as usize;
+        self.lines.iter().skip(skip).cloned().collect()
+    }
+}
+
+impl LogBuffer {
+    pub fn new(capacity: usize) -> LogBuffer {
+        let lines = Lines {
+            lines: VecDeque::with_capacity(capacity),
+            capacity: capacity,
+            first: 0,
+        };
+        LogBuffer { inner: Arc::new((Mutex::new(lines), Condvar::new())) }
+    }
+
+    /// Add a line, dropping the oldest if the buffer is full, and wake anyone waiting for it.
+    pub fn push(&self, line: String) {
+        let &(ref lock, ref cvar) = &*self.inner;
+        let mut lines = lock.lock().unwrap();
+        if lines.lines.len() >= lines.capacity {
+            lines.lines.pop_front();
+            lines.first += 1;
+        }
+        lines.lines.push_back(line);
+        cvar.notify_all();
+    }
+
+    /// The most recent `count` lines, oldest first, and the sequence number of the next line.
+    pub fn tail(&self, count: usize) -> (Vec<String>, u64) {
+        let &(ref lock, _) = &*self.inner;
+        let lines = lock.lock().unwrap();
+        let from = lines.next().saturating_sub(count as u64);
+        (lines.since(from), lines.next())
+    }
+
+    /// The lines from sequence number `from` on, waiting up to `timeout` for one if there are
+    /// none yet, and the sequence number of the next line.
+    pub fn wait_since(&self, from: u64, timeout: StdDuration) -> (Vec<String>, u64) {
+        let &(ref lock, ref cvar) = &*self.inner;
+        let mut lines = lock.lock().unwrap();
+        if lines.next() <= from {
+            lines = cvar.wait_timeout(lines, timeout).unwrap().0;
+        }
+        (lines.since(from), lines.next())
+    }
+}
+
+/// A log file we rotate according to a policy.
+pub struct LogFile {
+    path: PathBuf,
+    policy: LogPolicy,
+    file: File,
+    size: u64,
+    opened_at: SteadyTime,
+}
+
+impl LogFile {
+    /// Open the log file at `path` to append to it, creating it and its directory if need be.
+    /// An existing file keeps its age, so a Supervisor that restarts often still rotates it.
+    pub fn open(path: PathBuf, policy: LogPolicy) -> io::Result<LogFile> {
+        if let Some(dir) = path.parent() {
+            try!(fs::create_dir_all(dir));
+        }
+        let file = try!(OpenOptions::new().append(true).create(true).open(&path));
+        let metadata = try!(file.metadata());
+        // A file we carry on with after a restart is as old as we recorded; an empty file, or one
+        // we have no record for, starts now
+        let now = SystemTime::now();
+        let started = match read_started(&path) {
+            Some(at) if metadata.len() > 0 => at,
+            _ => {
+                try!(write_started(&path, now));
+                now
+            }
+        };
+        let age = now.duration_since(started)
+            .ok()
+            .map_or(Duration::zero(), |age| Duration::seconds(age.as_secs() as i64));
+        Ok(LogFile {
+            path: path,
+            policy: policy,
+            file: file,
+            size: metadata.len(),
+            opened_at: SteadyTime::now() - age,
+        })
+    }
+
+    /// Write a line to the log file, rotating it first if the line would take it past its size,
+    /// or it is too old.
+    pub fn write_line(&mut self, line: &str) -> io::Result<()> {
+        let len = line.len() as u64 + 1;
+        let too_big = match self.policy.max_size {
+            Some(max) => self.size > 0 && self.size + len > max,
+            None => false,
+        };
+        let too_old = match self.policy.max_age {
+            Some(max) => SteadyTime::now() - self.opened_at >= Duration::seconds(max as i64),
+            None => false,
+        };
+        if too_big || too_old {
+            try!(self.rotate());
+        }
+        try!(writeln!(self.file, "{}", line));
+        self.size += len;
+        Ok(())
+    }
+
+    /// Move the current file to `.1`, each rotated file up one, and start a new one.
+    fn rotate(&mut self) -> io::Result<()> {
+        for n in (1..self.policy.keep).rev() {
+            if let Err(e) = fs::rename(self.rotated_path(n), self.rotated_path(n + 1)) {
+                if e.kind() != io::ErrorKind::NotFound {
+                    return Err(e);
+                }
+            }
+        }
+        if self.policy.keep > 0 {
+            try!(fs::rename(&self.path, self.rotated_path(1)));
+        }
+        self.file = try!(OpenOptions::new()
+            .write(true)
+            .truncate(true)
+            .create(true)
+            .open(&self.path));
+        try!(write_started(&self.path, SystemTime::now()));
+        self.size = 0;
+        self.opened_at = SteadyTime::now();
+        Ok(())
+    }
+
+    fn rotated_path(&self, n: u32) -> PathBuf {
+        let mut name = self.path.clone().into_os_string();
+        name.push(format!(".{}", n));
+        PathBuf::from(name)
+    }
+}
+
+/// The file we record when the log file at `path` was started in. File times cannot tell us: not
+/// every filesystem records when a file was created, and when it was last written is no age.
+fn started_path(path: &Path) -> PathBuf {
+    let mut name = path.to_path_buf().into_os_string();
+    name.push(".started");
+    PathBuf::from(name)
+}
+
+/// When the log file at `path` was started, if we recorded it.
+fn read_started(path: &Path) -> Option<SystemTime> {
+    let mut contents = String::new();
+    if File::open(started_path(path)).and_then(|mut f| f.read_to_string(&mut contents)).is_err() {
+        return None;
+    }
+    contents.trim().parse().ok().map(|secs| UNIX_EPOCH + StdDuration::from_secs(secs))
+}
+
+/// Record that the log file at `path` was started at `at`, in seconds since the Unix epoch.
+fn write_started(path: &Path, at: SystemTime) -> io::Result<()> {
+    let secs = at.duration_since(UNIX_EPOCH).map(|since| since.as_secs()).unwrap_or(0);
+    let mut file = try!(File::create(started_path(path)));
+    writeln!(file, "{}", secs)
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs::File;
+    use std::io::prelude::*;
+
+    use tempdir::TempDir;
+
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    use logs::{LogBuffer, LogFile, LogPolicy, parse_size};
+
+    fn contents(path: &str, dir: &TempDir) -> String {
+        let mut contents = String::new();
+        File::open(dir.path().join(path)).unwrap().read_to_string(&mut contents).unwrap();
+        contents
+    }
+
+    #[test]
+    fn rotates_by_size_and_keeps_the_newest() {
+        let dir = TempDir::new("logs").unwrap();
+        let policy = LogPolicy {
+            max_size: Some(8),
+            max_age: None,
+            keep: 2,
+        };
+        let mut log = LogFile::open(dir.path().join("stdout.log"), policy).unwrap();
+        // "one" and "two" fit in a file together; every line after them needs a file of its own
+        for line in &["one", "two", "three", "four", "five"] {
+            log.write_line(line).unwrap();
+        }
+        assert_eq!(contents("stdout.log", &dir), "five\n");
+        assert_eq!(contents("stdout.log.1", &dir), "four\n");
+        assert_eq!(contents("stdout.log.2", &dir), "three\n");
+        assert!(!dir.path().join("stdout.log.3").exists());
+    }
+
+    #[test]
+    fn keeps_the_age_of_a_log_file_across_restarts() {
+        let dir = TempDir::new("logs").unwrap();
+        let policy = LogPolicy {
+            max_size: None,
+            max_age: Some(60),
+            keep: 1,
+        };
+        LogFile::open(dir.path().join("stdout.log"), policy.clone())
+            .unwrap()
+            .write_line("one")
+            .unwrap();
+        // The Supervisor stops, and starts again after the file has grown too old
+        let started = SystemTime::now() - Duration::from_secs(120);
+        let mut stamp = File::create(dir.path().join("stdout.log.started")).unwrap();
+        writeln!(stamp, "{}", started.duration_since(UNIX_EPOCH).unwrap().as_secs()).unwrap();
+        let mut log = LogFile::open(dir.path().join("stdout.log"), policy).unwrap();
+        log.write_line("two").unwrap();
+        assert_eq!(contents("stdout.log", &dir), "two\n");
+        assert_eq!(contents("stdout.log.1", &dir), "one\n");
+    }
+
+    #[test]
+    fn buffer_keeps_the_newest_lines() {
+        let buffer = LogBuffer::new(3);
+        for line in &["one", "two", "three", "four"] {
+            buffer.push(line.to_string());
+        }
+        assert_eq!(buffer.tail(2), (vec![String::from("three"), String::from("four")], 4));
+        assert_eq!(buffer.tail(10).0,
+                   vec![String::from("two"), String::from("three"), String::from("four")]);
+        // "one" has fallen out of the buffer, so a reader from the start gets the rest
+        let (lines, next) = buffer.wait_since(0, Duration::from_millis(1));
+        assert_eq!(lines.len(), 3);
+        assert_eq!(next, 4);
+        assert!(buffer.wait_since(4, Duration::from_millis(1)).0.is_empty());
+        buffer.push(String::from("five"));
+        assert_eq!(buffer.wait_since(4, Duration::from_millis(1)),
+                   (vec![String::from("five")], 5));
+    }
+
+    #[test]
+    fn parses_sizes() {
+        assert_eq!(parse_size("10M").unwrap(), 10 * 1024 * 1024);
+        assert!(parse_size("0").is_err());
+        assert!(parse_size("big").is_err());
+    }
+}
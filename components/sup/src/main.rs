@@ -38,12 +38,18 @@ use hcore::crypto::init as crypto_init;
 use hcore::package::{PackageArchive, PackageIdent};
 use hcore::url::{DEFAULT_DEPOT_URL, DEPOT_URL_ENVVAR};
 
-use sup::config::{gcache, gconfig, Command, Config, UpdateStrategy};
-use sup::error::{Error, Result, SupError};
+use sup::cgroup::{self, Limits};
+use sup::config::{gcache, gconfig, Command, Config, ServiceSpec, UpdateStrategy};
+use sup::error::{Result, SupError};
+use sup::gossip::detector::Timing;
+use sup::gossip::transport::TransportConfig;
+use sup::logs::LogPolicy;
 use sup::command::*;
+use sup::supervisor::{Restart, RestartPolicy};
 use sup::topology::Topology;
 use sup::util::parse_ip_port_with_defaults;
 use sup::util::path::busybox_paths;
+use sup::util::signals::Signal;
 use sup::util::sys::ip;
 
 /// Our output key
@@ -60,6 +66,10 @@ static DEFAULT_HTTP_LISTEN_PORT: u16 = 9631;
 
 const DEFAULT_GOSSIP_LISTEN_PORT: u16 = 9634;
 
+const DEFAULT_HEALTH_CHECK_INTERVAL: u64 = 30;
+
+const DEFAULT_UPDATE_GRACE: u64 = 300;
+
 static RING_ENVVAR: &'static str = "HAB_RING";
 static RING_KEY_ENVVAR: &'static str = "HAB_RING_KEY";
 
@@ -75,6 +85,19 @@ fn config_from_args(subcommand: &str, sub_args: &ArgMatches) -> Result<()> {
     if let Some(ref strategy) = sub_args.value_of("strategy") {
         config.set_update_strategy(UpdateStrategy::from_str(strategy));
     }
+    if sub_args.value_of("update-grace").is_some() {
+        let grace = value_t!(sub_args.value_of("update-grace"), u64).unwrap_or_else(|e| e.exit());
+        config.set_update_grace(grace);
+    } else {
+        config.set_update_grace(DEFAULT_UPDATE_GRACE);
+    }
+    if sub_args.value_of("health-check-interval").is_some() {
+        let interval = value_t!(sub_args.value_of("health-check-interval"), u64)
+            .unwrap_or_else(|e| e.exit());
+        config.set_health_check_interval(interval);
+    } else {
+        config.set_health_check_interval(DEFAULT_HEALTH_CHECK_INTERVAL);
+    }
     if let Some(ref archive) = sub_args.value_of("archive") {
         config.set_archive(archive.to_string());
     }
@@ -107,18 +130,7 @@ fn config_from_args(subcommand: &str, sub_args: &ArgMatches) -> Result<()> {
         config.set_outfile(outfile.to_string());
     }
     if let Some(topology) = sub_args.value_of("topology") {
-        match topology.as_ref() {
-            "standalone" => {
-                config.set_topology(Topology::Standalone);
-            }
-            "leader" => {
-                config.set_topology(Topology::Leader);
-            }
-            "initializer" => {
-                config.set_topology(Topology::Initializer);
-            }
-            t => return Err(sup_error!(Error::UnknownTopology(String::from(t)))),
-        }
+        config.set_topology(try!(Topology::from_str(topology)));
     }
     if sub_args.value_of("expire-days").is_some() {
         let ed = value_t!(sub_args.value_of("expire-days"), u16).unwrap_or_else(|e| e.exit());
@@ -127,12 +139,78 @@ fn config_from_args(subcommand: &str, sub_args: &ArgMatches) -> Result<()> {
     let env_or_default = henv::var(DEPOT_URL_ENVVAR).unwrap_or(DEFAULT_DEPOT_URL.to_string());
     let url = sub_args.value_of("url").unwrap_or(&env_or_default);
     config.set_url(url.to_string());
+    if let Some(channel) = sub_args.value_of("channel") {
+        config.set_channel(Some(channel.to_string()));
+    }
     config.set_group(sub_args.value_of("group").unwrap_or(DEFAULT_GROUP).to_string());
     let bindings = match sub_args.values_of("bind") {
         Some(bind) => bind.map(|s| s.to_string()).collect(),
         None => vec![],
     };
     config.set_bind(bindings);
+    let trusted_users = match sub_args.values_of("trust-user") {
+        Some(users) => users.map(|s| s.to_string()).collect(),
+        None => vec![],
+    };
+    config.set_trusted_users(trusted_users);
+    let mut restart_policy = RestartPolicy::default();
+    if let Some(restart) = sub_args.value_of("restart") {
+        restart_policy.restart = try!(Restart::from_str(restart));
+    }
+    if sub_args.value_of("restart-retries").is_some() {
+        let retries = value_t!(sub_args.value_of("restart-retries"), u32)
+            .unwrap_or_else(|e| e.exit());
+        restart_policy.max_retries = Some(retries);
+    }
+    if sub_args.value_of("restart-backoff").is_some() {
+        restart_policy.backoff = value_t!(sub_args.value_of("restart-backoff"), u64)
+            .unwrap_or_else(|e| e.exit());
+    }
+    if sub_args.value_of("restart-backoff-max").is_some() {
+        restart_policy.backoff_max = value_t!(sub_args.value_of("restart-backoff-max"), u64)
+            .unwrap_or_else(|e| e.exit());
+    }
+    config.set_restart_policy(restart_policy);
+    let mut limits = Limits::default();
+    if let Some(memory) = sub_args.value_of("memory-max") {
+        limits.memory_max = Some(try!(cgroup::parse_memory(memory)));
+    }
+    if let Some(cpu) = sub_args.value_of("cpu-max") {
+        limits.cpu_max = Some(try!(cgroup::parse_cpu(cpu)));
+    }
+    if let Some(pids) = sub_args.value_of("pids-max") {
+        limits.pids_max = Some(try!(cgroup::parse_pids(pids)));
+    }
+    limits.cgroup = sub_args.is_present("cgroup");
+    config.set_limits(limits);
+    if let Some(signal) = sub_args.value_of("stop-signal") {
+        config.set_stop_signal(Some(try!(Signal::from_str(signal))));
+    }
+    if sub_args.value_of("stop-timeout").is_some() {
+        let timeout = value_t!(sub_args.value_of("stop-timeout"), u64)
+            .unwrap_or_else(|e| e.exit());
+        config.set_stop_timeout(Some(timeout));
+    }
+    let mut log_policy = LogPolicy::default();
+    if let Some(size) = sub_args.value_of("log-max-size") {
+        log_policy.max_size = Some(try!(sup::logs::parse_size(size)));
+    }
+    if sub_args.value_of("log-max-age").is_some() {
+        let age = value_t!(sub_args.value_of("log-max-age"), u64).unwrap_or_else(|e| e.exit());
+        log_policy.max_age = Some(age);
+    }
+    if sub_args.value_of("log-keep").is_some() {
+        log_policy.keep = value_t!(sub_args.value_of("log-keep"), u32)
+            .unwrap_or_else(|e| e.exit());
+    }
+    config.set_log_policy(log_policy);
+    let mut services = vec![];
+    if let Some(specs) = sub_args.values_of("services") {
+        for spec in specs {
+            services.push(try!(ServiceSpec::from_spec(spec, config.group())));
+        }
+    }
+    config.set_services(services);
     config.set_path(sub_args.value_of("path")
         .unwrap_or(fs::svc_path(sup::PROGRAM_NAME.as_str())
             .join("data")
@@ -180,9 +258,67 @@ fn config_from_args(subcommand: &str, sub_args: &ArgMatches) -> Result<()> {
     if sub_args.is_present("permanent-peer") {
         config.set_gossip_permanent(true);
     }
+    let mut timing = Timing::default();
+    if sub_args.value_of("gossip-interval").is_some() {
+        timing.ping_interval = value_t!(sub_args.value_of("gossip-interval"), u64)
+            .unwrap_or_else(|e| e.exit());
+    }
+    if sub_args.value_of("gossip-pingreq-fanout").is_some() {
+        timing.pingreq_fanout = value_t!(sub_args.value_of("gossip-pingreq-fanout"), usize)
+            .unwrap_or_else(|e| e.exit());
+    }
+    if sub_args.value_of("gossip-ping-timeout").is_some() {
+        timing.ping_timeout = value_t!(sub_args.value_of("gossip-ping-timeout"), u64)
+            .unwrap_or_else(|e| e.exit());
+    }
+    if sub_args.value_of("gossip-suspicion-timeout").is_some() {
+        timing.suspicion_timeout = value_t!(sub_args.value_of("gossip-suspicion-timeout"), u64)
+            .unwrap_or_else(|e| e.exit());
+    }
+    if sub_args.value_of("gossip-suspicion-multiplier").is_some() {
+        timing.suspicion_multiplier =
+            value_t!(sub_args.value_of("gossip-suspicion-multiplier"), u64)
+                .unwrap_or_else(|e| e.exit());
+    }
+    if sub_args.value_of("gossip-departure-timeout").is_some() {
+        timing.departure_timeout = value_t!(sub_args.value_of("gossip-departure-timeout"), u64)
+            .unwrap_or_else(|e| e.exit());
+    }
+    if sub_args.value_of("gossip-push-pull-interval").is_some() {
+        timing.push_pull_interval =
+            value_t!(sub_args.value_of("gossip-push-pull-interval"), u64)
+                .unwrap_or_else(|e| e.exit());
+    }
+    try!(timing.validate());
+    config.set_gossip_timing(timing);
+    let mut transport = TransportConfig::default();
+    if let Some(kind) = sub_args.value_of("gossip-transport") {
+        transport.kind = try!(kind.parse());
+    }
+    if sub_args.value_of("gossip-max-datagram").is_some() {
+        transport.max_datagram = value_t!(sub_args.value_of("gossip-max-datagram"), usize)
+            .unwrap_or_else(|e| e.exit());
+    }
+    try!(transport.validate());
+    config.set_gossip_transport(transport);
+    if let Some(addr) = sub_args.value_of("sidecar") {
+        if addr.contains(':') {
+            config.set_sidecar_addr(addr.to_string());
+        } else {
+            config.set_sidecar_addr(format!("{}:{}", addr, DEFAULT_HTTP_LISTEN_PORT));
+        }
+    }
+    if let Some(signal) = sub_args.value_of("signal") {
+        config.set_signal(signal.to_string());
+    }
     if let Some(sg) = sub_args.value_of("service-group") {
         config.set_service_group(sg.to_string());
     }
+    if sub_args.value_of("lines").is_some() {
+        let lines = value_t!(sub_args.value_of("lines"), usize).unwrap_or_else(|e| e.exit());
+        config.set_log_lines(lines);
+    }
+    config.set_follow(sub_args.is_present("follow"));
     if let Some(fp) = sub_args.value_of("file-path") {
         config.set_file_path(fp.to_string());
     }
@@ -217,6 +353,9 @@ fn config_from_args(subcommand: &str, sub_args: &ArgMatches) -> Result<()> {
     if sub_args.is_present("no-color") {
         sup::output::set_no_color(true);
     }
+    if sub_args.is_present("json") {
+        sup::output::set_json(true);
+    }
     if let Some(org) = sub_args.value_of("organization") {
         config.set_organization(org.to_string());
     }
@@ -264,10 +403,26 @@ fn main() {
             .long("strategy")
             .short("s")
             .takes_value(true)
-            .possible_values(&["none", "at-once"])
+            .possible_values(&["none", "at-once", "rolling"])
             .help("The update strategy; [default: none].")
     };
 
+    let arg_sidecar = || {
+        Arg::with_name("sidecar")
+            .index(1)
+            .required(true)
+            .value_name("host:port")
+            .help("The HTTP API address of the Supervisor [default port: 9631]")
+    };
+
+    let arg_service_group = || {
+        Arg::with_name("service-group")
+            .long("service-group")
+            .value_name("service.group")
+            .help("The service to control, when the Supervisor runs more than one [default: the \
+                   first service it started]")
+    };
+
     let sub_start = SubCommand::with_name("start")
         .about("Start a Habitat-supervised service from a package or artifact")
         .aliases(&["st", "sta", "star"])
@@ -277,9 +432,25 @@ fn main() {
             .help("A Habitat package identifier (ex: acme/redis) or a filepath to a Habitat \
                    Artifact (ex: /home/acme-redis-3.0.7-21120102031201-x86_64-linux.hart)"))
         .arg(arg_url())
+        .arg(Arg::with_name("channel")
+            .long("channel")
+            .takes_value(true)
+            .help("Install and update from the latest release promoted to this depot channel"))
         .arg(arg_group())
         .arg(arg_org())
         .arg(arg_strategy())
+        .arg(Arg::with_name("update-grace")
+            .long("update-grace")
+            .value_name("seconds")
+            .takes_value(true)
+            .help("How long after an update a critical health check rolls it back \
+                   [default: 300]"))
+        .arg(Arg::with_name("health-check-interval")
+            .long("health-check-interval")
+            .value_name("seconds")
+            .takes_value(true)
+            .help("How often to run the health check; 0 to only run it on request \
+                   [default: 30]"))
         .arg(Arg::with_name("config-from")
             .short("C")
             .long("config-from")
@@ -295,6 +466,100 @@ fn main() {
             .value_name("bind")
             .multiple(true)
             .help("One or more service groups to bind to a configuration"))
+        .arg(Arg::with_name("services")
+            .long("service")
+            .value_name("ident[,setting=value]")
+            .multiple(true)
+            .number_of_values(1)
+            .help("Another package to run in this Supervisor; settings are topology=, group=, \
+                   bind=, trust=, restart=, restart-retries=, restart-backoff=, \
+                   restart-backoff-max=, memory-max=, cpu-max=, pids-max=, cgroup=, \
+                   stop-signal=, stop-timeout=, log-max-size=, log-max-age= and log-keep= \
+                   [default: standalone, the --group, restart always, the plan's limits and \
+                   stop settings, and the default log rotation]"))
+        .arg(Arg::with_name("trust-user")
+            .long("trust-user")
+            .value_name("user")
+            .multiple(true)
+            .number_of_values(1)
+            .help("A user allowed to change the service group's config and files, by name or \
+                   signing key name with revision; once given, changes must be signed by one"))
+        .arg(Arg::with_name("restart")
+            .long("restart")
+            .value_name("policy")
+            .takes_value(true)
+            .possible_values(&["always", "on-failure", "never"])
+            .help("When to restart the service's process after it exits on its own \
+                   [default: always]"))
+        .arg(Arg::with_name("restart-retries")
+            .long("restart-retries")
+            .value_name("count")
+            .takes_value(true)
+            .help("How many times in a row to restart a process that keeps exiting before \
+                   giving up [default: never give up]"))
+        .arg(Arg::with_name("restart-backoff")
+            .long("restart-backoff")
+            .value_name("seconds")
+            .takes_value(true)
+            .help("How long to wait before restarting the process; doubles each time it exits \
+                   again soon after starting [default: 1]"))
+        .arg(Arg::with_name("restart-backoff-max")
+            .long("restart-backoff-max")
+            .value_name("seconds")
+            .takes_value(true)
+            .help("The longest to wait before restarting the process; a process that stays up \
+                   this long has recovered [default: 60]"))
+        .arg(Arg::with_name("memory-max")
+            .long("memory-max")
+            .value_name("bytes")
+            .takes_value(true)
+            .help("The most memory the service may use, with an optional K, M or G suffix \
+                   [default: the plan's pkg_svc_memory_max, or unlimited]"))
+        .arg(Arg::with_name("cpu-max")
+            .long("cpu-max")
+            .value_name("cpus")
+            .takes_value(true)
+            .help("How many CPUs' worth of time the service may use, such as 0.5 \
+                   [default: the plan's pkg_svc_cpu_max, or unlimited]"))
+        .arg(Arg::with_name("pids-max")
+            .long("pids-max")
+            .value_name("count")
+            .takes_value(true)
+            .help("How many processes the service may run at once \
+                   [default: the plan's pkg_svc_pids_max, or unlimited]"))
+        .arg(Arg::with_name("cgroup")
+            .long("cgroup")
+            .help("Run the service in a cgroup of its own even without limits, so stopping it \
+                   kills every process it started"))
+        .arg(Arg::with_name("stop-signal")
+            .long("stop-signal")
+            .value_name("signal")
+            .takes_value(true)
+            .help("The signal that asks the service's process to stop, such as INT \
+                   [default: the plan's pkg_svc_stop_signal, or TERM]"))
+        .arg(Arg::with_name("stop-timeout")
+            .long("stop-timeout")
+            .value_name("seconds")
+            .takes_value(true)
+            .help("How long the process may take to stop before it is killed \
+                   [default: the plan's pkg_svc_stop_timeout, or 8]"))
+        .arg(Arg::with_name("log-max-size")
+            .long("log-max-size")
+            .value_name("bytes")
+            .takes_value(true)
+            .help("Rotate the service's log files once they grow past this size, with an \
+                   optional K, M or G suffix [default: 10M]"))
+        .arg(Arg::with_name("log-max-age")
+            .long("log-max-age")
+            .value_name("seconds")
+            .takes_value(true)
+            .help("Rotate the service's log files once they are this old [default: never]"))
+        .arg(Arg::with_name("log-keep")
+            .long("log-keep")
+            .value_name("count")
+            .takes_value(true)
+            .help("How many rotated log files to keep for each of the service's output \
+                   streams [default: 5]"))
         .arg(Arg::with_name("ring")
             .short("r")
             .long("ring")
@@ -316,7 +581,59 @@ fn main() {
         .arg(Arg::with_name("permanent-peer")
             .short("I")
             .long("permanent-peer")
-            .help("If this service is a permanent peer"));
+            .help("If this service is a permanent peer"))
+        .arg(Arg::with_name("gossip-interval")
+            .long("gossip-interval")
+            .value_name("ms")
+            .takes_value(true)
+            .help("How often to ping the next member of the ring [default: 200]"))
+        .arg(Arg::with_name("gossip-pingreq-fanout")
+            .long("gossip-pingreq-fanout")
+            .value_name("members")
+            .takes_value(true)
+            .help("How many members to ask to ping a member that did not answer [default: 5]"))
+        .arg(Arg::with_name("gossip-ping-timeout")
+            .long("gossip-ping-timeout")
+            .value_name("ms")
+            .takes_value(true)
+            .help("How long to wait for an ack, and then for a ping-req, before suspecting a \
+                   member [default: 5000]"))
+        .arg(Arg::with_name("gossip-suspicion-timeout")
+            .long("gossip-suspicion-timeout")
+            .value_name("ms")
+            .takes_value(true)
+            .help("The shortest time a member stays suspect before it is confirmed dead \
+                   [default: 10000]"))
+        .arg(Arg::with_name("gossip-suspicion-multiplier")
+            .long("gossip-suspicion-multiplier")
+            .value_name("n")
+            .takes_value(true)
+            .help("Wait this many times the suspicion timeout to confirm a member no one else \
+                   suspects; the wait shrinks as others suspect it too [default: 1]"))
+        .arg(Arg::with_name("gossip-departure-timeout")
+            .long("gossip-departure-timeout")
+            .value_name("ms")
+            .takes_value(true)
+            .help("How long a member stays confirmed dead before it is removed from the ring; \
+                   permanent peers are never removed [default: 259200000]"))
+        .arg(Arg::with_name("gossip-transport")
+            .long("gossip-transport")
+            .value_name("transport")
+            .takes_value(true)
+            .possible_values(&["utp", "udp"])
+            .help("Gossip over a uTP connection per message, or over UDP datagrams with a \
+                   periodic TCP push-pull [default: utp]"))
+        .arg(Arg::with_name("gossip-max-datagram")
+            .long("gossip-max-datagram")
+            .value_name("bytes")
+            .takes_value(true)
+            .help("The largest datagram to send when gossiping over UDP [default: 8192]"))
+        .arg(Arg::with_name("gossip-push-pull-interval")
+            .long("gossip-push-pull-interval")
+            .value_name("ms")
+            .takes_value(true)
+            .help("How often to swap every rumor with another member when gossiping over UDP \
+                   [default: 30000]"));
     let sub_bash = SubCommand::with_name("bash")
         .about("Start an interactive shell (bash)")
         .aliases(&["b", "ba", "bas"]);
@@ -328,6 +645,45 @@ fn main() {
             .index(1)
             .required(true)
             .help("Name of package"));
+    let sub_up = SubCommand::with_name("up")
+        .about("Start a supervised service's process, and keep it up")
+        .arg(arg_sidecar())
+        .arg(arg_service_group());
+    let sub_down = SubCommand::with_name("down")
+        .about("Stop a supervised service's process, and keep it down")
+        .arg(arg_sidecar())
+        .arg(arg_service_group());
+    let sub_restart = SubCommand::with_name("restart")
+        .about("Restart a supervised service's process")
+        .arg(arg_sidecar())
+        .arg(arg_service_group());
+    let sub_signal = SubCommand::with_name("signal")
+        .about("Send a Unix signal to a supervised service's process")
+        .arg(arg_sidecar())
+        .arg(Arg::with_name("signal")
+            .index(2)
+            .required(true)
+            .help("The signal to send: HUP, INT, QUIT, KILL, ALRM, TERM, USR1 or USR2"))
+        .arg(arg_service_group());
+    let sub_step_down = SubCommand::with_name("step-down")
+        .about("Hand leadership of a service group to another member, then stop the service's \
+                process")
+        .arg(arg_sidecar())
+        .arg(arg_service_group());
+    let sub_logs = SubCommand::with_name("logs")
+        .about("Show the most recent output of a supervised service's process")
+        .arg(arg_sidecar())
+        .arg(Arg::with_name("follow")
+            .short("f")
+            .long("follow")
+            .help("Keep showing the output as the process writes it"))
+        .arg(Arg::with_name("lines")
+            .short("n")
+            .long("lines")
+            .value_name("count")
+            .takes_value(true)
+            .help("How many of the most recent lines to show [default: 100]"))
+        .arg(arg_service_group());
     let args = App::new(sup::PROGRAM_NAME.as_str())
         .version(VERSION)
         .setting(AppSettings::VersionlessSubcommands)
@@ -340,10 +696,22 @@ fn main() {
             .long("no-color")
             .global(true)
             .help("Turn ANSI color off :("))
+        .arg(Arg::with_name("json")
+            .long("json")
+            .global(true)
+            .help("Write output as JSON lines"))
         .subcommand(sub_start)
         .subcommand(sub_bash)
         .subcommand(sub_sh)
-        .subcommand(sub_config);
+        .subcommand(sub_config)
+        .subcommand(sub_up)
+        .subcommand(sub_down)
+        .subcommand(sub_restart)
+        .subcommand(sub_signal)
+        .subcommand(sub_step_down)
+        .subcommand(sub_logs)
+        .after_help("\nThe up, down, restart, signal and step-down commands need \
+                     HAB_SUP_CONTROL_TOKEN set to the token the Supervisor was started with.");
     let matches = args.get_matches();
 
     debug!("clap matches {:?}", matches);
@@ -362,6 +730,12 @@ fn main() {
         Command::ShellSh => shell_sh(),
         Command::Config => configure(),
         Command::Start => start(),
+        Command::Up => control::up(),
+        Command::Down => control::down(),
+        Command::Restart => control::restart(),
+        Command::Signal => control::signal(),
+        Command::StepDown => control::step_down(),
+        Command::Logs => logs::logs(),
     };
 
     match result {
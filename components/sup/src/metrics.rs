@@ -0,0 +1,109 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Metrics in the Prometheus text exposition format.
+//!
+//! The sidecar renders the state of the Supervisor into `Metrics` on every scrape of `/metrics`;
+//! see the [Prometheus documentation](https://prometheus.io/docs/instrumenting/exposition_formats/)
+//! for the format itself.
+
+use std::fmt;
+
+/// The type of a metric.
+pub enum Kind {
+    Counter,
+    Gauge,
+}
+
+impl fmt::Display for Kind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let kind = match *self {
+            Kind::Counter => "counter",
+            Kind::Gauge => "gauge",
+        };
+        write!(f, "{}", kind)
+    }
+}
+
+/// A set of metrics, rendered as text by its `Display` implementation.
+pub struct Metrics {
+    body: String,
+}
+
+impl Metrics {
+    pub fn new() -> Metrics {
+        Metrics { body: String::new() }
+    }
+
+    /// Describe a metric. Call this once for each metric, before adding its samples.
+    pub fn describe(&mut self, name: &str, kind: Kind, help: &str) {
+        self.body.push_str(&format!("# HELP {} {}\n", name, help));
+        self.body.push_str(&format!("# TYPE {} {}\n", name, kind));
+    }
+
+    /// Add a sample of a metric, with optional labels.
+    pub fn sample(&mut self, name: &str, labels: &[(&str, &str)], value: f64) {
+        self.body.push_str(name);
+        if !labels.is_empty() {
+            let labels: Vec<String> = labels.iter()
+                .map(|&(k, v)| format!("{}=\"{}\"", k, escape(v)))
+                .collect();
+            self.body.push_str(&format!("{{{}}}", labels.join(",")));
+        }
+        self.body.push_str(&format!(" {}\n", value));
+    }
+
+    /// Describe a metric with a single, unlabeled sample.
+    pub fn single(&mut self, name: &str, kind: Kind, help: &str, value: f64) {
+        self.describe(name, kind, help);
+        self.sample(name, &[], value);
+    }
+}
+
+impl fmt::Display for Metrics {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.body)
+    }
+}
+
+/// Escape a label value.
+fn escape(value: &str) -> String {
+    value.replace("\\", "\\\\").replace("\"", "\\\"").replace("\n", "\\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Kind, Metrics};
+
+    #[test]
+    fn render() {
+        let mut metrics = Metrics::new();
+        metrics.single("hab_sup_process_restarts_total",
+                       Kind::Counter,
+                       "Restarts",
+                       3.0);
+        metrics.describe("hab_sup_census_population", Kind::Gauge, "Members");
+        metrics.sample("hab_sup_census_population",
+                       &[("service_group", "redis.default"), ("health", "al\"ive")],
+                       0.5);
+        assert_eq!(format!("{}", metrics),
+                   "# HELP hab_sup_process_restarts_total Restarts\n\
+                    # TYPE hab_sup_process_restarts_total counter\n\
+                    hab_sup_process_restarts_total 3\n\
+                    # HELP hab_sup_census_population Members\n\
+                    # TYPE hab_sup_census_population gauge\n\
+                    hab_sup_census_population{service_group=\"redis.default\",\
+                    health=\"al\\\"ive\"} 0.5\n");
+    }
+}
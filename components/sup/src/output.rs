@@ -20,15 +20,21 @@
 //! where the output was generated within the Supervisor. Also supported is a `preamble`, which is
 //! used to denote when output comes from a running service rather than the Supervisor itself.
 //!
-//! The `StructuredOutput` struct supports two global options - verbosity and coloring. If verbose
-//! is turned on, then every line printed is annotated with its preamble, logkey, and precise
-//! location. Without verbose, it prints simply the preamble and logkey. Coloring does what it says
-//! on the tin :)
+//! The `StructuredOutput` struct supports three global options - verbosity, coloring and JSON. If
+//! verbose is turned on, then every line printed is annotated with its preamble, logkey, and
+//! precise location. Without verbose, it prints simply the preamble and logkey. Coloring does what
+//! it says on the tin :)
+//!
+//! With JSON turned on, every line printed is a JSON object instead, so log shippers can parse it.
+//! Besides the content, it carries a timestamp, the preamble and logkey, and for output from a
+//! running service, its service group, our member ID, and the stream it came from.
 
 use std::sync::atomic::{AtomicBool, Ordering, ATOMIC_BOOL_INIT};
 use std::fmt;
 
 use ansi_term::Colour::{White, Cyan, Green};
+use rustc_serialize::json;
+use time;
 
 use PROGRAM_NAME;
 
@@ -36,6 +42,7 @@ static mut VERBOSE: AtomicBool = ATOMIC_BOOL_INIT;
 // I am sorry this isn't named the other way; I can't get an atomic initializer that defaults to
 // true. Them's the breaks.
 static mut NO_COLOR: AtomicBool = ATOMIC_BOOL_INIT;
+static mut JSON: AtomicBool = ATOMIC_BOOL_INIT;
 
 /// True if verbose output is on.
 pub fn is_verbose() -> bool {
@@ -67,6 +74,18 @@ pub fn set_no_color(booly: bool) {
     }
 }
 
+/// True if output is printed as JSON lines.
+pub fn is_json() -> bool {
+    unsafe { JSON.load(Ordering::Relaxed) }
+}
+
+/// Turn JSON output on or off.
+pub fn set_json(booly: bool) {
+    unsafe {
+        JSON.store(booly, Ordering::Relaxed);
+    }
+}
+
 /// Adds structure to printed output. Stores a preamble, a logkey, line, file, column, and content
 /// to print.
 pub struct StructuredOutput<'a> {
@@ -76,8 +95,28 @@ pub struct StructuredOutput<'a> {
     file: &'static str,
     column: u32,
     content: &'a str,
+    /// The service group a running service's output comes from
+    pub service_group: Option<&'a str>,
+    /// Our member ID in the service group
+    pub member_id: Option<&'a str>,
+    /// The stream a running service's output comes from, `stdout` or `stderr`
+    pub stream: Option<&'a str>,
     pub verbose: Option<bool>,
     pub color: Option<bool>,
+    pub json: Option<bool>,
+}
+
+/// A `StructuredOutput` as a line of JSON.
+#[derive(RustcEncodable)]
+struct JsonOutput<'a> {
+    timestamp: String,
+    preamble: &'a str,
+    logkey: &'a str,
+    service_group: Option<&'a str>,
+    member_id: Option<&'a str>,
+    stream: Option<&'a str>,
+    location: Option<String>,
+    content: &'a str,
 }
 
 impl<'a> StructuredOutput<'a> {
@@ -96,18 +135,45 @@ impl<'a> StructuredOutput<'a> {
             file: file,
             column: column,
             content: content,
+            service_group: None,
+            member_id: None,
+            stream: None,
             verbose: None,
             color: None,
+            json: None,
+        }
+    }
+
+    fn fmt_json(&self, f: &mut fmt::Formatter, verbose: bool) -> fmt::Result {
+        let location = if verbose {
+            Some(format!("{}:{}:{}", self.file, self.line, self.column))
+        } else {
+            None
+        };
+        let output = JsonOutput {
+            timestamp: time::now_utc().rfc3339().to_string(),
+            preamble: self.preamble,
+            logkey: self.logkey,
+            service_group: self.service_group,
+            member_id: self.member_id,
+            stream: self.stream,
+            location: location,
+            // Each JSON object is a line of its own
+            content: self.content.trim_right_matches(|c| c == '\n' || c == '\r'),
+        };
+        match json::encode(&output) {
+            Ok(encoded) => write!(f, "{}", encoded),
+            Err(_) => Err(fmt::Error),
         }
     }
 }
 
-// If we ever want to create multiple output formats in the future, we would do it here -
-// essentially create a flag we check to see what output you want, then call a different formatting
-// function. Viola!
 impl<'a> fmt::Display for StructuredOutput<'a> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let verbose = self.verbose.unwrap_or(is_verbose());
+        if self.json.unwrap_or(is_json()) {
+            return self.fmt_json(f, verbose);
+        }
         let color = self.color.unwrap_or(is_color());
         let preamble_color = if self.preamble == PROGRAM_NAME.as_str() {
             Cyan
@@ -151,6 +217,7 @@ impl<'a> fmt::Display for StructuredOutput<'a> {
 mod tests {
     use super::StructuredOutput;
     use ansi_term::Colour::{White, Cyan};
+    use rustc_serialize::json::Json;
 
     use PROGRAM_NAME;
 
@@ -176,6 +243,23 @@ mod tests {
         assert_eq!(format!("{}", so), "soup(SOT): opeth is amazing");
     }
 
+    #[test]
+    fn format_json() {
+        let mut so = so("redis", "ready to accept connections\n");
+        so.verbose = Some(false);
+        so.json = Some(true);
+        so.service_group = Some("redis.default");
+        so.stream = Some("stdout");
+        let output = Json::from_str(&format!("{}", so)).unwrap();
+        assert_eq!(output["preamble"].as_string(), Some("redis"));
+        assert_eq!(output["logkey"].as_string(), Some("SOT"));
+        assert_eq!(output["service_group"].as_string(), Some("redis.default"));
+        assert!(output["member_id"].is_null());
+        assert_eq!(output["stream"].as_string(), Some("stdout"));
+        assert_eq!(output["content"].as_string(), Some("ready to accept connections"));
+        assert!(output["timestamp"].is_string());
+    }
+
     #[test]
     fn format_color() {
         let progname = PROGRAM_NAME.as_str();
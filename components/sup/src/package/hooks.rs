@@ -14,6 +14,7 @@
 
 use std::fmt;
 use std::fs::{self, OpenOptions};
+use std::io::BufReader;
 use std::io::prelude::*;
 use std::os::unix::fs::OpenOptionsExt;
 use std::path::PathBuf;
@@ -30,6 +31,10 @@ use util::handlebars_helpers;
 use util::users as hab_users;
 
 pub const HOOK_PERMISSIONS: u32 = 0o755;
+/// The environment variable holding the term of the election that chose the current leader
+pub const ELECTION_TERM_ENVVAR: &'static str = "HAB_ELECTION_TERM";
+/// The environment variable holding the term of the election that chose the previous leader
+pub const PREVIOUS_ELECTION_TERM_ENVVAR: &'static str = "HAB_PREVIOUS_ELECTION_TERM";
 static LOGKEY: &'static str = "PH";
 
 #[derive(Debug, Clone)]
@@ -39,6 +44,9 @@ pub enum HookType {
     FileUpdated,
     Run,
     Init,
+    LeaderChanged,
+    PostRun,
+    PostStop,
 }
 
 impl fmt::Display for HookType {
@@ -49,6 +57,9 @@ impl fmt::Display for HookType {
             &HookType::FileUpdated => write!(f, "file_updated"),
             &HookType::Reconfigure => write!(f, "reconfigure"),
             &HookType::Run => write!(f, "run"),
+            &HookType::LeaderChanged => write!(f, "leader_changed"),
+            &HookType::PostRun => write!(f, "post_run"),
+            &HookType::PostStop => write!(f, "post_stop"),
         }
     }
 }
@@ -60,6 +71,8 @@ pub struct Hook {
     pub path: PathBuf,
     pub user: String,
     pub group: String,
+    /// The PATH the hook runs with: the package's run path
+    pub run_path: String,
 }
 
 impl Hook {
@@ -67,7 +80,8 @@ impl Hook {
                template: PathBuf,
                path: PathBuf,
                user: String,
-               group: String)
+               group: String,
+               run_path: String)
                -> Self {
         Hook {
             htype: htype,
@@ -75,16 +89,33 @@ impl Hook {
             path: path,
             user: user,
             group: group,
+            run_path: run_path,
         }
     }
 
     pub fn run(&self, context: Option<&ServiceConfig>) -> Result<String> {
+        self.run_with_env(context, &[])
+    }
+
+    /// Run the hook with extra environment variables. When we know the current election term, it
+    /// is always passed in `HAB_ELECTION_TERM`.
+    pub fn run_with_env(&self,
+                        context: Option<&ServiceConfig>,
+                        env: &[(&str, String)])
+                        -> Result<String> {
         try!(self.compile(context));
         let mut cmd = Command::new(&self.path);
         try!(self.run_platform(&mut cmd));
+        cmd.env("PATH", &self.run_path);
+        if let Some(term) = context.and_then(|ctx| ctx.current_election_term()) {
+            cmd.env(ELECTION_TERM_ENVVAR, term.to_string());
+        }
+        for &(key, ref value) in env.iter() {
+            cmd.env(key, value);
+        }
         let mut child = try!(cmd.spawn());
         {
-            let mut c_stdout = match child.stdout {
+            let c_stdout = match child.stdout {
                 Some(ref mut s) => s,
                 None => {
                     return Err(sup_error!(Error::HookFailed(self.htype.clone(),
@@ -93,25 +124,14 @@ impl Hook {
                 }
             };
             let preamble_str = format!("{}", &self.htype);
-            let mut line = output_format!(preamble & preamble_str, "");
-            loop {
-                let mut buf = [0u8; 1]; // Our byte buffer
-                let len = try!(c_stdout.read(&mut buf));
-                match len {
-                    0 => {
-                        // 0 == EOF, so stop writing and finish progress
-                        break;
-                    }
-                    _ => {
-                        // Write the buffer to the BufWriter on the Heap
-                        let buf_string = String::from_utf8_lossy(&buf[0..len]);
-                        line.push_str(&buf_string);
-                        if line.contains("\n") {
-                            print!("{}", line);
-                            line = output_format!(preamble & preamble_str, "");
-                        }
-                    }
-                }
+            let mut reader = BufReader::new(c_stdout);
+            let mut buffer = Vec::new();
+            // Hooks may write output that is not UTF-8, so we read bytes and convert each line
+            while try!(reader.read_until(b'\n', &mut buffer)) > 0 {
+                let line = String::from_utf8_lossy(&buffer).into_owned();
+                outputln!(preamble & preamble_str,
+                          line.trim_right_matches(|c| c == '\n' || c == '\r'));
+                buffer.clear();
             }
         }
         let exit_status = try!(child.wait());
@@ -190,6 +210,9 @@ pub struct HookTable<'a> {
     pub reconfigure_hook: Option<Hook>,
     pub file_updated_hook: Option<Hook>,
     pub run_hook: Option<Hook>,
+    pub leader_changed_hook: Option<Hook>,
+    pub post_run_hook: Option<Hook>,
+    pub post_stop_hook: Option<Hook>,
 }
 
 impl<'a> HookTable<'a> {
@@ -201,6 +224,9 @@ impl<'a> HookTable<'a> {
             reconfigure_hook: None,
             file_updated_hook: None,
             run_hook: None,
+            leader_changed_hook: None,
+            post_run_hook: None,
+            post_stop_hook: None,
         }
     }
 
@@ -214,6 +240,9 @@ impl<'a> HookTable<'a> {
                     self.reconfigure_hook = self.load_hook(HookType::Reconfigure);
                     self.health_check_hook = self.load_hook(HookType::HealthCheck);
                     self.run_hook = self.load_hook(HookType::Run);
+                    self.leader_changed_hook = self.load_hook(HookType::LeaderChanged);
+                    self.post_run_hook = self.load_hook(HookType::PostRun);
+                    self.post_stop_hook = self.load_hook(HookType::PostStop);
                 }
             }
             Err(_) => {}
@@ -228,7 +257,10 @@ impl<'a> HookTable<'a> {
             .expect("Can't determine user:group");
 
         match fs::metadata(&template) {
-            Ok(_) => Some(Hook::new(hook_type, template, concrete, user, group)),
+            Ok(_) => {
+                let run_path = self.package.run_path().expect("Can't determine the run path");
+                Some(Hook::new(hook_type, template, concrete, user, group, run_path))
+            }
             Err(_) => None,
         }
     }
@@ -34,7 +34,6 @@ use config::gconfig;
 use error::{Error, Result, SupError};
 use health_check::{self, CheckResult};
 use service_config::ServiceConfig;
-use supervisor::Supervisor;
 use util::path::busybox_paths;
 use util::users as hab_users;
 
@@ -44,6 +43,9 @@ const HEALTHCHECK_FILENAME: &'static str = "health_check";
 const FILEUPDATED_FILENAME: &'static str = "file_updated";
 const RECONFIGURE_FILENAME: &'static str = "reconfigure";
 const RUN_FILENAME: &'static str = "run";
+const LEADERCHANGED_FILENAME: &'static str = "leader_changed";
+const POSTRUN_FILENAME: &'static str = "post_run";
+const POSTSTOP_FILENAME: &'static str = "post_stop";
 
 #[derive(Debug, Clone)]
 pub struct Package {
@@ -125,6 +127,9 @@ impl Package {
             HookType::FileUpdated => base.join(FILEUPDATED_FILENAME),
             HookType::Reconfigure => base.join(RECONFIGURE_FILENAME),
             HookType::Run => base.join(RUN_FILENAME),
+            HookType::LeaderChanged => base.join(LEADERCHANGED_FILENAME),
+            HookType::PostRun => base.join(POSTRUN_FILENAME),
+            HookType::PostStop => base.join(POSTSTOP_FILENAME),
         }
     }
 
@@ -136,6 +141,9 @@ impl Package {
             HookType::FileUpdated => base.join(FILEUPDATED_FILENAME),
             HookType::Reconfigure => base.join(RECONFIGURE_FILENAME),
             HookType::Run => base.join(RUN_FILENAME),
+            HookType::LeaderChanged => base.join(LEADERCHANGED_FILENAME),
+            HookType::PostRun => base.join(POSTRUN_FILENAME),
+            HookType::PostStop => base.join(POSTSTOP_FILENAME),
         }
     }
 
@@ -306,9 +314,54 @@ impl Package {
         }
     }
 
+    /// Run leader_changed hook if present, with the terms of the elections that chose the old
+    /// and the new leader.
+    pub fn leader_changed(&self,
+                          context: &ServiceConfig,
+                          old_term: u32,
+                          new_term: u32)
+                          -> Result<bool> {
+        if let Some(hook) = self.hooks().leader_changed_hook {
+            let env = [(hooks::PREVIOUS_ELECTION_TERM_ENVVAR, old_term.to_string()),
+                       (hooks::ELECTION_TERM_ENVVAR, new_term.to_string())];
+            match hook.run_with_env(Some(context), &env) {
+                Ok(_) => Ok(true),
+                Err(e) => Err(e),
+            }
+        } else {
+            Ok(false)
+        }
+    }
+
+    /// Run post_run hook if present, once the service's process has started
+    pub fn post_run(&self, context: &ServiceConfig) -> Result<bool> {
+        if let Some(hook) = self.hooks().post_run_hook {
+            match hook.run(Some(context)) {
+                Ok(_) => Ok(true),
+                Err(e) => Err(e),
+            }
+        } else {
+            Ok(false)
+        }
+    }
+
+    /// Run post_stop hook if present, once the service's process has exited
+    pub fn post_stop(&self, context: &ServiceConfig) -> Result<bool> {
+        if let Some(hook) = self.hooks().post_stop_hook {
+            match hook.run(Some(context)) {
+                Ok(_) => Ok(true),
+                Err(e) => Err(e),
+            }
+        } else {
+            Ok(false)
+        }
+    }
+
+    /// Run the health_check hook, if the package has one, or report on the process's status,
+    /// the health and output of `Supervisor::status`, if it does not.
     pub fn health_check(&self,
                         config: &ServiceConfig,
-                        supervisor: &Supervisor)
+                        process_status: (bool, String))
                         -> Result<CheckResult> {
         if let Some(hook) = self.hooks().health_check_hook {
             match hook.run(Some(config)) {
@@ -331,7 +384,7 @@ impl Package {
                 Err(e) => Err(SupError::from(e)),
             }
         } else {
-            let (health, status) = supervisor.status();
+            let (health, status) = process_status;
             let last_config = try!(self.last_config());
             if health {
                 Ok(health_check::CheckResult::ok(format!("{}\n{}", status, last_config)))
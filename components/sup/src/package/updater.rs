@@ -12,30 +12,41 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::cmp;
+use std::fs;
 use std::sync::{Arc, RwLock};
 use std::path::Path;
 
 use depot_client::Client;
 use common::ui::UI;
 use hcore::crypto::default_cache_key_path;
-use hcore::fs::{CACHE_ARTIFACT_PATH, FS_ROOT_PATH};
-use hcore::package::PackageIdent;
+use hcore::fs::{CACHE_ARTIFACT_PATH, FS_ROOT_PATH, PKG_PATH};
+use hcore::package::{Identifiable, PackageIdent};
 use wonder;
 use wonder::actor::{GenServer, InitResult, HandleResult, ActorSender, ActorResult};
 
 use {PRODUCT, VERSION};
-use error::SupError;
+use error::{Result, SupError};
 use package::Package;
 
+static LOGKEY: &'static str = "PU";
 const TIMEOUT_MS: u64 = 60_000;
+const RETRY_MS: u64 = 5_000;
+const MAX_RETRY_MS: u64 = 600_000;
 
 pub type PackageUpdaterActor = wonder::actor::Actor<UpdaterMessage>;
 
 pub struct PackageUpdater;
 
 impl PackageUpdater {
-    pub fn start(url: &str, package: Arc<RwLock<Package>>) -> PackageUpdaterActor {
-        let state = UpdaterState::new(url.to_string(), package);
+    /// Start a package updater. If `version` is given, the updater is pinned to it: only releases
+    /// whose version is equal to it, or begins with it as a prefix (`3.2` matches `3.2.1`), are
+    /// considered updates.
+    pub fn start(url: &str,
+                 package: Arc<RwLock<Package>>,
+                 version: Option<String>)
+                 -> PackageUpdaterActor {
+        let state = UpdaterState::new(url.to_string(), package, version);
         wonder::actor::Builder::new(PackageUpdater)
             .name("package-updater".to_string())
             .start(state)
@@ -48,28 +59,136 @@ impl PackageUpdater {
     pub fn run(actor: &PackageUpdaterActor) -> ActorResult<()> {
         actor.cast(UpdaterMessage::Run)
     }
+
+    /// Tell a package updater never to update to the given release again; used when an update
+    /// has been rolled back.
+    pub fn skip(actor: &PackageUpdaterActor, ident: PackageIdent) -> ActorResult<()> {
+        actor.cast(UpdaterMessage::Skip(ident))
+    }
 }
 
 pub struct UpdaterState {
     pub depot: String,
     pub package: Arc<RwLock<Package>>,
+    pub version: Option<String>,
     pub status: UpdaterStatus,
+    /// Releases we must not update to again, because they were rolled back
+    pub skip: Vec<PackageIdent>,
+    /// How many times in a row we have failed to install an update
+    pub retries: u32,
 }
 
 impl UpdaterState {
-    pub fn new(depot: String, package: Arc<RwLock<Package>>) -> Self {
+    pub fn new(depot: String, package: Arc<RwLock<Package>>, version: Option<String>) -> Self {
         UpdaterState {
             depot: depot,
             package: package,
+            version: version,
             status: UpdaterStatus::Stopped,
+            skip: Vec::new(),
+            retries: 0,
+        }
+    }
+}
+
+/// Find the latest package in the depot that a service started as `ident` may run. If `ident`
+/// has a version, only releases of that version, or of versions within it when the version is a
+/// prefix (`3.2` matches `3.2.1`), are considered.
+///
+/// # Failures
+///
+/// * The depot is unavailable or cannot find the package
+pub fn latest_ident(depot_client: &Client, ident: &PackageIdent) -> Result<Option<PackageIdent>> {
+    match ident.version {
+        Some(ref version) => {
+            let unversioned =
+                PackageIdent::new(ident.origin.clone(), ident.name.clone(), None, None);
+            let idents = try!(depot_client.list_packages(&unversioned));
+            Ok(idents.into_iter()
+                .filter(|i| {
+                    i.fully_qualified() && version_matches(i.version.as_ref().unwrap(), version)
+                })
+                .max())
+        }
+        None => {
+            let remote = try!(depot_client.show_package(ident.clone()));
+            Ok(Some(remote.get_ident().clone().into()))
+        }
+    }
+}
+
+/// Returns the latest installed release of `ident` within its pinned version, or `None` if no
+/// release within it is installed. Unlike `Package::load`, a version prefix matches the versions
+/// within it.
+pub fn latest_installed(ident: &PackageIdent) -> Option<PackageIdent> {
+    let pin = match ident.version {
+        Some(ref version) => version,
+        None => return None,
+    };
+    let name_path = Path::new(FS_ROOT_PATH).join(PKG_PATH).join(&ident.origin).join(&ident.name);
+    let versions = match fs::read_dir(&name_path) {
+        Ok(versions) => versions,
+        Err(_) => return None,
+    };
+    let mut installed = Vec::new();
+    for version in versions.filter_map(|v| v.ok()) {
+        let version_name = version.file_name().to_string_lossy().into_owned();
+        if !version_matches(&version_name, pin) {
+            continue;
+        }
+        let releases = match fs::read_dir(version.path()) {
+            Ok(releases) => releases,
+            Err(_) => continue,
+        };
+        for release in releases.filter_map(|r| r.ok()) {
+            installed.push(PackageIdent::new(ident.origin.clone(),
+                                             ident.name.clone(),
+                                             Some(version_name.clone()),
+                                             Some(release.file_name()
+                                                 .to_string_lossy()
+                                                 .into_owned())));
         }
     }
+    installed.into_iter().max()
+}
+
+/// Download, verify and unpack a package from the depot, and load it.
+///
+/// # Failures
+///
+/// * The package cannot be downloaded
+/// * The package signature cannot be verified
+/// * The package cannot be unpacked or loaded
+fn download(depot_client: &Client, ident: &PackageIdent) -> Result<Package> {
+    let mut ui = UI::default();
+    let archive = try!(depot_client.fetch_package(ident.clone(),
+                                                  &Path::new(FS_ROOT_PATH)
+                                                      .join(CACHE_ARTIFACT_PATH),
+                                                  ui.progress()));
+    debug!("Updater downloaded new package to {:?}", archive);
+    try!(archive.verify(&default_cache_key_path(None)));
+    try!(archive.unpack(None));
+    Package::load(ident, None)
+}
+
+/// How long to wait before retrying a failed update; doubles with every failure, up to
+/// `MAX_RETRY_MS`.
+fn retry_timeout(retries: u32) -> u64 {
+    let backoff = RETRY_MS.saturating_mul(2u64.pow(cmp::min(retries, 16)));
+    cmp::min(backoff, MAX_RETRY_MS)
+}
+
+/// Returns true if `version` is the pinned version, or a version within it when the pin is a
+/// version prefix.
+fn version_matches(version: &str, pin: &str) -> bool {
+    version == pin || version.starts_with(&format!("{}.", pin))
 }
 
 #[derive(Debug)]
 pub enum UpdaterMessage {
     Ok,
     Run,
+    Skip(PackageIdent),
     Stop,
     Update(Package),
 }
@@ -95,10 +214,6 @@ impl GenServer for PackageUpdater {
                       state: &mut Self::S)
                       -> HandleResult<Self::T> {
         let package = state.package.read().unwrap();
-        // JW TODO: Store and use the version if the package was started with a specific version.
-        //          This will allow an operator to lock to a version and receive security updates
-        //          in the form of release updates for a package.
-        let ident = PackageIdent::new(package.origin.clone(), package.name.clone(), None, None);
         let depot_client = match Client::new(&state.depot, PRODUCT, VERSION, None) {
             Ok(client) => client,
             Err(e) => {
@@ -106,36 +221,44 @@ impl GenServer for PackageUpdater {
                 return HandleResult::NoReply(Some(TIMEOUT_MS));
             }
         };
-        match depot_client.show_package(ident) {
-            Ok(remote) => {
-                let latest_ident: PackageIdent = remote.get_ident().clone().into();
-                if &latest_ident > package.ident() {
-                    let mut ui = UI::default();
-                    match depot_client.fetch_package(latest_ident.clone(),
-                                                     &Path::new(FS_ROOT_PATH)
-                                                         .join(CACHE_ARTIFACT_PATH),
-                                                     ui.progress()) {
-                        Ok(archive) => {
-                            debug!("Updater downloaded new package to {:?}", archive);
-                            // JW TODO: actually handle verify and unpack results
-                            archive.verify(&default_cache_key_path(None)).unwrap();
-                            archive.unpack(None).unwrap();
-                            let latest_package = Package::load(&latest_ident, None).unwrap();
+        let ident = PackageIdent::new(package.origin.clone(),
+                                      package.name.clone(),
+                                      state.version.clone(),
+                                      None);
+        match latest_ident(&depot_client, &ident) {
+            Ok(Some(latest_ident)) => {
+                if state.skip.contains(&latest_ident) {
+                    debug!("Skipping {}; it was rolled back", &latest_ident);
+                    HandleResult::NoReply(Some(TIMEOUT_MS))
+                } else if &latest_ident > package.ident() {
+                    match download(&depot_client, &latest_ident) {
+                        Ok(latest_package) => {
+                            state.retries = 0;
                             state.status = UpdaterStatus::Stopped;
                             let msg = wonder::actor::Message::Cast(UpdaterMessage::Update(latest_package));
                             tx.send(msg).unwrap();
                             HandleResult::NoReply(None)
                         }
                         Err(e) => {
-                            debug!("Failed to download package: {:?}", e);
-                            HandleResult::NoReply(Some(TIMEOUT_MS))
+                            let timeout = retry_timeout(state.retries);
+                            state.retries += 1;
+                            outputln!("Failed to install update {}: {}; retrying in {}s",
+                                      &latest_ident,
+                                      e,
+                                      timeout / 1000);
+                            HandleResult::NoReply(Some(timeout))
                         }
                     }
                 } else {
                     debug!("Package found is not newer than ours");
+                    state.retries = 0;
                     HandleResult::NoReply(Some(TIMEOUT_MS))
                 }
             }
+            Ok(None) => {
+                debug!("No package found matching {}", &ident);
+                HandleResult::NoReply(Some(TIMEOUT_MS))
+            }
             Err(e) => {
                 debug!("Updater failed to get latest package: {:?}", e);
                 HandleResult::NoReply(Some(TIMEOUT_MS))
@@ -150,7 +273,17 @@ impl GenServer for PackageUpdater {
                    state: &mut Self::S)
                    -> HandleResult<Self::T> {
         match msg {
-            UpdaterMessage::Run => HandleResult::NoReply(Some(TIMEOUT_MS)),
+            UpdaterMessage::Run => {
+                state.status = UpdaterStatus::Running;
+                HandleResult::NoReply(Some(TIMEOUT_MS))
+            }
+            UpdaterMessage::Skip(ident) => {
+                state.skip.push(ident);
+                match state.status {
+                    UpdaterStatus::Running => HandleResult::NoReply(Some(TIMEOUT_MS)),
+                    UpdaterStatus::Stopped => HandleResult::NoReply(None),
+                }
+            }
             _ => {
                 match state.status {
                     UpdaterStatus::Running => HandleResult::NoReply(Some(TIMEOUT_MS)),
@@ -160,3 +293,25 @@ impl GenServer for PackageUpdater {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::{retry_timeout, version_matches};
+
+    #[test]
+    fn version_matches_exact_and_prefix() {
+        assert!(version_matches("3.2.1", "3.2.1"));
+        assert!(version_matches("3.2.1", "3.2"));
+        assert!(version_matches("3.2.10", "3"));
+        assert!(!version_matches("3.20.1", "3.2"));
+        assert!(!version_matches("4.0.0", "3"));
+    }
+
+    #[test]
+    fn retry_timeout_backs_off_to_a_cap() {
+        assert_eq!(retry_timeout(0), 5_000);
+        assert_eq!(retry_timeout(1), 10_000);
+        assert_eq!(retry_timeout(3), 40_000);
+        assert_eq!(retry_timeout(100), 600_000);
+    }
+}
@@ -44,7 +44,7 @@ static ENV_VAR_PREFIX: &'static str = "HAB";
 
 /// The top level struct for all our configuration - this corresponds to the top level namespaces
 /// available in `config.toml`.
-#[derive(Debug, RustcEncodable)]
+#[derive(Debug, Clone, RustcEncodable)]
 pub struct ServiceConfig {
     hab: Hab,
     pkg: Pkg,
@@ -52,6 +52,9 @@ pub struct ServiceConfig {
     cfg: Cfg,
     svc: Svc,
     bind: Bind,
+    // The term of the election that chose our service group's current leader, if there has been
+    // one
+    election_term: Option<u32>,
     // Keeps a list of the configuration files we have renders, and only re-writes them if they
     // have changed.
     config_hash: HashMap<String, String>,
@@ -77,6 +80,7 @@ impl ServiceConfig {
             cfg: cfg,
             svc: Svc::new(cl),
             bind: bind,
+            election_term: None,
             config_hash: HashMap::new(),
             needs_write: true,
         })
@@ -98,7 +102,7 @@ impl ServiceConfig {
         let cfg = self.cfg.to_toml();
         top.insert(String::from("cfg"), cfg);
 
-        let svc = self.svc.to_toml();
+        let svc = self.svc.to_toml(self.election_term);
         top.insert(String::from("svc"), svc);
 
         let bind = self.bind.to_toml();
@@ -119,6 +123,20 @@ impl ServiceConfig {
         self.needs_write = true
     }
 
+    /// Set the term of the election that chose our current leader, rendered as
+    /// `svc.election_term`.
+    pub fn election_term(&mut self, term: u32) {
+        if self.election_term != Some(term) {
+            self.election_term = Some(term);
+            self.needs_write = true
+        }
+    }
+
+    /// The term of the election that chose our current leader, if there has been one.
+    pub fn current_election_term(&self) -> Option<u32> {
+        self.election_term
+    }
+
     /// Replace the `svc` data.
     pub fn bind(&mut self, bindings: Vec<String>, cl: &CensusList) {
         // This is only safe because we will fail the first time if the bindings are badly
@@ -204,7 +222,7 @@ impl ServiceConfig {
     }
 }
 
-#[derive(Debug, RustcEncodable)]
+#[derive(Debug, Clone, RustcEncodable)]
 struct Bind {
     toml: toml::Table,
 }
@@ -245,7 +263,7 @@ impl Bind {
     }
 }
 
-#[derive(Debug, RustcEncodable)]
+#[derive(Debug, Clone, RustcEncodable)]
 struct Svc {
     toml: toml::Table,
 }
@@ -270,8 +288,12 @@ impl Svc {
         Svc { toml: top }
     }
 
-    fn to_toml(&self) -> toml::Value {
-        toml::Value::Table(self.toml.clone())
+    fn to_toml(&self, election_term: Option<u32>) -> toml::Value {
+        let mut toml = self.toml.clone();
+        if let Some(term) = election_term {
+            toml.insert("election_term".to_string(), toml::Value::Integer(term as i64));
+        }
+        toml::Value::Table(toml)
     }
 }
 
@@ -282,9 +304,13 @@ fn service_entry(census: &Census) -> toml::Table {
     let me = toml::encode(census.me());
     let leader = census.get_leader().map(|ce| toml::encode(ce));
     let mut members: Vec<toml::Value> = Vec::new();
+    let mut healthy_members: Vec<toml::Value> = Vec::new();
     let mut member_id = toml::Table::new();
     for (sg, ce) in census.iter() {
         members.push(toml::encode(ce));
+        if ce.is_healthy() {
+            healthy_members.push(toml::encode(ce));
+        }
         member_id.insert(format!("{}", sg), toml::encode(ce));
     }
     let mut result = toml::Table::new();
@@ -296,11 +322,13 @@ fn service_entry(census: &Census) -> toml::Table {
         result.insert("leader".to_string(), l);
     }
     result.insert("members".to_string(), toml::Value::Array(members));
+    result.insert("healthy_members".to_string(),
+                  toml::Value::Array(healthy_members));
     result.insert("member_id".to_string(), toml::Value::Table(member_id));
     result
 }
 
-#[derive(Debug, RustcEncodable)]
+#[derive(Debug, Clone, RustcEncodable)]
 struct Cfg {
     default: Option<toml::Value>,
     user: Option<toml::Value>,
@@ -476,7 +504,7 @@ impl Cfg {
     }
 }
 
-#[derive(Debug, RustcEncodable)]
+#[derive(Debug, Clone, RustcEncodable)]
 pub struct Pkg {
     pub origin: String,
     pub name: String,
@@ -578,7 +606,7 @@ impl Pkg {
     }
 }
 
-#[derive(Debug, RustcEncodable)]
+#[derive(Debug, Clone, RustcEncodable)]
 pub struct Sys {
     pub ip: String,
     pub hostname: String,
@@ -624,7 +652,7 @@ impl Sys {
     }
 }
 
-#[derive(Debug, RustcEncodable)]
+#[derive(Debug, Clone, RustcEncodable)]
 pub struct Hab {
     pub version: &'static str,
 }
@@ -716,6 +744,19 @@ mod test {
         assert!(re.is_match(&ip));
     }
 
+    #[test]
+    fn to_toml_svc_election_term() {
+        gcache(Config::new());
+        let pkg = gen_pkg();
+        let cl = gen_census_list();
+        let mut sc = ServiceConfig::new(&pkg, &cl, Vec::new()).unwrap();
+        assert!(sc.to_toml().unwrap().lookup("svc.election_term").is_none());
+        sc.election_term(3);
+        let toml = sc.to_toml().unwrap();
+        let term = toml.lookup("svc.election_term").unwrap().as_integer().unwrap();
+        assert_eq!(term, 3);
+    }
+
     #[test]
     fn merge_into_an_empty_start() {
         let override_config = "rando_key = \"rando_override\"
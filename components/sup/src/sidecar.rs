@@ -18,22 +18,58 @@
 //! Supports:
 //!
 //! * /config: Returns the current configuration of the service
+//! * /config/history: Returns the versions of the service group's gossiped configuration we have
+//!   applied
+//! * /config/diff/{from}/{to}: Returns the differences between two gossiped configuration versions
 //! * /health: Returns the current health of the service
-//! * /status: Returns the current status of the service, from the supervisors point of view
+//! * /health/history: Returns the results of the most recent health checks
+//! * /status: Returns the current status of the service, from the supervisors point of view,
+//!   with the most recent times its process exited on its own
+//! * /logs: Returns the most recent lines the service's process wrote; `?lines=` sets how many,
+//!   and `?follow=true` keeps the response open, streaming new lines as they are written; a few
+//!   callers at most may follow at once
+//! * /metrics: Returns metrics about the service and the Supervisor, in the Prometheus format
+//! * /services: Returns the services this Supervisor runs
+//!
+//! And, for callers holding the control token, these POST routes:
+//!
+//! * /control/up: Starts the service's process, and keeps it up
+//! * /control/down: Stops the service's process, and keeps it down; answers before it stops
+//! * /control/restart: Restarts the service's process; answers before it stops
+//! * /control/signal/{signal}: Sends a Unix signal, such as `HUP`, to the service's process
+//! * /control/step-down: Hands leadership of the service group to another member, then stops the
+//!   service's process
+//!
+//! When the Supervisor runs more than one service, the per-service routes above serve the first
+//! one; each service's are also served under `/services/{service_group}`, such as
+//! `/services/redis.default/health`.
 
 use std::collections::HashMap;
+use std::io;
+use std::io::prelude::*;
 use std::net::SocketAddrV4;
 use std::sync::{Arc, RwLock};
+use std::sync::atomic::{ATOMIC_USIZE_INIT, AtomicUsize, Ordering};
+use std::time::Duration;
 
+use std::str::FromStr;
+
+use hcore::env as henv;
+use hyper::mime::{Attr, Mime, SubLevel, TopLevel, Value};
 use rustc_serialize::json;
 use iron::prelude::*;
+use iron::headers::{Authorization, Bearer, ContentType};
+use iron::method::Method;
+use iron::response::{ResponseBody, WriteBody};
 use iron::status;
 use router::Router;
 use wonder;
 use wonder::actor::{GenServer, InitResult, HandleResult, StopReason, ActorSender};
 
 use error::{Error, SupError};
-use health_check;
+use health_check::{self, History};
+use logs::LogBuffer;
+use metrics::{Kind, Metrics};
 use package::Package;
 use common::gossip_file::{GossipFileList, FileWriteRetry};
 use service_config::ServiceConfig;
@@ -42,34 +78,79 @@ use gossip::rumor::RumorList;
 use gossip::detector::Detector;
 use census::{CensusList, CensusEntry, CensusEntryId, Census};
 use election::{Election, ElectionList};
-use supervisor::Supervisor;
+use supervisor::{Control, ProcessState, Supervisor};
+use util::signals::Signal;
 
 static LOGKEY: &'static str = "SI";
 const GET_HEALTH: &'static str = "/health";
+const GET_HEALTH_HISTORY: &'static str = "/health/history";
 const GET_CONFIG: &'static str = "/config";
+const GET_CONFIG_HISTORY: &'static str = "/config/history";
+const GET_CONFIG_DIFF: &'static str = "/config/diff/:from/:to";
 const GET_STATUS: &'static str = "/status";
 const GET_GOSSIP: &'static str = "/gossip";
 const GET_CENSUS: &'static str = "/census";
 const GET_ELECTION: &'static str = "/election";
+const GET_LOGS: &'static str = "/logs";
+const GET_METRICS: &'static str = "/metrics";
+const GET_SERVICES: &'static str = "/services";
+const POST_UP: &'static str = "/control/up";
+const POST_DOWN: &'static str = "/control/down";
+const POST_RESTART: &'static str = "/control/restart";
+const POST_SIGNAL: &'static str = "/control/signal/:signal";
+const POST_STEP_DOWN: &'static str = "/control/step-down";
+
+/// The environment variable holding the token callers of the control routes must present. The
+/// control routes are disabled when it is not set.
+pub const CONTROL_TOKEN_ENVVAR: &'static str = "HAB_SUP_CONTROL_TOKEN";
+/// How many lines /logs returns when the request does not say
+const DEFAULT_LOG_LINES: usize = 100;
+/// How long a /logs response that follows the output waits for new lines before checking again,
+/// in seconds
+const LOG_FOLLOW_WAIT: u64 = 5;
+/// The most /logs responses that may follow the output at once. Each holds one of the sidecar's
+/// threads, so we leave the rest to the other routes.
+const MAX_LOG_FOLLOWERS: usize = 4;
+
+/// How many /logs responses are following the output
+static LOG_FOLLOWERS: AtomicUsize = ATOMIC_USIZE_INIT;
 
 pub type SidecarActor = wonder::actor::Actor<SidecarMessage>;
 
 pub struct Sidecar;
 
-pub struct SidecarState {
-    /// The IP:Port where the sidecar listens
-    pub listen: SocketAddrV4,
-    /// The package this sidecar is helping out
+/// The parts of a supervised service the sidecar reports on.
+#[derive(Clone)]
+pub struct SidecarService {
+    /// The service group the service runs in
+    pub service_group: String,
+    /// The package the service runs
     pub package: Arc<RwLock<Package>>,
     /// The configuration of the supervised service
     pub config: Arc<RwLock<ServiceConfig>>,
-    pub member_list: Arc<RwLock<MemberList>>,
-    pub rumor_list: Arc<RwLock<RumorList>>,
+    pub supervisor: Arc<RwLock<Supervisor>>,
     pub census_list: Arc<RwLock<CensusList>>,
-    pub detector: Arc<RwLock<Detector>>,
     pub election_list: Arc<RwLock<ElectionList>>,
-    pub supervisor: Arc<RwLock<Supervisor>>,
     pub gossip_file_list: Arc<RwLock<GossipFileList>>,
+    pub health_check_history: Arc<RwLock<History>>,
+    /// Set to ask the service's topology to step down as the leader
+    pub step_down: Arc<RwLock<bool>>,
+}
+
+/// The services the sidecar reports on, in the order they were started.
+pub type Services = Arc<RwLock<Vec<SidecarService>>>;
+
+/// A route that reports on a single service.
+type ServiceHandler = fn(&SidecarService, &mut Request) -> IronResult<Response>;
+
+pub struct SidecarState {
+    /// The IP:Port where the sidecar listens
+    pub listen: SocketAddrV4,
+    pub member_list: Arc<RwLock<MemberList>>,
+    pub rumor_list: Arc<RwLock<RumorList>>,
+    pub detector: Arc<RwLock<Detector>>,
+    /// The services this sidecar is helping out
+    pub services: Services,
 }
 
 #[derive(Debug)]
@@ -80,54 +161,30 @@ pub enum SidecarMessage {
 
 impl SidecarState {
     pub fn new(listen: SocketAddrV4,
-               package: Arc<RwLock<Package>>,
-               config: Arc<RwLock<ServiceConfig>>,
                member_list: Arc<RwLock<MemberList>>,
                rumor_list: Arc<RwLock<RumorList>>,
-               census_list: Arc<RwLock<CensusList>>,
                detector: Arc<RwLock<Detector>>,
-               election_list: Arc<RwLock<ElectionList>>,
-               supervisor: Arc<RwLock<Supervisor>>,
-               gossip_file_list: Arc<RwLock<GossipFileList>>)
+               services: Services)
                -> Self {
         SidecarState {
             listen: listen,
-            package: package,
-            config: config,
             member_list: member_list,
             rumor_list: rumor_list,
-            census_list: census_list,
             detector: detector,
-            election_list: election_list,
-            supervisor: supervisor,
-            gossip_file_list: gossip_file_list,
+            services: services,
         }
     }
 }
 
 impl Sidecar {
-    /// Start the sidecar.
+    /// Start the sidecar. Services are added to `services` as they are started.
     pub fn start(listen: SocketAddrV4,
-                 package: Arc<RwLock<Package>>,
-                 config: Arc<RwLock<ServiceConfig>>,
                  member_list: Arc<RwLock<MemberList>>,
                  rumor_list: Arc<RwLock<RumorList>>,
-                 census_list: Arc<RwLock<CensusList>>,
                  detector: Arc<RwLock<Detector>>,
-                 election_list: Arc<RwLock<ElectionList>>,
-                 supervisor: Arc<RwLock<Supervisor>>,
-                 gossip_file_list: Arc<RwLock<GossipFileList>>)
+                 services: Services)
                  -> SidecarActor {
-        let state = SidecarState::new(listen,
-                                      package,
-                                      config,
-                                      member_list,
-                                      rumor_list,
-                                      census_list,
-                                      detector,
-                                      election_list,
-                                      supervisor,
-                                      gossip_file_list);
+        let state = SidecarState::new(listen, member_list, rumor_list, detector, services);
         wonder::actor::Builder::new(Sidecar).name("sidecar".to_string()).start(state).unwrap()
     }
 }
@@ -147,23 +204,30 @@ impl GenServer for Sidecar {
                       state: &mut Self::S)
                       -> HandleResult<Self::T> {
         let mut router = Router::new();
-        let package_1 = state.package.clone();
-        let package_2 = state.package.clone();
-        let config_1 = state.config.clone();
-
-        router.get(GET_CONFIG,
-                   move |r: &mut Request| config(&package_1, r),
-                   "config");
 
-        let supervisor_1 = state.supervisor.clone();
-        router.get(GET_STATUS,
-                   move |r: &mut Request| status(&supervisor_1, r),
-                   "status");
+        let routes: [(Method, &str, &str, ServiceHandler); 14] =
+            [(Method::Get, GET_CONFIG, "config", config),
+             (Method::Get, GET_CONFIG_HISTORY, "config_history", config_history),
+             (Method::Get, GET_CONFIG_DIFF, "config_diff", config_diff),
+             (Method::Get, GET_STATUS, "status", status),
+             (Method::Get, GET_HEALTH, "health", health),
+             (Method::Get, GET_HEALTH_HISTORY, "health_history", health_history),
+             (Method::Get, GET_CENSUS, "census", census),
+             (Method::Get, GET_ELECTION, "election", election),
+             (Method::Get, GET_LOGS, "logs", logs),
+             (Method::Post, POST_UP, "control_up", control_up),
+             (Method::Post, POST_DOWN, "control_down", control_down),
+             (Method::Post, POST_RESTART, "control_restart", control_restart),
+             (Method::Post, POST_SIGNAL, "control_signal", control_signal),
+             (Method::Post, POST_STEP_DOWN, "control_step_down", control_step_down)];
+        for &(ref method, path, id, handler) in routes.iter() {
+            service_route(&mut router, &state.services, method.clone(), path, id, handler);
+        }
 
-        let supervisor_2 = state.supervisor.clone();
-        router.get(GET_HEALTH,
-                   move |r: &mut Request| health(&package_2, &config_1, &supervisor_2, r),
-                   "health");
+        let services_1 = state.services.clone();
+        router.get(GET_SERVICES,
+                   move |r: &mut Request| services(&services_1, r),
+                   "services");
 
         let ml = state.member_list.clone();
         let rl = state.rumor_list.clone();
@@ -171,19 +235,18 @@ impl GenServer for Sidecar {
         let id = {
             Arc::new(ml.read().unwrap().my_id.clone())
         };
-        let gfl = state.gossip_file_list.clone();
+        let services_2 = state.services.clone();
 
         router.get(GET_GOSSIP,
-                   move |r: &mut Request| gossip(&ml, &rl, &gfl, &detector, &id, r),
+                   move |r: &mut Request| gossip(&ml, &rl, &services_2, &detector, &id, r),
                    "gossip");
 
-        let cl1 = state.census_list.clone();
-        router.get(GET_CENSUS, move |r: &mut Request| census(&cl1, r), "census");
-
-        let el = state.election_list.clone();
-        router.get(GET_ELECTION,
-                   move |r: &mut Request| election(&el, r),
-                   "election");
+        let rl2 = state.rumor_list.clone();
+        let detector_2 = state.detector.clone();
+        let services_3 = state.services.clone();
+        router.get(GET_METRICS,
+                   move |r: &mut Request| metrics(&services_3, &rl2, &detector_2, r),
+                   "metrics");
 
         match Iron::new(router).http(state.listen) {
             Ok(_) => HandleResult::NoReply(None),
@@ -194,14 +257,107 @@ impl GenServer for Sidecar {
     }
 }
 
+/// Routes `method` requests for `path` to the first service we run, and for
+/// `/services/:service{path}` to the service in the named service group.
+fn service_route(router: &mut Router,
+                 services: &Services,
+                 method: Method,
+                 path: &str,
+                 id: &str,
+                 handler: ServiceHandler) {
+    let services_1 = services.clone();
+    router.route(method.clone(),
+                 path,
+                 move |r: &mut Request| {
+                     let service = try!(find_service(&services_1, None));
+                     handler(&service, r)
+                 },
+                 id);
+    let services_2 = services.clone();
+    router.route(method,
+                 format!("{}/:service{}", GET_SERVICES, path),
+                 move |r: &mut Request| {
+                     let name = route_param(r, "service");
+                     let service = try!(find_service(&services_2, name));
+                     handler(&service, r)
+                 },
+                 format!("service_{}", id));
+}
+
+/// The value of the named parameter in the route of the request, if it has one.
+fn route_param(req: &Request, name: &str) -> Option<String> {
+    req.extensions
+        .get::<Router>()
+        .and_then(|params| params.find(name))
+        .map(|value| value.to_string())
+}
+
+/// An IronError that responds with `status`, and the error as the body.
+fn error_response(err: SupError, status: status::Status) -> IronError {
+    let body = err.to_string();
+    IronError {
+        error: Box::new(err),
+        response: Response::with((status, body)),
+    }
+}
+
+/// Find the service in the named service group, or the first service we run if there is no
+/// name.
+///
+/// # Failures
+///
+/// * We are not running the service
+fn find_service(services: &Services, name: Option<String>) -> IronResult<SidecarService> {
+    let services = services.read().unwrap();
+    let found = match name {
+        Some(ref name) => services.iter().find(|s| &s.service_group == name),
+        None => services.first(),
+    };
+    match found {
+        Some(service) => Ok(service.clone()),
+        None => {
+            let name = name.unwrap_or(String::from("any services yet"));
+            Err(error_response(sup_error!(Error::UnknownService(name)), status::NotFound))
+        }
+    }
+}
+
+#[derive(Debug, RustcEncodable)]
+struct ServiceResponse {
+    service_group: String,
+    package: String,
+    process: String,
+}
+
+/// The /services callback.
+///
+/// Returns the services this Supervisor runs, and the state of their processes.
+fn services(services: &Services, _req: &mut Request) -> IronResult<Response> {
+    let services = services.read().unwrap();
+    let response: Vec<ServiceResponse> = services.iter()
+        .map(|service| {
+            ServiceResponse {
+                service_group: service.service_group.clone(),
+                package: service.package.read().unwrap().ident().to_string(),
+                process: service.supervisor.read().unwrap().state.to_string(),
+            }
+        })
+        .collect();
+    let json_response = match json::encode(&response) {
+        Ok(json_response) => json_response,
+        Err(e) => return Err(IronError::from(sup_error!(Error::JsonEncode(e)))),
+    };
+    Ok(Response::with((status::Ok, json_response)))
+}
+
 #[derive(Debug, RustcEncodable)]
 struct ElectionResponse<'a> {
     elections: &'a HashMap<String, Election>,
     mine: Option<&'a Election>,
 }
 
-fn election(election_list: &Arc<RwLock<ElectionList>>, _req: &mut Request) -> IronResult<Response> {
-    let el = election_list.read().unwrap();
+fn election(service: &SidecarService, _req: &mut Request) -> IronResult<Response> {
+    let el = service.election_list.read().unwrap();
     let er = ElectionResponse {
         elections: &el.elections,
         mine: el.election(),
@@ -221,7 +377,7 @@ struct GossipResponse<'a> {
     member_list: &'a MemberList,
     rumor_list: &'a RumorList,
     detector: &'a Detector,
-    file_write_retries: &'a HashMap<String, FileWriteRetry>,
+    file_write_retries: HashMap<String, FileWriteRetry>,
 }
 
 /// The /gossip callback.
@@ -229,7 +385,7 @@ struct GossipResponse<'a> {
 /// Returns information about the gossip ring.
 fn gossip(member_list: &Arc<RwLock<MemberList>>,
           rumor_list: &Arc<RwLock<RumorList>>,
-          gossip_file_list: &Arc<RwLock<GossipFileList>>,
+          services: &Services,
           detector: &Arc<RwLock<Detector>>,
           id: &Arc<MemberId>,
           _req: &mut Request)
@@ -237,14 +393,20 @@ fn gossip(member_list: &Arc<RwLock<MemberList>>,
     let ml = member_list.read().unwrap();
     let rl = rumor_list.read().unwrap();
     let detector = detector.read().unwrap();
-    let gfl = gossip_file_list.read().unwrap();
+    let mut file_write_retries = HashMap::new();
+    for service in services.read().unwrap().iter() {
+        let gfl = service.gossip_file_list.read().unwrap();
+        for (file_name, retry) in gfl.file_write_retries.iter() {
+            file_write_retries.insert(file_name.clone(), retry.clone());
+        }
+    }
 
     let gossip_response = GossipResponse {
         id: id,
         member_list: &*ml,
         rumor_list: &*rl,
         detector: &*detector,
-        file_write_retries: &gfl.file_write_retries,
+        file_write_retries: file_write_retries,
     };
 
     let json_response = match json::encode(&gossip_response) {
@@ -269,8 +431,8 @@ struct CensusResponse<'a> {
 /// The /census callback.
 ///
 /// Returns information about the census.
-fn census(census_list: &Arc<RwLock<CensusList>>, _req: &mut Request) -> IronResult<Response> {
-    let cl = census_list.read().unwrap();
+fn census(service: &SidecarService, _req: &mut Request) -> IronResult<Response> {
+    let cl = service.census_list.read().unwrap();
     let response = CensusResponse {
         id: &cl.me().id.clone(),
         census_list: &cl,
@@ -296,12 +458,63 @@ fn census(census_list: &Arc<RwLock<CensusList>>, _req: &mut Request) -> IronResu
 /// # Failures
 ///
 /// * Fails if the configuration cannot be found.
-fn config(lock: &Arc<RwLock<Package>>, _req: &mut Request) -> IronResult<Response> {
-    let package = lock.read().unwrap();
+fn config(service: &SidecarService, _req: &mut Request) -> IronResult<Response> {
+    let package = service.package.read().unwrap();
     let last_config = try!(package.last_config());
     Ok(Response::with((status::Ok, last_config)))
 }
 
+/// The /config/history callback.
+///
+/// Returns the versions of the service group's gossiped configuration we have applied, oldest
+/// first.
+fn config_history(service: &SidecarService, _req: &mut Request) -> IronResult<Response> {
+    let gfl = service.gossip_file_list.read().unwrap();
+    let json_response = match json::encode(&gfl.config_history()) {
+        Ok(json_response) => json_response,
+        Err(e) => return Err(IronError::from(sup_error!(Error::JsonEncode(e)))),
+    };
+    Ok(Response::with((status::Ok, json_response)))
+}
+
+/// The /config/diff/:from/:to callback.
+///
+/// Returns a line by line diff between two versions of the service group's gossiped
+/// configuration.
+///
+/// # Failures
+///
+/// * A version number is not a number
+/// * We no longer remember, or never applied, one of the versions
+fn config_diff(service: &SidecarService, req: &mut Request) -> IronResult<Response> {
+    let from = try!(config_version_param(req, "from"));
+    let to = try!(config_version_param(req, "to"));
+    let gfl = service.gossip_file_list.read().unwrap();
+    let versions = (gfl.config_version(from), gfl.config_version(to));
+    match versions {
+        (Some(from), Some(to)) => Ok(Response::with((status::Ok, from.diff(to)))),
+        (None, _) => {
+            Err(error_response(sup_error!(Error::ConfigVersionNotFound(from)),
+                               status::NotFound))
+        }
+        (_, None) => {
+            Err(error_response(sup_error!(Error::ConfigVersionNotFound(to)), status::NotFound))
+        }
+    }
+}
+
+/// The configuration version number in the named route parameter.
+fn config_version_param(req: &Request, name: &str) -> IronResult<u64> {
+    let param = route_param(req, name).unwrap_or(String::new());
+    match param.parse::<u64>() {
+        Ok(version) => Ok(version),
+        Err(_) => {
+            Err(error_response(sup_error!(Error::InvalidConfigVersion(param)),
+                               status::BadRequest))
+        }
+    }
+}
+
 /// The /status callback.
 ///
 /// Returns the current status from the supervisors perspective.
@@ -309,12 +522,126 @@ fn config(lock: &Arc<RwLock<Package>>, _req: &mut Request) -> IronResult<Respons
 /// # Failures
 ///
 /// * Fails if the supervisor cannot return the status.
-fn status(lock: &Arc<RwLock<Supervisor>>, _req: &mut Request) -> IronResult<Response> {
-    let supervisor = lock.read().unwrap();
+fn status(service: &SidecarService, _req: &mut Request) -> IronResult<Response> {
+    let supervisor = service.supervisor.read().unwrap();
     let (_health, output) = supervisor.status();
     Ok(Response::with((status::Ok, output)))
 }
 
+/// The /logs callback.
+///
+/// Returns the most recent lines the service's process wrote to stdout or stderr, oldest first,
+/// as the Supervisor printed them. With `follow=true`, the response stays open, and new lines
+/// are sent as they are written, until the caller goes away.
+///
+/// # Failures
+///
+/// * The `lines` parameter is not a number
+/// * `MAX_LOG_FOLLOWERS` responses are following the output already
+fn logs(service: &SidecarService, req: &mut Request) -> IronResult<Response> {
+    let mut count = DEFAULT_LOG_LINES;
+    let mut follow = false;
+    let url = req.url.clone().into_generic_url();
+    for (key, value) in url.query_pairs() {
+        match &*key {
+            "lines" => {
+                count = match value.parse() {
+                    Ok(count) => count,
+                    Err(_) => {
+                        let err = sup_error!(Error::InvalidLogLines(value.into_owned()));
+                        return Err(error_response(err, status::BadRequest));
+                    }
+                }
+            }
+            "follow" => follow = value == "true",
+            _ => {}
+        }
+    }
+    let follower = if follow {
+        match Follower::new() {
+            Some(follower) => Some(follower),
+            None => {
+                return Ok(Response::with((status::ServiceUnavailable,
+                                          format!("{} callers are following the logs already; \
+                                                   try again later",
+                                                  MAX_LOG_FOLLOWERS))))
+            }
+        }
+    } else {
+        None
+    };
+    let buffer = service.supervisor.read().unwrap().runtime_config.logs.buffer.clone();
+    let body: Box<WriteBody + Send> = Box::new(LogStream {
+        buffer: buffer,
+        count: count,
+        follower: follower,
+    });
+    Ok(Response::with((status::Ok, body)))
+}
+
+/// The body of a /logs response.
+struct LogStream {
+    buffer: LogBuffer,
+    count: usize,
+    /// Set when the response follows the output
+    follower: Option<Follower>,
+}
+
+impl WriteBody for LogStream {
+    fn write_body(&mut self, res: &mut ResponseBody) -> io::Result<()> {
+        let (lines, mut next) = self.buffer.tail(self.count);
+        try!(write_lines(res, &lines));
+        while self.follower.is_some() {
+            let (lines, after) = self.buffer
+                .wait_since(next, Duration::from_secs(LOG_FOLLOW_WAIT));
+            if lines.is_empty() {
+                // We only find out the caller has gone away when a write fails, so we write a
+                // blank line, which `hab-sup logs` skips, each time we wait in vain
+                try!(res.write_all(b"\n"));
+                try!(res.flush());
+            } else {
+                try!(write_lines(res, &lines));
+            }
+            next = after;
+        }
+        Ok(())
+    }
+}
+
+/// Counts a /logs response that follows the output, for as long as it lives.
+struct Follower;
+
+impl Follower {
+    /// Count another follower, unless there are `MAX_LOG_FOLLOWERS` already.
+    fn new() -> Option<Follower> {
+        if LOG_FOLLOWERS.fetch_add(1, Ordering::SeqCst) >= MAX_LOG_FOLLOWERS {
+            LOG_FOLLOWERS.fetch_sub(1, Ordering::SeqCst);
+            return None;
+        }
+        Some(Follower)
+    }
+}
+
+impl Drop for Follower {
+    fn drop(&mut self) {
+        LOG_FOLLOWERS.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// Write lines of output in one chunk, so a follower sees them straight away.
+fn write_lines(res: &mut ResponseBody, lines: &[String]) -> io::Result<()> {
+    if lines.is_empty() {
+        return Ok(());
+    }
+    let mut chunk = String::new();
+    for line in lines {
+        chunk.push_str(line);
+        chunk.push('\n');
+    }
+    try!(res.write_all(chunk.as_bytes()));
+    res.flush()
+}
+
 /// The /health callback.
 ///
 /// Runs the packages health_check, if one is defined. Returns the status, and outputs both the
@@ -323,17 +650,11 @@ fn status(lock: &Arc<RwLock<Supervisor>>, _req: &mut Request) -> IronResult<Resp
 /// # Failures
 ///
 /// * If the health_check cannot be run.
-fn health(package_lock: &Arc<RwLock<Package>>,
-          config_lock: &Arc<RwLock<ServiceConfig>>,
-          supervisor_lock: &Arc<RwLock<Supervisor>>,
-          _req: &mut Request)
-          -> IronResult<Response> {
-    let result = {
-        let package = package_lock.read().unwrap();
-        let config = config_lock.read().unwrap();
-        let supervisor = supervisor_lock.read().unwrap();
-        try!(package.health_check(&config, &supervisor))
-    };
+fn health(service: &SidecarService, _req: &mut Request) -> IronResult<Response> {
+    let result = try!(health_check::run(&service.package,
+                                        &service.config,
+                                        &service.supervisor,
+                                        &service.health_check_history));
 
     match result.status {
         health_check::Status::Ok |
@@ -347,6 +668,310 @@ fn health(package_lock: &Arc<RwLock<Package>>,
     }
 }
 
+/// The /health/history callback.
+///
+/// Returns the results of the most recent health checks, oldest first.
+fn health_history(service: &SidecarService, _req: &mut Request) -> IronResult<Response> {
+    let history = service.health_check_history.read().unwrap();
+    let json_response = match json::encode(&*history) {
+        Ok(json_response) => json_response,
+        Err(e) => return Err(IronError::from(sup_error!(Error::JsonEncode(e)))),
+    };
+    Ok(Response::with((status::Ok, json_response)))
+}
+
+/// The /metrics callback.
+///
+/// Returns metrics about each supervised process, the census, the gossip ring and the last health
+/// checks, in the Prometheus text format. Metrics about a service are labelled with its service
+/// group.
+fn metrics(services: &Services,
+           rumor_list: &Arc<RwLock<RumorList>>,
+           detector: &Arc<RwLock<Detector>>,
+           _req: &mut Request)
+           -> IronResult<Response> {
+    let mut metrics = Metrics::new();
+    let services = services.read().unwrap();
+
+    metrics.describe("hab_sup_process_state",
+                     Kind::Gauge,
+                     "The state of the supervised process; 1 for the current state");
+    for service in services.iter() {
+        let supervisor = service.supervisor.read().unwrap();
+        let current = supervisor.state.to_string();
+        for state in &[ProcessState::Down,
+                       ProcessState::Up,
+                       ProcessState::Start,
+                       ProcessState::Restart,
+                       ProcessState::CrashLoop] {
+            let state = state.to_string();
+            let value = if state == current { 1.0 } else { 0.0 };
+            metrics.sample("hab_sup_process_state",
+                           &[("service_group", service.service_group.as_str()),
+                             ("state", state.as_str())],
+                           value);
+        }
+    }
+    metrics.describe("hab_sup_process_restarts_total",
+                     Kind::Counter,
+                     "How many times the supervised process has been restarted");
+    for service in services.iter() {
+        let supervisor = service.supervisor.read().unwrap();
+        metrics.sample("hab_sup_process_restarts_total",
+                       &[("service_group", service.service_group.as_str())],
+                       supervisor.restarts as f64);
+    }
+
+    // Every service's census list holds the same censuses, so any of them will do for the
+    // population
+    if let Some(service) = services.first() {
+        let cl = service.census_list.read().unwrap();
+        metrics.describe("hab_sup_census_population",
+                         Kind::Gauge,
+                         "Members of each service group in the census, by health");
+        for (service_group, census) in cl.iter() {
+            let counts = [("alive", census.values().filter(|ce| ce.alive).count()),
+                          ("suspect", census.values().filter(|ce| ce.suspect).count()),
+                          ("confirmed", census.values().filter(|ce| ce.confirmed).count()),
+                          ("detached", census.values().filter(|ce| ce.detached).count())];
+            for &(health, count) in counts.iter() {
+                metrics.sample("hab_sup_census_population",
+                               &[("service_group", service_group.as_str()), ("health", health)],
+                               count as f64);
+            }
+        }
+    }
+    metrics.describe("hab_sup_leader",
+                     Kind::Gauge,
+                     "1 if this member is the leader of its service group");
+    for service in services.iter() {
+        let leader = service.census_list.read().unwrap().me().leader;
+        metrics.sample("hab_sup_leader",
+                       &[("service_group", service.service_group.as_str())],
+                       if leader { 1.0 } else { 0.0 });
+    }
+    metrics.describe("hab_sup_follower",
+                     Kind::Gauge,
+                     "1 if this member is a follower in its service group");
+    for service in services.iter() {
+        let follower = service.census_list.read().unwrap().me().follower;
+        metrics.sample("hab_sup_follower",
+                       &[("service_group", service.service_group.as_str())],
+                       if follower { 1.0 } else { 0.0 });
+    }
+
+    {
+        let rl = rumor_list.read().unwrap();
+        metrics.single("hab_sup_rumors",
+                       Kind::Gauge,
+                       "Rumors known to this member",
+                       rl.rumors.len() as f64);
+        metrics.describe("hab_sup_rumor_heat",
+                         Kind::Gauge,
+                         "Total heat of the rumors shared with each member");
+        for (member_id, heat) in rl.heat.iter() {
+            let total = heat.values().fold(0, |total, h| total + h);
+            metrics.sample("hab_sup_rumor_heat",
+                           &[("member_id", member_id.simple().to_string().as_str())],
+                           total as f64);
+        }
+    }
+
+    {
+        let detector = detector.read().unwrap();
+        metrics.single("hab_sup_detector_inflight_pings",
+                       Kind::Gauge,
+                       "Pings the failure detector is waiting on",
+                       detector.open_requests.len() as f64);
+    }
+
+    metrics.describe("hab_sup_health_check_status",
+                     Kind::Gauge,
+                     "The status of the last health check; 1 for the current status");
+    for service in services.iter() {
+        if let Some(record) = service.health_check_history.read().unwrap().last() {
+            for status in &[health_check::Status::Ok,
+                            health_check::Status::Warning,
+                            health_check::Status::Critical,
+                            health_check::Status::Unknown] {
+                let value = if *status == record.result.status { 1.0 } else { 0.0 };
+                let label = format!("{:?}", status).to_lowercase();
+                metrics.sample("hab_sup_health_check_status",
+                               &[("service_group", service.service_group.as_str()),
+                                 ("status", label.as_str())],
+                               value);
+            }
+        }
+    }
+    metrics.describe("hab_sup_health_check_duration_seconds",
+                     Kind::Gauge,
+                     "How long the last health check took to run");
+    for service in services.iter() {
+        if let Some(record) = service.health_check_history.read().unwrap().last() {
+            let seconds = match record.duration.num_microseconds() {
+                Some(micros) => micros as f64 / 1_000_000.0,
+                None => record.duration.num_seconds() as f64,
+            };
+            metrics.sample("hab_sup_health_check_duration_seconds",
+                           &[("service_group", service.service_group.as_str())],
+                           seconds);
+        }
+    }
+
+    let mut response = Response::with((status::Ok, metrics.to_string()));
+    // The version of the text format, which Prometheus expects alongside the media type
+    response.headers.set(ContentType(Mime(TopLevel::Text,
+                                          SubLevel::Plain,
+                                          vec![(Attr::Ext(String::from("version")),
+                                                Value::Ext(String::from("0.0.4")))])));
+    Ok(response)
+}
+
+/// Check the request carries the control token as its bearer token.
+///
+/// # Failures
+///
+/// * The control routes are disabled, because there is no control token
+/// * The request's token is missing or wrong
+fn authorize(req: &Request) -> IronResult<()> {
+    let token = match henv::var(CONTROL_TOKEN_ENVVAR) {
+        Ok(ref token) if !token.is_empty() => token.clone(),
+        _ => return Err(error_response(sup_error!(Error::ControlTokenNotSet), status::Forbidden)),
+    };
+    match req.headers.get::<Authorization<Bearer>>() {
+        Some(&Authorization(Bearer { token: ref given })) if tokens_match(given, &token) => Ok(()),
+        _ => {
+            outputln!("Refused a control request from {}", req.remote_addr);
+            Err(error_response(sup_error!(Error::ControlUnauthorized), status::Unauthorized))
+        }
+    }
+}
+
+/// Compare two tokens, taking the same time wherever they differ.
+fn tokens_match(given: &str, expected: &str) -> bool {
+    if given.len() != expected.len() {
+        return false;
+    }
+    given.bytes().zip(expected.bytes()).fold(0, |diff, (a, b)| diff | (a ^ b)) == 0
+}
+
+/// Fails if the service's topology has not started its process yet, such as while it waits on an
+/// election; starting it from here would jump the queue.
+fn ensure_started(service: &SidecarService, supervisor: &Supervisor) -> IronResult<()> {
+    if supervisor.has_started {
+        Ok(())
+    } else {
+        let err = sup_error!(Error::ServiceNotStarted(service.service_group.clone()));
+        Err(error_response(err, status::Conflict))
+    }
+}
+
+/// The /control/up callback.
+///
+/// Starts the service's process, and brings it back up whenever it exits. A process that was
+/// crash looping is started straight away, and a down or restart asked for before is dropped.
+fn control_up(service: &SidecarService, req: &mut Request) -> IronResult<Response> {
+    try!(authorize(req));
+    let mut supervisor = service.supervisor.write().unwrap();
+    try!(ensure_started(service, &supervisor));
+    outputln!("Bringing {} up, as requested by {}",
+              service.service_group,
+              req.remote_addr);
+    supervisor.take_request();
+    supervisor.forget_failures();
+    try!(supervisor.start());
+    Ok(Response::with((status::Ok, supervisor.status().1)))
+}
+
+/// The /control/down callback.
+///
+/// Asks the service's topology to stop its process, and keep it down until it is brought up
+/// again. We answer without waiting for the process to stop.
+fn control_down(service: &SidecarService, req: &mut Request) -> IronResult<Response> {
+    try!(authorize(req));
+    let mut supervisor = service.supervisor.write().unwrap();
+    outputln!("Taking {} down, as requested by {}",
+              service.service_group,
+              req.remote_addr);
+    supervisor.request(Control::Down);
+    Ok(Response::with((status::Accepted, supervisor.status().1)))
+}
+
+/// The /control/restart callback.
+///
+/// Asks the service's topology to stop its process, and start it again. We answer without waiting
+/// for the process to stop.
+fn control_restart(service: &SidecarService, req: &mut Request) -> IronResult<Response> {
+    try!(authorize(req));
+    let mut supervisor = service.supervisor.write().unwrap();
+    try!(ensure_started(service, &supervisor));
+    outputln!("Restarting {}, as requested by {}",
+              service.service_group,
+              req.remote_addr);
+    supervisor.request(Control::Restart);
+    Ok(Response::with((status::Accepted, supervisor.status().1)))
+}
+
+/// The /control/signal/:signal callback.
+///
+/// Sends the signal to the service's process.
+///
+/// # Failures
+///
+/// * The signal is not one we know
+/// * The signal cannot be sent
+fn control_signal(service: &SidecarService, req: &mut Request) -> IronResult<Response> {
+    try!(authorize(req));
+    let name = route_param(req, "signal").unwrap_or(String::new());
+    let signal = match Signal::from_str(&name) {
+        Ok(signal) => signal,
+        Err(e) => return Err(error_response(e, status::BadRequest)),
+    };
+    let supervisor = service.supervisor.read().unwrap();
+    if supervisor.pid.is_none() {
+        let err = sup_error!(Error::ServiceNotStarted(service.service_group.clone()));
+        return Err(error_response(err, status::Conflict));
+    }
+    outputln!("Sending {:?} to {}, as requested by {}",
+              signal,
+              service.service_group,
+              req.remote_addr);
+    try!(supervisor.send_unix_signal(signal));
+    Ok(Response::with((status::Ok, supervisor.status().1)))
+}
+
+/// The /control/step-down callback.
+///
+/// Asks the service's topology to give up leadership of the service group. It calls an election
+/// it cannot win, waits for another member to be promoted, and then stops its process.
+///
+/// # Failures
+///
+/// * We are not the leader of the service group
+/// * No other member is alive to take over
+fn control_step_down(service: &SidecarService, req: &mut Request) -> IronResult<Response> {
+    try!(authorize(req));
+    {
+        let cl = service.census_list.read().unwrap();
+        let census = cl.local_census();
+        if !census.me().leader {
+            let err = sup_error!(Error::NotLeader(service.service_group.clone()));
+            return Err(error_response(err, status::Conflict));
+        }
+        if census.alive_population() < 2 {
+            let err = sup_error!(Error::NoLeaderCandidate(service.service_group.clone()));
+            return Err(error_response(err, status::Conflict));
+        }
+    }
+    outputln!("Stepping down as the leader of {}, as requested by {}",
+              service.service_group,
+              req.remote_addr);
+    *service.step_down.write().unwrap() = true;
+    Ok(Response::with((status::Ok,
+                       format!("Stepping down as the leader of {}",
+                               service.service_group))))
+}
+
 /// Translates SupErrors into IronErrors
 impl From<SupError> for IronError {
     fn from(err: SupError) -> IronError {
@@ -356,3 +981,16 @@ impl From<SupError> for IronError {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::tokens_match;
+
+    #[test]
+    fn tokens_must_match_exactly() {
+        assert!(tokens_match("s3cret", "s3cret"));
+        assert!(!tokens_match("s3cres", "s3cret"));
+        assert!(!tokens_match("s3cre", "s3cret"));
+        assert!(!tokens_match("", "s3cret"));
+    }
+}
@@ -16,27 +16,46 @@
 ///
 /// The supervisor is responsible for running any services we are asked to start. It handles
 /// spawning the new process, watching for failure, and ensuring the service is either up or down.
-/// If the process dies, the supervisor will restart it.
+/// If the process dies, the supervisor restarts it according to the service's restart policy,
+/// backing off further each time it dies again soon after starting. The package's `post_run`
+/// hook runs once the process has been spawned, without waiting for it to be ready, and its
+/// `post_stop` hook once it has exited.
 
+use std::cmp;
+use std::collections::VecDeque;
 use std::fmt;
 use std::fs::{self, File};
-use std::io::BufReader;
+use std::io;
 use std::io::prelude::*;
 use std::path::PathBuf;
-use std::process::{Command, Stdio, Child};
+use std::process::{Command, Stdio};
+use std::result;
+use std::str::FromStr;
+use std::sync::{mpsc, Arc, Mutex, RwLock};
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::thread;
+use std::time::Duration as StdDuration;
 
 use hcore;
-use hcore::package::PackageIdent;
+use hcore::package::{PackageIdent, PackageInstall};
 use libc::{pid_t, c_int};
-use time::{Duration, SteadyTime};
+use time::{self, Duration, SteadyTime, Tm};
 
-use error::{Result, Error};
+use cgroup::{self, Cgroup};
+use error::{Result, Error, SupError};
+use logs::ServiceLogs;
+use package::{HookType, Package};
+use service_config::ServiceConfig;
 use util::signals;
 use util::users as hab_users;
 
 const PIDFILE_NAME: &'static str = "PID";
 static LOGKEY: &'static str = "SV";
+/// How many times in a row a process has to exit soon after starting before we call it a crash
+/// loop
+const CRASH_LOOP_AFTER: u32 = 3;
+/// How many of the process's most recent exits we remember
+const EXIT_HISTORY_SIZE: usize = 10;
 
 // Functions from POSIX libc.
 extern "C" {
@@ -97,6 +116,24 @@ pub fn WTERMSIG(status: c_int) -> c_int {
     status & 0x7f
 }
 
+/// Switch the calling process to a user and group, as `CommandExt::uid` and `gid` would. We call
+/// this in the child between fork and exec, so it makes system calls and nothing else.
+#[cfg(any(target_os="linux", target_os="macos"))]
+fn drop_privileges(uid: u32, gid: u32) -> io::Result<()> {
+    use std::ptr;
+    use libc;
+    unsafe {
+        // Leave none of root's supplementary groups behind
+        if libc::getuid() == 0 && libc::setgroups(0, ptr::null()) != 0 {
+            return Err(io::Error::last_os_error());
+        }
+        if libc::setgid(gid) != 0 || libc::setuid(uid) != 0 {
+            return Err(io::Error::last_os_error());
+        }
+    }
+    Ok(())
+}
+
 pub type Pid = u32;
 
 #[derive(Debug)]
@@ -105,6 +142,9 @@ pub enum ProcessState {
     Up,
     Start,
     Restart,
+    /// The process keeps exiting soon after it starts; we are waiting to start it again, or have
+    /// given up
+    CrashLoop,
 }
 
 impl fmt::Display for ProcessState {
@@ -114,11 +154,198 @@ impl fmt::Display for ProcessState {
             &ProcessState::Up => "up",
             &ProcessState::Start => "start",
             &ProcessState::Restart => "restart",
+            &ProcessState::CrashLoop => "crash-loop",
         };
         write!(f, "{}", state)
     }
 }
 
+/// What someone asked us to do with a service's process, which the service's topology does the
+/// next time it checks on the process.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Control {
+    Down,
+    Restart,
+}
+
+/// When we start a service's process again after it exits on its own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Restart {
+    Always,
+    OnFailure,
+    Never,
+}
+
+impl FromStr for Restart {
+    type Err = SupError;
+
+    fn from_str(restart: &str) -> result::Result<Restart, SupError> {
+        match restart {
+            "always" => Ok(Restart::Always),
+            "on-failure" => Ok(Restart::OnFailure),
+            "never" => Ok(Restart::Never),
+            r => Err(sup_error!(Error::UnknownRestartPolicy(String::from(r)))),
+        }
+    }
+}
+
+impl fmt::Display for Restart {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let restart = match *self {
+            Restart::Always => "always",
+            Restart::OnFailure => "on-failure",
+            Restart::Never => "never",
+        };
+        write!(f, "{}", restart)
+    }
+}
+
+/// How we restart a service's process after it exits on its own.
+///
+/// Each time the process exits again soon after we started it, we wait twice as long as the last
+/// time before starting it again, up to `backoff_max`. A process that stays up for `backoff_max`
+/// has recovered, and the next time it exits we start counting again.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RestartPolicy {
+    pub restart: Restart,
+    /// How many times in a row we restart the process before giving up; `None` never gives up
+    pub max_retries: Option<u32>,
+    /// How long to wait before the first restart, in seconds
+    pub backoff: u64,
+    /// The longest we wait before a restart, in seconds
+    pub backoff_max: u64,
+}
+
+impl Default for RestartPolicy {
+    fn default() -> RestartPolicy {
+        RestartPolicy {
+            restart: Restart::Always,
+            max_retries: None,
+            backoff: 1,
+            backoff_max: 60,
+        }
+    }
+}
+
+impl RestartPolicy {
+    /// Whether we restart a process that exited with `status`.
+    pub fn restarts(&self, status: ExitStatus) -> bool {
+        match self.restart {
+            Restart::Always => true,
+            Restart::OnFailure => status.is_failure(),
+            Restart::Never => false,
+        }
+    }
+
+    /// How long to wait before starting the process again, once it has exited `exits` times in a
+    /// row.
+    pub fn delay(&self, exits: u32) -> Duration {
+        let doublings = cmp::min(exits.saturating_sub(1), 32);
+        let secs = self.backoff.saturating_mul(1u64 << doublings);
+        Duration::seconds(cmp::min(secs, self.backoff_max) as i64)
+    }
+}
+
+/// How a process ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExitStatus {
+    Code(c_int),
+    Signal(c_int),
+    Unknown,
+}
+
+impl ExitStatus {
+    fn from_wait_status(status: c_int) -> ExitStatus {
+        if WIFEXITED(status) {
+            ExitStatus::Code(WEXITSTATUS(status))
+        } else if WIFSIGNALED(status) {
+            ExitStatus::Signal(WTERMSIG(status))
+        } else {
+            ExitStatus::Unknown
+        }
+    }
+
+    /// Anything but exiting with code 0 is a failure.
+    pub fn is_failure(&self) -> bool {
+        *self != ExitStatus::Code(0)
+    }
+}
+
+impl fmt::Display for ExitStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ExitStatus::Code(code) => write!(f, "exit code {}", code),
+            ExitStatus::Signal(signal) => write!(f, "signal {}", signal),
+            ExitStatus::Unknown => write!(f, "an unknown status"),
+        }
+    }
+}
+
+/// A time the process exited on its own.
+#[derive(Debug, Clone)]
+pub struct Exit {
+    pub status: ExitStatus,
+    pub exited_at: Tm,
+    /// How long the process had been up
+    pub uptime: Duration,
+}
+
+impl fmt::Display for Exit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f,
+               "exited with {} at {} after {}s",
+               self.status,
+               self.exited_at.rfc3339(),
+               self.uptime.num_seconds())
+    }
+}
+
+
+/// How we stop a service's process: the signal that asks it to stop, and how long we give it to
+/// exit before we kill it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StopPolicy {
+    pub signal: signals::Signal,
+    /// How long we wait for the process to exit, in seconds
+    pub timeout: u64,
+}
+
+impl Default for StopPolicy {
+    fn default() -> StopPolicy {
+        StopPolicy {
+            signal: signals::Signal::SIGTERM,
+            timeout: 8,
+        }
+    }
+}
+
+impl StopPolicy {
+    /// The policy in a package's plan, with `signal` and `timeout` overriding it when given.
+    ///
+    /// # Failures
+    ///
+    /// * The plan's stop signal or timeout cannot be read, or is not valid
+    pub fn for_package(pkg_install: &PackageInstall,
+                       signal: Option<signals::Signal>,
+                       timeout: Option<u64>)
+                       -> Result<StopPolicy> {
+        let mut stop = StopPolicy::default();
+        if let Some(signal) = try!(pkg_install.svc_stop_signal()) {
+            stop.signal = try!(signals::Signal::from_str(&signal));
+        }
+        if let Some(timeout) = try!(pkg_install.svc_stop_timeout()) {
+            stop.timeout = try!(timeout.parse()
+                .map_err(|_| sup_error!(Error::InvalidStopTimeout(timeout.clone()))));
+        }
+        if let Some(signal) = signal {
+            stop.signal = signal;
+        }
+        if let Some(timeout) = timeout {
+            stop.timeout = timeout;
+        }
+        Ok(stop)
+    }
+}
 
 /// Additional params used to start the Supervisor.
 /// These params are outside the scope of what is in
@@ -128,17 +355,115 @@ impl fmt::Display for ProcessState {
 pub struct RuntimeConfig {
     pub svc_user: String,
     pub svc_group: String,
+    /// The cgroup the service's processes run in, if it has one
+    pub cgroup: Option<Cgroup>,
+    pub stop: StopPolicy,
+    /// Where the process's output goes
+    pub logs: ServiceLogs,
 }
 
 impl RuntimeConfig {
-    pub fn new(svc_user: String, svc_group: String) -> RuntimeConfig {
+    pub fn new(svc_user: String,
+               svc_group: String,
+               cgroup: Option<Cgroup>,
+               stop: StopPolicy,
+               logs: ServiceLogs)
+               -> RuntimeConfig {
         RuntimeConfig {
             svc_user: svc_user,
             svc_group: svc_group,
+            cgroup: cgroup,
+            stop: stop,
+            logs: logs,
+        }
+    }
+}
+
+/// Runs the package's hooks around its process. They are rendered with the service's current
+/// configuration, like the package's other hooks. Also gives the process the package's run path.
+///
+/// The hooks run on a thread of their own, one at a time in the order we ask for them, so that
+/// nobody waits on the supervisor's lock while a hook runs.
+#[derive(Debug)]
+pub struct ProcessHooks {
+    package: Arc<RwLock<Package>>,
+    service_config: Arc<RwLock<ServiceConfig>>,
+    /// Hands hooks to the thread that runs them
+    runner: Mutex<mpsc::Sender<(HookType, Package, ServiceConfig)>>,
+    /// How many of the hooks we asked for have not finished
+    pending: Arc<AtomicUsize>,
+}
+
+impl ProcessHooks {
+    pub fn new(package: Arc<RwLock<Package>>,
+               service_config: Arc<RwLock<ServiceConfig>>)
+               -> ProcessHooks {
+        let (tx, rx) = mpsc::channel();
+        let pending = Arc::new(AtomicUsize::new(0));
+        let running = pending.clone();
+        let _t = thread::Builder::new()
+            .name("process_hooks".to_string())
+            .spawn(move || run_hooks(rx, running));
+        ProcessHooks {
+            package: package,
+            service_config: service_config,
+            runner: Mutex::new(tx),
+            pending: pending,
+        }
+    }
+
+    /// The package's run path, which its process and hooks run with as their PATH.
+    fn run_path(&self) -> Result<String> {
+        self.package.read().unwrap().run_path()
+    }
+
+    /// Run the `post_run` hook, if the package has one. A hook that fails is logged; the process
+    /// carries on.
+    fn post_run(&self) {
+        self.queue(HookType::PostRun);
+    }
+
+    /// Run the `post_stop` hook, if the package has one. A hook that fails is logged; we still
+    /// start the process again if we should, once it has finished.
+    fn post_stop(&self) {
+        self.queue(HookType::PostStop);
+    }
+
+    /// Whether a hook we asked for is yet to finish.
+    fn running(&self) -> bool {
+        self.pending.load(Ordering::SeqCst) > 0
+    }
+
+    fn queue(&self, hook: HookType) {
+        let package = self.package.read().unwrap().clone();
+        let service_config = self.service_config.read().unwrap().clone();
+        self.pending.fetch_add(1, Ordering::SeqCst);
+        if let Err(e) = self.runner.lock().unwrap().send((hook, package, service_config)) {
+            let (hook, package, _) = e.0;
+            outputln!(preamble & package.name,
+                      "Not running the {} hook: its thread has gone",
+                      hook);
+            self.pending.fetch_sub(1, Ordering::SeqCst);
         }
     }
 }
 
+/// Run each hook we are handed, until the `ProcessHooks` that hands them to us is gone.
+fn run_hooks(hooks: mpsc::Receiver<(HookType, Package, ServiceConfig)>,
+             pending: Arc<AtomicUsize>) {
+    for (hook, package, service_config) in hooks {
+        let result = match hook {
+            HookType::PostRun => package.post_run(&service_config),
+            HookType::PostStop => package.post_stop(&service_config),
+            _ => Ok(false),
+        };
+        if let Err(e) = result {
+            outputln!(preamble & package.name, "The {} hook failed: {}", hook, e);
+        }
+        pending.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
 
 #[derive(Debug)]
 pub struct Supervisor {
@@ -147,18 +472,41 @@ pub struct Supervisor {
     pub state: ProcessState,
     pub state_entered: SteadyTime,
     pub has_started: bool,
+    /// How many times the process has been started again since it first started
+    pub restarts: u64,
     pub runtime_config: RuntimeConfig,
+    pub restart_policy: RestartPolicy,
+    /// How many times in a row the process has exited soon after starting
+    pub failures: u32,
+    /// The most recent times the process exited on its own, oldest first
+    pub exits: VecDeque<Exit>,
+    /// When to start the process again, after it exited on its own
+    next_start: Option<SteadyTime>,
+    /// What someone asked us to do with the process, that the topology has yet to do
+    requested: Option<Control>,
+    hooks: ProcessHooks,
 }
 
 impl Supervisor {
-    pub fn new(package_ident: PackageIdent, runtime_config: RuntimeConfig) -> Supervisor {
+    pub fn new(package_ident: PackageIdent,
+               runtime_config: RuntimeConfig,
+               restart_policy: RestartPolicy,
+               hooks: ProcessHooks)
+               -> Supervisor {
         Supervisor {
             pid: None,
             package_ident: package_ident,
             state: ProcessState::Down,
             state_entered: SteadyTime::now(),
             has_started: false,
+            restarts: 0,
             runtime_config: runtime_config,
+            restart_policy: restart_policy,
+            failures: 0,
+            exits: VecDeque::with_capacity(EXIT_HISTORY_SIZE),
+            next_start: None,
+            requested: None,
+            hooks: hooks,
         }
     }
 
@@ -167,41 +515,89 @@ impl Supervisor {
         self.state_entered = SteadyTime::now();
     }
 
+    /// Whether the process is healthy, and a line describing its state. When the process has
+    /// exited on its own, we follow with a line for each of its most recent exits.
     pub fn status(&self) -> (bool, String) {
-        let status = format!("{}: {} for {}",
-                             self.package_ident,
-                             self.state,
-                             SteadyTime::now() - self.state_entered);
+        let mut status = format!("{}: {} for {}",
+                                 self.package_ident,
+                                 self.state,
+                                 SteadyTime::now() - self.state_entered);
+        if self.failures > 0 {
+            status.push_str(&format!("; exited {} times in a row", self.failures));
+            match self.next_start {
+                Some(at) => {
+                    let wait = cmp::max(at - SteadyTime::now(), Duration::zero());
+                    status.push_str(&format!(", restarting in {}s", wait.num_seconds()));
+                }
+                None if self.pid.is_none() => status.push_str(", not restarting"),
+                None => {}
+            }
+        }
+        match self.requested {
+            Some(Control::Down) => status.push_str("; going down"),
+            Some(Control::Restart) => status.push_str("; restarting"),
+            None => {}
+        }
+        for exit in self.exits.iter() {
+            status.push_str(&format!("\n  {}", exit));
+        }
         let healthy = match self.state {
             ProcessState::Up | ProcessState::Start | ProcessState::Restart => true,
-            ProcessState::Down => false,
+            ProcessState::Down | ProcessState::CrashLoop => false,
         };
         (healthy, status)
     }
 
     pub fn start(&mut self) -> Result<()> {
-        if self.pid.is_none() {
+        if self.pid.is_none() && self.hooks.running() {
+            // The process's post_stop hook may still be cleaning up after it; we start the process
+            // once the hooks have finished, the next time it is due
+            outputln!(preamble & self.package_ident.name,
+                      "Starting once the running hooks finish");
+            self.enter_state(ProcessState::Up);
+            self.next_start = None;
+        } else if self.pid.is_none() {
             outputln!(preamble & self.package_ident.name, "Starting");
             self.enter_state(ProcessState::Start);
+            self.next_start = None;
 
             let mut cmd = Command::new(self.run_cmd());
             try!(self.start_platform(&mut cmd));
+            cmd.env("PATH", try!(self.hooks.run_path()));
             let mut child = try!(cmd.spawn());
 
             self.pid = Some(child.id());
             try!(self.create_pidfile());
-            let package_name = self.package_ident.name.clone();
-            try!(thread::Builder::new()
-                .name(String::from("sup-service-read"))
-                .spawn(move || -> Result<()> { child_reader(&mut child, package_name) }));
+            if let Some(stdout) = child.stdout.take() {
+                try!(self.capture_output("stdout", stdout));
+            }
+            if let Some(stderr) = child.stderr.take() {
+                try!(self.capture_output("stderr", stderr));
+            }
             self.enter_state(ProcessState::Up);
+            if self.has_started {
+                self.restarts += 1;
+            }
             self.has_started = true;
+            self.hooks.post_run();
         } else {
             outputln!(preamble & self.package_ident.name, "Already started");
         }
         Ok(())
     }
 
+    /// Read a stream of the process's output on a thread of its own until the process closes it.
+    fn capture_output<R>(&self, stream: &'static str, reader: R) -> Result<()>
+        where R: Read + Send + 'static
+    {
+        let logs = self.runtime_config.logs.clone();
+        let package_name = self.package_ident.name.clone();
+        try!(thread::Builder::new()
+            .name(format!("sup-service-{}", stream))
+            .spawn(move || -> Result<()> { logs.capture(&package_name, stream, reader) }));
+        Ok(())
+    }
+
     #[cfg(any(target_os="linux", target_os="macos"))]
     fn start_platform(&mut self, cmd: &mut Command) -> Result<()> {
         use std::os::unix::process::CommandExt;
@@ -219,9 +615,24 @@ impl Supervisor {
         let gid = gid.unwrap();
         cmd.stdin(Stdio::null())
             .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .uid(uid)
-            .gid(gid);
+            .stderr(Stdio::piped());
+        match self.runtime_config.cgroup {
+            // The process joins the cgroup before it execs the run hook, so nothing the hook
+            // starts escapes it. Only root may move it in, so it drops to the service's user and
+            // group after joining, rather than before as `uid` and `gid` would have it.
+            Some(ref cgroup) => {
+                let procs = try!(cgroup.procs_path());
+                unsafe {
+                    cmd.before_exec(move || {
+                        try!(cgroup::join(&procs));
+                        drop_privileges(uid, gid)
+                    });
+                }
+            }
+            None => {
+                cmd.uid(uid).gid(gid);
+            }
+        }
         Ok(())
     }
 
@@ -230,12 +641,14 @@ impl Supervisor {
         unimplemented!();
     }
 
-    /// Send a SIGTERM to a process, wait 8 seconds, then send SIGKILL
+    /// Send the stop signal to a process, wait for the stop timeout, then send SIGKILL. When the
+    /// service has a cgroup, we then kill any other process in it.
     pub fn stop(&mut self) -> Result<()> {
+        let stop = self.runtime_config.stop.clone();
         let wait = match self.pid {
             Some(ref pid) => {
                 outputln!(preamble & self.package_ident.name, "Stopping");
-                try!(signals::send_signal_to_pid(*pid, signals::Signal::SIGTERM));
+                try!(signals::send_signal_to_pid(*pid, stop.signal.clone()));
                 true
             }
             None => {
@@ -244,12 +657,14 @@ impl Supervisor {
             }
         };
         if wait {
-            let stop_time = SteadyTime::now() + Duration::seconds(8);
+            let stop_time = SteadyTime::now() + Duration::seconds(stop.timeout as i64);
             loop {
                 try!(self.check_process());
                 if SteadyTime::now() > stop_time {
                     outputln!(preamble & self.package_ident.name,
-                              "Process failed to stop with SIGTERM; sending SIGKILL");
+                              "Process failed to stop with {:?} after {}s; sending SIGKILL",
+                              stop.signal,
+                              stop.timeout);
                     if let Some(pid) = self.pid {
                         try!(signals::send_signal_to_pid(pid, signals::Signal::SIGKILL));
                     }
@@ -258,13 +673,27 @@ impl Supervisor {
                 if self.pid.is_none() {
                     break;
                 } else {
+                    thread::sleep(StdDuration::from_millis(10));
                     continue;
                 }
             }
         }
+        self.kill_cgroup();
         Ok(())
     }
 
+    /// Kill every process left in the service's cgroup, if it has one. We only log a failure;
+    /// the service itself has stopped, and the processes left are what is wrong.
+    fn kill_cgroup(&self) {
+        if let Some(ref cgroup) = self.runtime_config.cgroup {
+            if let Err(e) = cgroup.kill() {
+                outputln!(preamble & self.package_ident.name,
+                          "Failed to kill the processes left in its cgroup: {}",
+                          e);
+            }
+        }
+    }
+
     pub fn is_up(&self) -> bool {
         if let ProcessState::Up = self.state {
             true
@@ -281,8 +710,47 @@ impl Supervisor {
         }
     }
 
+    pub fn is_crash_looping(&self) -> bool {
+        if let ProcessState::CrashLoop = self.state {
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Whether the process should be up, isn't, and we are done waiting to start it again.
+    pub fn start_due(&self) -> bool {
+        if self.pid.is_some() || self.hooks.running() {
+            return false;
+        }
+        match self.state {
+            ProcessState::Up => self.next_start.map_or(true, |at| SteadyTime::now() >= at),
+            ProcessState::CrashLoop => self.next_start.map_or(false, |at| SteadyTime::now() >= at),
+            _ => false,
+        }
+    }
+
+    /// Forget that the process has been failing, so that the next time it exits we restart it
+    /// straight away; used when someone asks for the process to be started, stopped or restarted.
+    pub fn forget_failures(&mut self) {
+        self.failures = 0;
+        self.next_start = None;
+    }
+
+    /// Ask for the process to be taken down or restarted. Stopping the process can take as long as
+    /// its stop timeout, so we leave it to the topology rather than wait on it here.
+    pub fn request(&mut self, control: Control) {
+        self.requested = Some(control);
+    }
+
+    /// What someone asked us to do with the process, if anything, forgetting the request.
+    pub fn take_request(&mut self) -> Option<Control> {
+        self.requested.take()
+    }
+
     pub fn down(&mut self) -> Result<()> {
         self.enter_state(ProcessState::Down);
+        self.forget_failures();
         try!(self.stop());
         self.cleanup_pidfile();
         Ok(())
@@ -290,6 +758,7 @@ impl Supervisor {
 
     pub fn restart(&mut self) -> Result<()> {
         self.enter_state(ProcessState::Restart);
+        self.forget_failures();
         try!(self.stop());
         try!(self.start());
         Ok(())
@@ -335,7 +804,11 @@ impl Supervisor {
                                   pid);
                     }
                     match self.state {
-                        ProcessState::Up | ProcessState::Start | ProcessState::Restart => {
+                        ProcessState::Up | ProcessState::Start | ProcessState::CrashLoop => {
+                            self.pid = None;
+                            self.exited(ExitStatus::from_wait_status(status));
+                        }
+                        ProcessState::Restart => {
                             outputln!("{} - Service exited", self.package_ident.name);
                             self.pid = None;
                         }
@@ -344,6 +817,9 @@ impl Supervisor {
                             self.pid = None;
                         }
                     }
+                    // Don't leave anything the process started behind to meet the next one
+                    self.kill_cgroup();
+                    self.hooks.post_stop();
                 }
                 // ZOMBIES! Bad zombies! We listen for zombies. ZOMBOCOM!
                 pid => {
@@ -362,6 +838,62 @@ impl Supervisor {
         Ok(())
     }
 
+    /// The process exited on its own. We remember how, and decide whether and when to start it
+    /// again according to the restart policy.
+    fn exited(&mut self, status: ExitStatus) {
+        let uptime = SteadyTime::now() - self.state_entered;
+        if self.exits.len() >= EXIT_HISTORY_SIZE {
+            self.exits.pop_front();
+        }
+        self.exits.push_back(Exit {
+            status: status,
+            exited_at: time::now_utc(),
+            uptime: uptime,
+        });
+
+        let policy = self.restart_policy.clone();
+        if !policy.restarts(status) {
+            outputln!(preamble & self.package_ident.name,
+                      "Service exited with {}; not restarting, as the restart policy is {}",
+                      status,
+                      policy.restart);
+            self.forget_failures();
+            self.enter_state(ProcessState::Down);
+            return;
+        }
+        if uptime >= Duration::seconds(policy.backoff_max as i64) {
+            self.failures = 0;
+        }
+        self.failures += 1;
+        if let Some(max_retries) = policy.max_retries {
+            if self.failures > max_retries {
+                outputln!(preamble & self.package_ident.name,
+                          "Service exited with {}; giving up after {} restarts in a row",
+                          status,
+                          max_retries);
+                self.next_start = None;
+                self.enter_state(ProcessState::CrashLoop);
+                return;
+            }
+        }
+        let delay = policy.delay(self.failures);
+        self.next_start = Some(SteadyTime::now() + delay);
+        if self.failures >= CRASH_LOOP_AFTER {
+            outputln!(preamble & self.package_ident.name,
+                      "Service exited with {}, {} times in a row; crash looping, restarting in \
+                       {}s",
+                      status,
+                      self.failures,
+                      delay.num_seconds());
+            self.enter_state(ProcessState::CrashLoop);
+        } else {
+            outputln!(preamble & self.package_ident.name,
+                      "Service exited with {}; restarting in {}s",
+                      status,
+                      delay.num_seconds());
+        }
+    }
+
     pub fn run_cmd(&self) -> PathBuf {
         self.service_dir().join("run")
     }
@@ -435,22 +967,31 @@ impl Drop for Supervisor {
     }
 }
 
-/// Consume output from a child process until EOF, then finish
-fn child_reader(child: &mut Child, package_name: String) -> Result<()> {
-    let c_stdout = match child.stdout {
-        Some(ref mut s) => s,
-        None => return Err(sup_error!(Error::UnpackFailed)),
-    };
-
-    let mut reader = BufReader::new(c_stdout);
-    let mut buffer = String::new();
+#[cfg(test)]
+mod test {
+    use time::Duration;
+
+    use supervisor::{ExitStatus, Restart, RestartPolicy};
+
+    #[test]
+    fn restarts_by_policy() {
+        let mut policy = RestartPolicy::default();
+        assert!(policy.restarts(ExitStatus::Code(0)));
+        policy.restart = Restart::OnFailure;
+        assert!(!policy.restarts(ExitStatus::Code(0)));
+        assert!(policy.restarts(ExitStatus::Code(1)));
+        assert!(policy.restarts(ExitStatus::Signal(9)));
+        policy.restart = Restart::Never;
+        assert!(!policy.restarts(ExitStatus::Code(1)));
+    }
 
-    while reader.read_line(&mut buffer).unwrap() > 0 {
-        let mut line = output_format!(preamble &package_name, logkey "O");
-        line.push_str(&buffer);
-        print!("{}", line);
-        buffer.clear();
+    #[test]
+    fn backs_off_exponentially_up_to_the_cap() {
+        let policy = RestartPolicy { backoff: 2, backoff_max: 30, ..RestartPolicy::default() };
+        assert_eq!(policy.delay(1), Duration::seconds(2));
+        assert_eq!(policy.delay(2), Duration::seconds(4));
+        assert_eq!(policy.delay(4), Duration::seconds(16));
+        assert_eq!(policy.delay(5), Duration::seconds(30));
+        assert_eq!(policy.delay(100), Duration::seconds(30));
     }
-    debug!("child_reader exiting");
-    Ok(())
 }
@@ -23,7 +23,6 @@
 use error::{Result, SupError};
 use state_machine::StateMachine;
 use topology::{self, standalone, State, Worker};
-use package::Package;
 
 static LOGKEY: &'static str = "TI";
 
@@ -33,8 +32,7 @@ enum InitGate {
     Done,
 }
 
-pub fn run(package: Package) -> Result<()> {
-    let mut worker = try!(Worker::new(package, String::from("initializer")));
+pub fn run(mut worker: Worker) -> Result<()> {
     let mut sm: StateMachine<State, Worker, SupError> =
         StateMachine::new(State::DetermineViability);
     sm.add_dispatch(State::DetermineViability, state_determine_viability);
@@ -15,15 +15,11 @@
 use topology::{self, standalone, State, Worker};
 use state_machine::StateMachine;
 use error::{Result, SupError};
-use package::Package;
-use config::gconfig;
 use census::MIN_QUORUM;
-use gossip::server;
 
 static LOGKEY: &'static str = "TL";
 
-pub fn run(package: Package) -> Result<()> {
-    let mut worker = try!(Worker::new(package, String::from("leader")));
+pub fn run(mut worker: Worker) -> Result<()> {
     let mut sm: StateMachine<State, Worker, SupError> = StateMachine::new(State::Init);
     sm.add_dispatch(State::Init, state_init);
     sm.add_dispatch(State::MinimumQuorum, state_minimum_quorum);
@@ -35,10 +31,14 @@ pub fn run(package: Package) -> Result<()> {
     sm.add_dispatch(State::BecomeLeader, state_become_leader);
     sm.add_dispatch(State::BecomeFollower, state_become_follower);
     sm.add_dispatch(State::Starting, state_starting);
+    sm.add_dispatch(State::StepDown, state_step_down);
+    sm.add_dispatch(State::Handoff, state_handoff);
+    sm.add_dispatch(State::Demote, state_demote);
     topology::run_internal(&mut sm, &mut worker)
 }
 
 fn state_init(worker: &mut Worker) -> Result<(State, u64)> {
+    let quorum = has_quorum(worker);
     let cl = worker.census_list.read().unwrap();
     let census = cl.local_census();
     if !census.minimum_quorum() {
@@ -47,7 +47,7 @@ fn state_init(worker: &mut Worker) -> Result<(State, u64)> {
                   MIN_QUORUM,
                   );
         Ok((State::MinimumQuorum, 0))
-    } else if !census.has_quorum() {
+    } else if !quorum {
         outputln!("Waiting for quorum; {} of {} are alive",
                   census.alive_population(),
                   census.total_population());
@@ -73,9 +73,7 @@ fn state_minimum_quorum(worker: &mut Worker) -> Result<(State, u64)> {
 }
 
 fn state_waiting_for_quorum(worker: &mut Worker) -> Result<(State, u64)> {
-    let cl = worker.census_list.read().unwrap();
-    let census = cl.local_census();
-    if census.has_quorum() {
+    if has_quorum(worker) {
         outputln!("We have quorum!");
         Ok((worker.return_state.take().unwrap(), 0))
     } else {
@@ -94,19 +92,22 @@ fn state_restore_dataset(worker: &mut Worker) -> Result<(State, u64)> {
 }
 
 fn state_check_for_election(worker: &mut Worker) -> Result<(State, u64)> {
-    let (has_quorum, am_leader, am_follower, has_leader) = {
+    let quorum = has_quorum(worker);
+    let (am_leader, am_follower, has_leader) = {
         let cl = worker.census_list.read().unwrap();
         let census = cl.local_census();
-        let has_quorum = census.has_quorum();
         let am_leader = census.me().leader;
         let am_follower = census.me().follower;
         let has_leader = census.has_leader();
-        (has_quorum, am_leader, am_follower, has_leader)
+        (am_leader, am_follower, has_leader)
     };
+    let step_down = worker.take_step_down();
 
-    if has_quorum {
+    if quorum {
         if has_leader {
-            if am_leader || am_follower {
+            if am_leader && step_down {
+                Ok((State::StepDown, 0))
+            } else if am_leader || am_follower {
                 // Are you already the leader or a follower? - good on you!
                 Ok((State::CheckForElection, 500))
             } else {
@@ -115,69 +116,73 @@ fn state_check_for_election(worker: &mut Worker) -> Result<(State, u64)> {
                 // If you aren't, you should be a follower!
                 Ok((State::BecomeFollower, 0))
             }
+        } else if worker.election_list.read().unwrap().in_step_down() {
+            outputln!("The leader is stepping down; joining the election for a new one");
+            Ok((State::Election, 0))
         } else {
             outputln!("I have quorum, but no leader; starting an election!");
             Ok((State::StartElection, 0))
         }
     } else {
-        if am_leader {
-            outputln!("I have lost quorum, and I am the leader - I must abdicate!");
-            {
-                let mut cl = worker.census_list.write().unwrap();
-                let mut census = cl.local_census_mut();
-                census.in_event = true;
-                census.no_leaders_allowed();
-                let mut me = census.me_mut();
-                me.leader(false);
-                me.follower(false);
-            }
-            outputln!("Stopping the service to ensure there is only one master");
-            {
-                let mut supervisor = worker.supervisor.write().unwrap();
-                try!(supervisor.down());
-            }
-        } else {
-            outputln!("I have lost quorum - getting rid of any leader");
-            {
-                let mut cl = worker.census_list.write().unwrap();
-                let mut census = cl.local_census_mut();
-                census.in_event = true;
-                census.no_leaders_allowed();
-                let mut me = census.me_mut();
-                me.leader(false);
-                me.follower(false);
-            }
-        }
-        Ok((State::CheckForElection, 200))
+        Ok((State::Demote, 0))
     }
 }
 
+/// We have lost quorum, as far as our own failure detector can tell. Give up any leadership,
+/// stopping the service if we were the leader so there is only ever one, and wait for quorum to
+/// return before taking part in another election.
+fn state_demote(worker: &mut Worker) -> Result<(State, u64)> {
+    let was_leader = {
+        let mut cl = worker.census_list.write().unwrap();
+        let mut census = cl.local_census_mut();
+        census.in_event = true;
+        let was_leader = census.me().leader;
+        census.no_leaders_allowed();
+        let mut me = census.me_mut();
+        me.leader(false);
+        me.follower(false);
+        was_leader
+    };
+    if was_leader {
+        outputln!("I have lost quorum, and I am the leader - I must abdicate!");
+        outputln!("Stopping the service to ensure there is only one master");
+        let mut supervisor = worker.supervisor.write().unwrap();
+        try!(supervisor.down());
+    } else {
+        outputln!("I have lost quorum - getting rid of any leader");
+    }
+    worker.stepping_down = false;
+    worker.return_state = Some(State::CheckForElection);
+    Ok((State::WaitingForQuorum, 0))
+}
+
+/// Whether we have quorum, as far as our own failure detector can tell.
+fn has_quorum(worker: &Worker) -> bool {
+    let cl = worker.census_list.read().unwrap();
+    let ml = worker.member_list.read().unwrap();
+    cl.local_census().has_quorum_in(&ml)
+}
+
 pub fn state_start_election(worker: &mut Worker) -> Result<(State, u64)> {
     outputln!("Starting election");
     let rumor_list = {
         let el = worker.election_list.read().unwrap();
-        el.generate_rumor_list_for(worker.package_name.clone(), gconfig().group().to_string())
+        el.generate_rumor_list_for(worker.package_name.clone(), worker.spec.group.clone())
     };
-    server::process_rumors(rumor_list,
-                           worker.rumor_list.clone(),
-                           worker.member_list.clone(),
-                           worker.census_list.clone(),
-                           worker.election_list.clone(),
-                           worker.gossip_file_list.clone());
+    worker.gossip_server.process_rumors(rumor_list);
     Ok((State::Election, 200))
 }
 
 pub fn state_election(worker: &mut Worker) -> Result<(State, u64)> {
-    let (alive_population, has_quorum) = {
+    if !has_quorum(worker) {
+        outputln!("Lost quorum during the election");
+        return Ok((State::Demote, 0));
+    }
+    let alive_population = {
         let cl = worker.census_list.read().unwrap();
-        let census = cl.local_census();
-        (census.alive_population(), census.has_quorum())
+        cl.local_census().alive_population()
     };
 
-    if !has_quorum {
-        outputln!("Lost quorum during election - should have a state for this")
-    }
-
     let final_rumor_list = {
         let el = worker.election_list.read().unwrap();
         let election = match el.election() {
@@ -190,13 +195,15 @@ pub fn state_election(worker: &mut Worker) -> Result<(State, u64)> {
         if election.finished() {
             if el.member_id == election.leader_id {
                 return Ok((State::BecomeLeader, 0));
+            } else if worker.stepping_down {
+                return Ok((State::Handoff, 0));
             } else {
                 return Ok((State::BecomeFollower, 0));
             }
         } else {
             if election.should_finish(&el.member_id, alive_population) {
                 Some(el.finished_rumor_list_for(worker.package_name.clone(),
-                                                gconfig().group().to_string()))
+                                                worker.spec.group.clone()))
             } else {
                 None
             }
@@ -204,18 +211,72 @@ pub fn state_election(worker: &mut Worker) -> Result<(State, u64)> {
     };
 
     if let Some(rumor_list) = final_rumor_list {
-        server::process_rumors(rumor_list,
-                               worker.rumor_list.clone(),
-                               worker.member_list.clone(),
-                               worker.census_list.clone(),
-                               worker.election_list.clone(),
-                               worker.gossip_file_list.clone());
+        worker.gossip_server.process_rumors(rumor_list);
     }
 
     Ok((State::Election, 200))
 }
 
+/// Give up our leader flag, and call an election for a new term that we vote in but cannot win.
+pub fn state_step_down(worker: &mut Worker) -> Result<(State, u64)> {
+    outputln!("Stepping down as the leader; starting an election for a new one");
+    {
+        let mut cl = worker.census_list.write().unwrap();
+        let mut census = cl.local_census_mut();
+        census.in_event = true;
+        let mut me = census.me_mut();
+        me.leader(false);
+    }
+    let rumor_list = {
+        let el = worker.election_list.read().unwrap();
+        el.step_down_rumor_list_for(worker.package_name.clone(), worker.spec.group.clone())
+    };
+    worker.gossip_server.process_rumors(rumor_list);
+    worker.stepping_down = true;
+    Ok((State::Election, 200))
+}
+
+/// Wait for the member that won the election to be promoted, then follow it and drain our
+/// process. It stays down until it is brought up through the sidecar.
+pub fn state_handoff(worker: &mut Worker) -> Result<(State, u64)> {
+    {
+        let cl = worker.census_list.read().unwrap();
+        let census = cl.local_census();
+        if !census.has_leader() {
+            debug!("Waiting for the new leader to be promoted");
+            return Ok((State::Handoff, 200));
+        }
+    }
+    outputln!("A new leader has been promoted; becoming a follower and stopping the service");
+    {
+        let mut cl = worker.census_list.write().unwrap();
+        {
+            let mut ce = cl.me_mut();
+            ce.leader(false);
+            ce.follower(true);
+            if ce.election.is_some() {
+                ce.election(None);
+            }
+            if ce.vote.is_some() {
+                ce.vote(None);
+            }
+        }
+        let mut census = cl.local_census_mut();
+        census.in_event = false;
+    }
+    worker.stepping_down = false;
+    {
+        let mut supervisor = worker.supervisor.write().unwrap();
+        try!(supervisor.down());
+    }
+    Ok((State::CheckForElection, 200))
+}
+
 pub fn state_become_leader(worker: &mut Worker) -> Result<(State, u64)> {
+    if !has_quorum(worker) {
+        outputln!("Won the election, but lost quorum; refusing to lead");
+        return Ok((State::Demote, 0));
+    }
     {
         let mut cl = worker.census_list.write().unwrap();
         let mut census = cl.local_census_mut();
@@ -263,6 +324,13 @@ pub fn state_become_follower(worker: &mut Worker) -> Result<(State, u64)> {
 }
 
 pub fn state_starting(worker: &mut Worker) -> Result<(State, u64)> {
+    let am_leader = {
+        let cl = worker.census_list.read().unwrap();
+        cl.local_census().me().leader
+    };
+    if am_leader && !has_quorum(worker) {
+        return Ok((State::Demote, 0));
+    }
     let is_running = {
         let supervisor = worker.supervisor.read().unwrap();
         supervisor.pid.is_some()
@@ -20,45 +20,55 @@
 //! 1. Processing the main event loop
 //! 1. Registering callbacks with the [discovery](../discovery) system
 //!
-//! Typically, topologies are created from the [start](../command/start) command.
+//! Typically, topologies are created from the [start](../command/start) command. A Supervisor may
+//! run several services, each with its own topology in its own thread; they share one gossip
+//! server and sidecar.
 
 pub mod standalone;
 pub mod leader;
 pub mod initializer;
 
+use std::fmt;
 use std::mem;
 use std::net::SocketAddrV4;
 use std::ops::DerefMut;
+use std::result;
 use std::str::FromStr;
-use std::sync::{Arc, RwLock};
-use std::sync::mpsc::TryRecvError;
+use std::sync::{mpsc, Arc, RwLock};
+use std::sync::mpsc::{Receiver, Sender, TryRecvError};
 use std::thread;
 use std::time::Duration;
 
+use hcore::package::PackageIdent;
 use wonder;
 
 use state_machine::StateMachine;
 use census::{self, CensusList};
+use cgroup::{Cgroup, Limits};
 use common::gossip_file::GossipFileList;
 use package::{self, Package, PackageUpdaterActor};
 use util::signals::SignalNotifier;
-use error::{Result, SupError};
-use config::{gconfig, UpdateStrategy};
+use error::{Error, Result, SupError};
+use config::{gconfig, ServiceSpec, UpdateStrategy};
 use service_config::ServiceConfig;
 use sidecar;
-use supervisor::{RuntimeConfig, Supervisor};
+use supervisor::{Control, ProcessHooks, RuntimeConfig, StopPolicy, Supervisor};
 use gossip;
-use gossip::rumor::{Rumor, RumorList};
+use gossip::rumor::RumorList;
 use gossip::member::MemberList;
+use gossip::server::ServiceLists;
 use election::ElectionList;
-use time::SteadyTime;
+use health_check::{self, CheckResult};
+use logs::ServiceLogs;
+use time::{self, SteadyTime};
 use util::signals;
 use util::users as hab_users;
 
 static LOGKEY: &'static str = "TP";
 static MINIMUM_LOOP_TIME_MS: i64 = 200;
+static UPDATE_CHECK_SECS: i64 = 5;
 
-#[derive(PartialEq, Eq, Debug, RustcEncodable)]
+#[derive(PartialEq, Eq, Debug, Clone, Copy, RustcEncodable)]
 pub enum Topology {
     Standalone,
     Leader,
@@ -71,6 +81,30 @@ impl Default for Topology {
     }
 }
 
+impl FromStr for Topology {
+    type Err = SupError;
+
+    fn from_str(topology: &str) -> result::Result<Topology, SupError> {
+        match topology {
+            "standalone" => Ok(Topology::Standalone),
+            "leader" => Ok(Topology::Leader),
+            "initializer" => Ok(Topology::Initializer),
+            t => Err(sup_error!(Error::UnknownTopology(String::from(t)))),
+        }
+    }
+}
+
+impl fmt::Display for Topology {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let topology = match *self {
+            Topology::Standalone => "standalone",
+            Topology::Leader => "leader",
+            Topology::Initializer => "initializer",
+        };
+        write!(f, "{}", topology)
+    }
+}
+
 /// Viable states for the topologies. Not every topology will implement every state.
 #[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
 pub enum State {
@@ -85,6 +119,9 @@ pub enum State {
     Election,
     StartElection,
     InElection,
+    StepDown,
+    Handoff,
+    Demote,
     Leader,
     Follower,
     Configure,
@@ -93,6 +130,32 @@ pub enum State {
     Running,
 }
 
+/// Watches a service after its package was updated. If its health check turns critical before
+/// `grace_until`, or while it is our turn in a rolling update, the update is rolled back to the
+/// `previous` package.
+pub struct UpdateWatch {
+    /// The package we were running before the update
+    pub previous: PackageIdent,
+    /// The package we updated to
+    pub updated: PackageIdent,
+    /// Until when a critical health check rolls the update back
+    pub grace_until: SteadyTime,
+    /// When we next run the health check
+    pub next_check: SteadyTime,
+}
+
+impl UpdateWatch {
+    pub fn new(previous: PackageIdent, updated: PackageIdent) -> UpdateWatch {
+        let now = SteadyTime::now();
+        UpdateWatch {
+            previous: previous,
+            updated: updated,
+            grace_until: now + time::Duration::seconds(gconfig().update_grace() as i64),
+            next_check: now + time::Duration::seconds(UPDATE_CHECK_SECS),
+        }
+    }
+}
+
 /// The topology `Worker` is where everything our state machine needs between states lives.
 pub struct Worker {
     /// The package we are supervising
@@ -101,30 +164,52 @@ pub struct Worker {
     pub package_name: String,
     /// The topology we are running
     pub topology: String,
+    /// The service we are running, as it was given to us on the command line
+    pub spec: ServiceSpec,
     /// Our Service Configuration; manages changes to our configuration,
     pub service_config: Arc<RwLock<ServiceConfig>>,
-    /// The Gossip Server; listens for inbound gossip traffic
-    pub gossip_server: gossip::server::Server,
+    /// The Gossip Server; listens for inbound gossip traffic, shared with our other services
+    pub gossip_server: Arc<gossip::server::Server>,
     pub census_list: Arc<RwLock<CensusList>>,
     pub rumor_list: Arc<RwLock<RumorList>>,
     pub election_list: Arc<RwLock<ElectionList>>,
     pub member_list: Arc<RwLock<MemberList>>,
     pub gossip_file_list: Arc<RwLock<GossipFileList>>,
-    /// Our Sidecar Actor; exposes a restful HTTP interface to the outside world
-    pub sidecar_actor: sidecar::SidecarActor,
     /// Watches a package Depot for updates and signals the main thread when an update is available. Optionally
     /// started if a value is passed for the url option on startup.
     pub pkg_updater: Option<PackageUpdaterActor>,
+    /// An updated package waiting for our turn in a rolling update
+    pub pending_update: Option<Package>,
+    /// Watches the health of the service after we update it, so we can roll back
+    pub update_watch: Option<UpdateWatch>,
+    /// Releases we have told the updater to skip, because we or another member of the service
+    /// group rolled them back
+    pub skipped_updates: Vec<PackageIdent>,
     /// The service supervisor
     pub supervisor: Arc<RwLock<Supervisor>>,
+    /// The results of the most recent health checks
+    pub health_check_history: Arc<RwLock<health_check::History>>,
+    /// The signals caught by the Supervisor, passed on to us
+    pub signals: Receiver<signals::Signal>,
+    /// Set by the sidecar to ask us to step down as the leader
+    pub step_down: Arc<RwLock<bool>>,
+    /// True while we hand leadership over to another member
+    pub stepping_down: bool,
+    /// The term of the election that chose the leader we last saw
+    pub election_term: Option<u32>,
     pub return_state: Option<State>,
 }
 
 impl Worker {
-    /// Create a new worker
-    ///
-    /// Automatically sets the backend to Etcd.
-    pub fn new(package: Package, topology: String) -> Result<Worker> {
+    /// Create a new worker for a service, with the lists the gossip server keeps for it, and
+    /// register the service with the sidecar.
+    pub fn new(package: Package,
+               spec: ServiceSpec,
+               gossip_server: Arc<gossip::server::Server>,
+               lists: ServiceLists,
+               sidecar_services: &sidecar::Services,
+               signals: Receiver<signals::Signal>)
+               -> Result<Worker> {
         let mut pkg_updater = None;
         let package_name = package.name.clone();
 
@@ -132,89 +217,116 @@ impl Worker {
         outputln!("Child process will run as user={}, group={}",
                   &svc_user,
                   &svc_group);
-        let runtime_config = RuntimeConfig::new(svc_user, svc_group);
+        // Limits we were started with override those in the package's plan
+        let limits = spec.limits.or(&try!(Limits::from_package(&package.pkg_install)));
+        let cgroup = if limits.needs_cgroup() {
+            Some(try!(Cgroup::create(&spec.service_group(), &limits)))
+        } else {
+            None
+        };
+        let stop = try!(StopPolicy::for_package(&package.pkg_install,
+                                                spec.stop_signal.clone(),
+                                                spec.stop_timeout));
+        let logs = ServiceLogs::new(spec.service_group(),
+                                    gossip_server.peer.member_id.to_string(),
+                                    spec.logs.clone(),
+                                    package.pkg_install.svc_var_path());
+        let runtime_config = RuntimeConfig::new(svc_user, svc_group, cgroup, stop, logs);
 
-        let package_exposes = package.exposes().clone();
-        let package_port = package_exposes.first().map(|e| e.clone());
         let package_ident = package.ident().clone();
         let pkg_lock = Arc::new(RwLock::new(package));
-        let pkg_lock_1 = pkg_lock.clone();
-
 
         match gconfig().update_strategy() {
             UpdateStrategy::None => {}
             _ => {
                 let pkg_lock_2 = pkg_lock.clone();
-                pkg_updater = Some(package::PackageUpdater::start(gconfig().url(), pkg_lock_2));
+                let version = spec.package.version.clone();
+                let depot_url = gconfig().depot_url();
+                pkg_updater = Some(package::PackageUpdater::start(&depot_url, pkg_lock_2, version));
             }
         }
 
-        let gossip_server = gossip::server::Server::new(String::from(gconfig().gossip_listen_ip()),
-                                                        gconfig().gossip_listen_port(),
-                                                        gconfig().gossip_permanent(),
-                                                        gconfig().ring().clone(),
-                                                        package_name.clone(),
-                                                        gconfig().group().to_string(),
-                                                        gconfig().organization().clone(),
-                                                        Some(package_exposes),
-                                                        package_port);
-
-        try!(gossip_server.start_inbound());
-        try!(gossip_server.initial_peers(gconfig().gossip_peer()));
-        gossip_server.start_outbound();
-        gossip_server.start_failure_detector();
-        census::start_health_adjuster(gossip_server.census_list.clone(),
+        census::start_health_adjuster(lists.census_list.clone(),
                                       gossip_server.member_list.clone());
 
         // Setup the Service Configuration
         let service_config = {
-            let cl = gossip_server.census_list.read().unwrap();
+            let cl = lists.census_list.read().unwrap();
             let pkg = pkg_lock.read().unwrap();
-            let sc = try!(ServiceConfig::new(&pkg, &cl, gconfig().bind()));
+            let sc = try!(ServiceConfig::new(&pkg, &cl, spec.bind.clone()));
             sc
         };
         let service_config_lock = Arc::new(RwLock::new(service_config));
-        let service_config_lock_1 = service_config_lock.clone();
-
-        let supervisor = Arc::new(RwLock::new(Supervisor::new(package_ident, runtime_config)));
-
-        let sidecar_ml = gossip_server.member_list.clone();
-        let sidecar_rl = gossip_server.rumor_list.clone();
-        let sidecar_cl = gossip_server.census_list.clone();
-        let sidecar_gfl = gossip_server.gossip_file_list.clone();
-        let sidecar_detector = gossip_server.detector.clone();
-        let sidecar_el = gossip_server.election_list.clone();
-        let sidecar_sup = supervisor.clone();
-        let sidecar_listen = try!(SocketAddrV4::from_str(&format!("{}:{}",
-                                                                  &gconfig().http_listen_ip(),
-                                                                  gconfig().http_listen_port())));
+
+        let hooks = ProcessHooks::new(pkg_lock.clone(), service_config_lock.clone());
+        let supervisor = Arc::new(RwLock::new(Supervisor::new(package_ident,
+                                                              runtime_config,
+                                                              spec.restart.clone(),
+                                                              hooks)));
+
+        let health_check_history = Arc::new(RwLock::new(health_check::History::new()));
+        let step_down = Arc::new(RwLock::new(false));
+        if gconfig().health_check_interval() > 0 {
+            health_check::start_health_checker(gconfig().health_check_interval(),
+                                               pkg_lock.clone(),
+                                               service_config_lock.clone(),
+                                               supervisor.clone(),
+                                               health_check_history.clone(),
+                                               lists.census_list.clone());
+        }
+
+        {
+            let mut services = sidecar_services.write().unwrap();
+            services.push(sidecar::SidecarService {
+                service_group: spec.service_group(),
+                package: pkg_lock.clone(),
+                config: service_config_lock.clone(),
+                supervisor: supervisor.clone(),
+                census_list: lists.census_list.clone(),
+                election_list: lists.election_list.clone(),
+                gossip_file_list: lists.gossip_file_list.clone(),
+                health_check_history: health_check_history.clone(),
+                step_down: step_down.clone(),
+            });
+        }
+
         Ok(Worker {
             package: pkg_lock,
             package_name: package_name,
-            topology: topology,
-            census_list: gossip_server.census_list.clone(),
+            topology: spec.topology.to_string(),
+            spec: spec,
+            census_list: lists.census_list,
             rumor_list: gossip_server.rumor_list.clone(),
-            election_list: gossip_server.election_list.clone(),
-            gossip_file_list: gossip_server.gossip_file_list.clone(),
+            election_list: lists.election_list,
+            gossip_file_list: lists.gossip_file_list,
             member_list: gossip_server.member_list.clone(),
             gossip_server: gossip_server,
             service_config: service_config_lock,
-            sidecar_actor: sidecar::Sidecar::start(sidecar_listen,
-                                                   pkg_lock_1,
-                                                   service_config_lock_1,
-                                                   sidecar_ml,
-                                                   sidecar_rl,
-                                                   sidecar_cl,
-                                                   sidecar_detector,
-                                                   sidecar_el,
-                                                   sidecar_sup,
-                                                   sidecar_gfl),
             supervisor: supervisor,
+            health_check_history: health_check_history,
+            signals: signals,
+            step_down: step_down,
+            stepping_down: false,
+            election_term: None,
             pkg_updater: pkg_updater,
+            pending_update: None,
+            update_watch: None,
+            skipped_updates: Vec::new(),
             return_state: None,
         })
     }
 
+    /// The identifier of the package we are running
+    pub fn package_ident(&self) -> PackageIdent {
+        self.package.read().unwrap().ident().clone()
+    }
+
+    /// Returns true if the sidecar has asked us to step down as the leader, and clears the request.
+    pub fn take_step_down(&self) -> bool {
+        let mut step_down = self.step_down.write().unwrap();
+        mem::replace(step_down.deref_mut(), false)
+    }
+
     /// update a package, but does NOT restart the service
     pub fn update_package(&self, updated: Package) -> Result<()> {
         let service_config = self.service_config.read().unwrap();
@@ -228,10 +340,178 @@ impl Worker {
     }
 }
 
+/// Runs each of our services with its topology, in its own thread. The services share one gossip
+/// server and one sidecar.
+///
+/// Every signal we catch is passed on to each service; on SIGINT or SIGTERM they each take their
+/// process down, and we return once all of them have stopped. On SIGTERM, we first announce to the
+/// gossip ring that we are departing.
+///
+/// # Failures
+///
+/// * Two services are in the same service group
+/// * We cannot start the gossip server, or the sidecar address is invalid
+/// * We fail to create the worker for a service
+/// * A service stopped because of an error
+pub fn run(services: Vec<(Package, ServiceSpec)>) -> Result<()> {
+    let mut service_groups: Vec<String> = Vec::new();
+    for &(_, ref spec) in services.iter() {
+        let service_group = spec.service_group();
+        if service_groups.contains(&service_group) {
+            let msg = format!("{} is started more than once", service_group);
+            return Err(sup_error!(Error::InvalidServiceSpec(msg)));
+        }
+        service_groups.push(service_group);
+    }
+
+    let gossip_server = Arc::new(try!(gossip::server::Server::new(String::from(gconfig()
+                                                                      .gossip_listen_ip()),
+                                                                  gconfig().gossip_listen_port(),
+                                                                  gconfig().gossip_permanent(),
+                                                                  gconfig().ring().clone(),
+                                                                  gconfig()
+                                                                      .gossip_timing()
+                                                                      .clone(),
+                                                                  gconfig().gossip_transport())));
+    let mut registered = Vec::new();
+    for (package, spec) in services.into_iter() {
+        let package_exposes = package.exposes().clone();
+        let package_port = package_exposes.first().map(|e| e.clone());
+        let lists = gossip_server.add_service(package.name.clone(),
+                                              spec.group.clone(),
+                                              gconfig().organization().clone(),
+                                              Some(package_exposes),
+                                              package_port);
+        if !spec.trusted_users.is_empty() {
+            outputln!("Only applying config and files for {} signed by {}",
+                      spec.service_group(),
+                      spec.trusted_users.join(", "));
+            lists.gossip_file_list.write().unwrap().set_trusted_users(spec.trusted_users.clone());
+        }
+        registered.push((package, spec, lists));
+    }
+
+    try!(gossip_server.start_inbound());
+    try!(gossip_server.initial_peers(gconfig().gossip_peer()));
+    gossip_server.start_outbound();
+    gossip_server.start_failure_detector();
+
+    let sidecar_services: sidecar::Services = Arc::new(RwLock::new(Vec::new()));
+    let sidecar_listen = try!(SocketAddrV4::from_str(&format!("{}:{}",
+                                                              &gconfig().http_listen_ip(),
+                                                              gconfig().http_listen_port())));
+    let _sidecar = sidecar::Sidecar::start(sidecar_listen,
+                                           gossip_server.member_list.clone(),
+                                           gossip_server.rumor_list.clone(),
+                                           gossip_server.detector.clone(),
+                                           sidecar_services.clone());
+
+    let mut workers = Vec::new();
+    let mut subscribers = Vec::new();
+    for (package, spec, lists) in registered.into_iter() {
+        let (tx, rx) = mpsc::channel();
+        subscribers.push(tx);
+        workers.push(try!(Worker::new(package,
+                                      spec,
+                                      gossip_server.clone(),
+                                      lists,
+                                      &sidecar_services,
+                                      rx)));
+    }
+
+    let (done_tx, done_rx) = mpsc::channel();
+    for worker in workers.into_iter() {
+        let service_group = worker.spec.service_group();
+        let mut done = ServiceDone {
+            service_group: service_group.clone(),
+            ok: false,
+            tx: done_tx.clone(),
+        };
+        try!(thread::Builder::new().name(format!("service-{}", service_group)).spawn(move || {
+            let result = match worker.spec.topology {
+                Topology::Standalone => standalone::run(worker),
+                Topology::Leader => leader::run(worker),
+                Topology::Initializer => initializer::run(worker),
+            };
+            match result {
+                Ok(()) => done.ok = true,
+                Err(e) => outputln!("{} stopped: {}", done.service_group, e),
+            }
+        }));
+    }
+    dispatch_signals(&gossip_server, subscribers, done_rx)
+}
+
+/// Tells the main thread that a service's thread has ended, and whether it ended well; a panic
+/// counts as ending badly.
+struct ServiceDone {
+    service_group: String,
+    ok: bool,
+    tx: Sender<(String, bool)>,
+}
+
+impl Drop for ServiceDone {
+    fn drop(&mut self) {
+        let ok = self.ok && !thread::panicking();
+        let _ = self.tx.send((self.service_group.clone(), ok));
+    }
+}
+
+/// Passes every signal we catch on to each of our services, until all of them have stopped. A
+/// SIGTERM means we are shutting down for good, so once our services have it, we tell the ring we
+/// are departing while they stop; peers drop us from their census straight away, rather than
+/// waiting to find out we are dead.
+///
+/// # Failures
+///
+/// * A service stopped because of an error
+fn dispatch_signals(gossip_server: &gossip::server::Server,
+                    subscribers: Vec<Sender<signals::Signal>>,
+                    done: Receiver<(String, bool)>)
+                    -> Result<()> {
+    let handler = wonder::actor::Builder::new(SignalNotifier)
+        .name("signal-handler".to_string())
+        .start(())
+        .unwrap();
+    let mut running = subscribers.len();
+    let mut failed = Vec::new();
+    while running > 0 {
+        match handler.receiver.try_recv() {
+            Ok(wonder::actor::Message::Cast(signals::Message::Signal(sig))) => {
+                debug!("SIG = {:?}", sig);
+                for subscriber in subscribers.iter() {
+                    // A service that has stopped is no longer listening, which is fine
+                    let _ = subscriber.send(sig.clone());
+                }
+                if let signals::Signal::SIGTERM = sig {
+                    gossip_server.depart();
+                }
+            }
+            Ok(_) => {}
+            Err(TryRecvError::Empty) => {}
+            Err(TryRecvError::Disconnected) => {
+                panic!("signal handler crashed!");
+            }
+        }
+        while let Ok((service_group, ok)) = done.try_recv() {
+            running = running - 1;
+            if !ok {
+                failed.push(service_group);
+            }
+        }
+        thread::sleep(Duration::from_millis(MINIMUM_LOOP_TIME_MS as u64));
+    }
+    if failed.is_empty() {
+        Ok(())
+    } else {
+        Err(sup_error!(Error::ServiceFailed(failed.join(", "))))
+    }
+}
+
 /// The main loop of a topology.
 ///
 /// 1. Loops forever
-/// 1. Checks if we have caught a signal; if so, acts on the signal. (May exit entirely)
+/// 1. Checks if the Supervisor has caught a signal; if so, acts on the signal. (May exit)
 /// 1. Checks the current `state` of our [StateMachine](../state_machine)
 /// 1. If it is running, we run a non-blocking `waitpid`, and inspect why the child died;
 ///    depending on the circumstances, we may exit with an error here
@@ -254,15 +534,10 @@ fn run_internal(sm: &mut StateMachine<State, Worker, SupError>,
         try!(package.create_svc_path());
         try!(package.copy_run(&service_config));
     }
-    let handler = wonder::actor::Builder::new(SignalNotifier)
-        .name("signal-handler".to_string())
-        .start(())
-        .unwrap();
     loop {
         let start_time = SteadyTime::now();
-        match handler.receiver.try_recv() {
-            Ok(wonder::actor::Message::Cast(signals::Message::Signal(sig))) => {
-                debug!("SIG = {:?}", sig);
+        match worker.signals.try_recv() {
+            Ok(sig) => {
                 match sig {
                     signals::Signal::SIGINT | signals::Signal::SIGTERM => {
                         let mut supervisor = worker.supervisor.write().unwrap();
@@ -276,7 +551,6 @@ fn run_internal(sm: &mut StateMachine<State, Worker, SupError>,
                     }
                 };
             }
-            Ok(_) => {}
             Err(TryRecvError::Empty) => {}
             Err(TryRecvError::Disconnected) => {
                 panic!("signal handler crashed!");
@@ -284,8 +558,14 @@ fn run_internal(sm: &mut StateMachine<State, Worker, SupError>,
         }
 
         {
-            let mut supervisor = worker.supervisor.write().unwrap();
-            try!(supervisor.check_process());
+            let crash_loop = {
+                let mut supervisor = worker.supervisor.write().unwrap();
+                try!(supervisor.check_process());
+                supervisor.is_crash_looping()
+            };
+            // Let the rest of the service group know when our process is crash looping
+            let mut cl = worker.census_list.write().unwrap();
+            cl.me_mut().crash_loop(crash_loop);
         }
 
         let mut restart_process = false;
@@ -310,12 +590,11 @@ fn run_internal(sm: &mut StateMachine<State, Worker, SupError>,
                     let mut service_config = worker.service_config.write().unwrap();
                     let cl = worker.census_list.read().unwrap();
                     service_config.svc(&cl);
-                    service_config.bind(gconfig().bind(), &cl);
+                    service_config.bind(worker.spec.bind.clone(), &cl);
                 }
                 if write_rumor {
                     debug!("Writing our census rumor: {:#?}", me_clone);
-                    let mut rl = worker.rumor_list.write().unwrap();
-                    rl.add_rumor(Rumor::census_entry(me_clone));
+                    worker.gossip_server.publish_census_entry(me_clone);
                 }
                 let mut cl = worker.census_list.write().unwrap();
                 cl.written();
@@ -343,6 +622,31 @@ fn run_internal(sm: &mut StateMachine<State, Worker, SupError>,
             }
         }
 
+        {
+            // When an election finishes in a new term, render the term into the configuration,
+            // and tell the service that the leader changed, so it can fence off the old one.
+            let finished_term = {
+                let el = worker.election_list.read().unwrap();
+                el.finished_term()
+            };
+            if let Some(term) = finished_term {
+                if worker.election_term != Some(term) {
+                    let mut service_config = worker.service_config.write().unwrap();
+                    service_config.election_term(term);
+                    if let Some(old_term) = worker.election_term {
+                        outputln!("The leader changed in election term {} (was {})",
+                                  term,
+                                  old_term);
+                        let package = worker.package.read().unwrap();
+                        if let Err(e) = package.leader_changed(&service_config, old_term, term) {
+                            outputln!("The leader_changed hook failed: {}", e);
+                        }
+                    }
+                    worker.election_term = Some(term);
+                }
+            }
+        }
+
         {
             let in_event = {
                 let census_list = worker.census_list.read().unwrap();
@@ -393,11 +697,22 @@ fn run_internal(sm: &mut StateMachine<State, Worker, SupError>,
                 Ok(wonder::actor::Message::Cast(package::UpdaterMessage::Update(package))) => {
                     debug!("Main loop received package update notification: {:?}",
                            &package);
-                    try!(worker.update_package(package));
-                    try!(package::PackageUpdater::run(&updater));
-                    // force the package to restart
-                    outputln!("Restarting because the package was updated");
-                    restart_process = true;
+                    if gconfig().update_strategy() == UpdateStrategy::Rolling {
+                        outputln!("Waiting for our turn in the rolling update to {}",
+                                  package.ident());
+                        let mut cl = worker.census_list.write().unwrap();
+                        cl.me_mut().update_pending(true);
+                        worker.pending_update = Some(package);
+                    } else {
+                        let previous = worker.package_ident();
+                        let watch = UpdateWatch::new(previous, package.ident().clone());
+                        try!(worker.update_package(package));
+                        worker.update_watch = Some(watch);
+                        try!(package::PackageUpdater::run(&updater));
+                        // force the package to restart
+                        outputln!("Restarting because the package was updated");
+                        restart_process = true;
+                    }
                 }
                 Ok(_) => {}
                 Err(TryRecvError::Empty) => {}
@@ -407,15 +722,52 @@ fn run_internal(sm: &mut StateMachine<State, Worker, SupError>,
             }
         }
 
+        try!(skip_rolled_back_updates(worker));
+
+        if worker.pending_update.is_some() {
+            let my_turn = {
+                let cl = worker.census_list.read().unwrap();
+                cl.local_census().rolling_update_turn()
+            };
+            if my_turn {
+                if let Some(package) = worker.pending_update.take() {
+                    {
+                        let mut cl = worker.census_list.write().unwrap();
+                        let me = cl.me_mut();
+                        me.update_pending(false);
+                        me.updating(true);
+                    }
+                    let previous = worker.package_ident();
+                    let watch = UpdateWatch::new(previous, package.ident().clone());
+                    try!(worker.update_package(package));
+                    worker.update_watch = Some(watch);
+                    outputln!("Restarting because it is our turn in the rolling update");
+                    restart_process = true;
+                }
+            }
+        }
+
+        if worker.update_watch.is_some() {
+            if try!(watch_update(worker)) {
+                restart_process = true;
+            }
+        }
+
         {
             let mut supervisor = worker.supervisor.write().unwrap();
-            // If our target is that the process is up
-            if supervisor.is_up() {
-                // And no process is running
-                if supervisor.pid.is_none() {
-                    // Start a new one
-                    try!(supervisor.start());
-                } else {
+            // If someone asked for the process to be taken down or restarted
+            if let Some(control) = supervisor.take_request() {
+                match control {
+                    Control::Down => try!(supervisor.down()),
+                    Control::Restart => try!(supervisor.restart()),
+                }
+            } else if supervisor.start_due() {
+                // If no process is running, and the restart policy says it is time for a new one,
+                // start a new one
+                try!(supervisor.start());
+            } else if supervisor.is_up() {
+                // If our target is that the process is up, and one is running
+                if supervisor.pid.is_some() {
                     // If we were supposed to restart
                     if restart_process {
                         // And we have ever started before...
@@ -441,3 +793,144 @@ fn run_internal(sm: &mut StateMachine<State, Worker, SupError>,
     }
     Ok(())
 }
+
+/// Tell the updater to skip the releases other members of the service group rolled back. An
+/// update to one of them that is waiting for our turn in a rolling update is dropped.
+fn skip_rolled_back_updates(worker: &mut Worker) -> Result<()> {
+    let rolled_back = {
+        let cl = worker.census_list.read().unwrap();
+        cl.local_census().rolled_back_updates()
+    };
+    for ident in rolled_back {
+        if worker.skipped_updates.contains(&ident) {
+            continue;
+        }
+        outputln!("Skipping {}; it was rolled back in the service group", &ident);
+        if let Some(ref updater) = worker.pkg_updater {
+            try!(package::PackageUpdater::skip(&updater, ident.clone()));
+        }
+        let pending = match worker.pending_update {
+            Some(ref package) => package.ident() == &ident,
+            None => false,
+        };
+        if pending {
+            worker.pending_update = None;
+            {
+                let mut cl = worker.census_list.write().unwrap();
+                cl.me_mut().update_pending(false);
+            }
+            if let Some(ref updater) = worker.pkg_updater {
+                try!(package::PackageUpdater::run(&updater));
+            }
+        }
+        worker.skipped_updates.push(ident);
+    }
+    Ok(())
+}
+
+/// Check on the health of the service after a package update, once the `UpdateWatch` says it is
+/// time to.
+///
+/// * If the health check is critical inside the grace window, or at any time during our turn in
+///   a rolling update, we roll back to the package we were running before. The updater never
+///   updates to the bad release again, and the census tells the rest of the service group to
+///   skip it too
+/// * A service that is still not up when the grace window ends, during our turn in a rolling
+///   update, counts as critical, so it does not hold up the rest of the service group
+/// * Once the service is healthy, we finish our turn in a rolling update, so the next member of
+///   the service group can take theirs
+///
+/// Returns true if the service needs to be restarted because we rolled back.
+fn watch_update(worker: &mut Worker) -> Result<bool> {
+    let now = SteadyTime::now();
+    let (previous, updated, grace_until) = match worker.update_watch {
+        Some(ref watch) if now >= watch.next_check => {
+            (watch.previous.clone(), watch.updated.clone(), watch.grace_until)
+        }
+        _ => return Ok(false),
+    };
+    let updating = {
+        let cl = worker.census_list.read().unwrap();
+        cl.me().is_updating()
+    };
+    let health = match update_health(worker) {
+        None if updating && now >= grace_until => {
+            outputln!("The service is still not up after updating to {}", &updated);
+            Some(health_check::Status::Critical)
+        }
+        health => health,
+    };
+    match health {
+        Some(health_check::Status::Critical) if now < grace_until || updating => {
+            outputln!("Health check is critical after updating to {}; rolling back to {}",
+                      &updated,
+                      &previous);
+            let package = try!(Package::load(&previous, None));
+            try!(worker.update_package(package));
+            worker.update_watch = None;
+            {
+                let mut cl = worker.census_list.write().unwrap();
+                cl.me_mut().rolled_back(&updated);
+            }
+            if let Some(ref updater) = worker.pkg_updater {
+                try!(package::PackageUpdater::skip(&updater, updated.clone()));
+            }
+            worker.skipped_updates.push(updated);
+            if updating {
+                try!(finish_rolling_update(worker));
+            }
+            return Ok(true);
+        }
+        Some(health_check::Status::Critical) | None => {}
+        Some(_) => {
+            if updating {
+                outputln!("Rolling update finished; the service is healthy");
+                try!(finish_rolling_update(worker));
+            }
+        }
+    }
+    let still_updating = {
+        let cl = worker.census_list.read().unwrap();
+        cl.me().is_updating()
+    };
+    if now >= grace_until && !still_updating {
+        worker.update_watch = None;
+    } else if let Some(ref mut watch) = worker.update_watch {
+        watch.next_check = now + time::Duration::seconds(UPDATE_CHECK_SECS);
+    }
+    Ok(false)
+}
+
+/// Our turn in a rolling update is over; let the rest of the service group know, and start
+/// watching for updates again.
+fn finish_rolling_update(worker: &mut Worker) -> Result<()> {
+    {
+        let mut cl = worker.census_list.write().unwrap();
+        cl.me_mut().updating(false);
+    }
+    if let Some(ref updater) = worker.pkg_updater {
+        try!(package::PackageUpdater::run(&updater));
+    }
+    Ok(())
+}
+
+/// The status of the health check for the service, or `None` if the service is not up yet. A
+/// health check that fails to run counts as critical.
+fn update_health(worker: &Worker) -> Option<health_check::Status> {
+    {
+        let supervisor = worker.supervisor.read().unwrap();
+        if !supervisor.is_up() || supervisor.pid.is_none() {
+            return None;
+        }
+    }
+    match health_check::run(&worker.package,
+                            &worker.service_config,
+                            &worker.supervisor,
+                            &worker.health_check_history) {
+        Ok(CheckResult { status, .. }) => Some(status),
+        Err(e) => {
+            outputln!("Health check failed to run after update: {}", e);
+            Some(health_check::Status::Critical)
+        }
+    }
+}
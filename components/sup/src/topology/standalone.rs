@@ -23,16 +23,14 @@
 //! * **Running**: The state for the 'normal' operating condition.
 
 use error::{Result, SupError};
-use package::Package;
 use state_machine::StateMachine;
 use topology::{self, State, Worker};
 
 /// Sets up the topology and calls run_internal.
 ///
-/// Add's the state transitions to the state machine, sets up the signal handlers, and runs the
-/// `topology::run_internal` function.
-pub fn run(package: Package) -> Result<()> {
-    let mut worker = try!(Worker::new(package, String::from("standalone")));
+/// Add's the state transitions to the state machine, and runs the `topology::run_internal`
+/// function.
+pub fn run(mut worker: Worker) -> Result<()> {
     let mut sm: StateMachine<State, Worker, SupError> = StateMachine::new(State::Initializing);
     sm.add_dispatch(State::Initializing, state_initializing);
     sm.add_dispatch(State::Starting, state_starting);
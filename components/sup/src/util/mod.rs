@@ -36,6 +36,20 @@ pub fn stop_time(duration: i64) -> time::Timespec {
     stop_time
 }
 
+/// Parse a size in bytes, optionally with a `K`, `M` or `G` suffix, such as `512M`.
+pub fn parse_bytes(size: &str) -> Option<u64> {
+    let (number, multiplier) = match size.chars().last() {
+        Some('K') | Some('k') => (&size[..size.len() - 1], 1 << 10),
+        Some('M') | Some('m') => (&size[..size.len() - 1], 1 << 20),
+        Some('G') | Some('g') => (&size[..size.len() - 1], 1 << 30),
+        _ => (size, 1),
+    };
+    match number.parse::<u64>() {
+        Ok(number) => number.checked_mul(multiplier),
+        Err(_) => None,
+    }
+}
+
 /// This function takes a string as an option.
 /// - If you haven't sent a string, then we'll return (default_ip, default_port).
 /// - If you sent Some(some_ip_without_port), we'll return (your IP, default_port).
@@ -67,7 +81,16 @@ pub fn parse_ip_port_with_defaults(s: Option<&str>,
 
 #[cfg(test)]
 mod tests {
-    use super::parse_ip_port_with_defaults;
+    use super::{parse_bytes, parse_ip_port_with_defaults};
+
+    #[test]
+    fn parse_bytes_test() {
+        assert_eq!(parse_bytes("512M"), Some(512 * 1024 * 1024));
+        assert_eq!(parse_bytes("2g"), Some(2 * 1024 * 1024 * 1024));
+        assert_eq!(parse_bytes("65536"), Some(65536));
+        assert_eq!(parse_bytes("lots"), None);
+        assert_eq!(parse_bytes("M"), None);
+    }
 
     #[test]
     fn parse_ip_port_with_defaults_test() {
@@ -18,6 +18,7 @@
 //! sent to the running process and notifies the receiver channel of a caught
 //! `signals::Signal`.
 
+use std::str::FromStr;
 use std::sync::{Once, ONCE_INIT};
 use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering, ATOMIC_USIZE_INIT, ATOMIC_BOOL_INIT};
 
@@ -55,7 +56,7 @@ pub enum Message {
 }
 
 /// `i32` representation of each Unix Signal of interest.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Signal {
     /// terminate process - terminal line hangup
     SIGHUP = 1,
@@ -75,6 +76,27 @@ pub enum Signal {
     SIGUSR2 = 31,
 }
 
+impl FromStr for Signal {
+    type Err = SupError;
+
+    /// Parse a signal name, with or without its `SIG` prefix, such as `HUP` or `SIGHUP`.
+    fn from_str(s: &str) -> Result<Signal> {
+        let name = s.to_uppercase();
+        let name = if name.starts_with("SIG") { &name[3..] } else { &name[..] };
+        match name {
+            "HUP" => Ok(Signal::SIGHUP),
+            "INT" => Ok(Signal::SIGINT),
+            "QUIT" => Ok(Signal::SIGQUIT),
+            "KILL" => Ok(Signal::SIGKILL),
+            "ALRM" => Ok(Signal::SIGALRM),
+            "TERM" => Ok(Signal::SIGTERM),
+            "USR1" => Ok(Signal::SIGUSR1),
+            "USR2" => Ok(Signal::SIGUSR2),
+            _ => Err(sup_error!(Error::UnknownSignal(s.to_string()))),
+        }
+    }
+}
+
 /// Thread worker that traps UNIX signals and sends a `Signal` down the receiver
 /// channel representing the trapped UNIX signal.
 pub struct SignalNotifier;
@@ -196,3 +218,17 @@ pub fn send_signal_to_pid(pid: u32, sig: Signal) -> Result<()> {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use super::Signal;
+
+    #[test]
+    fn signal_from_str() {
+        assert_eq!(Signal::from_str("HUP").unwrap() as u32, Signal::SIGHUP as u32);
+        assert_eq!(Signal::from_str("sigusr1").unwrap() as u32, Signal::SIGUSR1 as u32);
+        assert!(Signal::from_str("WINCH").is_err());
+    }
+}